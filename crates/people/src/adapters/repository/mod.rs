@@ -2,3 +2,4 @@ pub mod person;
 pub mod person_document_type;
 pub mod person_gender;
 pub mod types;
+pub mod user_authority;
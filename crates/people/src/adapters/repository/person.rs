@@ -5,14 +5,16 @@ use ids_std_domain::pagination::{Page, PaginationQuery};
 use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
 use ids_std_sea::convert::into::IntoDomain;
 use ids_std_sea::paginator;
+use lumx_sea_orm::sea_orm::sqlx::types::chrono::Utc;
 use lumx_sea_orm::sea_orm::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, DbConn, EntityTrait, PaginatorTrait, QueryFilter,
-    QueryOrder, QuerySelect, RelationTrait,
+    ActiveModelTrait, ActiveValue, ColumnTrait, DbConn, EntityTrait, Order, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, RelationTrait,
 };
 use portal_schema::{person, person_document_type, person_gender};
 
 use crate::domain::entities::person::PersonEnt;
 use crate::domain::ports::spi::person::PersonRepository;
+use crate::domain::queries::person::{PersonQuery, PersonSortField};
 use crate::domain::selectors::person::{PersonDetailsSelector, PersonPageSelector, PersonSelector};
 
 use super::types::person::PersonAndGenderAndDocument;
@@ -86,6 +88,7 @@ impl PersonRepository for PersonSeaRepository {
         id: i32,
     ) -> Result<Option<PersonDetailsSelector>, SelectRepoFailure> {
         let maybe_model = person::Entity::find_by_id(id)
+            .filter(person::Column::DeletedAt.is_null())
             .column_as(person_gender::Column::Name, "gender_name")
             .column_as(person_document_type::Column::Name, "document_type_name")
             .join(
@@ -111,6 +114,7 @@ impl PersonRepository for PersonSeaRepository {
     ) -> Result<Option<PersonSelector>, SelectRepoFailure> {
         let maybe_model = person::Entity::find()
             .filter(person::Column::Signature.eq(signature))
+            .filter(person::Column::DeletedAt.is_null())
             .one(self.db.as_ref())
             .await
             .map_err(|err| err.into_domain())?
@@ -130,6 +134,7 @@ impl PersonRepository for PersonSeaRepository {
                     .eq(document_number)
                     .and(person::Column::DocumentTypeId.eq(document_type_id)),
             )
+            .filter(person::Column::DeletedAt.is_null())
             .one(self.db.as_ref())
             .await
             .map_err(|err| err.into_domain())?
@@ -151,6 +156,7 @@ impl PersonRepository for PersonSeaRepository {
                     .and(person::Column::DocumentTypeId.eq(document_type_id))
                     .and(person::Column::PersonId.is_not_in(vec![self_id])),
             )
+            .filter(person::Column::DeletedAt.is_null())
             .one(self.db.as_ref())
             .await
             .map_err(|err| err.into_domain())?;
@@ -161,8 +167,51 @@ impl PersonRepository for PersonSeaRepository {
     async fn find_all(
         &self,
         query: &PaginationQuery,
+        criteria: &PersonQuery,
     ) -> Result<Page<PersonPageSelector>, SelectRepoFailure> {
-        let paginator = person::Entity::find()
+        let mut select = person::Entity::find();
+        if !criteria.include_archived {
+            select = select.filter(person::Column::DeletedAt.is_null());
+        }
+        if let Some(first_name) = &criteria.first_name {
+            select = select.filter(person::Column::FirstName.contains(first_name));
+        }
+        if let Some(last_name) = &criteria.last_name {
+            select = select.filter(person::Column::LastName.contains(last_name));
+        }
+        if let Some(document_number) = &criteria.document_number {
+            select = select.filter(person::Column::DocumentNumber.contains(document_number));
+        }
+        if let Some(document_type_id) = criteria.document_type_id {
+            select = select.filter(person::Column::DocumentTypeId.eq(document_type_id));
+        }
+        if let Some(gender_id) = criteria.gender_id {
+            select = select.filter(person::Column::GenderId.eq(gender_id));
+        }
+
+        for sort in &criteria.sort {
+            let column = match sort.field {
+                PersonSortField::PersonId => person::Column::PersonId,
+                PersonSortField::FirstName => person::Column::FirstName,
+                PersonSortField::LastName => person::Column::LastName,
+                PersonSortField::DocumentNumber => person::Column::DocumentNumber,
+            };
+            let order = if sort.descending {
+                Order::Desc
+            } else {
+                Order::Asc
+            };
+            select = select.order_by(column, order);
+        }
+        if !criteria
+            .sort
+            .iter()
+            .any(|sort| sort.field == PersonSortField::PersonId)
+        {
+            select = select.order_by_desc(person::Column::PersonId);
+        }
+
+        let paginator = select
             .column_as(person_gender::Column::Name, "gender_name")
             .column_as(person_document_type::Column::Name, "document_type_name")
             .join(
@@ -173,10 +222,60 @@ impl PersonRepository for PersonSeaRepository {
                 lumx_sea_orm::sea_orm::JoinType::LeftJoin,
                 person::Relation::PersonGender.def(),
             )
-            .order_by_desc(person::Column::PersonId)
             .into_model::<PersonAndGenderAndDocument>()
             .paginate(self.db.as_ref(), query.page_size);
 
         paginator::fetch_page(&paginator, query, |model| PersonPageSelector::from(model)).await
     }
+
+    async fn count_by_gender(&self, gender_id: i32) -> Result<u64, SelectRepoFailure> {
+        person::Entity::find()
+            .filter(person::Column::GenderId.eq(gender_id))
+            .count(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn count_by_document_type(
+        &self,
+        document_type_id: i32,
+    ) -> Result<u64, SelectRepoFailure> {
+        person::Entity::find()
+            .filter(person::Column::DocumentTypeId.eq(document_type_id))
+            .count(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn archive(&self, person_id: i32) -> Result<(), SaveRepoFailure> {
+        let result = person::Entity::find_by_id(person_id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        let mut person_to_archive: person::ActiveModel = result.unwrap().into();
+        person_to_archive.deleted_at = ActiveValue::Set(Some(Utc::now().naive_utc()));
+
+        person_to_archive
+            .save(self.db.as_ref())
+            .await
+            .map(|_| {})
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn restore(&self, person_id: i32) -> Result<(), SaveRepoFailure> {
+        let result = person::Entity::find_by_id(person_id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        let mut person_to_restore: person::ActiveModel = result.unwrap().into();
+        person_to_restore.deleted_at = ActiveValue::Set(None);
+
+        person_to_restore
+            .save(self.db.as_ref())
+            .await
+            .map(|_| {})
+            .map_err(|err| err.into_domain())
+    }
 }
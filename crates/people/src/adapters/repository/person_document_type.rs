@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use crate::domain::{
+    changes::person_document_type::{AddPersonDocumentType, UpdatePersonDocumentType},
     ports::spi::person_document_type::PersonDocumentTypeRepository,
     queries::person_document_type::PersonDocumentTypeQuery,
     selectors::person_document_type::{PersonDocumentTypePageSelector, PersonDocumentTypeSelector},
@@ -8,14 +9,14 @@ use crate::domain::{
 use async_trait::async_trait;
 use ids_std_domain::{
     pagination::{Page, PaginationQuery},
-    spi::failure::SelectRepoFailure,
+    spi::failure::{SaveRepoFailure, SelectRepoFailure},
 };
 use ids_std_sea::convert::into::IntoDomain;
 use ids_std_sea::paginator;
 use lumx_sea_orm::sea_orm::PaginatorTrait;
 use lumx_sea_orm::sea_orm::QueryFilter;
 use lumx_sea_orm::sea_orm::QueryOrder;
-use lumx_sea_orm::sea_orm::{ColumnTrait, DbConn, EntityTrait};
+use lumx_sea_orm::sea_orm::{ActiveModelTrait, ActiveValue, ColumnTrait, DbConn, EntityTrait};
 use portal_schema::{self, person_document_type};
 
 #[derive(Clone)]
@@ -41,14 +42,7 @@ impl PersonDocumentTypeRepository for PersonDocumentTypeSeaRepository {
             .one(self.db.as_ref())
             .await
             .map_err(|err| err.into_domain())?
-            .and_then(|model| {
-                Some(PersonDocumentTypeSelector {
-                    person_document_type_id: model.person_document_type_id,
-                    name: model.name,
-                    summary: model.summary,
-                    signature: model.signature,
-                })
-            });
+            .map(PersonDocumentTypeSelector::from);
 
         Ok(maybe_model)
     }
@@ -88,4 +82,76 @@ impl PersonDocumentTypeRepository for PersonDocumentTypeSeaRepository {
         })
         .await
     }
+
+    async fn find_by_signature(
+        &self,
+        signature: &str,
+    ) -> Result<Option<PersonDocumentTypeSelector>, SelectRepoFailure> {
+        let model_opt = person_document_type::Entity::find()
+            .filter(person_document_type::Column::Signature.eq(signature))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(PersonDocumentTypeSelector::from);
+
+        Ok(model_opt)
+    }
+
+    async fn find_other_by_signature(
+        &self,
+        self_id: i32,
+        signature: &str,
+    ) -> Result<Option<PersonDocumentTypeSelector>, SelectRepoFailure> {
+        let model_opt = person_document_type::Entity::find()
+            .filter(person_document_type::Column::Signature.eq(signature))
+            .filter(person_document_type::Column::PersonDocumentTypeId.ne(self_id))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(PersonDocumentTypeSelector::from);
+
+        Ok(model_opt)
+    }
+
+    async fn save(&self, event: AddPersonDocumentType) -> Result<i32, SaveRepoFailure> {
+        let document_type_model = person_document_type::ActiveModel {
+            name: ActiveValue::Set(event.name.to_owned()),
+            summary: ActiveValue::Set(event.summary.to_owned()),
+            signature: ActiveValue::Set(event.signature.to_owned()),
+            ..Default::default()
+        };
+
+        document_type_model
+            .save(self.db.as_ref())
+            .await
+            .map(|model| model.person_document_type_id.unwrap())
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn update(&self, event: &UpdatePersonDocumentType) -> Result<(), SaveRepoFailure> {
+        let model_opt = person_document_type::Entity::find_by_id(event.person_document_type_id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        let mut document_type_to_modified: person_document_type::ActiveModel =
+            model_opt.unwrap().into();
+        document_type_to_modified.name = ActiveValue::Set(event.name.to_owned());
+        document_type_to_modified.summary = ActiveValue::Set(event.summary.to_owned());
+        document_type_to_modified.signature = ActiveValue::Set(event.signature.to_owned());
+
+        document_type_to_modified
+            .save(self.db.as_ref())
+            .await
+            .map(|_| {})
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn delete(&self, person_document_type_id: i32) -> Result<(), SaveRepoFailure> {
+        person_document_type::Entity::delete_by_id(person_document_type_id)
+            .exec(self.db.as_ref())
+            .await
+            .map(|_| {})
+            .map_err(|err| err.into_domain())
+    }
 }
@@ -40,14 +40,7 @@ impl PersonGenderRepository for PersonGenderSeaRepository {
             .one(self.db.as_ref())
             .await
             .map_err(|err| err.into_domain())?
-            .and_then(|model| {
-                Some(PersonGenderSelector {
-                    person_gender_id: model.person_gender_id,
-                    name: model.name,
-                    summary: model.summary,
-                    signature: model.signature,
-                })
-            });
+            .map(PersonGenderSelector::from);
 
         Ok(maybe_model)
     }
@@ -136,4 +129,12 @@ impl PersonGenderRepository for PersonGenderSeaRepository {
             .map(|_| {})
             .map_err(|err| err.into_domain())
     }
+
+    async fn delete(&self, person_gender_id: i32) -> Result<(), SaveRepoFailure> {
+        person_gender::Entity::delete_by_id(person_gender_id)
+            .exec(self.db.as_ref())
+            .await
+            .map(|_| {})
+            .map_err(|err| err.into_domain())
+    }
 }
@@ -1,4 +1,5 @@
 use lumx_sea_orm::sea_orm;
+use lumx_sea_orm::sea_orm::prelude::DateTime;
 use portal_schema::person;
 
 use crate::domain::selectors::person::{PersonDetailsSelector, PersonPageSelector, PersonSelector};
@@ -14,6 +15,7 @@ pub struct PersonAndGenderAndDocument {
     pub gender_id: i32,
     pub gender_name: String,
     pub signature: String,
+    pub deleted_at: Option<DateTime>,
 }
 
 impl From<PersonAndGenderAndDocument> for PersonDetailsSelector {
@@ -42,6 +44,7 @@ impl From<person::Model> for PersonSelector {
             document_type_id: value.document_type_id,
             gender_id: value.gender_id,
             signature: value.signature.to_string(),
+            archived: value.deleted_at.is_some(),
         }
     }
 }
@@ -58,6 +61,7 @@ impl From<&PersonAndGenderAndDocument> for PersonPageSelector {
             gender_id: value.gender_id,
             gender_name: value.gender_name.to_string(),
             signature: value.signature.to_string(),
+            archived: value.deleted_at.is_some(),
         }
     }
 }
@@ -0,0 +1,14 @@
+use portal_schema::person_document_type;
+
+use crate::domain::selectors::person_document_type::PersonDocumentTypeSelector;
+
+impl From<person_document_type::Model> for PersonDocumentTypeSelector {
+    fn from(value: person_document_type::Model) -> Self {
+        Self {
+            person_document_type_id: value.person_document_type_id,
+            name: value.name.to_string(),
+            summary: value.summary,
+            signature: value.signature.to_string(),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use ids_std_domain::spi::failure::SelectRepoFailure;
+use ids_std_sea::convert::into::IntoDomain;
+use lumx_sea_orm::sea_orm::{ColumnTrait, DbConn, EntityTrait, PaginatorTrait, QueryFilter};
+use portal_schema::user_authority;
+
+use crate::domain::{
+    ports::spi::user_authority::UserAuthorityRepository, valuables::user_authority::UserAuthority,
+};
+
+#[derive(Clone)]
+pub struct UserAuthoritySeaRepository {
+    db: Arc<DbConn>,
+}
+
+impl UserAuthoritySeaRepository {
+    pub fn new(db: &Arc<DbConn>) -> Self {
+        Self {
+            db: Arc::clone(&db),
+        }
+    }
+}
+
+#[async_trait]
+impl UserAuthorityRepository for UserAuthoritySeaRepository {
+    async fn has_authority(
+        &self,
+        user_id: i32,
+        authority: UserAuthority,
+    ) -> Result<bool, SelectRepoFailure> {
+        let granted = user_authority::Entity::find()
+            .filter(user_authority::Column::UserId.eq(user_id))
+            .filter(user_authority::Column::Authority.eq(authority.as_str()))
+            .count(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(granted > 0)
+    }
+}
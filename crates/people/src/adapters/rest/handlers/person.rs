@@ -1,9 +1,11 @@
 use crate::adapters::rest::types::person::{
-    CreatePersonParams, PersonDetailsQuery, PersonPageQuery, UpdatePersonParams,
+    CreatePersonParams, PersonCriteriaParams, PersonDetailsQuery, PersonPageQuery,
+    UpdatePersonParams,
 };
 use crate::domain::commands::person::{CreatePersonCommand, UpdatePersonCommand};
 use crate::domain::ports::api::person::{
-    CreatePersonUseCase, FindAllPeopleUseCase, FindPersonDetailsUseCase, UpdatePersonUseCase,
+    ArchivePersonUseCase, CheckArchivedPeopleAccessUseCase, CreatePersonUseCase,
+    FindAllPeopleUseCase, FindPersonDetailsUseCase, RestorePersonUseCase, UpdatePersonUseCase,
 };
 use crate::domain::services::person::PersonService;
 use std::sync::Arc;
@@ -43,11 +45,28 @@ pub async fn create_person(
 
 pub async fn find_all_people(
     Component(uc): Component<PersonService>,
+    Extension(principal): Extension<Arc<dyn ClaimsPrincipal>>,
     Query(payload): Query<PaginationParams>,
+    Query(criteria): Query<PersonCriteriaParams>,
 ) -> ApiResult<Paged<PersonPageQuery>> {
-    tracing::info!("find and paginate all people {:?}", payload);
+    tracing::info!("find and paginate all people {:?} {:?}", payload, criteria);
+
+    if criteria.include_deleted.unwrap_or_default()
+        && !uc.can_list_archived_people(principal.sub_id()).await?
+    {
+        tracing::info!(
+            user_id = principal.sub_id(),
+            "archived people are listed to admins only"
+        );
+
+        return Err(ApiFailure::Forbidden(
+            "archived people are listed to admins only".to_string(),
+        ));
+    }
 
-    let people = uc.find_all_people(&payload.into()).await?;
+    let people = uc
+        .find_all_people(&payload.into(), &criteria.into())
+        .await?;
 
     Ok(Replier::ok(Paged::from(&people, |item| {
         PersonPageQuery::from(item)
@@ -86,3 +105,27 @@ pub async fn find_person(
         .map(|person| Replier::ok(PersonDetailsQuery::from(&person)))
         .map_err(ApiFailure::from)
 }
+
+pub async fn archive_person(
+    Path(person_id): Path<i32>,
+    Component(uc): Component<PersonService>,
+) -> Result<StatusCode, ApiFailure> {
+    tracing::info!(person_id = person_id, "archiving person");
+
+    uc.archive_person(person_id)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiFailure::from)
+}
+
+pub async fn restore_person(
+    Path(person_id): Path<i32>,
+    Component(uc): Component<PersonService>,
+) -> Result<StatusCode, ApiFailure> {
+    tracing::info!(person_id = person_id, "restoring person");
+
+    uc.restore_person(person_id)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiFailure::from)
+}
@@ -0,0 +1,126 @@
+use crate::{
+    adapters::rest::types::person_document_type::{
+        CreatePersonDocumentTypeParams, PersonDocumentTypeCriteriaParams,
+        PersonDocumentTypeDetailsQuery, PersonDocumentTypePageQuery,
+        UpdatePersonDocumentTypeParams,
+    },
+    domain::{
+        commands::person_document_type::{
+            CreatePersonDocumentTypeCommand, UpdatePersonDocumentTypeCommand,
+        },
+        ports::api::person_document_type::{
+            CreatePersonDocumentTypeUseCase, DeletePersonDocumentTypeUseCase,
+            FindAllPersonDocumentTypeUseCase, FindPersonDocumentTypeByCriteriaUseCase,
+            FindPersonDocumentTypeUseCase, UpdatePersonDocumentTypeUseCase,
+        },
+        queries::person_document_type::PersonDocumentTypeQuery,
+        services::person_document_type::PersonDocumentTypeService,
+    },
+};
+use ids_std_domain::pagination::PaginationQuery;
+use ids_std_rest_api::{
+    failure::ApiFailure,
+    replier::Replier,
+    types::{
+        created::Created,
+        pagination::{Paged, PaginationParams},
+        result::ApiResult,
+    },
+};
+use lumx_axum::axum::{
+    extract::{Path, Query},
+    http::StatusCode,
+    Json,
+};
+use lumx_axum::extractor::Component;
+
+pub async fn find_all_person_document_types(
+    Component(uc): Component<PersonDocumentTypeService>,
+    Query(pagination): Query<PaginationParams>,
+    Query(criteria): Query<PersonDocumentTypeCriteriaParams>,
+) -> ApiResult<Paged<PersonDocumentTypePageQuery>> {
+    tracing::info!(
+        "find and paginate person document types {:?} {:?}",
+        pagination,
+        criteria
+    );
+
+    let query: PaginationQuery = pagination.into();
+    let document_types = match criteria.name {
+        Some(name) => {
+            uc.find_person_document_type_by_criteria(&query, &PersonDocumentTypeQuery { name })
+                .await?
+        }
+        None => uc.find_all_person_document_type(&query).await?,
+    };
+
+    Ok(Replier::ok(Paged::from(&document_types, |item| {
+        PersonDocumentTypePageQuery::from(item)
+    })))
+}
+
+pub async fn find_person_document_type(
+    Path(person_document_type_id): Path<i32>,
+    Component(uc): Component<PersonDocumentTypeService>,
+) -> ApiResult<PersonDocumentTypeDetailsQuery> {
+    tracing::info!(
+        person_document_type_id = person_document_type_id,
+        "finding person document type"
+    );
+
+    uc.find_person_document_type(person_document_type_id)
+        .await
+        .map(|document_type| Replier::ok(PersonDocumentTypeDetailsQuery::from(&document_type)))
+        .map_err(ApiFailure::from)
+}
+
+pub async fn create_person_document_type(
+    Component(uc): Component<PersonDocumentTypeService>,
+    Json(payload): Json<CreatePersonDocumentTypeParams>,
+) -> ApiResult<Created<i32>> {
+    tracing::info!("creating person document type {:?}", payload);
+
+    let command = CreatePersonDocumentTypeCommand {
+        name: payload.name,
+        summary: payload.summary,
+    };
+
+    uc.create(&command)
+        .await
+        .map(|id| Replier::ok(Created::new(id)))
+        .map_err(ApiFailure::from)
+}
+
+pub async fn update_person_document_type(
+    Path(person_document_type_id): Path<i32>,
+    Component(uc): Component<PersonDocumentTypeService>,
+    Json(payload): Json<UpdatePersonDocumentTypeParams>,
+) -> Result<StatusCode, ApiFailure> {
+    tracing::info!("updating person document type {:?}", payload);
+
+    let command = UpdatePersonDocumentTypeCommand {
+        person_document_type_id,
+        name: payload.name,
+        summary: payload.summary,
+    };
+
+    uc.update_person_document_type(&command)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiFailure::from)
+}
+
+pub async fn delete_person_document_type(
+    Path(person_document_type_id): Path<i32>,
+    Component(uc): Component<PersonDocumentTypeService>,
+) -> Result<StatusCode, ApiFailure> {
+    tracing::info!(
+        person_document_type_id = person_document_type_id,
+        "deleting person document type"
+    );
+
+    uc.delete_person_document_type(person_document_type_id)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiFailure::from)
+}
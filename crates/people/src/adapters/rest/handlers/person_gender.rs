@@ -1,19 +1,69 @@
 use crate::{
-    adapters::rest::types::person_gender::{CreatePersonGenderParams, UpdatePersonGenderParams},
+    adapters::rest::types::person_gender::{
+        CreatePersonGenderParams, PersonGenderCriteriaParams, PersonGenderDetailsQuery,
+        PersonGenderPageQuery, UpdatePersonGenderParams,
+    },
     domain::{
         commands::person_gender::{CreatePersonGenderCommand, UpdatePersonGenderCommand},
-        ports::api::person_gender::{CreatePersonGenderUseCase, UpdatePersonGenderUseCase},
+        ports::api::person_gender::{
+            CreatePersonGenderUseCase, DeletePersonGenderUseCase,
+            FindPersonGenderByCriteriaUseCase, FindPersonGenderUseCase, UpdatePersonGenderUseCase,
+        },
+        queries::person_gender::PersonGenderQuery,
         services::person_gender::PersonGenderService,
     },
 };
 use ids_std_rest_api::{
     failure::ApiFailure,
     replier::Replier,
-    types::{created::Created, result::ApiResult},
+    types::{
+        created::Created,
+        pagination::{Paged, PaginationParams},
+        result::ApiResult,
+    },
+};
+use lumx_axum::axum::{
+    extract::{Path, Query},
+    http::StatusCode,
+    Json,
 };
-use lumx_axum::axum::{extract::Path, http::StatusCode, Json};
 use lumx_axum::extractor::Component;
 
+pub async fn find_all_person_genders(
+    Component(uc): Component<PersonGenderService>,
+    Query(pagination): Query<PaginationParams>,
+    Query(criteria): Query<PersonGenderCriteriaParams>,
+) -> ApiResult<Paged<PersonGenderPageQuery>> {
+    tracing::info!(
+        "find and paginate person genders {:?} {:?}",
+        pagination,
+        criteria
+    );
+
+    let gender_criteria = PersonGenderQuery {
+        name: criteria.name.unwrap_or_default(),
+    };
+    let person_genders = uc
+        .find_person_gender_by_criteria(&pagination.into(), &gender_criteria)
+        .await?;
+
+    Ok(Replier::ok(Paged::from(&person_genders, |item| {
+        PersonGenderPageQuery::from(item)
+    })))
+}
+
+pub async fn find_person_gender(
+    Path(person_gender_id): Path<i32>,
+    Component(uc): Component<PersonGenderService>,
+) -> ApiResult<PersonGenderDetailsQuery> {
+    tracing::info!(person_gender_id = person_gender_id, "finding person gender");
+
+    uc.find_person_gender(person_gender_id)
+        .await
+        .map(|person_gender| Replier::ok(PersonGenderDetailsQuery::from(&person_gender)))
+        .map_err(ApiFailure::from)
+}
+
 pub async fn create_person_gender(
     Component(uc): Component<PersonGenderService>,
     Json(payload): Json<CreatePersonGenderParams>,
@@ -49,3 +99,18 @@ pub async fn update_person_gender(
         .map(|_| StatusCode::NO_CONTENT)
         .map_err(ApiFailure::from)
 }
+
+pub async fn delete_person_gender(
+    Path(person_gender_id): Path<i32>,
+    Component(uc): Component<PersonGenderService>,
+) -> Result<StatusCode, ApiFailure> {
+    tracing::info!(
+        person_gender_id = person_gender_id,
+        "deleting person gender"
+    );
+
+    uc.delete_person_gender(person_gender_id)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiFailure::from)
+}
@@ -11,6 +11,14 @@ pub fn router() -> Router {
             "/api/v1/people/:person_id",
             routing::put(handlers::person::update_person).get(handlers::person::find_person),
         )
+        .route(
+            "/api/v1/people/:person_id/archive",
+            routing::post(handlers::person::archive_person),
+        )
+        .route(
+            "/api/v1/people/:person_id/restore",
+            routing::post(handlers::person::restore_person),
+        )
         .route_layer(middleware::from_fn(
             lumx_passport::middleware::auth::jwt_auth,
         ))
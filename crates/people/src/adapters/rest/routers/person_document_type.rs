@@ -0,0 +1,18 @@
+use lumx_axum::axum::{routing, Router};
+
+use crate::adapters::rest::handlers;
+
+pub fn router() -> Router {
+    Router::new()
+        .route(
+            "/api/v1/person-document-types",
+            routing::post(handlers::person_document_type::create_person_document_type)
+                .get(handlers::person_document_type::find_all_person_document_types),
+        )
+        .route(
+            "/api/v1/person-document-types/:person_document_type_id",
+            routing::put(handlers::person_document_type::update_person_document_type)
+                .get(handlers::person_document_type::find_person_document_type)
+                .delete(handlers::person_document_type::delete_person_document_type),
+        )
+}
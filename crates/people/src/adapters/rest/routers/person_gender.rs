@@ -6,10 +6,13 @@ pub fn router() -> Router {
     Router::new()
         .route(
             "/api/v1/person-genders",
-            routing::post(handlers::person_gender::create_person_gender),
+            routing::post(handlers::person_gender::create_person_gender)
+                .get(handlers::person_gender::find_all_person_genders),
         )
         .route(
             "/api/v1/person-genders/:person_gender_id",
-            routing::put(handlers::person_gender::update_person_gender),
+            routing::put(handlers::person_gender::update_person_gender)
+                .get(handlers::person_gender::find_person_gender)
+                .delete(handlers::person_gender::delete_person_gender),
         )
 }
@@ -1,2 +1,3 @@
 pub mod person;
+pub mod person_document_type;
 pub mod person_gender;
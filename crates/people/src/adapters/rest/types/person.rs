@@ -1,6 +1,7 @@
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize};
 
 use crate::domain::{
+    queries::person::{PersonQuery, PersonSort, PersonSortField},
     selectors::person::{PersonDetailsSelector, PersonPageSelector},
     valuables::person::PersonFullName,
 };
@@ -25,6 +26,60 @@ pub struct UpdatePersonParams {
     pub gender_id: i32,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonCriteriaParams {
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub document_number: Option<String>,
+    pub document_type_id: Option<i32>,
+    pub gender_id: Option<i32>,
+    pub include_deleted: Option<bool>,
+    #[serde(default, deserialize_with = "deserialize_person_sort")]
+    pub sort: Vec<PersonSort>,
+}
+
+impl From<PersonCriteriaParams> for PersonQuery {
+    fn from(value: PersonCriteriaParams) -> Self {
+        Self {
+            first_name: value.first_name,
+            last_name: value.last_name,
+            document_number: value.document_number,
+            document_type_id: value.document_type_id,
+            gender_id: value.gender_id,
+            include_archived: value.include_deleted.unwrap_or_default(),
+            sort: value.sort,
+        }
+    }
+}
+
+fn deserialize_person_sort<'de, D>(deserializer: D) -> Result<Vec<PersonSort>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let raw = String::deserialize(deserializer)?;
+
+    raw.split(',')
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+        .map(|key| {
+            let (descending, name) = match key.strip_prefix('-') {
+                Some(name) => (true, name),
+                None => (false, key),
+            };
+            let field = match name {
+                "personId" => PersonSortField::PersonId,
+                "firstName" => PersonSortField::FirstName,
+                "lastName" => PersonSortField::LastName,
+                "documentNumber" => PersonSortField::DocumentNumber,
+                _ => return Err(de::Error::custom(format!("unknown sort field `{name}`"))),
+            };
+
+            Ok(PersonSort { field, descending })
+        })
+        .collect()
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PersonPageQuery {
@@ -37,6 +92,7 @@ pub struct PersonPageQuery {
     pub document_type_name: String,
     pub gender_id: i32,
     pub gender_name: String,
+    pub archived: bool,
 }
 
 impl From<&PersonPageSelector> for PersonPageQuery {
@@ -51,6 +107,7 @@ impl From<&PersonPageSelector> for PersonPageQuery {
             document_type_name: value.document_type_name.to_string(),
             gender_id: value.gender_id,
             gender_name: value.gender_name.to_string(),
+            archived: value.archived,
         }
     }
 }
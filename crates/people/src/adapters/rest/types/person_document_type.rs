@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::selectors::person_document_type::{
+    PersonDocumentTypePageSelector, PersonDocumentTypeSelector,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePersonDocumentTypeParams {
+    pub name: String,
+    pub summary: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePersonDocumentTypeParams {
+    pub name: String,
+    pub summary: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonDocumentTypeCriteriaParams {
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonDocumentTypePageQuery {
+    pub person_document_type_id: i32,
+    pub name: String,
+    pub summary: Option<String>,
+}
+
+impl From<&PersonDocumentTypePageSelector> for PersonDocumentTypePageQuery {
+    fn from(value: &PersonDocumentTypePageSelector) -> Self {
+        Self {
+            person_document_type_id: value.person_document_type_id,
+            name: value.name.to_string(),
+            summary: value.summary.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonDocumentTypeDetailsQuery {
+    pub person_document_type_id: i32,
+    pub name: String,
+    pub summary: Option<String>,
+}
+
+impl From<&PersonDocumentTypeSelector> for PersonDocumentTypeDetailsQuery {
+    fn from(value: &PersonDocumentTypeSelector) -> Self {
+        Self {
+            person_document_type_id: value.person_document_type_id,
+            name: value.name.to_string(),
+            summary: value.summary.clone(),
+        }
+    }
+}
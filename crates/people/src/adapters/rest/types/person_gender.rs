@@ -1,4 +1,6 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::selectors::person_gender::{PersonGenderPageSelector, PersonGenderSelector};
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -13,3 +15,45 @@ pub struct UpdatePersonGenderParams {
     pub name: String,
     pub summary: Option<String>,
 }
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonGenderCriteriaParams {
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonGenderPageQuery {
+    pub person_gender_id: i32,
+    pub name: String,
+    pub summary: Option<String>,
+}
+
+impl From<&PersonGenderPageSelector> for PersonGenderPageQuery {
+    fn from(value: &PersonGenderPageSelector) -> Self {
+        Self {
+            person_gender_id: value.person_gender_id,
+            name: value.name.to_string(),
+            summary: value.summary.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonGenderDetailsQuery {
+    pub person_gender_id: i32,
+    pub name: String,
+    pub summary: Option<String>,
+}
+
+impl From<&PersonGenderSelector> for PersonGenderDetailsQuery {
+    fn from(value: &PersonGenderSelector) -> Self {
+        Self {
+            person_gender_id: value.person_gender_id,
+            name: value.name.to_string(),
+            summary: value.summary.clone(),
+        }
+    }
+}
@@ -1,9 +1,12 @@
 use crate::{
     adapters::repository::{
         person::PersonSeaRepository, person_document_type::PersonDocumentTypeSeaRepository,
-        person_gender::PersonGenderSeaRepository,
+        person_gender::PersonGenderSeaRepository, user_authority::UserAuthoritySeaRepository,
+    },
+    domain::services::{
+        person::PersonService, person_document_type::PersonDocumentTypeService,
+        person_gender::PersonGenderService,
     },
-    domain::services::{person::PersonService, person_gender::PersonGenderService},
 };
 use async_trait::async_trait;
 use lumx_core::{plugable::plugin::Plugin, program::ProgramBuilder};
@@ -19,10 +22,12 @@ impl PeoplePlugin {
         let person_repo = PersonSeaRepository::new(&db);
         let person_document_type_repo = PersonDocumentTypeSeaRepository::new(&db);
         let person_gender_repo = PersonGenderSeaRepository::new(&db);
+        let user_authority_repo = UserAuthoritySeaRepository::new(&db);
 
         app.add_component(person_repo);
         app.add_component(person_document_type_repo);
         app.add_component(person_gender_repo);
+        app.add_component(user_authority_repo);
     }
 
     fn expose_services(&self, app: &mut ProgramBuilder) {
@@ -30,16 +35,22 @@ impl PeoplePlugin {
         let person_document_type_repo =
             app.get_expect_component::<PersonDocumentTypeSeaRepository>();
         let person_gender_repo = app.get_expect_component::<PersonGenderSeaRepository>();
+        let user_authority_repo = app.get_expect_component::<UserAuthoritySeaRepository>();
 
         let person_service = PersonService::new(
-            person_repo,
-            person_document_type_repo,
+            person_repo.clone(),
+            person_document_type_repo.clone(),
             person_gender_repo.clone(),
+            user_authority_repo.clone(),
         );
 
-        let person_gender_service = PersonGenderService::new(person_gender_repo.clone());
+        let person_document_type_service =
+            PersonDocumentTypeService::new(person_document_type_repo.clone(), person_repo.clone());
+        let person_gender_service =
+            PersonGenderService::new(person_gender_repo.clone(), person_repo.clone());
 
         app.add_component(person_service);
+        app.add_component(person_document_type_service);
         app.add_component(person_gender_service);
     }
 }
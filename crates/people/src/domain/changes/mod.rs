@@ -1 +1,2 @@
+pub mod person_document_type;
 pub mod person_gender;
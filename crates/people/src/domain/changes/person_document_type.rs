@@ -0,0 +1,12 @@
+pub struct AddPersonDocumentType {
+    pub name: String,
+    pub summary: Option<String>,
+    pub signature: String,
+}
+
+pub struct UpdatePersonDocumentType {
+    pub person_document_type_id: i32,
+    pub name: String,
+    pub summary: Option<String>,
+    pub signature: String,
+}
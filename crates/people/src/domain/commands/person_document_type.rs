@@ -0,0 +1,19 @@
+use validator::Validate;
+
+#[derive(Validate, Debug, Clone)]
+pub struct CreatePersonDocumentTypeCommand {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+
+    pub summary: Option<String>,
+}
+
+#[derive(Debug, Validate, Clone)]
+pub struct UpdatePersonDocumentTypeCommand {
+    pub person_document_type_id: i32,
+
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+
+    pub summary: Option<String>,
+}
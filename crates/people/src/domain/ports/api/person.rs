@@ -5,6 +5,7 @@ use ids_std_domain::api::failure::{
 use ids_std_domain::pagination::{Page, PaginationQuery};
 
 use crate::domain::commands::person::{CreatePersonCommand, UpdatePersonCommand};
+use crate::domain::queries::person::PersonQuery;
 use crate::domain::selectors::person::{PersonDetailsSelector, PersonPageSelector};
 
 #[async_trait]
@@ -17,15 +18,31 @@ pub trait FindAllPeopleUseCase: Send + Sync + 'static {
     async fn find_all_people(
         &self,
         query: &PaginationQuery,
+        criteria: &PersonQuery,
     ) -> Result<Page<PersonPageSelector>, FindManyFailure>;
 }
 
+#[async_trait]
+pub trait CheckArchivedPeopleAccessUseCase: Send + Sync + 'static {
+    async fn can_list_archived_people(&self, user_id: i32) -> Result<bool, FindOneFailure>;
+}
+
 #[async_trait]
 pub trait UpdatePersonUseCase: Send + Sync + 'static {
     async fn update_person(&self, command: &UpdatePersonCommand)
         -> Result<(), UpdateDomainFailure>;
 }
 
+#[async_trait]
+pub trait ArchivePersonUseCase: Send + Sync + 'static {
+    async fn archive_person(&self, person_id: i32) -> Result<(), UpdateDomainFailure>;
+}
+
+#[async_trait]
+pub trait RestorePersonUseCase: Send + Sync + 'static {
+    async fn restore_person(&self, person_id: i32) -> Result<(), UpdateDomainFailure>;
+}
+
 #[async_trait]
 pub trait FindPersonDetailsUseCase: Send + Sync + 'static {
     async fn find_person_details(
@@ -1,12 +1,15 @@
 use async_trait::async_trait;
 use ids_std_domain::{
-    api::failure::FindManyFailure,
+    api::failure::{CreateDomainFailure, FindManyFailure, FindOneFailure, UpdateDomainFailure},
     pagination::{Page, PaginationQuery},
 };
 
 use crate::domain::{
+    commands::person_document_type::{
+        CreatePersonDocumentTypeCommand, UpdatePersonDocumentTypeCommand,
+    },
     queries::person_document_type::PersonDocumentTypeQuery,
-    selectors::person_document_type::PersonDocumentTypePageSelector,
+    selectors::person_document_type::{PersonDocumentTypePageSelector, PersonDocumentTypeSelector},
 };
 
 #[async_trait]
@@ -25,3 +28,35 @@ pub trait FindPersonDocumentTypeByCriteriaUseCase: Send + Sync + 'static {
         criteria: &PersonDocumentTypeQuery,
     ) -> Result<Page<PersonDocumentTypePageSelector>, FindManyFailure>;
 }
+
+#[async_trait]
+pub trait FindPersonDocumentTypeUseCase: Send + Sync + 'static {
+    async fn find_person_document_type(
+        &self,
+        person_document_type_id: i32,
+    ) -> Result<PersonDocumentTypeSelector, FindOneFailure>;
+}
+
+#[async_trait]
+pub trait CreatePersonDocumentTypeUseCase: Send + Sync + 'static {
+    async fn create(
+        &self,
+        cmd: &CreatePersonDocumentTypeCommand,
+    ) -> Result<i32, CreateDomainFailure>;
+}
+
+#[async_trait]
+pub trait UpdatePersonDocumentTypeUseCase: Send + Sync + 'static {
+    async fn update_person_document_type(
+        &self,
+        command: &UpdatePersonDocumentTypeCommand,
+    ) -> Result<(), UpdateDomainFailure>;
+}
+
+#[async_trait]
+pub trait DeletePersonDocumentTypeUseCase: Send + Sync + 'static {
+    async fn delete_person_document_type(
+        &self,
+        person_document_type_id: i32,
+    ) -> Result<(), UpdateDomainFailure>;
+}
@@ -1,13 +1,13 @@
 use async_trait::async_trait;
 use ids_std_domain::{
-    api::failure::{CreateDomainFailure, FindManyFailure, UpdateDomainFailure},
+    api::failure::{CreateDomainFailure, FindManyFailure, FindOneFailure, UpdateDomainFailure},
     pagination::{Page, PaginationQuery},
 };
 
 use crate::domain::{
     commands::person_gender::{CreatePersonGenderCommand, UpdatePersonGenderCommand},
     queries::person_gender::PersonGenderQuery,
-    selectors::person_gender::PersonGenderPageSelector,
+    selectors::person_gender::{PersonGenderPageSelector, PersonGenderSelector},
 };
 
 #[async_trait]
@@ -19,6 +19,14 @@ pub trait FindPersonGenderByCriteriaUseCase: Send + Sync + 'static {
     ) -> Result<Page<PersonGenderPageSelector>, FindManyFailure>;
 }
 
+#[async_trait]
+pub trait FindPersonGenderUseCase: Send + Sync + 'static {
+    async fn find_person_gender(
+        &self,
+        person_gender_id: i32,
+    ) -> Result<PersonGenderSelector, FindOneFailure>;
+}
+
 #[async_trait]
 pub trait CreatePersonGenderUseCase: Send + Sync + 'static {
     async fn create(&self, cmd: &CreatePersonGenderCommand) -> Result<i32, CreateDomainFailure>;
@@ -31,3 +39,8 @@ pub trait UpdatePersonGenderUseCase: Send + Sync + 'static {
         command: &UpdatePersonGenderCommand,
     ) -> Result<(), UpdateDomainFailure>;
 }
+
+#[async_trait]
+pub trait DeletePersonGenderUseCase: Send + Sync + 'static {
+    async fn delete_person_gender(&self, person_gender_id: i32) -> Result<(), UpdateDomainFailure>;
+}
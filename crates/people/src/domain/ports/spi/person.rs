@@ -1,4 +1,5 @@
 use crate::domain::entities::person::PersonEnt;
+use crate::domain::queries::person::PersonQuery;
 use crate::domain::selectors::person::{PersonDetailsSelector, PersonPageSelector, PersonSelector};
 use async_trait::async_trait;
 use ids_std_domain::pagination::{Page, PaginationQuery};
@@ -38,5 +39,15 @@ pub trait PersonRepository: Send + Sync + 'static {
     async fn find_all(
         &self,
         query: &PaginationQuery,
+        criteria: &PersonQuery,
     ) -> Result<Page<PersonPageSelector>, SelectRepoFailure>;
+
+    async fn count_by_gender(&self, gender_id: i32) -> Result<u64, SelectRepoFailure>;
+
+    async fn count_by_document_type(&self, document_type_id: i32)
+        -> Result<u64, SelectRepoFailure>;
+
+    async fn archive(&self, person_id: i32) -> Result<(), SaveRepoFailure>;
+
+    async fn restore(&self, person_id: i32) -> Result<(), SaveRepoFailure>;
 }
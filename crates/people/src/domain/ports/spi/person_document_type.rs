@@ -1,10 +1,13 @@
+use crate::domain::changes::person_document_type::{
+    AddPersonDocumentType, UpdatePersonDocumentType,
+};
 use crate::domain::queries::person_document_type::PersonDocumentTypeQuery;
 use crate::domain::selectors::person_document_type::{
     PersonDocumentTypePageSelector, PersonDocumentTypeSelector,
 };
 use async_trait::async_trait;
 use ids_std_domain::pagination::{Page, PaginationQuery};
-use ids_std_domain::spi::failure::SelectRepoFailure;
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
 
 #[async_trait]
 pub trait PersonDocumentTypeRepository: Send + Sync + 'static {
@@ -23,4 +26,21 @@ pub trait PersonDocumentTypeRepository: Send + Sync + 'static {
         query: &PaginationQuery,
         criteria: &PersonDocumentTypeQuery,
     ) -> Result<Page<PersonDocumentTypePageSelector>, SelectRepoFailure>;
+
+    async fn find_by_signature(
+        &self,
+        signature: &str,
+    ) -> Result<Option<PersonDocumentTypeSelector>, SelectRepoFailure>;
+
+    async fn find_other_by_signature(
+        &self,
+        self_id: i32,
+        signature: &str,
+    ) -> Result<Option<PersonDocumentTypeSelector>, SelectRepoFailure>;
+
+    async fn save(&self, event: AddPersonDocumentType) -> Result<i32, SaveRepoFailure>;
+
+    async fn update(&self, event: &UpdatePersonDocumentType) -> Result<(), SaveRepoFailure>;
+
+    async fn delete(&self, person_document_type_id: i32) -> Result<(), SaveRepoFailure>;
 }
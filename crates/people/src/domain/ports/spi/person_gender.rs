@@ -29,4 +29,6 @@ pub trait PersonGenderRepository: Send + Sync + 'static {
     async fn save(&self, event: AddPersonGender) -> Result<i32, SaveRepoFailure>;
 
     async fn update(&self, event: &UpdatePersonGender) -> Result<(), SaveRepoFailure>;
+
+    async fn delete(&self, person_gender_id: i32) -> Result<(), SaveRepoFailure>;
 }
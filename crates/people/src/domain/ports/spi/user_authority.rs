@@ -0,0 +1,12 @@
+use crate::domain::valuables::user_authority::UserAuthority;
+use async_trait::async_trait;
+use ids_std_domain::spi::failure::SelectRepoFailure;
+
+#[async_trait]
+pub trait UserAuthorityRepository: Send + Sync + 'static {
+    async fn has_authority(
+        &self,
+        user_id: i32,
+        authority: UserAuthority,
+    ) -> Result<bool, SelectRepoFailure>;
+}
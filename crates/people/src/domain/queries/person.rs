@@ -0,0 +1,24 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PersonSortField {
+    PersonId,
+    FirstName,
+    LastName,
+    DocumentNumber,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PersonSort {
+    pub field: PersonSortField,
+    pub descending: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PersonQuery {
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub document_number: Option<String>,
+    pub document_type_id: Option<i32>,
+    pub gender_id: Option<i32>,
+    pub include_archived: bool,
+    pub sort: Vec<PersonSort>,
+}
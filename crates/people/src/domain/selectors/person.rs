@@ -8,6 +8,7 @@ pub struct PersonSelector {
     pub document_type_id: i32,
     pub gender_id: i32,
     pub signature: String,
+    pub archived: bool,
 }
 
 pub struct PersonDetailsSelector {
@@ -38,6 +39,7 @@ pub struct PersonPageSelector {
     pub gender_id: i32,
     pub gender_name: String,
     pub signature: String,
+    pub archived: bool,
 }
 
 impl PersonFullName for PersonPageSelector {
@@ -1,3 +1,4 @@
+#[derive(Debug, Clone)]
 pub struct PersonDocumentTypeSelector {
     pub person_document_type_id: i32,
     pub name: String,
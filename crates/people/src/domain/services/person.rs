@@ -3,12 +3,16 @@ use std::sync::Arc;
 use crate::domain::commands::person::{CreatePersonCommand, UpdatePersonCommand};
 use crate::domain::entities::person::PersonEnt;
 use crate::domain::ports::api::person::{
-    CreatePersonUseCase, FindAllPeopleUseCase, FindPersonDetailsUseCase, UpdatePersonUseCase,
+    ArchivePersonUseCase, CheckArchivedPeopleAccessUseCase, CreatePersonUseCase,
+    FindAllPeopleUseCase, FindPersonDetailsUseCase, RestorePersonUseCase, UpdatePersonUseCase,
 };
 use crate::domain::ports::spi::person::PersonRepository;
 use crate::domain::ports::spi::person_document_type::PersonDocumentTypeRepository;
 use crate::domain::ports::spi::person_gender::PersonGenderRepository;
+use crate::domain::ports::spi::user_authority::UserAuthorityRepository;
+use crate::domain::queries::person::PersonQuery;
 use crate::domain::selectors::person::{PersonDetailsSelector, PersonPageSelector};
+use crate::domain::valuables::user_authority::UserAuthority;
 use async_trait::async_trait;
 use ids_std_domain::api::failure::{
     CreateDomainFailure, FindManyFailure, FindOneFailure, InvalidField, UpdateDomainFailure,
@@ -20,6 +24,7 @@ pub struct PersonService {
     person_repo: Arc<dyn PersonRepository>,
     person_document_type_repo: Arc<dyn PersonDocumentTypeRepository>,
     person_gender_repo: Arc<dyn PersonGenderRepository>,
+    user_authority_repo: Arc<dyn UserAuthorityRepository>,
 }
 
 impl PersonService {
@@ -27,11 +32,13 @@ impl PersonService {
         person_repo: Arc<dyn PersonRepository>,
         person_document_type_repo: Arc<dyn PersonDocumentTypeRepository>,
         person_gender_repo: Arc<dyn PersonGenderRepository>,
+        user_authority_repo: Arc<dyn UserAuthorityRepository>,
     ) -> Self {
         Self {
             person_repo,
             person_document_type_repo,
             person_gender_repo,
+            user_authority_repo,
         }
     }
 }
@@ -115,8 +122,19 @@ impl FindAllPeopleUseCase for PersonService {
     async fn find_all_people(
         &self,
         query: &PaginationQuery,
+        criteria: &PersonQuery,
     ) -> Result<Page<PersonPageSelector>, FindManyFailure> {
-        Ok(self.person_repo.find_all(query).await?)
+        Ok(self.person_repo.find_all(query, criteria).await?)
+    }
+}
+
+#[async_trait]
+impl CheckArchivedPeopleAccessUseCase for PersonService {
+    async fn can_list_archived_people(&self, user_id: i32) -> Result<bool, FindOneFailure> {
+        Ok(self
+            .user_authority_repo
+            .has_authority(user_id, UserAuthority::Admin)
+            .await?)
     }
 }
 
@@ -134,7 +152,7 @@ impl UpdatePersonUseCase for PersonService {
             .person_repo
             .find_by_id(person_ent.person_id.unwrap())
             .await?;
-        if person.is_none() {
+        if person.is_none_or(|p| p.archived) {
             tracing::info!(
                 person_id = &person_ent.person_id.unwrap(),
                 "person does not exist"
@@ -216,6 +234,99 @@ impl UpdatePersonUseCase for PersonService {
     }
 }
 
+#[async_trait]
+impl ArchivePersonUseCase for PersonService {
+    async fn archive_person(&self, person_id: i32) -> Result<(), UpdateDomainFailure> {
+        tracing::info!(person_id = &person_id, "archiving person");
+
+        let person = self.person_repo.find_by_id(person_id).await?;
+        if person.is_none() {
+            tracing::info!(person_id = &person_id, "person does not exist");
+
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "person_id".into(),
+                "person does not exist".into(),
+            )))?;
+        }
+
+        if person.unwrap().archived {
+            tracing::info!(person_id = &person_id, "person already archived");
+
+            Err(UpdateDomainFailure::Conflict(
+                "person already archived".to_string(),
+            ))?;
+        }
+
+        self.person_repo.archive(person_id).await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl RestorePersonUseCase for PersonService {
+    async fn restore_person(&self, person_id: i32) -> Result<(), UpdateDomainFailure> {
+        tracing::info!(person_id = &person_id, "restoring person");
+
+        let person = self.person_repo.find_by_id(person_id).await?;
+        if person.is_none() {
+            tracing::info!(person_id = &person_id, "person does not exist");
+
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "person_id".into(),
+                "person does not exist".into(),
+            )))?;
+        }
+
+        let person = person.unwrap();
+        if !person.archived {
+            tracing::info!(person_id = &person_id, "person is not archived");
+
+            Err(UpdateDomainFailure::Conflict(
+                "person is not archived".to_string(),
+            ))?;
+        }
+
+        let person_selector = self
+            .person_repo
+            .find_by_signature(&person.signature)
+            .await?;
+
+        if let Some(c) = person_selector {
+            tracing::info!(signature = &c.signature, "person signature already exist");
+
+            Err(UpdateDomainFailure::Conflict(
+                "person with signature already exist".to_string(),
+            ))?;
+        }
+
+        let someone_with_same_document = self
+            .person_repo
+            .find_another_by_document_and_type(
+                person_id,
+                &person.document_number,
+                person.document_type_id,
+            )
+            .await?;
+
+        if let Some(someone_else) = someone_with_same_document {
+            tracing::info!(
+                document_number = &someone_else.document_number,
+                document_type_id = &someone_else.document_type_id,
+                "person document already exist"
+            );
+
+            Err(UpdateDomainFailure::Conflict(
+                "person document already exist".to_string(),
+            ))?;
+        }
+
+        self.person_repo.restore(person_id).await?;
+
+        Ok(())
+    }
+}
+
 #[async_trait]
 impl FindPersonDetailsUseCase for PersonService {
     async fn find_person_details(
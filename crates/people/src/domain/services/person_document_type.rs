@@ -1,30 +1,45 @@
 use std::sync::Arc;
 
 use crate::domain::{
+    changes::person_document_type::{AddPersonDocumentType, UpdatePersonDocumentType},
+    commands::person_document_type::{
+        CreatePersonDocumentTypeCommand, UpdatePersonDocumentTypeCommand,
+    },
     ports::{
         api::person_document_type::{
+            CreatePersonDocumentTypeUseCase, DeletePersonDocumentTypeUseCase,
             FindAllPersonDocumentTypeUseCase, FindPersonDocumentTypeByCriteriaUseCase,
+            FindPersonDocumentTypeUseCase, UpdatePersonDocumentTypeUseCase,
         },
-        spi::person_document_type::PersonDocumentTypeRepository,
+        spi::{person::PersonRepository, person_document_type::PersonDocumentTypeRepository},
     },
     queries::person_document_type::PersonDocumentTypeQuery,
-    selectors::person_document_type::PersonDocumentTypePageSelector,
+    selectors::person_document_type::{PersonDocumentTypePageSelector, PersonDocumentTypeSelector},
+    valuables::person_document_type::{PersonDocumentTypeName, PersonDocumentTypeSignature},
 };
 use async_trait::async_trait;
 use ids_std_domain::{
-    api::failure::FindManyFailure,
+    api::failure::{
+        CreateDomainFailure, FindManyFailure, FindOneFailure, InvalidField, UpdateDomainFailure,
+    },
     pagination::{Page, PaginationQuery},
+    validation,
 };
 
 #[derive(Clone)]
 pub struct PersonDocumentTypeService {
     person_document_type_repo: Arc<dyn PersonDocumentTypeRepository>,
+    person_repo: Arc<dyn PersonRepository>,
 }
 
 impl PersonDocumentTypeService {
-    pub fn new(person_document_type_repo: Arc<dyn PersonDocumentTypeRepository>) -> Self {
+    pub fn new(
+        person_document_type_repo: Arc<dyn PersonDocumentTypeRepository>,
+        person_repo: Arc<dyn PersonRepository>,
+    ) -> Self {
         Self {
             person_document_type_repo,
+            person_repo,
         }
     }
 }
@@ -52,3 +67,181 @@ impl FindPersonDocumentTypeByCriteriaUseCase for PersonDocumentTypeService {
             .await?)
     }
 }
+
+#[async_trait]
+impl FindPersonDocumentTypeUseCase for PersonDocumentTypeService {
+    async fn find_person_document_type(
+        &self,
+        person_document_type_id: i32,
+    ) -> Result<PersonDocumentTypeSelector, FindOneFailure> {
+        let document_type = self
+            .person_document_type_repo
+            .find_by_id(person_document_type_id)
+            .await?;
+
+        if document_type.is_none() {
+            tracing::info!(
+                person_document_type_id = &person_document_type_id,
+                "document type does not exist"
+            );
+
+            Err(FindOneFailure::NotFound(
+                "document type does not exist".to_string(),
+            ))?;
+        }
+
+        Ok(document_type.unwrap())
+    }
+}
+
+#[async_trait]
+impl CreatePersonDocumentTypeUseCase for PersonDocumentTypeService {
+    async fn create(
+        &self,
+        cmd: &CreatePersonDocumentTypeCommand,
+    ) -> Result<i32, CreateDomainFailure> {
+        tracing::info!("creating person document type {:?}", cmd);
+
+        validation::Validator::try_validate(cmd)?;
+
+        let document_type_signature = PersonDocumentTypeSignature::new(cmd.name.as_str()).get();
+        let document_type_name = PersonDocumentTypeName::new(cmd.name.as_str()).get();
+
+        let document_type = self
+            .person_document_type_repo
+            .find_by_signature(document_type_signature.as_str())
+            .await?;
+
+        if let Some(selector) = document_type {
+            tracing::info!(
+                signature = &selector.signature,
+                "person document type signature already exist"
+            );
+
+            return Err(CreateDomainFailure::Conflict(
+                "document type with signature already exist".to_string(),
+            ));
+        }
+
+        let event = AddPersonDocumentType {
+            name: document_type_name.to_owned(),
+            summary: cmd.summary.to_owned(),
+            signature: document_type_signature.to_owned(),
+        };
+
+        let person_document_type_id = self.person_document_type_repo.save(event).await?;
+
+        Ok(person_document_type_id)
+    }
+}
+
+#[async_trait]
+impl UpdatePersonDocumentTypeUseCase for PersonDocumentTypeService {
+    async fn update_person_document_type(
+        &self,
+        command: &UpdatePersonDocumentTypeCommand,
+    ) -> Result<(), UpdateDomainFailure> {
+        tracing::info!("updating person document type {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let document_type = self
+            .person_document_type_repo
+            .find_by_id(command.person_document_type_id)
+            .await?;
+
+        if document_type.is_none() {
+            tracing::info!(
+                person_document_type_id = &command.person_document_type_id,
+                "document type does not exist"
+            );
+
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "person_document_type_id".into(),
+                "document type does not exist".into(),
+            )))?;
+        }
+
+        let document_type_signature = PersonDocumentTypeSignature::new(command.name.as_str()).get();
+        let document_type_name = PersonDocumentTypeName::new(command.name.as_str()).get();
+
+        let other_document_type = self
+            .person_document_type_repo
+            .find_other_by_signature(command.person_document_type_id, &document_type_signature)
+            .await?;
+
+        if let Some(selector) = other_document_type {
+            tracing::info!(
+                signature = &selector.signature,
+                "person document type signature already exist"
+            );
+
+            Err(UpdateDomainFailure::Conflict(
+                "document type with signature already exist".to_string(),
+            ))?;
+        }
+
+        let event = UpdatePersonDocumentType {
+            person_document_type_id: command.person_document_type_id,
+            name: document_type_name.to_owned(),
+            summary: command.summary.to_owned(),
+            signature: document_type_signature.to_owned(),
+        };
+        self.person_document_type_repo.update(&event).await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl DeletePersonDocumentTypeUseCase for PersonDocumentTypeService {
+    async fn delete_person_document_type(
+        &self,
+        person_document_type_id: i32,
+    ) -> Result<(), UpdateDomainFailure> {
+        tracing::info!(
+            person_document_type_id = &person_document_type_id,
+            "deleting person document type"
+        );
+
+        let document_type = self
+            .person_document_type_repo
+            .find_by_id(person_document_type_id)
+            .await?;
+
+        if document_type.is_none() {
+            tracing::info!(
+                person_document_type_id = &person_document_type_id,
+                "document type does not exist"
+            );
+
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "person_document_type_id".into(),
+                "document type does not exist".into(),
+            )))?;
+        }
+
+        let people_with_document_type = self
+            .person_repo
+            .count_by_document_type(person_document_type_id)
+            .await?;
+
+        if people_with_document_type > 0 {
+            tracing::info!(
+                person_document_type_id = &person_document_type_id,
+                people = &people_with_document_type,
+                "document type is still assigned to people"
+            );
+
+            Err(UpdateDomainFailure::Conflict(
+                "document type is still assigned to people".to_string(),
+            ))?;
+        }
+
+        self.person_document_type_repo
+            .delete(person_document_type_id)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -2,7 +2,9 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use ids_std_domain::{
-    api::failure::{CreateDomainFailure, FindManyFailure, UpdateDomainFailure},
+    api::failure::{
+        CreateDomainFailure, FindManyFailure, FindOneFailure, InvalidField, UpdateDomainFailure,
+    },
     pagination::{Page, PaginationQuery},
     validation,
 };
@@ -12,23 +14,31 @@ use crate::domain::{
     commands::person_gender::{CreatePersonGenderCommand, UpdatePersonGenderCommand},
     ports::{
         api::person_gender::{
-            CreatePersonGenderUseCase, FindPersonGenderByCriteriaUseCase, UpdatePersonGenderUseCase,
+            CreatePersonGenderUseCase, DeletePersonGenderUseCase,
+            FindPersonGenderByCriteriaUseCase, FindPersonGenderUseCase, UpdatePersonGenderUseCase,
         },
-        spi::person_gender::PersonGenderRepository,
+        spi::{person::PersonRepository, person_gender::PersonGenderRepository},
     },
     queries::person_gender::PersonGenderQuery,
-    selectors::person_gender::PersonGenderPageSelector,
+    selectors::person_gender::{PersonGenderPageSelector, PersonGenderSelector},
     valuables::person_gender::{PersonGenderName, PersonGenderSignature},
 };
 
 #[derive(Clone)]
 pub struct PersonGenderService {
     person_gender_repo: Arc<dyn PersonGenderRepository>,
+    person_repo: Arc<dyn PersonRepository>,
 }
 
 impl PersonGenderService {
-    pub fn new(person_gender_repo: Arc<dyn PersonGenderRepository>) -> Self {
-        Self { person_gender_repo }
+    pub fn new(
+        person_gender_repo: Arc<dyn PersonGenderRepository>,
+        person_repo: Arc<dyn PersonRepository>,
+    ) -> Self {
+        Self {
+            person_gender_repo,
+            person_repo,
+        }
     }
 }
 
@@ -46,6 +56,29 @@ impl FindPersonGenderByCriteriaUseCase for PersonGenderService {
     }
 }
 
+#[async_trait]
+impl FindPersonGenderUseCase for PersonGenderService {
+    async fn find_person_gender(
+        &self,
+        person_gender_id: i32,
+    ) -> Result<PersonGenderSelector, FindOneFailure> {
+        let person_gender = self.person_gender_repo.find_by_id(person_gender_id).await?;
+
+        if person_gender.is_none() {
+            tracing::info!(
+                person_gender_id = &person_gender_id,
+                "gender does not exist"
+            );
+
+            Err(FindOneFailure::NotFound(
+                "gender does not exist".to_string(),
+            ))?;
+        }
+
+        Ok(person_gender.unwrap())
+    }
+}
+
 #[async_trait]
 impl CreatePersonGenderUseCase for PersonGenderService {
     async fn create(&self, cmd: &CreatePersonGenderCommand) -> Result<i32, CreateDomainFailure> {
@@ -124,3 +157,43 @@ impl UpdatePersonGenderUseCase for PersonGenderService {
         Ok(())
     }
 }
+
+#[async_trait]
+impl DeletePersonGenderUseCase for PersonGenderService {
+    async fn delete_person_gender(&self, person_gender_id: i32) -> Result<(), UpdateDomainFailure> {
+        tracing::info!(
+            person_gender_id = &person_gender_id,
+            "deleting person gender"
+        );
+
+        let person_gender = self.person_gender_repo.find_by_id(person_gender_id).await?;
+        if person_gender.is_none() {
+            tracing::info!(
+                person_gender_id = &person_gender_id,
+                "gender does not exist"
+            );
+
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "person_gender_id".into(),
+                "gender does not exist".into(),
+            )))?;
+        }
+
+        let people_with_gender = self.person_repo.count_by_gender(person_gender_id).await?;
+        if people_with_gender > 0 {
+            tracing::info!(
+                person_gender_id = &person_gender_id,
+                people = &people_with_gender,
+                "gender is still assigned to people"
+            );
+
+            Err(UpdateDomainFailure::Conflict(
+                "gender is still assigned to people".to_string(),
+            ))?;
+        }
+
+        self.person_gender_repo.delete(person_gender_id).await?;
+
+        Ok(())
+    }
+}
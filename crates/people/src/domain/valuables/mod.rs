@@ -1,2 +1,4 @@
 pub mod person;
+pub mod person_document_type;
 pub mod person_gender;
+pub mod user_authority;
@@ -0,0 +1,62 @@
+use ids_std_utils::signer;
+
+pub struct PersonDocumentTypeSignature {
+    signature: String,
+}
+
+impl PersonDocumentTypeSignature {
+    pub fn new(name: &str) -> Self {
+        Self {
+            signature: Self::sign(name),
+        }
+    }
+
+    pub fn get(self) -> String {
+        self.signature
+    }
+
+    fn sign(name: &str) -> String {
+        let fingerprint = name.trim().to_lowercase();
+
+        signer::sign(fingerprint)
+    }
+}
+
+pub struct PersonDocumentTypeName {
+    name: String,
+}
+
+impl PersonDocumentTypeName {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.trim().to_string(),
+        }
+    }
+
+    pub fn get(self) -> String {
+        self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PersonDocumentTypeName, PersonDocumentTypeSignature};
+
+    #[test]
+    fn it_keep_document_type_acronyms() {
+        let expected = "P.IVA";
+
+        let result = PersonDocumentTypeName::new(" P.IVA ").get();
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn it_sign_document_type_ignoring_case_and_spaces() {
+        let expected = PersonDocumentTypeSignature::new("dni").get();
+
+        let result = PersonDocumentTypeSignature::new(" DNI ").get();
+
+        assert_eq!(expected, result);
+    }
+}
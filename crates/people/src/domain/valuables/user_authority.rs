@@ -0,0 +1,12 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserAuthority {
+    Admin,
+}
+
+impl UserAuthority {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Admin => "admin",
+        }
+    }
+}
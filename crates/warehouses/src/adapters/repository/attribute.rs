@@ -0,0 +1,163 @@
+use super::factories::attribute::AttributeValueAndAttribute;
+use crate::domain::changes::attribute::{AddAttributeValue, AddProductAttribute};
+use crate::domain::ports::spi::attribute::ProductAttributeRepository;
+use crate::domain::selectors::attribute::{AttributeValueSelector, ProductAttributeSelector};
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+use ids_std_sea::convert::into::IntoDomain;
+use lumx_sea_orm::sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DbConn, EntityTrait, JoinType, QueryFilter,
+    QueryOrder, QuerySelect, RelationTrait, TransactionTrait,
+};
+use portal_schema::{product_attribute, product_attribute_value};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct ProductAttributeSeaRepository {
+    db: Arc<DbConn>,
+}
+
+impl ProductAttributeSeaRepository {
+    pub fn new(db: &Arc<DbConn>) -> Self {
+        Self {
+            db: Arc::clone(&db),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ProductAttributeRepository for ProductAttributeSeaRepository {
+    async fn find_by_id(
+        &self,
+        id: i32,
+    ) -> Result<Option<ProductAttributeSelector>, SelectRepoFailure> {
+        let maybe_model = product_attribute::Entity::find_by_id(id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(ProductAttributeSelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_by_name(
+        &self,
+        name: &str,
+    ) -> Result<Option<ProductAttributeSelector>, SelectRepoFailure> {
+        let maybe_model = product_attribute::Entity::find()
+            .filter(product_attribute::Column::Name.eq(name))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(ProductAttributeSelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_all(&self) -> Result<Vec<ProductAttributeSelector>, SelectRepoFailure> {
+        let models = product_attribute::Entity::find()
+            .order_by_asc(product_attribute::Column::ProductAttributeId)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(models
+            .into_iter()
+            .map(ProductAttributeSelector::from)
+            .collect())
+    }
+
+    async fn find_value(
+        &self,
+        attribute_id: i32,
+        value: &str,
+    ) -> Result<Option<AttributeValueSelector>, SelectRepoFailure> {
+        let maybe_model = product_attribute_value::Entity::find()
+            .column_as(product_attribute::Column::Name, "attribute_name")
+            .join(
+                JoinType::InnerJoin,
+                product_attribute_value::Relation::ProductAttribute.def(),
+            )
+            .filter(product_attribute_value::Column::AttributeId.eq(attribute_id))
+            .filter(product_attribute_value::Column::Value.eq(value))
+            .into_model::<AttributeValueAndAttribute>()
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(AttributeValueSelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_values(
+        &self,
+        value_ids: Option<Vec<i32>>,
+    ) -> Result<Vec<AttributeValueSelector>, SelectRepoFailure> {
+        let mut select = product_attribute_value::Entity::find()
+            .column_as(product_attribute::Column::Name, "attribute_name")
+            .join(
+                JoinType::InnerJoin,
+                product_attribute_value::Relation::ProductAttribute.def(),
+            );
+
+        if let Some(value_ids) = value_ids {
+            select = select
+                .filter(product_attribute_value::Column::ProductAttributeValueId.is_in(value_ids));
+        }
+
+        let models = select
+            .order_by_asc(product_attribute_value::Column::ProductAttributeValueId)
+            .into_model::<AttributeValueAndAttribute>()
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(models
+            .into_iter()
+            .map(AttributeValueSelector::from)
+            .collect())
+    }
+
+    async fn save(&self, event: &AddProductAttribute) -> Result<i32, SaveRepoFailure> {
+        let txn = self.db.begin().await.map_err(|err| err.into_domain())?;
+
+        let attribute_model = product_attribute::ActiveModel {
+            name: ActiveValue::Set(event.name.to_owned()),
+            ..Default::default()
+        };
+        let product_attribute_id = attribute_model
+            .save(&txn)
+            .await
+            .map(|model| model.product_attribute_id.unwrap())
+            .map_err(|err| err.into_domain())?;
+
+        for value in &event.values {
+            let value_model = product_attribute_value::ActiveModel {
+                attribute_id: ActiveValue::Set(product_attribute_id),
+                value: ActiveValue::Set(value.to_owned()),
+                ..Default::default()
+            };
+            value_model
+                .save(&txn)
+                .await
+                .map_err(|err| err.into_domain())?;
+        }
+
+        txn.commit().await.map_err(|err| err.into_domain())?;
+
+        Ok(product_attribute_id)
+    }
+
+    async fn save_value(&self, event: &AddAttributeValue) -> Result<i32, SaveRepoFailure> {
+        let value_model = product_attribute_value::ActiveModel {
+            attribute_id: ActiveValue::Set(event.attribute_id),
+            value: ActiveValue::Set(event.value.to_owned()),
+            ..Default::default()
+        };
+
+        value_model
+            .save(self.db.as_ref())
+            .await
+            .map(|model| model.product_attribute_value_id.unwrap())
+            .map_err(|err| err.into_domain())
+    }
+}
@@ -0,0 +1,101 @@
+use super::factories::barcode::ProductBarcodeAndProduct;
+use crate::domain::changes::barcode::AddProductBarcode;
+use crate::domain::ports::spi::barcode::ProductBarcodeRepository;
+use crate::domain::selectors::barcode::{ProductBarcodeSelector, ScannedProductSelector};
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+use ids_std_sea::convert::into::IntoDomain;
+use lumx_sea_orm::sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DbConn, EntityTrait, JoinType, QueryFilter,
+    QueryOrder, QuerySelect, RelationTrait,
+};
+use portal_schema::{product, product_barcode};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct ProductBarcodeSeaRepository {
+    db: Arc<DbConn>,
+}
+
+impl ProductBarcodeSeaRepository {
+    pub fn new(db: &Arc<DbConn>) -> Self {
+        Self {
+            db: Arc::clone(&db),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ProductBarcodeRepository for ProductBarcodeSeaRepository {
+    async fn find_by_gtin(
+        &self,
+        gtin: &str,
+    ) -> Result<Option<ProductBarcodeSelector>, SelectRepoFailure> {
+        let maybe_model = product_barcode::Entity::find()
+            .filter(product_barcode::Column::Gtin.eq(gtin))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(ProductBarcodeSelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_by_product(
+        &self,
+        product_id: i32,
+    ) -> Result<Vec<ProductBarcodeSelector>, SelectRepoFailure> {
+        let models = product_barcode::Entity::find()
+            .filter(product_barcode::Column::ProductId.eq(product_id))
+            .order_by_asc(product_barcode::Column::ProductBarcodeId)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(models
+            .into_iter()
+            .map(ProductBarcodeSelector::from)
+            .collect())
+    }
+
+    async fn find_scanned(
+        &self,
+        gtin: &str,
+    ) -> Result<Option<ScannedProductSelector>, SelectRepoFailure> {
+        let maybe_model = product_barcode::Entity::find()
+            .select_only()
+            .column(product_barcode::Column::ProductId)
+            .column_as(product::Column::Name, "product_name")
+            .column(product::Column::Sku)
+            .column(product_barcode::Column::Code)
+            .column(product_barcode::Column::Symbology)
+            .column(product::Column::DiscontinuedAt)
+            .join(
+                JoinType::InnerJoin,
+                product_barcode::Relation::Product.def(),
+            )
+            .filter(product_barcode::Column::Gtin.eq(gtin))
+            .into_model::<ProductBarcodeAndProduct>()
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(ScannedProductSelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn save(&self, event: &AddProductBarcode) -> Result<i32, SaveRepoFailure> {
+        let barcode_model = product_barcode::ActiveModel {
+            product_id: ActiveValue::Set(event.product_id),
+            code: ActiveValue::Set(event.code.to_owned()),
+            gtin: ActiveValue::Set(event.gtin.to_owned()),
+            symbology: ActiveValue::Set(event.symbology.as_str().to_string()),
+            ..Default::default()
+        };
+
+        barcode_model
+            .save(self.db.as_ref())
+            .await
+            .map(|model| model.product_barcode_id.unwrap())
+            .map_err(|err| err.into_domain())
+    }
+}
@@ -0,0 +1,222 @@
+use crate::domain::changes::count::{
+    AddInventoryCount, AddInventoryCountLine, CountInventoryLine, PostInventoryCount,
+    StartInventoryCount,
+};
+use crate::domain::ports::spi::count::InventoryCountRepository;
+use crate::domain::queries::count::InventoryCountQuery;
+use crate::domain::selectors::count::{InventoryCountLineSelector, InventoryCountSelector};
+use crate::domain::valuables::count::InventoryCountStatus;
+use ids_std_domain::pagination::{Page, PaginationQuery};
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+use ids_std_sea::convert::into::IntoDomain;
+use ids_std_sea::paginator;
+use lumx_sea_orm::sea_orm::sqlx::types::chrono::Utc;
+use lumx_sea_orm::sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DbConn, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, TransactionTrait,
+};
+use portal_schema::{inventory_count, inventory_count_line, stock_movement};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct InventoryCountSeaRepository {
+    db: Arc<DbConn>,
+}
+
+impl InventoryCountSeaRepository {
+    pub fn new(db: &Arc<DbConn>) -> Self {
+        Self {
+            db: Arc::clone(&db),
+        }
+    }
+}
+
+fn count_line_model(event: &AddInventoryCountLine) -> inventory_count_line::ActiveModel {
+    inventory_count_line::ActiveModel {
+        inventory_count_id: ActiveValue::Set(event.inventory_count_id),
+        product_id: ActiveValue::Set(event.product_id),
+        storage_location_id: ActiveValue::Set(event.storage_location_id),
+        book_quantity: ActiveValue::Set(event.book_quantity),
+        counted_quantity: ActiveValue::Set(event.counted_quantity),
+        ..Default::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl InventoryCountRepository for InventoryCountSeaRepository {
+    async fn find_by_id(
+        &self,
+        id: i32,
+    ) -> Result<Option<InventoryCountSelector>, SelectRepoFailure> {
+        let maybe_model = inventory_count::Entity::find_by_id(id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(|model| InventoryCountSelector::from(&model));
+
+        Ok(maybe_model)
+    }
+
+    async fn find_all(
+        &self,
+        query: &PaginationQuery,
+        criteria: &InventoryCountQuery,
+    ) -> Result<Page<InventoryCountSelector>, SelectRepoFailure> {
+        let mut select = inventory_count::Entity::find();
+        if let Some(status) = criteria.status {
+            select = select.filter(inventory_count::Column::Status.eq(status.as_str()));
+        }
+        if let Some(warehouse_id) = criteria.warehouse_id {
+            select = select.filter(inventory_count::Column::WarehouseId.eq(warehouse_id));
+        }
+
+        let paginator = select
+            .order_by_desc(inventory_count::Column::InventoryCountId)
+            .paginate(self.db.as_ref(), query.page_size);
+
+        paginator::fetch_page(&paginator, query, |model| {
+            InventoryCountSelector::from(model)
+        })
+        .await
+    }
+
+    async fn find_lines(
+        &self,
+        inventory_count_id: i32,
+    ) -> Result<Vec<InventoryCountLineSelector>, SelectRepoFailure> {
+        let models = inventory_count_line::Entity::find()
+            .filter(inventory_count_line::Column::InventoryCountId.eq(inventory_count_id))
+            .order_by_asc(inventory_count_line::Column::InventoryCountLineId)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(models
+            .into_iter()
+            .map(InventoryCountLineSelector::from)
+            .collect())
+    }
+
+    async fn save(&self, event: &AddInventoryCount) -> Result<i32, SaveRepoFailure> {
+        let inventory_count_model = inventory_count::ActiveModel {
+            warehouse_id: ActiveValue::Set(event.warehouse_id),
+            family_id: ActiveValue::Set(event.family_id),
+            status: ActiveValue::Set(InventoryCountStatus::Open.as_str().to_owned()),
+            opened_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        inventory_count_model
+            .save(self.db.as_ref())
+            .await
+            .map(|model| model.inventory_count_id.unwrap())
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn start(&self, event: &StartInventoryCount) -> Result<(), SaveRepoFailure> {
+        let txn = self.db.begin().await.map_err(|err| err.into_domain())?;
+
+        for line in &event.lines {
+            count_line_model(line)
+                .save(&txn)
+                .await
+                .map_err(|err| err.into_domain())?;
+        }
+
+        let result = inventory_count::Entity::find_by_id(event.inventory_count_id)
+            .one(&txn)
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        let mut count_to_start: inventory_count::ActiveModel = result.unwrap().into();
+        count_to_start.status =
+            ActiveValue::Set(InventoryCountStatus::Counting.as_str().to_owned());
+        count_to_start
+            .save(&txn)
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        txn.commit().await.map_err(|err| err.into_domain())
+    }
+
+    async fn add_line(&self, event: &AddInventoryCountLine) -> Result<i32, SaveRepoFailure> {
+        count_line_model(event)
+            .save(self.db.as_ref())
+            .await
+            .map(|model| model.inventory_count_line_id.unwrap())
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn count_line(&self, event: &CountInventoryLine) -> Result<(), SaveRepoFailure> {
+        let result = inventory_count_line::Entity::find_by_id(event.inventory_count_line_id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        let mut line_to_count: inventory_count_line::ActiveModel = result.unwrap().into();
+        line_to_count.counted_quantity = ActiveValue::Set(Some(event.counted_quantity));
+        line_to_count
+            .save(self.db.as_ref())
+            .await
+            .map(|_| {})
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn review(&self, inventory_count_id: i32) -> Result<(), SaveRepoFailure> {
+        let result = inventory_count::Entity::find_by_id(inventory_count_id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        let mut count_to_review: inventory_count::ActiveModel = result.unwrap().into();
+        count_to_review.status =
+            ActiveValue::Set(InventoryCountStatus::Reviewed.as_str().to_owned());
+        count_to_review
+            .save(self.db.as_ref())
+            .await
+            .map(|_| {})
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn post(&self, event: &PostInventoryCount) -> Result<(), SaveRepoFailure> {
+        let txn = self.db.begin().await.map_err(|err| err.into_domain())?;
+
+        for adjustment in &event.adjustments {
+            let stock_movement_model = stock_movement::ActiveModel {
+                product_id: ActiveValue::Set(adjustment.product_id),
+                kind: ActiveValue::Set(adjustment.kind.as_str().to_owned()),
+                quantity: ActiveValue::Set(adjustment.quantity),
+                storage_location_id: ActiveValue::Set(adjustment.storage_location_id),
+                stock_transfer_id: ActiveValue::Set(None),
+                lot_number: ActiveValue::Set(adjustment.lot_number.to_owned()),
+                serial_number: ActiveValue::Set(adjustment.serial_number.to_owned()),
+                unit_cost: ActiveValue::Set(
+                    adjustment.unit_cost.map(|unit_cost| unit_cost.minor()),
+                ),
+                reason: ActiveValue::Set(adjustment.reason.to_owned()),
+                moved_at: ActiveValue::Set(Utc::now().naive_utc()),
+                ..Default::default()
+            };
+
+            stock_movement_model
+                .save(&txn)
+                .await
+                .map_err(|err| err.into_domain())?;
+        }
+
+        let result = inventory_count::Entity::find_by_id(event.inventory_count_id)
+            .one(&txn)
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        let mut count_to_post: inventory_count::ActiveModel = result.unwrap().into();
+        count_to_post.status = ActiveValue::Set(InventoryCountStatus::Posted.as_str().to_owned());
+        count_to_post.posted_at = ActiveValue::Set(Some(Utc::now().naive_utc()));
+        count_to_post
+            .save(&txn)
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        txn.commit().await.map_err(|err| err.into_domain())
+    }
+}
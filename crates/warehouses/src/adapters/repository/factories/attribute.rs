@@ -0,0 +1,31 @@
+use crate::domain::selectors::attribute::{AttributeValueSelector, ProductAttributeSelector};
+use lumx_sea_orm::sea_orm;
+use portal_schema::product_attribute;
+
+impl From<product_attribute::Model> for ProductAttributeSelector {
+    fn from(value: product_attribute::Model) -> Self {
+        Self {
+            product_attribute_id: value.product_attribute_id,
+            name: value.name,
+        }
+    }
+}
+
+#[derive(sea_orm::FromQueryResult)]
+pub struct AttributeValueAndAttribute {
+    pub product_attribute_value_id: i32,
+    pub attribute_id: i32,
+    pub attribute_name: String,
+    pub value: String,
+}
+
+impl From<AttributeValueAndAttribute> for AttributeValueSelector {
+    fn from(value: AttributeValueAndAttribute) -> Self {
+        Self {
+            product_attribute_value_id: value.product_attribute_value_id,
+            attribute_id: value.attribute_id,
+            attribute_name: value.attribute_name,
+            value: value.value,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::domain::selectors::barcode::{ProductBarcodeSelector, ScannedProductSelector};
+use crate::domain::valuables::barcode::BarcodeSymbology;
+use lumx_sea_orm::sea_orm;
+use lumx_sea_orm::sea_orm::prelude::DateTime;
+use portal_schema::product_barcode;
+
+impl From<product_barcode::Model> for ProductBarcodeSelector {
+    fn from(value: product_barcode::Model) -> Self {
+        Self {
+            product_barcode_id: value.product_barcode_id,
+            product_id: value.product_id,
+            code: value.code,
+            gtin: value.gtin,
+            symbology: BarcodeSymbology::parse(value.symbology.as_str())
+                .unwrap_or(BarcodeSymbology::Gtin14),
+        }
+    }
+}
+
+#[derive(sea_orm::FromQueryResult)]
+pub struct ProductBarcodeAndProduct {
+    pub product_id: i32,
+    pub product_name: String,
+    pub sku: Option<String>,
+    pub code: String,
+    pub symbology: String,
+    pub discontinued_at: Option<DateTime>,
+}
+
+impl From<ProductBarcodeAndProduct> for ScannedProductSelector {
+    fn from(value: ProductBarcodeAndProduct) -> Self {
+        Self {
+            product_id: value.product_id,
+            product_name: value.product_name,
+            sku: value.sku,
+            code: value.code,
+            symbology: BarcodeSymbology::parse(value.symbology.as_str())
+                .unwrap_or(BarcodeSymbology::Gtin14),
+            discontinued: value.discontinued_at.is_some(),
+        }
+    }
+}
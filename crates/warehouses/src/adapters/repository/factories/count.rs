@@ -0,0 +1,29 @@
+use crate::domain::selectors::count::{InventoryCountLineSelector, InventoryCountSelector};
+use crate::domain::valuables::count::InventoryCountStatus;
+use portal_schema::{inventory_count, inventory_count_line};
+
+impl From<&inventory_count::Model> for InventoryCountSelector {
+    fn from(model: &inventory_count::Model) -> Self {
+        Self {
+            inventory_count_id: model.inventory_count_id,
+            warehouse_id: model.warehouse_id,
+            family_id: model.family_id,
+            status: InventoryCountStatus::parse(&model.status)
+                .unwrap_or(InventoryCountStatus::Open),
+            opened_at: model.opened_at,
+            posted_at: model.posted_at,
+        }
+    }
+}
+
+impl From<inventory_count_line::Model> for InventoryCountLineSelector {
+    fn from(model: inventory_count_line::Model) -> Self {
+        Self {
+            inventory_count_line_id: model.inventory_count_line_id,
+            product_id: model.product_id,
+            storage_location_id: model.storage_location_id,
+            book_quantity: model.book_quantity,
+            counted_quantity: model.counted_quantity,
+        }
+    }
+}
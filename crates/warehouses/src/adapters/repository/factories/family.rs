@@ -1,10 +1,25 @@
-use crate::domain::selectors::family::ProductFamilySelector;
+use crate::domain::selectors::family::{ProductFamilyPageSelector, ProductFamilySelector};
 use portal_schema::product_family;
 
 impl From<product_family::Model> for ProductFamilySelector {
     fn from(model: product_family::Model) -> Self {
         Self {
             product_family_id: model.product_family_id,
+            name: model.name,
+            summary: model.summary,
+            parent_family_id: model.parent_family_id,
+            signature: model.signature,
+        }
+    }
+}
+
+impl From<&product_family::Model> for ProductFamilyPageSelector {
+    fn from(model: &product_family::Model) -> Self {
+        Self {
+            product_family_id: model.product_family_id,
+            name: model.name.to_string(),
+            summary: model.summary.clone(),
+            parent_family_id: model.parent_family_id,
         }
     }
 }
@@ -0,0 +1,13 @@
+use crate::domain::selectors::kit::KitComponentSelector;
+use portal_schema::product_component;
+
+impl From<product_component::Model> for KitComponentSelector {
+    fn from(model: product_component::Model) -> Self {
+        Self {
+            product_component_id: model.product_component_id,
+            kit_product_id: model.kit_product_id,
+            component_product_id: model.component_product_id,
+            quantity: model.quantity,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use crate::domain::selectors::location::{StorageLocationPageSelector, StorageLocationSelector};
+use lumx_sea_orm::sea_orm;
+use portal_schema::storage_location;
+
+#[derive(sea_orm::FromQueryResult)]
+pub struct StorageLocationAndWarehouse {
+    pub storage_location_id: i32,
+    pub warehouse_id: i32,
+    pub warehouse_code: String,
+    pub aisle: String,
+    pub shelf: String,
+    pub bin: String,
+}
+
+impl From<StorageLocationAndWarehouse> for StorageLocationSelector {
+    fn from(value: StorageLocationAndWarehouse) -> Self {
+        Self {
+            storage_location_id: value.storage_location_id,
+            warehouse_id: value.warehouse_id,
+            warehouse_code: value.warehouse_code,
+            aisle: value.aisle,
+            shelf: value.shelf,
+            bin: value.bin,
+        }
+    }
+}
+
+impl From<&storage_location::Model> for StorageLocationPageSelector {
+    fn from(model: &storage_location::Model) -> Self {
+        Self {
+            storage_location_id: model.storage_location_id,
+            warehouse_id: model.warehouse_id,
+            aisle: model.aisle.to_string(),
+            shelf: model.shelf.to_string(),
+            bin: model.bin.to_string(),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use crate::domain::selectors::lot::StockLotSelector;
+use portal_schema::stock_lot;
+
+impl From<stock_lot::Model> for StockLotSelector {
+    fn from(model: stock_lot::Model) -> Self {
+        Self {
+            stock_lot_id: model.stock_lot_id,
+            product_id: model.product_id,
+            lot_number: model.lot_number,
+            expires_on: model.expires_on,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::domain::selectors::price::{PriceListSelector, ProductPriceSelector};
+use crate::domain::valuables::money::Money;
+use crate::domain::valuables::price::PriceListKind;
+use portal_schema::{price_list, product_price};
+
+impl From<price_list::Model> for PriceListSelector {
+    fn from(model: price_list::Model) -> Self {
+        Self {
+            price_list_id: model.price_list_id,
+            name: model.name,
+            kind: PriceListKind::parse(model.kind.as_str()).unwrap_or(PriceListKind::Retail),
+            currency: model.currency,
+            valid_from: model.valid_from,
+            valid_to: model.valid_to,
+        }
+    }
+}
+
+impl From<product_price::Model> for ProductPriceSelector {
+    fn from(model: product_price::Model) -> Self {
+        Self {
+            product_price_id: model.product_price_id,
+            price_list_id: model.price_list_id,
+            product_id: model.product_id,
+            amount: Money::from_minor(model.amount),
+            valid_from: model.valid_from,
+            valid_to: model.valid_to,
+        }
+    }
+}
@@ -1,11 +1,82 @@
-use crate::domain::selectors::product::ProductSelector;
+use crate::domain::selectors::product::{
+    ProductDetailsSelector, ProductPageSelector, ProductSelector,
+};
+use crate::domain::valuables::tracking::TrackingMode;
+use crate::domain::valuables::valuation::CostingMethod;
+use lumx_sea_orm::sea_orm;
+use lumx_sea_orm::sea_orm::prelude::DateTime;
 use portal_schema::product;
 
 impl From<product::Model> for ProductSelector {
     fn from(value: product::Model) -> Self {
         Self {
             product_id: value.product_id,
+            family_id: value.family_id,
             signature: value.signature.to_owned(),
+            purchasable: value.purchasable != 0,
+            saleable: value.saleable != 0,
+            saleable_without_stock: value.saleable_without_stock != 0,
+            discontinued: value.discontinued_at.is_some(),
+            tracking_mode: TrackingMode::parse(value.tracking_mode.as_str())
+                .unwrap_or(TrackingMode::None),
+            base_unit_id: value.base_unit_id,
+            purchase_unit_id: value.purchase_unit_id,
+            sale_unit_id: value.sale_unit_id,
+            sku: value.sku,
+            costing_method: CostingMethod::parse(value.costing_method.as_str())
+                .unwrap_or(CostingMethod::MovingAverage),
+        }
+    }
+}
+
+#[derive(sea_orm::FromQueryResult)]
+pub struct ProductAndFamily {
+    pub product_id: i32,
+    pub family_id: i32,
+    pub family_name: String,
+    pub name: String,
+    pub summary: Option<String>,
+    pub purchasable: i8,
+    pub saleable: i8,
+    pub saleable_without_stock: i8,
+    pub signature: String,
+    pub discontinued_at: Option<DateTime>,
+    pub tracking_mode: String,
+}
+
+impl From<ProductAndFamily> for ProductDetailsSelector {
+    fn from(value: ProductAndFamily) -> Self {
+        Self {
+            product_id: value.product_id,
+            family_id: value.family_id,
+            family_name: value.family_name,
+            name: value.name,
+            summary: value.summary,
+            purchasable: value.purchasable != 0,
+            saleable: value.saleable != 0,
+            saleable_without_stock: value.saleable_without_stock != 0,
+            discontinued: value.discontinued_at.is_some(),
+            tracking_mode: TrackingMode::parse(value.tracking_mode.as_str())
+                .unwrap_or(TrackingMode::None),
+            signature: value.signature,
+        }
+    }
+}
+
+impl From<&ProductAndFamily> for ProductPageSelector {
+    fn from(value: &ProductAndFamily) -> Self {
+        Self {
+            product_id: value.product_id,
+            family_id: value.family_id,
+            family_name: value.family_name.to_string(),
+            name: value.name.to_string(),
+            summary: value.summary.clone(),
+            purchasable: value.purchasable != 0,
+            saleable: value.saleable != 0,
+            saleable_without_stock: value.saleable_without_stock != 0,
+            discontinued: value.discontinued_at.is_some(),
+            tracking_mode: TrackingMode::parse(value.tracking_mode.as_str())
+                .unwrap_or(TrackingMode::None),
         }
     }
 }
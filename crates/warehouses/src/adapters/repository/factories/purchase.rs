@@ -0,0 +1,32 @@
+use crate::domain::selectors::purchase::{PurchaseOrderLineSelector, PurchaseOrderSelector};
+use crate::domain::valuables::money::Money;
+use crate::domain::valuables::purchase::PurchaseOrderStatus;
+use portal_schema::{purchase_order, purchase_order_line};
+
+impl From<&purchase_order::Model> for PurchaseOrderSelector {
+    fn from(model: &purchase_order::Model) -> Self {
+        Self {
+            purchase_order_id: model.purchase_order_id,
+            supplier_id: model.supplier_id,
+            status: PurchaseOrderStatus::parse(&model.status).unwrap_or(PurchaseOrderStatus::Draft),
+            currency: model.currency.to_owned(),
+            created_at: model.created_at,
+            approved_at: model.approved_at,
+            sent_at: model.sent_at,
+            closed_at: model.closed_at,
+        }
+    }
+}
+
+impl From<purchase_order_line::Model> for PurchaseOrderLineSelector {
+    fn from(model: purchase_order_line::Model) -> Self {
+        Self {
+            purchase_order_line_id: model.purchase_order_line_id,
+            product_id: model.product_id,
+            ordered_quantity: model.ordered_quantity,
+            received_quantity: model.received_quantity,
+            unit_id: model.unit_id,
+            unit_price: Money::from_minor(model.unit_price),
+        }
+    }
+}
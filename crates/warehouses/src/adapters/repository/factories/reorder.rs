@@ -0,0 +1,21 @@
+use crate::domain::selectors::reorder::ReorderPointSelector;
+use lumx_sea_orm::sea_orm;
+
+#[derive(sea_orm::FromQueryResult)]
+pub struct ReorderPointAndProduct {
+    pub product_id: i32,
+    pub product_name: String,
+    pub min_quantity: i32,
+    pub max_quantity: Option<i32>,
+}
+
+impl From<ReorderPointAndProduct> for ReorderPointSelector {
+    fn from(value: ReorderPointAndProduct) -> Self {
+        Self {
+            product_id: value.product_id,
+            product_name: value.product_name,
+            min_quantity: value.min_quantity,
+            max_quantity: value.max_quantity,
+        }
+    }
+}
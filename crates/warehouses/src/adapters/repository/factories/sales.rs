@@ -0,0 +1,29 @@
+use crate::domain::selectors::sales::{SalesOrderLineSelector, SalesOrderSelector};
+use crate::domain::valuables::sales::SalesOrderStatus;
+use portal_schema::{sales_order, sales_order_line};
+
+impl From<&sales_order::Model> for SalesOrderSelector {
+    fn from(model: &sales_order::Model) -> Self {
+        Self {
+            sales_order_id: model.sales_order_id,
+            customer_id: model.customer_id,
+            status: SalesOrderStatus::parse(&model.status).unwrap_or(SalesOrderStatus::Draft),
+            created_at: model.created_at,
+            confirmed_at: model.confirmed_at,
+            cancelled_at: model.cancelled_at,
+            fulfilled_at: model.fulfilled_at,
+        }
+    }
+}
+
+impl From<sales_order_line::Model> for SalesOrderLineSelector {
+    fn from(model: sales_order_line::Model) -> Self {
+        Self {
+            sales_order_line_id: model.sales_order_line_id,
+            product_id: model.product_id,
+            ordered_quantity: model.ordered_quantity,
+            reserved_quantity: model.reserved_quantity,
+            backordered_quantity: model.backordered_quantity,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::domain::selectors::stock::{CostedMovementSelector, StockMovementPageSelector};
+use crate::domain::valuables::money::Money;
+use crate::domain::valuables::stock::StockMovementKind;
+use portal_schema::stock_movement;
+
+impl From<&stock_movement::Model> for StockMovementPageSelector {
+    fn from(model: &stock_movement::Model) -> Self {
+        Self {
+            stock_movement_id: model.stock_movement_id,
+            product_id: model.product_id,
+            kind: StockMovementKind::parse(&model.kind).unwrap_or(StockMovementKind::Adjustment),
+            quantity: model.quantity,
+            storage_location_id: model.storage_location_id,
+            stock_transfer_id: model.stock_transfer_id,
+            lot_number: model.lot_number.clone(),
+            serial_number: model.serial_number.clone(),
+            unit_cost: model.unit_cost.map(Money::from_minor),
+            reason: model.reason.clone(),
+            moved_at: model.moved_at,
+        }
+    }
+}
+
+impl From<&stock_movement::Model> for CostedMovementSelector {
+    fn from(model: &stock_movement::Model) -> Self {
+        Self {
+            product_id: model.product_id,
+            kind: StockMovementKind::parse(&model.kind).unwrap_or(StockMovementKind::Adjustment),
+            quantity: model.quantity,
+            unit_cost: model.unit_cost.map(Money::from_minor),
+        }
+    }
+}
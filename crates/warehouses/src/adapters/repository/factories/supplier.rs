@@ -0,0 +1,63 @@
+use crate::domain::selectors::supplier::{
+    SupplierOfferSelector, SupplierProductSelector, SupplierSelector,
+};
+use crate::domain::valuables::money::Money;
+use lumx_sea_orm::sea_orm;
+use portal_schema::{supplier, supplier_product};
+
+impl From<supplier::Model> for SupplierSelector {
+    fn from(model: supplier::Model) -> Self {
+        Self {
+            supplier_id: model.supplier_id,
+            name: model.name,
+            contact_person_id: model.contact_person_id,
+            currency: model.currency,
+        }
+    }
+}
+
+impl From<supplier_product::Model> for SupplierProductSelector {
+    fn from(model: supplier_product::Model) -> Self {
+        Self {
+            supplier_product_id: model.supplier_product_id,
+            supplier_id: model.supplier_id,
+            product_id: model.product_id,
+            supplier_code: model.supplier_code,
+            pack_size: model.pack_size,
+            lead_time_days: model.lead_time_days,
+            last_price: model.last_price.map(Money::from_minor),
+            preferred: model.preferred != 0,
+        }
+    }
+}
+
+#[derive(sea_orm::FromQueryResult)]
+pub struct SupplierProductAndSupplier {
+    pub supplier_product_id: i32,
+    pub supplier_id: i32,
+    pub supplier_name: String,
+    pub currency: String,
+    pub product_id: i32,
+    pub supplier_code: String,
+    pub pack_size: i32,
+    pub lead_time_days: i32,
+    pub last_price: Option<i64>,
+    pub preferred: i8,
+}
+
+impl From<SupplierProductAndSupplier> for SupplierOfferSelector {
+    fn from(value: SupplierProductAndSupplier) -> Self {
+        Self {
+            supplier_product_id: value.supplier_product_id,
+            supplier_id: value.supplier_id,
+            supplier_name: value.supplier_name,
+            currency: value.currency,
+            product_id: value.product_id,
+            supplier_code: value.supplier_code,
+            pack_size: value.pack_size,
+            lead_time_days: value.lead_time_days,
+            last_price: value.last_price.map(Money::from_minor),
+            preferred: value.preferred != 0,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::domain::selectors::transfer::StockTransferSelector;
+use crate::domain::valuables::money::Money;
+use crate::domain::valuables::transfer::StockTransferStatus;
+use portal_schema::stock_transfer;
+
+impl From<&stock_transfer::Model> for StockTransferSelector {
+    fn from(model: &stock_transfer::Model) -> Self {
+        Self {
+            stock_transfer_id: model.stock_transfer_id,
+            product_id: model.product_id,
+            source_location_id: model.source_location_id,
+            source_warehouse_id: model.source_warehouse_id,
+            destination_warehouse_id: model.destination_warehouse_id,
+            quantity: model.quantity,
+            received_quantity: model.received_quantity,
+            status: StockTransferStatus::parse(&model.status)
+                .unwrap_or(StockTransferStatus::InTransit),
+            transferred_at: model.transferred_at,
+            closed_at: model.closed_at,
+            lot_number: model.lot_number.clone(),
+            serial_number: model.serial_number.clone(),
+            unit_cost: model.unit_cost.map(Money::from_minor),
+        }
+    }
+}
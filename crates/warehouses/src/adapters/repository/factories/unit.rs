@@ -0,0 +1,23 @@
+use crate::domain::selectors::unit::{UnitConversionSelector, UnitOfMeasureSelector};
+use portal_schema::{unit_conversion, unit_of_measure};
+
+impl From<unit_of_measure::Model> for UnitOfMeasureSelector {
+    fn from(model: unit_of_measure::Model) -> Self {
+        Self {
+            unit_of_measure_id: model.unit_of_measure_id,
+            code: model.code,
+            name: model.name,
+        }
+    }
+}
+
+impl From<unit_conversion::Model> for UnitConversionSelector {
+    fn from(model: unit_conversion::Model) -> Self {
+        Self {
+            unit_conversion_id: model.unit_conversion_id,
+            from_unit_id: model.from_unit_id,
+            to_unit_id: model.to_unit_id,
+            factor: model.factor,
+        }
+    }
+}
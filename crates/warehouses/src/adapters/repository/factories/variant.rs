@@ -0,0 +1,35 @@
+use crate::domain::selectors::attribute::AttributeValueSelector;
+use crate::domain::selectors::variant::ProductVariantSelector;
+use lumx_sea_orm::sea_orm;
+use portal_schema::product_variant;
+
+impl From<product_variant::Model> for ProductVariantSelector {
+    fn from(value: product_variant::Model) -> Self {
+        Self {
+            product_variant_id: value.product_variant_id,
+            product_id: value.product_id,
+            sku: value.sku,
+            signature: value.signature,
+        }
+    }
+}
+
+#[derive(sea_orm::FromQueryResult)]
+pub struct VariantValueAndAttribute {
+    pub variant_id: i32,
+    pub product_attribute_value_id: i32,
+    pub attribute_id: i32,
+    pub attribute_name: String,
+    pub value: String,
+}
+
+impl From<VariantValueAndAttribute> for AttributeValueSelector {
+    fn from(value: VariantValueAndAttribute) -> Self {
+        Self {
+            product_attribute_value_id: value.product_attribute_value_id,
+            attribute_id: value.attribute_id,
+            attribute_name: value.attribute_name,
+            value: value.value,
+        }
+    }
+}
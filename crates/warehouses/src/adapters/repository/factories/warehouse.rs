@@ -0,0 +1,24 @@
+use crate::domain::selectors::warehouse::{WarehousePageSelector, WarehouseSelector};
+use portal_schema::warehouse;
+
+impl From<warehouse::Model> for WarehouseSelector {
+    fn from(model: warehouse::Model) -> Self {
+        Self {
+            warehouse_id: model.warehouse_id,
+            code: model.code,
+            name: model.name,
+            address: model.address,
+        }
+    }
+}
+
+impl From<&warehouse::Model> for WarehousePageSelector {
+    fn from(model: &warehouse::Model) -> Self {
+        Self {
+            warehouse_id: model.warehouse_id,
+            code: model.code.to_string(),
+            name: model.name.to_string(),
+            address: model.address.clone(),
+        }
+    }
+}
@@ -1,8 +1,15 @@
+use crate::domain::changes::family::{AddProductFamily, UpdateProductFamily};
 use crate::domain::ports::spi::family::ProductFamilyRepository;
-use crate::domain::selectors::family::ProductFamilySelector;
-use ids_std_domain::spi::failure::SelectRepoFailure;
+use crate::domain::queries::family::ProductFamilyQuery;
+use crate::domain::selectors::family::{ProductFamilyPageSelector, ProductFamilySelector};
+use ids_std_domain::pagination::{Page, PaginationQuery};
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
 use ids_std_sea::convert::into::IntoDomain;
-use lumx_sea_orm::sea_orm::{DbConn, EntityTrait};
+use ids_std_sea::paginator;
+use lumx_sea_orm::sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DbConn, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder,
+};
 use portal_schema::product_family;
 use std::sync::Arc;
 
@@ -33,4 +40,106 @@ impl ProductFamilyRepository for ProductFamilySeaRepository {
 
         Ok(maybe_model)
     }
+
+    async fn find_by_signature(
+        &self,
+        signature: &str,
+    ) -> Result<Option<ProductFamilySelector>, SelectRepoFailure> {
+        let maybe_model = product_family::Entity::find()
+            .filter(product_family::Column::Signature.eq(signature))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(ProductFamilySelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_other_by_signature(
+        &self,
+        self_id: i32,
+        signature: &str,
+    ) -> Result<Option<ProductFamilySelector>, SelectRepoFailure> {
+        let maybe_model = product_family::Entity::find()
+            .filter(product_family::Column::Signature.eq(signature))
+            .filter(product_family::Column::ProductFamilyId.ne(self_id))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(ProductFamilySelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_children(
+        &self,
+        parent_family_ids: &[i32],
+    ) -> Result<Vec<ProductFamilySelector>, SelectRepoFailure> {
+        let models = product_family::Entity::find()
+            .filter(product_family::Column::ParentFamilyId.is_in(parent_family_ids.to_vec()))
+            .order_by_asc(product_family::Column::ProductFamilyId)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(models
+            .into_iter()
+            .map(ProductFamilySelector::from)
+            .collect())
+    }
+
+    async fn find_all(
+        &self,
+        query: &PaginationQuery,
+        criteria: &ProductFamilyQuery,
+    ) -> Result<Page<ProductFamilyPageSelector>, SelectRepoFailure> {
+        let mut select = product_family::Entity::find();
+        if let Some(name) = &criteria.name {
+            select = select.filter(product_family::Column::Name.contains(name));
+        }
+
+        let paginator = select
+            .order_by_desc(product_family::Column::ProductFamilyId)
+            .paginate(self.db.as_ref(), query.page_size);
+
+        paginator::fetch_page(&paginator, query, |model| {
+            ProductFamilyPageSelector::from(model)
+        })
+        .await
+    }
+
+    async fn save(&self, event: &AddProductFamily) -> Result<i32, SaveRepoFailure> {
+        let family_model = product_family::ActiveModel {
+            name: ActiveValue::Set(event.name.to_owned()),
+            summary: ActiveValue::Set(event.summary.to_owned()),
+            parent_family_id: ActiveValue::Set(event.parent_family_id),
+            signature: ActiveValue::Set(event.signature.to_owned()),
+            ..Default::default()
+        };
+
+        family_model
+            .save(self.db.as_ref())
+            .await
+            .map(|model| model.product_family_id.unwrap())
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn update(&self, event: &UpdateProductFamily) -> Result<(), SaveRepoFailure> {
+        let result = product_family::Entity::find_by_id(event.product_family_id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        let mut family_to_modify: product_family::ActiveModel = result.unwrap().into();
+        family_to_modify.name = ActiveValue::Set(event.name.to_owned());
+        family_to_modify.summary = ActiveValue::Set(event.summary.to_owned());
+        family_to_modify.parent_family_id = ActiveValue::Set(event.parent_family_id);
+        family_to_modify.signature = ActiveValue::Set(event.signature.to_owned());
+
+        family_to_modify
+            .save(self.db.as_ref())
+            .await
+            .map(|_| {})
+            .map_err(|err| err.into_domain())
+    }
 }
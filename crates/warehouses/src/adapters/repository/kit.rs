@@ -0,0 +1,87 @@
+use crate::domain::changes::kit::AddKitComponent;
+use crate::domain::ports::spi::kit::KitComponentRepository;
+use crate::domain::selectors::kit::KitComponentSelector;
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+use ids_std_sea::convert::into::IntoDomain;
+use lumx_sea_orm::sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DbConn, EntityTrait, QueryFilter, QueryOrder,
+};
+use portal_schema::product_component;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct KitComponentSeaRepository {
+    db: Arc<DbConn>,
+}
+
+impl KitComponentSeaRepository {
+    pub fn new(db: &Arc<DbConn>) -> Self {
+        Self {
+            db: Arc::clone(&db),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl KitComponentRepository for KitComponentSeaRepository {
+    async fn find_component(
+        &self,
+        kit_product_id: i32,
+        component_product_id: i32,
+    ) -> Result<Option<KitComponentSelector>, SelectRepoFailure> {
+        let maybe_model = product_component::Entity::find()
+            .filter(product_component::Column::KitProductId.eq(kit_product_id))
+            .filter(product_component::Column::ComponentProductId.eq(component_product_id))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(KitComponentSelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_by_kits(
+        &self,
+        kit_product_ids: &[i32],
+    ) -> Result<Vec<KitComponentSelector>, SelectRepoFailure> {
+        let models = product_component::Entity::find()
+            .filter(product_component::Column::KitProductId.is_in(kit_product_ids.to_vec()))
+            .order_by_asc(product_component::Column::ProductComponentId)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(models.into_iter().map(KitComponentSelector::from).collect())
+    }
+
+    async fn find_by_components(
+        &self,
+        component_product_ids: &[i32],
+    ) -> Result<Vec<KitComponentSelector>, SelectRepoFailure> {
+        let models = product_component::Entity::find()
+            .filter(
+                product_component::Column::ComponentProductId.is_in(component_product_ids.to_vec()),
+            )
+            .order_by_asc(product_component::Column::ProductComponentId)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(models.into_iter().map(KitComponentSelector::from).collect())
+    }
+
+    async fn save(&self, event: &AddKitComponent) -> Result<i32, SaveRepoFailure> {
+        let component_model = product_component::ActiveModel {
+            kit_product_id: ActiveValue::Set(event.kit_product_id),
+            component_product_id: ActiveValue::Set(event.component_product_id),
+            quantity: ActiveValue::Set(event.quantity),
+            ..Default::default()
+        };
+
+        component_model
+            .save(self.db.as_ref())
+            .await
+            .map(|model| model.product_component_id.unwrap())
+            .map_err(|err| err.into_domain())
+    }
+}
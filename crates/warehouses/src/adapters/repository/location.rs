@@ -0,0 +1,153 @@
+use super::factories::location::StorageLocationAndWarehouse;
+use crate::domain::changes::location::{AddStorageLocation, UpdateStorageLocation};
+use crate::domain::ports::spi::location::StorageLocationRepository;
+use crate::domain::selectors::location::{StorageLocationPageSelector, StorageLocationSelector};
+use ids_std_domain::pagination::{Page, PaginationQuery};
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+use ids_std_sea::convert::into::IntoDomain;
+use ids_std_sea::paginator;
+use lumx_sea_orm::sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DbConn, EntityTrait, JoinType, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, RelationTrait,
+};
+use portal_schema::{storage_location, warehouse};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct StorageLocationSeaRepository {
+    db: Arc<DbConn>,
+}
+
+impl StorageLocationSeaRepository {
+    pub fn new(db: &Arc<DbConn>) -> Self {
+        Self {
+            db: Arc::clone(&db),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl StorageLocationRepository for StorageLocationSeaRepository {
+    async fn find_by_id(
+        &self,
+        id: i32,
+    ) -> Result<Option<StorageLocationSelector>, SelectRepoFailure> {
+        let maybe_model = storage_location::Entity::find_by_id(id)
+            .column_as(warehouse::Column::Code, "warehouse_code")
+            .join(
+                JoinType::InnerJoin,
+                storage_location::Relation::Warehouse.def(),
+            )
+            .into_model::<StorageLocationAndWarehouse>()
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(StorageLocationSelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_by_ids(
+        &self,
+        ids: &[i32],
+    ) -> Result<Vec<StorageLocationSelector>, SelectRepoFailure> {
+        let models = storage_location::Entity::find()
+            .filter(storage_location::Column::StorageLocationId.is_in(ids.to_vec()))
+            .column_as(warehouse::Column::Code, "warehouse_code")
+            .join(
+                JoinType::InnerJoin,
+                storage_location::Relation::Warehouse.def(),
+            )
+            .order_by_asc(warehouse::Column::Code)
+            .order_by_asc(storage_location::Column::Aisle)
+            .order_by_asc(storage_location::Column::Shelf)
+            .order_by_asc(storage_location::Column::Bin)
+            .into_model::<StorageLocationAndWarehouse>()
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(models
+            .into_iter()
+            .map(StorageLocationSelector::from)
+            .collect())
+    }
+
+    async fn find_by_address(
+        &self,
+        warehouse_id: i32,
+        aisle: &str,
+        shelf: &str,
+        bin: &str,
+    ) -> Result<Option<StorageLocationSelector>, SelectRepoFailure> {
+        let maybe_model = storage_location::Entity::find()
+            .filter(storage_location::Column::WarehouseId.eq(warehouse_id))
+            .filter(storage_location::Column::Aisle.eq(aisle))
+            .filter(storage_location::Column::Shelf.eq(shelf))
+            .filter(storage_location::Column::Bin.eq(bin))
+            .column_as(warehouse::Column::Code, "warehouse_code")
+            .join(
+                JoinType::InnerJoin,
+                storage_location::Relation::Warehouse.def(),
+            )
+            .into_model::<StorageLocationAndWarehouse>()
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(StorageLocationSelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_by_warehouse(
+        &self,
+        query: &PaginationQuery,
+        warehouse_id: i32,
+    ) -> Result<Page<StorageLocationPageSelector>, SelectRepoFailure> {
+        let paginator = storage_location::Entity::find()
+            .filter(storage_location::Column::WarehouseId.eq(warehouse_id))
+            .order_by_asc(storage_location::Column::Aisle)
+            .order_by_asc(storage_location::Column::Shelf)
+            .order_by_asc(storage_location::Column::Bin)
+            .paginate(self.db.as_ref(), query.page_size);
+
+        paginator::fetch_page(&paginator, query, |model| {
+            StorageLocationPageSelector::from(model)
+        })
+        .await
+    }
+
+    async fn save(&self, event: &AddStorageLocation) -> Result<i32, SaveRepoFailure> {
+        let location_model = storage_location::ActiveModel {
+            warehouse_id: ActiveValue::Set(event.warehouse_id),
+            aisle: ActiveValue::Set(event.aisle.to_owned()),
+            shelf: ActiveValue::Set(event.shelf.to_owned()),
+            bin: ActiveValue::Set(event.bin.to_owned()),
+            ..Default::default()
+        };
+
+        location_model
+            .save(self.db.as_ref())
+            .await
+            .map(|model| model.storage_location_id.unwrap())
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn update(&self, event: &UpdateStorageLocation) -> Result<(), SaveRepoFailure> {
+        let result = storage_location::Entity::find_by_id(event.storage_location_id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        let mut location_to_modify: storage_location::ActiveModel = result.unwrap().into();
+        location_to_modify.aisle = ActiveValue::Set(event.aisle.to_owned());
+        location_to_modify.shelf = ActiveValue::Set(event.shelf.to_owned());
+        location_to_modify.bin = ActiveValue::Set(event.bin.to_owned());
+
+        location_to_modify
+            .save(self.db.as_ref())
+            .await
+            .map(|_| {})
+            .map_err(|err| err.into_domain())
+    }
+}
@@ -0,0 +1,69 @@
+use crate::domain::ports::spi::lot::StockLotRepository;
+use crate::domain::selectors::lot::StockLotSelector;
+use chrono::NaiveDate;
+use ids_std_domain::spi::failure::SelectRepoFailure;
+use ids_std_sea::convert::into::IntoDomain;
+use lumx_sea_orm::sea_orm::{ColumnTrait, DbConn, EntityTrait, QueryFilter, QueryOrder};
+use portal_schema::stock_lot;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct StockLotSeaRepository {
+    db: Arc<DbConn>,
+}
+
+impl StockLotSeaRepository {
+    pub fn new(db: &Arc<DbConn>) -> Self {
+        Self {
+            db: Arc::clone(&db),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl StockLotRepository for StockLotSeaRepository {
+    async fn find_by_lot(
+        &self,
+        product_id: i32,
+        lot_number: &str,
+    ) -> Result<Option<StockLotSelector>, SelectRepoFailure> {
+        let maybe_model = stock_lot::Entity::find()
+            .filter(stock_lot::Column::ProductId.eq(product_id))
+            .filter(stock_lot::Column::LotNumber.eq(lot_number))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(StockLotSelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_by_product(
+        &self,
+        product_id: i32,
+    ) -> Result<Vec<StockLotSelector>, SelectRepoFailure> {
+        let models = stock_lot::Entity::find()
+            .filter(stock_lot::Column::ProductId.eq(product_id))
+            .order_by_asc(stock_lot::Column::ExpiresOn)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(models.into_iter().map(StockLotSelector::from).collect())
+    }
+
+    async fn find_expiring(
+        &self,
+        until: NaiveDate,
+    ) -> Result<Vec<StockLotSelector>, SelectRepoFailure> {
+        let models = stock_lot::Entity::find()
+            .filter(stock_lot::Column::ExpiresOn.lte(until))
+            .order_by_asc(stock_lot::Column::ExpiresOn)
+            .order_by_asc(stock_lot::Column::StockLotId)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(models.into_iter().map(StockLotSelector::from).collect())
+    }
+}
@@ -0,0 +1,110 @@
+use crate::domain::changes::price::{AddPriceList, AddProductPrice};
+use crate::domain::ports::spi::price::PriceListRepository;
+use crate::domain::selectors::price::{PriceListSelector, ProductPriceSelector};
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+use ids_std_sea::convert::into::IntoDomain;
+use lumx_sea_orm::sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DbConn, EntityTrait, QueryFilter, QueryOrder,
+};
+use portal_schema::{price_list, product_price};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct PriceListSeaRepository {
+    db: Arc<DbConn>,
+}
+
+impl PriceListSeaRepository {
+    pub fn new(db: &Arc<DbConn>) -> Self {
+        Self {
+            db: Arc::clone(&db),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl PriceListRepository for PriceListSeaRepository {
+    async fn find_by_id(&self, id: i32) -> Result<Option<PriceListSelector>, SelectRepoFailure> {
+        let maybe_model = price_list::Entity::find_by_id(id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(PriceListSelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_by_name(
+        &self,
+        name: &str,
+    ) -> Result<Option<PriceListSelector>, SelectRepoFailure> {
+        let maybe_model = price_list::Entity::find()
+            .filter(price_list::Column::Name.eq(name))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(PriceListSelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_all(&self) -> Result<Vec<PriceListSelector>, SelectRepoFailure> {
+        let models = price_list::Entity::find()
+            .order_by_asc(price_list::Column::Name)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(models.into_iter().map(PriceListSelector::from).collect())
+    }
+
+    async fn find_prices(
+        &self,
+        price_list_id: i32,
+        product_id: i32,
+    ) -> Result<Vec<ProductPriceSelector>, SelectRepoFailure> {
+        let models = product_price::Entity::find()
+            .filter(product_price::Column::PriceListId.eq(price_list_id))
+            .filter(product_price::Column::ProductId.eq(product_id))
+            .order_by_asc(product_price::Column::ValidFrom)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(models.into_iter().map(ProductPriceSelector::from).collect())
+    }
+
+    async fn save(&self, event: &AddPriceList) -> Result<i32, SaveRepoFailure> {
+        let price_list_model = price_list::ActiveModel {
+            name: ActiveValue::Set(event.name.to_owned()),
+            kind: ActiveValue::Set(event.kind.as_str().to_owned()),
+            currency: ActiveValue::Set(event.currency.to_owned()),
+            valid_from: ActiveValue::Set(event.valid_from),
+            valid_to: ActiveValue::Set(event.valid_to),
+            ..Default::default()
+        };
+
+        price_list_model
+            .save(self.db.as_ref())
+            .await
+            .map(|model| model.price_list_id.unwrap())
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn save_price(&self, event: &AddProductPrice) -> Result<i32, SaveRepoFailure> {
+        let price_model = product_price::ActiveModel {
+            price_list_id: ActiveValue::Set(event.price_list_id),
+            product_id: ActiveValue::Set(event.product_id),
+            amount: ActiveValue::Set(event.amount.minor()),
+            valid_from: ActiveValue::Set(event.valid_from),
+            valid_to: ActiveValue::Set(event.valid_to),
+            ..Default::default()
+        };
+
+        price_model
+            .save(self.db.as_ref())
+            .await
+            .map(|model| model.product_price_id.unwrap())
+            .map_err(|err| err.into_domain())
+    }
+}
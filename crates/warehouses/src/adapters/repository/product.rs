@@ -1,12 +1,23 @@
-use crate::domain::changes::product::AddProduct;
+use super::factories::product::ProductAndFamily;
+use crate::domain::changes::product::{
+    AddProduct, UpdateProduct, UpdateProductCostingMethod, UpdateProductSku, UpdateProductUnits,
+};
 use crate::domain::ports::spi::product::ProductRepository;
-use crate::domain::selectors::product::ProductSelector;
+use crate::domain::queries::product::ProductQuery;
+use crate::domain::selectors::product::{
+    ProductDetailsSelector, ProductPageSelector, ProductSelector,
+};
+use crate::domain::valuables::tracking::TrackingMode;
+use ids_std_domain::pagination::{Page, PaginationQuery};
 use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
 use ids_std_sea::convert::into::IntoDomain;
+use ids_std_sea::paginator;
+use lumx_sea_orm::sea_orm::sqlx::types::chrono::Utc;
 use lumx_sea_orm::sea_orm::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, DbConn, EntityTrait, QueryFilter,
+    ActiveModelTrait, ActiveValue, ColumnTrait, DbConn, EntityTrait, JoinType, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, RelationTrait,
 };
-use portal_schema::product;
+use portal_schema::{product, product_family};
 use std::sync::Arc;
 
 #[derive(Clone)]
@@ -33,6 +44,7 @@ impl ProductRepository for ProductSeaRepository {
             saleable: ActiveValue::Set(event.saleable.into()),
             saleable_without_stock: ActiveValue::Set(event.saleable_without_stock.into()),
             signature: ActiveValue::Set(event.signature.to_owned()),
+            tracking_mode: ActiveValue::Set(event.tracking_mode.as_str().to_owned()),
             ..Default::default()
         };
 
@@ -43,6 +55,112 @@ impl ProductRepository for ProductSeaRepository {
             .map_err(|err| err.into_domain())
     }
 
+    async fn update(&self, event: &UpdateProduct) -> Result<(), SaveRepoFailure> {
+        let result = product::Entity::find_by_id(event.product_id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        let mut product_to_modify: product::ActiveModel = result.unwrap().into();
+        product_to_modify.family_id = ActiveValue::Set(event.family_id);
+        product_to_modify.name = ActiveValue::Set(event.name.to_owned());
+        product_to_modify.summary = ActiveValue::Set(event.summary.to_owned());
+        product_to_modify.purchasable = ActiveValue::Set(event.purchasable.into());
+        product_to_modify.saleable = ActiveValue::Set(event.saleable.into());
+        product_to_modify.saleable_without_stock =
+            ActiveValue::Set(event.saleable_without_stock.into());
+        product_to_modify.signature = ActiveValue::Set(event.signature.to_owned());
+        product_to_modify.tracking_mode = ActiveValue::Set(event.tracking_mode.as_str().to_owned());
+
+        product_to_modify
+            .save(self.db.as_ref())
+            .await
+            .map(|_| {})
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn discontinue(&self, product_id: i32) -> Result<(), SaveRepoFailure> {
+        let result = product::Entity::find_by_id(product_id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        let mut product_to_discontinue: product::ActiveModel = result.unwrap().into();
+        product_to_discontinue.saleable = ActiveValue::Set(false.into());
+        product_to_discontinue.saleable_without_stock = ActiveValue::Set(false.into());
+        product_to_discontinue.discontinued_at = ActiveValue::Set(Some(Utc::now().naive_utc()));
+
+        product_to_discontinue
+            .save(self.db.as_ref())
+            .await
+            .map(|_| {})
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn update_units(&self, event: &UpdateProductUnits) -> Result<(), SaveRepoFailure> {
+        let result = product::Entity::find_by_id(event.product_id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        let mut product_to_modify: product::ActiveModel = result.unwrap().into();
+        product_to_modify.base_unit_id = ActiveValue::Set(Some(event.base_unit_id));
+        product_to_modify.purchase_unit_id = ActiveValue::Set(event.purchase_unit_id);
+        product_to_modify.sale_unit_id = ActiveValue::Set(event.sale_unit_id);
+
+        product_to_modify
+            .save(self.db.as_ref())
+            .await
+            .map(|_| {})
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn update_sku(&self, event: &UpdateProductSku) -> Result<(), SaveRepoFailure> {
+        let result = product::Entity::find_by_id(event.product_id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        let mut product_to_modify: product::ActiveModel = result.unwrap().into();
+        product_to_modify.sku = ActiveValue::Set(Some(event.sku.to_owned()));
+
+        product_to_modify
+            .save(self.db.as_ref())
+            .await
+            .map(|_| {})
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn update_costing_method(
+        &self,
+        event: &UpdateProductCostingMethod,
+    ) -> Result<(), SaveRepoFailure> {
+        let result = product::Entity::find_by_id(event.product_id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        let mut product_to_modify: product::ActiveModel = result.unwrap().into();
+        product_to_modify.costing_method =
+            ActiveValue::Set(event.costing_method.as_str().to_owned());
+
+        product_to_modify
+            .save(self.db.as_ref())
+            .await
+            .map(|_| {})
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn find_by_id(&self, id: i32) -> Result<Option<ProductSelector>, SelectRepoFailure> {
+        let maybe_model = product::Entity::find_by_id(id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(ProductSelector::from);
+
+        Ok(maybe_model)
+    }
+
     async fn find_by_signature(
         &self,
         signature: &str,
@@ -56,4 +174,122 @@ impl ProductRepository for ProductSeaRepository {
 
         Ok(maybe_model)
     }
+
+    async fn find_other_by_signature(
+        &self,
+        self_id: i32,
+        signature: &str,
+    ) -> Result<Option<ProductSelector>, SelectRepoFailure> {
+        let maybe_model = product::Entity::find()
+            .filter(product::Column::Signature.eq(signature))
+            .filter(product::Column::ProductId.ne(self_id))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(ProductSelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_by_sku(&self, sku: &str) -> Result<Option<ProductSelector>, SelectRepoFailure> {
+        let maybe_model = product::Entity::find()
+            .filter(product::Column::Sku.eq(sku))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(ProductSelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_other_by_sku(
+        &self,
+        self_id: i32,
+        sku: &str,
+    ) -> Result<Option<ProductSelector>, SelectRepoFailure> {
+        let maybe_model = product::Entity::find()
+            .filter(product::Column::Sku.eq(sku))
+            .filter(product::Column::ProductId.ne(self_id))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(ProductSelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_untracked(
+        &self,
+        family_ids: Option<Vec<i32>>,
+    ) -> Result<Vec<ProductSelector>, SelectRepoFailure> {
+        let mut select = product::Entity::find()
+            .filter(product::Column::TrackingMode.eq(TrackingMode::None.as_str()));
+        if let Some(family_ids) = family_ids {
+            select = select.filter(product::Column::FamilyId.is_in(family_ids));
+        }
+
+        let models = select
+            .order_by_asc(product::Column::ProductId)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(models.into_iter().map(ProductSelector::from).collect())
+    }
+
+    async fn find_by_ids(&self, ids: Vec<i32>) -> Result<Vec<ProductSelector>, SelectRepoFailure> {
+        let models = product::Entity::find()
+            .filter(product::Column::ProductId.is_in(ids))
+            .order_by_asc(product::Column::ProductId)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(models.into_iter().map(ProductSelector::from).collect())
+    }
+
+    async fn find_details_by_id(
+        &self,
+        id: i32,
+    ) -> Result<Option<ProductDetailsSelector>, SelectRepoFailure> {
+        let maybe_model = product::Entity::find_by_id(id)
+            .column_as(product_family::Column::Name, "family_name")
+            .join(JoinType::LeftJoin, product::Relation::ProductFamily.def())
+            .into_model::<ProductAndFamily>()
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(ProductDetailsSelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_all(
+        &self,
+        query: &PaginationQuery,
+        criteria: &ProductQuery,
+    ) -> Result<Page<ProductPageSelector>, SelectRepoFailure> {
+        let mut select = product::Entity::find();
+        if !criteria.family_ids.is_empty() {
+            select = select.filter(product::Column::FamilyId.is_in(criteria.family_ids.to_vec()));
+        }
+        if let Some(name) = &criteria.name {
+            select = select.filter(product::Column::Name.contains(name));
+        }
+        if let Some(purchasable) = criteria.purchasable {
+            select = select.filter(product::Column::Purchasable.eq(i8::from(purchasable)));
+        }
+        if let Some(saleable) = criteria.saleable {
+            select = select.filter(product::Column::Saleable.eq(i8::from(saleable)));
+        }
+
+        let paginator = select
+            .column_as(product_family::Column::Name, "family_name")
+            .join(JoinType::LeftJoin, product::Relation::ProductFamily.def())
+            .order_by_desc(product::Column::ProductId)
+            .into_model::<ProductAndFamily>()
+            .paginate(self.db.as_ref(), query.page_size);
+
+        paginator::fetch_page(&paginator, query, |model| ProductPageSelector::from(model)).await
+    }
 }
@@ -0,0 +1,307 @@
+use crate::domain::changes::purchase::{
+    AddPurchaseOrder, AddPurchaseOrderLine, ChangePurchaseOrderStatus, ReceivePurchaseOrder,
+};
+use crate::domain::ports::spi::purchase::PurchaseOrderRepository;
+use crate::domain::queries::purchase::PurchaseOrderQuery;
+use crate::domain::selectors::purchase::{PurchaseOrderLineSelector, PurchaseOrderSelector};
+use crate::domain::valuables::purchase::{LineReceipt, PurchaseOrderStatus};
+use ids_std_domain::pagination::{Page, PaginationQuery};
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+use ids_std_sea::convert::into::IntoDomain;
+use ids_std_sea::paginator;
+use lumx_sea_orm::sea_orm::sqlx::types::chrono::Utc;
+use lumx_sea_orm::sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseTransaction, DbConn, DbErr, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, TransactionTrait,
+};
+use portal_schema::{
+    purchase_order, purchase_order_line, stock_lot, stock_movement, supplier_product,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct PurchaseOrderSeaRepository {
+    db: Arc<DbConn>,
+}
+
+impl PurchaseOrderSeaRepository {
+    pub fn new(db: &Arc<DbConn>) -> Self {
+        Self {
+            db: Arc::clone(&db),
+        }
+    }
+}
+
+async fn find_order_for_update(
+    txn: &DatabaseTransaction,
+    purchase_order_id: i32,
+) -> Result<Option<purchase_order::Model>, DbErr> {
+    purchase_order::Entity::find_by_id(purchase_order_id)
+        .lock_exclusive()
+        .one(txn)
+        .await
+}
+
+#[async_trait::async_trait]
+impl PurchaseOrderRepository for PurchaseOrderSeaRepository {
+    async fn find_by_id(
+        &self,
+        id: i32,
+    ) -> Result<Option<PurchaseOrderSelector>, SelectRepoFailure> {
+        let maybe_model = purchase_order::Entity::find_by_id(id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(|model| PurchaseOrderSelector::from(&model));
+
+        Ok(maybe_model)
+    }
+
+    async fn find_all(
+        &self,
+        query: &PaginationQuery,
+        criteria: &PurchaseOrderQuery,
+    ) -> Result<Page<PurchaseOrderSelector>, SelectRepoFailure> {
+        let mut select = purchase_order::Entity::find();
+        if let Some(status) = criteria.status {
+            select = select.filter(purchase_order::Column::Status.eq(status.as_str()));
+        }
+        if let Some(supplier_id) = criteria.supplier_id {
+            select = select.filter(purchase_order::Column::SupplierId.eq(supplier_id));
+        }
+
+        let paginator = select
+            .order_by_desc(purchase_order::Column::PurchaseOrderId)
+            .paginate(self.db.as_ref(), query.page_size);
+
+        paginator::fetch_page(&paginator, query, |model| {
+            PurchaseOrderSelector::from(model)
+        })
+        .await
+    }
+
+    async fn find_lines(
+        &self,
+        purchase_order_id: i32,
+    ) -> Result<Vec<PurchaseOrderLineSelector>, SelectRepoFailure> {
+        let models = purchase_order_line::Entity::find()
+            .filter(purchase_order_line::Column::PurchaseOrderId.eq(purchase_order_id))
+            .order_by_asc(purchase_order_line::Column::PurchaseOrderLineId)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(models
+            .into_iter()
+            .map(PurchaseOrderLineSelector::from)
+            .collect())
+    }
+
+    async fn save(&self, event: &AddPurchaseOrder) -> Result<i32, SaveRepoFailure> {
+        let purchase_order_model = purchase_order::ActiveModel {
+            supplier_id: ActiveValue::Set(event.supplier_id),
+            status: ActiveValue::Set(PurchaseOrderStatus::Draft.as_str().to_owned()),
+            currency: ActiveValue::Set(event.currency.to_owned()),
+            created_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        purchase_order_model
+            .save(self.db.as_ref())
+            .await
+            .map(|model| model.purchase_order_id.unwrap())
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn add_line(&self, event: &AddPurchaseOrderLine) -> Result<i32, SaveRepoFailure> {
+        let purchase_order_line_model = purchase_order_line::ActiveModel {
+            purchase_order_id: ActiveValue::Set(event.purchase_order_id),
+            product_id: ActiveValue::Set(event.product_id),
+            ordered_quantity: ActiveValue::Set(event.ordered_quantity),
+            received_quantity: ActiveValue::Set(0),
+            unit_id: ActiveValue::Set(event.unit_id),
+            unit_price: ActiveValue::Set(event.unit_price.minor()),
+            ..Default::default()
+        };
+
+        purchase_order_line_model
+            .save(self.db.as_ref())
+            .await
+            .map(|model| model.purchase_order_line_id.unwrap())
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn change_status(
+        &self,
+        event: &ChangePurchaseOrderStatus,
+    ) -> Result<bool, SaveRepoFailure> {
+        let txn = self.db.begin().await.map_err(|err| err.into_domain())?;
+
+        let order = find_order_for_update(&txn, event.purchase_order_id)
+            .await
+            .map_err(|err| err.into_domain())?
+            .filter(|order| order.status == event.current_status.as_str());
+        let Some(order) = order else {
+            txn.rollback().await.map_err(|err| err.into_domain())?;
+
+            return Ok(false);
+        };
+
+        let now = Utc::now().naive_utc();
+        let mut order_to_modify: purchase_order::ActiveModel = order.into();
+        order_to_modify.status = ActiveValue::Set(event.status.as_str().to_owned());
+        match event.status {
+            PurchaseOrderStatus::Approved => {
+                order_to_modify.approved_at = ActiveValue::Set(Some(now))
+            }
+            PurchaseOrderStatus::Sent => order_to_modify.sent_at = ActiveValue::Set(Some(now)),
+            PurchaseOrderStatus::Closed => order_to_modify.closed_at = ActiveValue::Set(Some(now)),
+            _ => {}
+        }
+        order_to_modify
+            .save(&txn)
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        txn.commit().await.map_err(|err| err.into_domain())?;
+
+        Ok(true)
+    }
+
+    async fn receive(&self, event: &ReceivePurchaseOrder) -> Result<bool, SaveRepoFailure> {
+        let txn = self.db.begin().await.map_err(|err| err.into_domain())?;
+
+        let order = find_order_for_update(&txn, event.purchase_order_id)
+            .await
+            .map_err(|err| err.into_domain())?
+            .filter(|order| {
+                PurchaseOrderStatus::parse(&order.status)
+                    .is_some_and(|status| status.is_receivable())
+            });
+        let Some(order) = order else {
+            txn.rollback().await.map_err(|err| err.into_domain())?;
+
+            return Ok(false);
+        };
+
+        let lines = purchase_order_line::Entity::find()
+            .filter(purchase_order_line::Column::PurchaseOrderId.eq(event.purchase_order_id))
+            .lock_exclusive()
+            .all(&txn)
+            .await
+            .map_err(|err| err.into_domain())?;
+        let mut line_receipts: HashMap<i32, LineReceipt> = lines
+            .iter()
+            .map(|line| {
+                (
+                    line.purchase_order_line_id,
+                    LineReceipt::new(line.ordered_quantity, line.received_quantity),
+                )
+            })
+            .collect();
+
+        for line in &event.lines {
+            let Some(line_receipt) = line_receipts.get(&line.purchase_order_line_id) else {
+                txn.rollback().await.map_err(|err| err.into_domain())?;
+
+                return Ok(false);
+            };
+
+            let line_receipt = line_receipt.receive(line.quantity);
+            if line_receipt.over_received_quantity() > 0 && !event.allow_over_receipt {
+                txn.rollback().await.map_err(|err| err.into_domain())?;
+
+                return Ok(false);
+            }
+            line_receipts.insert(line.purchase_order_line_id, line_receipt);
+        }
+
+        for line in lines {
+            let received_quantity = line_receipts[&line.purchase_order_line_id].received_quantity();
+            if received_quantity == line.received_quantity {
+                continue;
+            }
+
+            let mut line_to_receive: purchase_order_line::ActiveModel = line.into();
+            line_to_receive.received_quantity = ActiveValue::Set(received_quantity);
+            line_to_receive
+                .save(&txn)
+                .await
+                .map_err(|err| err.into_domain())?;
+        }
+
+        for receipt in &event.receipts {
+            if let (Some(lot_number), Some(expires_on)) =
+                (&receipt.lot_number, receipt.lot_expires_on)
+            {
+                let stock_lot_model = stock_lot::ActiveModel {
+                    product_id: ActiveValue::Set(receipt.product_id),
+                    lot_number: ActiveValue::Set(lot_number.to_owned()),
+                    expires_on: ActiveValue::Set(expires_on),
+                    ..Default::default()
+                };
+
+                stock_lot_model
+                    .save(&txn)
+                    .await
+                    .map_err(|err| err.into_domain())?;
+            }
+
+            let stock_movement_model = stock_movement::ActiveModel {
+                product_id: ActiveValue::Set(receipt.product_id),
+                kind: ActiveValue::Set(receipt.kind.as_str().to_owned()),
+                quantity: ActiveValue::Set(receipt.quantity),
+                storage_location_id: ActiveValue::Set(receipt.storage_location_id),
+                stock_transfer_id: ActiveValue::Set(None),
+                lot_number: ActiveValue::Set(receipt.lot_number.to_owned()),
+                serial_number: ActiveValue::Set(receipt.serial_number.to_owned()),
+                unit_cost: ActiveValue::Set(receipt.unit_cost.map(|unit_cost| unit_cost.minor())),
+                reason: ActiveValue::Set(receipt.reason.to_owned()),
+                moved_at: ActiveValue::Set(Utc::now().naive_utc()),
+                ..Default::default()
+            };
+
+            stock_movement_model
+                .save(&txn)
+                .await
+                .map_err(|err| err.into_domain())?;
+        }
+
+        for last_price in &event.last_prices {
+            let maybe_model = supplier_product::Entity::find()
+                .filter(supplier_product::Column::SupplierId.eq(last_price.supplier_id))
+                .filter(supplier_product::Column::ProductId.eq(last_price.product_id))
+                .one(&txn)
+                .await
+                .map_err(|err| err.into_domain())?;
+
+            if let Some(model) = maybe_model {
+                let mut product_to_modify: supplier_product::ActiveModel = model.into();
+                product_to_modify.last_price =
+                    ActiveValue::Set(Some(last_price.last_price.minor()));
+                product_to_modify
+                    .save(&txn)
+                    .await
+                    .map_err(|err| err.into_domain())?;
+            }
+        }
+
+        let status =
+            PurchaseOrderStatus::after_receipt(&line_receipts.into_values().collect::<Vec<_>>());
+
+        let mut order_to_receive: purchase_order::ActiveModel = order.into();
+        order_to_receive.status = ActiveValue::Set(status.as_str().to_owned());
+        if status == PurchaseOrderStatus::Closed {
+            order_to_receive.closed_at = ActiveValue::Set(Some(Utc::now().naive_utc()));
+        }
+        order_to_receive
+            .save(&txn)
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        txn.commit().await.map_err(|err| err.into_domain())?;
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,84 @@
+use super::factories::reorder::ReorderPointAndProduct;
+use crate::domain::changes::reorder::SetReorderPoint;
+use crate::domain::ports::spi::reorder::ReorderPointRepository;
+use crate::domain::selectors::reorder::ReorderPointSelector;
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+use ids_std_sea::convert::into::IntoDomain;
+use lumx_sea_orm::sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DbConn, EntityTrait, JoinType, QueryFilter,
+    QueryOrder, QuerySelect, RelationTrait,
+};
+use portal_schema::{product, reorder_point};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct ReorderPointSeaRepository {
+    db: Arc<DbConn>,
+}
+
+impl ReorderPointSeaRepository {
+    pub fn new(db: &Arc<DbConn>) -> Self {
+        Self {
+            db: Arc::clone(&db),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ReorderPointRepository for ReorderPointSeaRepository {
+    async fn find_by_product(
+        &self,
+        product_id: i32,
+    ) -> Result<Option<ReorderPointSelector>, SelectRepoFailure> {
+        let maybe_model = reorder_point::Entity::find()
+            .column_as(product::Column::Name, "product_name")
+            .join(JoinType::InnerJoin, reorder_point::Relation::Product.def())
+            .filter(reorder_point::Column::ProductId.eq(product_id))
+            .into_model::<ReorderPointAndProduct>()
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(ReorderPointSelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_purchasable(&self) -> Result<Vec<ReorderPointSelector>, SelectRepoFailure> {
+        let models = reorder_point::Entity::find()
+            .column_as(product::Column::Name, "product_name")
+            .join(JoinType::InnerJoin, reorder_point::Relation::Product.def())
+            .filter(product::Column::Purchasable.eq(i8::from(true)))
+            .filter(product::Column::DiscontinuedAt.is_null())
+            .order_by_asc(reorder_point::Column::ProductId)
+            .into_model::<ReorderPointAndProduct>()
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(models.into_iter().map(ReorderPointSelector::from).collect())
+    }
+
+    async fn save(&self, event: &SetReorderPoint) -> Result<(), SaveRepoFailure> {
+        let result = reorder_point::Entity::find()
+            .filter(reorder_point::Column::ProductId.eq(event.product_id))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        let mut reorder_point_model: reorder_point::ActiveModel = match result {
+            Some(model) => model.into(),
+            None => reorder_point::ActiveModel {
+                product_id: ActiveValue::Set(event.product_id),
+                ..Default::default()
+            },
+        };
+        reorder_point_model.min_quantity = ActiveValue::Set(event.min_quantity);
+        reorder_point_model.max_quantity = ActiveValue::Set(event.max_quantity);
+
+        reorder_point_model
+            .save(self.db.as_ref())
+            .await
+            .map(|_| {})
+            .map_err(|err| err.into_domain())
+    }
+}
@@ -0,0 +1,334 @@
+use crate::adapters::repository::stock::{
+    insert_movements, is_out_of_stock, lock_products, on_hand_of_products, sum_of,
+};
+use crate::domain::changes::sales::{
+    AddSalesOrder, AddSalesOrderLine, ChangeSalesOrderStatus, CommittedStock, FulfilSalesOrder,
+    ReserveSalesOrderLine,
+};
+use crate::domain::ports::spi::sales::SalesOrderRepository;
+use crate::domain::queries::sales::SalesOrderQuery;
+use crate::domain::selectors::sales::{
+    ProductCommitmentSelector, SalesOrderLineSelector, SalesOrderSelector,
+};
+use crate::domain::valuables::sales::SalesOrderStatus;
+use ids_std_domain::pagination::{Page, PaginationQuery};
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+use ids_std_sea::convert::into::IntoDomain;
+use ids_std_sea::paginator;
+use lumx_sea_orm::sea_orm::sqlx::types::chrono::Utc;
+use lumx_sea_orm::sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DatabaseTransaction, DbConn,
+    DbErr, EntityTrait, JoinType, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
+    RelationTrait, TransactionTrait,
+};
+use portal_schema::{person, sales_order, sales_order_line};
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct SalesOrderSeaRepository {
+    db: Arc<DbConn>,
+}
+
+impl SalesOrderSeaRepository {
+    pub fn new(db: &Arc<DbConn>) -> Self {
+        Self {
+            db: Arc::clone(&db),
+        }
+    }
+}
+
+async fn find_order_for_update(
+    txn: &DatabaseTransaction,
+    sales_order_id: i32,
+    current_status: SalesOrderStatus,
+) -> Result<Option<sales_order::Model>, DbErr> {
+    let order = sales_order::Entity::find_by_id(sales_order_id)
+        .lock_exclusive()
+        .one(txn)
+        .await?;
+
+    Ok(order.filter(|order| order.status == current_status.as_str()))
+}
+
+async fn select_commitments<C: ConnectionTrait>(
+    db: &C,
+    product_ids: &[i32],
+) -> Result<Vec<ProductCommitmentSelector>, DbErr> {
+    let quantities = sales_order_line::Entity::find()
+        .select_only()
+        .column(sales_order_line::Column::ProductId)
+        .column_as(
+            sum_of(sales_order_line::Column::ReservedQuantity),
+            "reserved",
+        )
+        .column_as(
+            sum_of(sales_order_line::Column::BackorderedQuantity),
+            "backordered",
+        )
+        .join(
+            JoinType::InnerJoin,
+            sales_order_line::Relation::SalesOrder.def(),
+        )
+        .filter(sales_order_line::Column::ProductId.is_in(product_ids.iter().copied()))
+        .filter(sales_order::Column::Status.eq(SalesOrderStatus::Confirmed.as_str()))
+        .group_by(sales_order_line::Column::ProductId)
+        .order_by_asc(sales_order_line::Column::ProductId)
+        .into_tuple::<(i32, i64, i64)>()
+        .all(db)
+        .await?;
+
+    Ok(quantities
+        .into_iter()
+        .map(
+            |(product_id, reserved, backordered)| ProductCommitmentSelector {
+                product_id,
+                reserved: reserved as i32,
+                backordered: backordered as i32,
+            },
+        )
+        .collect())
+}
+
+async fn is_less_to_promise(
+    txn: &DatabaseTransaction,
+    committed_stock: &[CommittedStock],
+) -> Result<bool, DbErr> {
+    let product_ids: Vec<i32> = committed_stock
+        .iter()
+        .map(|stock| stock.product_id)
+        .collect();
+    let on_hand = on_hand_of_products(txn, &product_ids).await?;
+    let commitments = select_commitments(txn, &product_ids).await?;
+
+    // Less on hand or more committed since the reservation was worked out
+    // means another order took the stock it was promised.
+    Ok(committed_stock.iter().any(|seen| {
+        let commitment = commitments
+            .iter()
+            .find(|commitment| commitment.product_id == seen.product_id);
+
+        on_hand.get(&seen.product_id).copied().unwrap_or_default() < seen.on_hand
+            || commitment.map_or(0, |commitment| commitment.reserved) > seen.reserved
+            || commitment.map_or(0, |commitment| commitment.backordered) > seen.backordered
+    }))
+}
+
+async fn update_order_status(
+    txn: &DatabaseTransaction,
+    order: sales_order::Model,
+    status: SalesOrderStatus,
+    lines: &[ReserveSalesOrderLine],
+) -> Result<(), DbErr> {
+    for line in lines {
+        let result = sales_order_line::Entity::find_by_id(line.sales_order_line_id)
+            .one(txn)
+            .await?;
+
+        let mut line_to_reserve: sales_order_line::ActiveModel = result.unwrap().into();
+        line_to_reserve.reserved_quantity = ActiveValue::Set(line.reserved_quantity);
+        line_to_reserve.backordered_quantity = ActiveValue::Set(line.backordered_quantity);
+        line_to_reserve.save(txn).await?;
+    }
+
+    let now = Utc::now().naive_utc();
+    let mut order_to_modify: sales_order::ActiveModel = order.into();
+    order_to_modify.status = ActiveValue::Set(status.as_str().to_owned());
+    match status {
+        SalesOrderStatus::Confirmed => order_to_modify.confirmed_at = ActiveValue::Set(Some(now)),
+        SalesOrderStatus::Cancelled => order_to_modify.cancelled_at = ActiveValue::Set(Some(now)),
+        SalesOrderStatus::Fulfilled => order_to_modify.fulfilled_at = ActiveValue::Set(Some(now)),
+        SalesOrderStatus::Draft => {}
+    }
+    order_to_modify.save(txn).await?;
+
+    Ok(())
+}
+
+#[async_trait::async_trait]
+impl SalesOrderRepository for SalesOrderSeaRepository {
+    async fn find_by_id(&self, id: i32) -> Result<Option<SalesOrderSelector>, SelectRepoFailure> {
+        let maybe_model = sales_order::Entity::find_by_id(id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(|model| SalesOrderSelector::from(&model));
+
+        Ok(maybe_model)
+    }
+
+    async fn find_all(
+        &self,
+        query: &PaginationQuery,
+        criteria: &SalesOrderQuery,
+    ) -> Result<Page<SalesOrderSelector>, SelectRepoFailure> {
+        let mut select = sales_order::Entity::find();
+        if let Some(status) = criteria.status {
+            select = select.filter(sales_order::Column::Status.eq(status.as_str()));
+        }
+        if let Some(customer_id) = criteria.customer_id {
+            select = select.filter(sales_order::Column::CustomerId.eq(customer_id));
+        }
+
+        let paginator = select
+            .order_by_desc(sales_order::Column::SalesOrderId)
+            .paginate(self.db.as_ref(), query.page_size);
+
+        paginator::fetch_page(&paginator, query, |model| SalesOrderSelector::from(model)).await
+    }
+
+    async fn find_lines(
+        &self,
+        sales_order_id: i32,
+    ) -> Result<Vec<SalesOrderLineSelector>, SelectRepoFailure> {
+        let models = sales_order_line::Entity::find()
+            .filter(sales_order_line::Column::SalesOrderId.eq(sales_order_id))
+            .order_by_asc(sales_order_line::Column::SalesOrderLineId)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(models
+            .into_iter()
+            .map(SalesOrderLineSelector::from)
+            .collect())
+    }
+
+    async fn find_commitments(
+        &self,
+        product_ids: &[i32],
+    ) -> Result<Vec<ProductCommitmentSelector>, SelectRepoFailure> {
+        select_commitments(self.db.as_ref(), product_ids)
+            .await
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn exists_customer(&self, person_id: i32) -> Result<bool, SelectRepoFailure> {
+        let maybe_model = person::Entity::find_by_id(person_id)
+            .filter(person::Column::DeletedAt.is_null())
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(maybe_model.is_some())
+    }
+
+    async fn save(&self, event: &AddSalesOrder) -> Result<i32, SaveRepoFailure> {
+        let sales_order_model = sales_order::ActiveModel {
+            customer_id: ActiveValue::Set(event.customer_id),
+            status: ActiveValue::Set(SalesOrderStatus::Draft.as_str().to_owned()),
+            created_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        sales_order_model
+            .save(self.db.as_ref())
+            .await
+            .map(|model| model.sales_order_id.unwrap())
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn add_line(&self, event: &AddSalesOrderLine) -> Result<i32, SaveRepoFailure> {
+        let sales_order_line_model = sales_order_line::ActiveModel {
+            sales_order_id: ActiveValue::Set(event.sales_order_id),
+            product_id: ActiveValue::Set(event.product_id),
+            ordered_quantity: ActiveValue::Set(event.ordered_quantity),
+            reserved_quantity: ActiveValue::Set(0),
+            backordered_quantity: ActiveValue::Set(0),
+            ..Default::default()
+        };
+
+        sales_order_line_model
+            .save(self.db.as_ref())
+            .await
+            .map(|model| model.sales_order_line_id.unwrap())
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn change_status(&self, event: &ChangeSalesOrderStatus) -> Result<bool, SaveRepoFailure> {
+        let txn = self.db.begin().await.map_err(|err| err.into_domain())?;
+
+        let order = find_order_for_update(&txn, event.sales_order_id, event.current_status)
+            .await
+            .map_err(|err| err.into_domain())?;
+        let Some(order) = order else {
+            txn.rollback().await.map_err(|err| err.into_domain())?;
+
+            return Ok(false);
+        };
+
+        let product_ids: BTreeSet<i32> = event
+            .committed_stock
+            .iter()
+            .map(|stock| stock.product_id)
+            .collect();
+        lock_products(&txn, &product_ids)
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        if is_less_to_promise(&txn, &event.committed_stock)
+            .await
+            .map_err(|err| err.into_domain())?
+        {
+            txn.rollback().await.map_err(|err| err.into_domain())?;
+
+            return Ok(false);
+        }
+
+        update_order_status(&txn, order, event.status, &event.lines)
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        txn.commit()
+            .await
+            .map(|_| true)
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn fulfil(&self, event: &FulfilSalesOrder) -> Result<bool, SaveRepoFailure> {
+        let txn = self.db.begin().await.map_err(|err| err.into_domain())?;
+
+        let order = find_order_for_update(&txn, event.sales_order_id, SalesOrderStatus::Confirmed)
+            .await
+            .map_err(|err| err.into_domain())?;
+        let Some(order) = order else {
+            txn.rollback().await.map_err(|err| err.into_domain())?;
+
+            return Ok(false);
+        };
+
+        let product_ids: BTreeSet<i32> =
+            event.issues.iter().map(|issue| issue.product_id).collect();
+        lock_products(&txn, &product_ids)
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        insert_movements(&txn, &event.issues)
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        let guarded_issues = event
+            .issues
+            .iter()
+            .filter(|issue| event.in_stock_product_ids.contains(&issue.product_id));
+        for issue in guarded_issues {
+            if is_out_of_stock(&txn, issue)
+                .await
+                .map_err(|err| err.into_domain())?
+            {
+                txn.rollback().await.map_err(|err| err.into_domain())?;
+
+                return Ok(false);
+            }
+        }
+
+        update_order_status(&txn, order, SalesOrderStatus::Fulfilled, &event.lines)
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        txn.commit()
+            .await
+            .map(|_| true)
+            .map_err(|err| err.into_domain())
+    }
+}
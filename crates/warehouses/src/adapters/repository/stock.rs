@@ -0,0 +1,482 @@
+use crate::domain::changes::stock::AddStockMovement;
+use crate::domain::ports::spi::stock::StockMovementRepository;
+use crate::domain::selectors::stock::{
+    CostedMovementSelector, LocationOnHandSelector, LotOnHandSelector, StockMovementPageSelector,
+    StockedLocationSelector,
+};
+use crate::domain::valuables::stock::StockMovementKind;
+use crate::domain::valuables::tracking::TrackedUnit;
+use chrono::NaiveDateTime;
+use ids_std_domain::pagination::{Page, PaginationQuery};
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+use ids_std_sea::convert::into::IntoDomain;
+use ids_std_sea::paginator;
+use lumx_sea_orm::sea_orm::sea_query::{Alias, Expr, Func, SimpleExpr};
+use lumx_sea_orm::sea_orm::sqlx::types::chrono::Utc;
+use lumx_sea_orm::sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, ConnectionTrait, DatabaseTransaction,
+    DbConn, DbErr, EntityTrait, JoinType, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
+    RelationTrait, Select, TransactionTrait,
+};
+use portal_schema::{product, stock_lot, stock_movement, storage_location};
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct StockMovementSeaRepository {
+    db: Arc<DbConn>,
+}
+
+impl StockMovementSeaRepository {
+    pub fn new(db: &Arc<DbConn>) -> Self {
+        Self {
+            db: Arc::clone(&db),
+        }
+    }
+}
+
+pub(crate) fn sum_of(column: impl ColumnTrait) -> SimpleExpr {
+    // MySQL sums integer columns as DECIMAL, cast the total back to an integer.
+    Func::cast_as(column.sum(), Alias::new("SIGNED")).into()
+}
+
+fn on_hand_of_movements() -> SimpleExpr {
+    sum_of(stock_movement::Column::Quantity)
+}
+
+async fn sum_on_hand<C: ConnectionTrait>(
+    db: &C,
+    select: Select<stock_movement::Entity>,
+) -> Result<i32, DbErr> {
+    let on_hand = select
+        .select_only()
+        .column_as(on_hand_of_movements(), "on_hand")
+        .into_tuple::<Option<i64>>()
+        .one(db)
+        .await?;
+
+    Ok(on_hand.flatten().unwrap_or_default() as i32)
+}
+
+pub(crate) async fn on_hand_of_products<C: ConnectionTrait>(
+    db: &C,
+    product_ids: &[i32],
+) -> Result<HashMap<i32, i32>, DbErr> {
+    let quantities = stock_movement::Entity::find()
+        .select_only()
+        .column(stock_movement::Column::ProductId)
+        .column_as(on_hand_of_movements(), "on_hand")
+        .filter(stock_movement::Column::ProductId.is_in(product_ids.iter().copied()))
+        .group_by(stock_movement::Column::ProductId)
+        .into_tuple::<(i32, i64)>()
+        .all(db)
+        .await?;
+
+    Ok(quantities
+        .into_iter()
+        .map(|(product_id, on_hand)| (product_id, on_hand as i32))
+        .collect())
+}
+
+fn select_of_unit(product_id: i32, unit: &TrackedUnit) -> Select<stock_movement::Entity> {
+    let select =
+        stock_movement::Entity::find().filter(stock_movement::Column::ProductId.eq(product_id));
+
+    match unit {
+        TrackedUnit::Lot(lot_number) => {
+            select.filter(stock_movement::Column::LotNumber.eq(lot_number.as_str()))
+        }
+        TrackedUnit::Serial(serial_number) => {
+            select.filter(stock_movement::Column::SerialNumber.eq(serial_number.as_str()))
+        }
+        TrackedUnit::Untracked => select,
+    }
+}
+
+fn select_of_valued_movements(
+    moved_before: Option<NaiveDateTime>,
+) -> Select<stock_movement::Entity> {
+    // Transfers only move stock between locations, replaying them through the
+    // cost ledger would reorder FIFO layers and drop stock in transit.
+    let mut select =
+        stock_movement::Entity::find().filter(stock_movement::Column::Kind.is_not_in([
+            StockMovementKind::TransferOut.as_str(),
+            StockMovementKind::TransferIn.as_str(),
+        ]));
+    if let Some(moved_before) = moved_before {
+        select = select.filter(stock_movement::Column::MovedAt.lt(moved_before));
+    }
+
+    select
+}
+
+pub(crate) async fn lock_products(
+    txn: &DatabaseTransaction,
+    product_ids: &BTreeSet<i32>,
+) -> Result<(), DbErr> {
+    product::Entity::find()
+        .filter(product::Column::ProductId.is_in(product_ids.iter().copied()))
+        .lock_exclusive()
+        .all(txn)
+        .await?;
+
+    Ok(())
+}
+
+pub(crate) async fn insert_movements(
+    txn: &DatabaseTransaction,
+    events: &[AddStockMovement],
+) -> Result<Vec<i32>, DbErr> {
+    let mut stock_movement_ids = Vec::new();
+    for event in events {
+        if let (Some(lot_number), Some(expires_on)) = (&event.lot_number, event.lot_expires_on) {
+            let stock_lot_model = stock_lot::ActiveModel {
+                product_id: ActiveValue::Set(event.product_id),
+                lot_number: ActiveValue::Set(lot_number.to_owned()),
+                expires_on: ActiveValue::Set(expires_on),
+                ..Default::default()
+            };
+
+            stock_lot_model.save(txn).await?;
+        }
+
+        let stock_movement_model = stock_movement::ActiveModel {
+            product_id: ActiveValue::Set(event.product_id),
+            kind: ActiveValue::Set(event.kind.as_str().to_owned()),
+            quantity: ActiveValue::Set(event.quantity),
+            storage_location_id: ActiveValue::Set(event.storage_location_id),
+            stock_transfer_id: ActiveValue::Set(None),
+            lot_number: ActiveValue::Set(event.lot_number.to_owned()),
+            serial_number: ActiveValue::Set(event.serial_number.to_owned()),
+            unit_cost: ActiveValue::Set(event.unit_cost.map(|unit_cost| unit_cost.minor())),
+            reason: ActiveValue::Set(event.reason.to_owned()),
+            moved_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        let stock_movement_id = stock_movement_model
+            .save(txn)
+            .await
+            .map(|model| model.stock_movement_id.unwrap())?;
+        stock_movement_ids.push(stock_movement_id);
+    }
+
+    Ok(stock_movement_ids)
+}
+
+pub(crate) async fn is_out_of_stock(
+    txn: &DatabaseTransaction,
+    event: &AddStockMovement,
+) -> Result<bool, DbErr> {
+    let mut select = stock_movement::Entity::find()
+        .filter(stock_movement::Column::ProductId.eq(event.product_id));
+    if let Some(storage_location_id) = event.storage_location_id {
+        select = select.filter(stock_movement::Column::StorageLocationId.eq(storage_location_id));
+    }
+    if sum_on_hand(txn, select).await? < 0 {
+        return Ok(true);
+    }
+
+    let unit = match (&event.lot_number, &event.serial_number) {
+        (Some(lot_number), _) => TrackedUnit::Lot(lot_number.to_owned()),
+        (_, Some(serial_number)) => TrackedUnit::Serial(serial_number.to_owned()),
+        _ => return Ok(false),
+    };
+
+    let mut select = select_of_unit(event.product_id, &unit);
+    if let Some(storage_location_id) = event.storage_location_id {
+        select = select.filter(stock_movement::Column::StorageLocationId.eq(storage_location_id));
+    }
+
+    Ok(sum_on_hand(txn, select).await? < 0)
+}
+
+#[async_trait::async_trait]
+impl StockMovementRepository for StockMovementSeaRepository {
+    async fn save(&self, event: &AddStockMovement) -> Result<i32, SaveRepoFailure> {
+        self.save_all(std::slice::from_ref(event))
+            .await
+            .map(|stock_movement_ids| stock_movement_ids[0])
+    }
+
+    async fn save_all(&self, events: &[AddStockMovement]) -> Result<Vec<i32>, SaveRepoFailure> {
+        let txn = self.db.begin().await.map_err(|err| err.into_domain())?;
+
+        let stock_movement_ids = insert_movements(&txn, events)
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        txn.commit()
+            .await
+            .map(|_| stock_movement_ids)
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn save_all_in_stock(
+        &self,
+        events: &[AddStockMovement],
+    ) -> Result<Option<Vec<i32>>, SaveRepoFailure> {
+        let txn = self.db.begin().await.map_err(|err| err.into_domain())?;
+
+        let product_ids: BTreeSet<i32> = events.iter().map(|event| event.product_id).collect();
+        lock_products(&txn, &product_ids)
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        let stock_movement_ids = insert_movements(&txn, events)
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        for event in events.iter().filter(|event| event.quantity < 0) {
+            if is_out_of_stock(&txn, event)
+                .await
+                .map_err(|err| err.into_domain())?
+            {
+                txn.rollback().await.map_err(|err| err.into_domain())?;
+
+                return Ok(None);
+            }
+        }
+
+        txn.commit()
+            .await
+            .map(|_| Some(stock_movement_ids))
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn find_on_hand(&self, product_id: i32) -> Result<i32, SelectRepoFailure> {
+        let select =
+            stock_movement::Entity::find().filter(stock_movement::Column::ProductId.eq(product_id));
+
+        sum_on_hand(self.db.as_ref(), select)
+            .await
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn find_on_hand_at(
+        &self,
+        product_id: i32,
+        storage_location_id: i32,
+    ) -> Result<i32, SelectRepoFailure> {
+        let select = stock_movement::Entity::find()
+            .filter(stock_movement::Column::ProductId.eq(product_id))
+            .filter(stock_movement::Column::StorageLocationId.eq(storage_location_id));
+
+        sum_on_hand(self.db.as_ref(), select)
+            .await
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn find_on_hand_in_warehouse(
+        &self,
+        product_id: i32,
+        warehouse_id: i32,
+    ) -> Result<i32, SelectRepoFailure> {
+        let select = stock_movement::Entity::find()
+            .join(
+                JoinType::InnerJoin,
+                stock_movement::Relation::StorageLocation.def(),
+            )
+            .filter(stock_movement::Column::ProductId.eq(product_id))
+            .filter(storage_location::Column::WarehouseId.eq(warehouse_id));
+
+        sum_on_hand(self.db.as_ref(), select)
+            .await
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn find_on_hand_of_unit(
+        &self,
+        product_id: i32,
+        unit: &TrackedUnit,
+    ) -> Result<i32, SelectRepoFailure> {
+        sum_on_hand(self.db.as_ref(), select_of_unit(product_id, unit))
+            .await
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn find_on_hand_of_unit_at(
+        &self,
+        product_id: i32,
+        unit: &TrackedUnit,
+        storage_location_id: i32,
+    ) -> Result<i32, SelectRepoFailure> {
+        let select = select_of_unit(product_id, unit)
+            .filter(stock_movement::Column::StorageLocationId.eq(storage_location_id));
+
+        sum_on_hand(self.db.as_ref(), select)
+            .await
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn find_on_hand_by_location(
+        &self,
+        product_id: i32,
+    ) -> Result<Vec<LocationOnHandSelector>, SelectRepoFailure> {
+        let quantities = stock_movement::Entity::find()
+            .select_only()
+            .column(stock_movement::Column::StorageLocationId)
+            .column_as(on_hand_of_movements(), "on_hand")
+            .filter(stock_movement::Column::ProductId.eq(product_id))
+            .filter(stock_movement::Column::StorageLocationId.is_not_null())
+            .group_by(stock_movement::Column::StorageLocationId)
+            .order_by_asc(stock_movement::Column::StorageLocationId)
+            .into_tuple::<(i32, i64)>()
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(quantities
+            .into_iter()
+            .map(|(storage_location_id, on_hand)| LocationOnHandSelector {
+                storage_location_id,
+                on_hand: on_hand as i32,
+            })
+            .collect())
+    }
+
+    async fn find_stocked_locations_in_warehouse(
+        &self,
+        warehouse_id: i32,
+    ) -> Result<Vec<StockedLocationSelector>, SelectRepoFailure> {
+        let quantities = stock_movement::Entity::find()
+            .select_only()
+            .column(stock_movement::Column::ProductId)
+            .column(stock_movement::Column::StorageLocationId)
+            .column_as(on_hand_of_movements(), "on_hand")
+            .join(
+                JoinType::InnerJoin,
+                stock_movement::Relation::StorageLocation.def(),
+            )
+            .filter(storage_location::Column::WarehouseId.eq(warehouse_id))
+            .group_by(stock_movement::Column::ProductId)
+            .group_by(stock_movement::Column::StorageLocationId)
+            .having(Expr::expr(stock_movement::Column::Quantity.sum()).ne(0))
+            .order_by_asc(stock_movement::Column::ProductId)
+            .order_by_asc(stock_movement::Column::StorageLocationId)
+            .into_tuple::<(i32, i32, i64)>()
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(quantities
+            .into_iter()
+            .map(
+                |(product_id, storage_location_id, on_hand)| StockedLocationSelector {
+                    product_id,
+                    storage_location_id,
+                    on_hand: on_hand as i32,
+                },
+            )
+            .collect())
+    }
+
+    async fn find_on_hand_by_lot(
+        &self,
+        product_id: i32,
+    ) -> Result<Vec<LotOnHandSelector>, SelectRepoFailure> {
+        let quantities = stock_movement::Entity::find()
+            .select_only()
+            .column(stock_movement::Column::LotNumber)
+            .column_as(on_hand_of_movements(), "on_hand")
+            .filter(stock_movement::Column::ProductId.eq(product_id))
+            .filter(stock_movement::Column::LotNumber.is_not_null())
+            .group_by(stock_movement::Column::LotNumber)
+            .order_by_asc(stock_movement::Column::LotNumber)
+            .into_tuple::<(String, i64)>()
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(quantities
+            .into_iter()
+            .map(|(lot_number, on_hand)| LotOnHandSelector {
+                lot_number,
+                on_hand: on_hand as i32,
+            })
+            .collect())
+    }
+
+    async fn find_stocked_products(
+        &self,
+        moved_before: NaiveDateTime,
+    ) -> Result<Vec<i32>, SelectRepoFailure> {
+        select_of_valued_movements(Some(moved_before))
+            .select_only()
+            .column(stock_movement::Column::ProductId)
+            .group_by(stock_movement::Column::ProductId)
+            .having(Expr::expr(stock_movement::Column::Quantity.sum()).ne(0))
+            .order_by_asc(stock_movement::Column::ProductId)
+            .into_tuple::<i32>()
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn find_by_product(
+        &self,
+        query: &PaginationQuery,
+        product_id: i32,
+    ) -> Result<Page<StockMovementPageSelector>, SelectRepoFailure> {
+        let paginator = stock_movement::Entity::find()
+            .filter(stock_movement::Column::ProductId.eq(product_id))
+            .order_by_desc(stock_movement::Column::StockMovementId)
+            .paginate(self.db.as_ref(), query.page_size);
+
+        paginator::fetch_page(&paginator, query, |model| {
+            StockMovementPageSelector::from(model)
+        })
+        .await
+    }
+    async fn find_by_tracking_code(
+        &self,
+        query: &PaginationQuery,
+        tracking_code: &str,
+    ) -> Result<Page<StockMovementPageSelector>, SelectRepoFailure> {
+        let paginator = stock_movement::Entity::find()
+            .filter(
+                Condition::any()
+                    .add(stock_movement::Column::LotNumber.eq(tracking_code))
+                    .add(stock_movement::Column::SerialNumber.eq(tracking_code)),
+            )
+            .order_by_asc(stock_movement::Column::StockMovementId)
+            .paginate(self.db.as_ref(), query.page_size);
+
+        paginator::fetch_page(&paginator, query, |model| {
+            StockMovementPageSelector::from(model)
+        })
+        .await
+    }
+
+    async fn find_costed_movements(
+        &self,
+        product_id: i32,
+        moved_before: Option<NaiveDateTime>,
+    ) -> Result<Vec<CostedMovementSelector>, SelectRepoFailure> {
+        let models = select_of_valued_movements(moved_before)
+            .filter(stock_movement::Column::ProductId.eq(product_id))
+            .order_by_asc(stock_movement::Column::MovedAt)
+            .order_by_asc(stock_movement::Column::StockMovementId)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(models.iter().map(CostedMovementSelector::from).collect())
+    }
+
+    async fn find_costed_movements_of_products(
+        &self,
+        product_ids: &[i32],
+        moved_before: NaiveDateTime,
+    ) -> Result<Vec<CostedMovementSelector>, SelectRepoFailure> {
+        let models = select_of_valued_movements(Some(moved_before))
+            .filter(stock_movement::Column::ProductId.is_in(product_ids.iter().copied()))
+            .order_by_asc(stock_movement::Column::MovedAt)
+            .order_by_asc(stock_movement::Column::StockMovementId)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(models.iter().map(CostedMovementSelector::from).collect())
+    }
+}
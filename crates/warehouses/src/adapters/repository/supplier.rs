@@ -0,0 +1,252 @@
+use super::factories::supplier::SupplierProductAndSupplier;
+use crate::domain::changes::supplier::{AddSupplier, AddSupplierProduct, UpdateSupplierProduct};
+use crate::domain::ports::spi::supplier::SupplierRepository;
+use crate::domain::selectors::supplier::{
+    SupplierOfferSelector, SupplierProductSelector, SupplierSelector,
+};
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+use ids_std_sea::convert::into::IntoDomain;
+use lumx_sea_orm::sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseTransaction, DbConn, DbErr, EntityTrait,
+    JoinType, QueryFilter, QueryOrder, QuerySelect, RelationTrait, TransactionTrait,
+};
+use portal_schema::{person, supplier, supplier_product};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct SupplierSeaRepository {
+    db: Arc<DbConn>,
+}
+
+impl SupplierSeaRepository {
+    pub fn new(db: &Arc<DbConn>) -> Self {
+        Self {
+            db: Arc::clone(&db),
+        }
+    }
+}
+
+async fn clear_preferred(
+    txn: &DatabaseTransaction,
+    product_id: i32,
+    supplier_product_id: i32,
+) -> Result<(), DbErr> {
+    let preferred_models = supplier_product::Entity::find()
+        .filter(supplier_product::Column::ProductId.eq(product_id))
+        .filter(supplier_product::Column::SupplierProductId.ne(supplier_product_id))
+        .filter(supplier_product::Column::Preferred.eq(i8::from(true)))
+        .all(txn)
+        .await?;
+
+    for preferred_model in preferred_models {
+        let mut model_to_clear: supplier_product::ActiveModel = preferred_model.into();
+        model_to_clear.preferred = ActiveValue::Set(false.into());
+        model_to_clear.save(txn).await?;
+    }
+
+    Ok(())
+}
+
+#[async_trait::async_trait]
+impl SupplierRepository for SupplierSeaRepository {
+    async fn find_by_id(&self, id: i32) -> Result<Option<SupplierSelector>, SelectRepoFailure> {
+        let maybe_model = supplier::Entity::find_by_id(id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(SupplierSelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_by_name(
+        &self,
+        name: &str,
+    ) -> Result<Option<SupplierSelector>, SelectRepoFailure> {
+        let maybe_model = supplier::Entity::find()
+            .filter(supplier::Column::Name.eq(name))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(SupplierSelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_all(&self) -> Result<Vec<SupplierSelector>, SelectRepoFailure> {
+        let models = supplier::Entity::find()
+            .order_by_asc(supplier::Column::Name)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(models.into_iter().map(SupplierSelector::from).collect())
+    }
+
+    async fn exists_contact(&self, person_id: i32) -> Result<bool, SelectRepoFailure> {
+        let maybe_model = person::Entity::find_by_id(person_id)
+            .filter(person::Column::DeletedAt.is_null())
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(maybe_model.is_some())
+    }
+
+    async fn find_product(
+        &self,
+        supplier_id: i32,
+        product_id: i32,
+    ) -> Result<Option<SupplierProductSelector>, SelectRepoFailure> {
+        let maybe_model = supplier_product::Entity::find()
+            .filter(supplier_product::Column::SupplierId.eq(supplier_id))
+            .filter(supplier_product::Column::ProductId.eq(product_id))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(SupplierProductSelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_product_by_code(
+        &self,
+        supplier_id: i32,
+        supplier_code: &str,
+    ) -> Result<Option<SupplierProductSelector>, SelectRepoFailure> {
+        let maybe_model = supplier_product::Entity::find()
+            .filter(supplier_product::Column::SupplierId.eq(supplier_id))
+            .filter(supplier_product::Column::SupplierCode.eq(supplier_code))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(SupplierProductSelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_products(
+        &self,
+        supplier_id: i32,
+    ) -> Result<Vec<SupplierProductSelector>, SelectRepoFailure> {
+        let models = supplier_product::Entity::find()
+            .filter(supplier_product::Column::SupplierId.eq(supplier_id))
+            .order_by_asc(supplier_product::Column::SupplierCode)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(models
+            .into_iter()
+            .map(SupplierProductSelector::from)
+            .collect())
+    }
+
+    async fn find_offers(
+        &self,
+        product_id: i32,
+    ) -> Result<Vec<SupplierOfferSelector>, SelectRepoFailure> {
+        let models = supplier_product::Entity::find()
+            .select_only()
+            .column(supplier_product::Column::SupplierProductId)
+            .column(supplier_product::Column::SupplierId)
+            .column_as(supplier::Column::Name, "supplier_name")
+            .column(supplier::Column::Currency)
+            .column(supplier_product::Column::ProductId)
+            .column(supplier_product::Column::SupplierCode)
+            .column(supplier_product::Column::PackSize)
+            .column(supplier_product::Column::LeadTimeDays)
+            .column(supplier_product::Column::LastPrice)
+            .column(supplier_product::Column::Preferred)
+            .join(
+                JoinType::InnerJoin,
+                supplier_product::Relation::Supplier.def(),
+            )
+            .filter(supplier_product::Column::ProductId.eq(product_id))
+            .order_by_asc(supplier_product::Column::SupplierId)
+            .into_model::<SupplierProductAndSupplier>()
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(models
+            .into_iter()
+            .map(SupplierOfferSelector::from)
+            .collect())
+    }
+
+    async fn save(&self, event: &AddSupplier) -> Result<i32, SaveRepoFailure> {
+        let supplier_model = supplier::ActiveModel {
+            name: ActiveValue::Set(event.name.to_owned()),
+            contact_person_id: ActiveValue::Set(event.contact_person_id),
+            currency: ActiveValue::Set(event.currency.to_owned()),
+            ..Default::default()
+        };
+
+        supplier_model
+            .save(self.db.as_ref())
+            .await
+            .map(|model| model.supplier_id.unwrap())
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn save_product(&self, event: &AddSupplierProduct) -> Result<i32, SaveRepoFailure> {
+        let txn = self.db.begin().await.map_err(|err| err.into_domain())?;
+
+        let supplier_product_model = supplier_product::ActiveModel {
+            supplier_id: ActiveValue::Set(event.supplier_id),
+            product_id: ActiveValue::Set(event.product_id),
+            supplier_code: ActiveValue::Set(event.supplier_code.to_owned()),
+            pack_size: ActiveValue::Set(event.pack_size),
+            lead_time_days: ActiveValue::Set(event.lead_time_days),
+            last_price: ActiveValue::Set(event.last_price.map(|price| price.minor())),
+            preferred: ActiveValue::Set(event.preferred.into()),
+            ..Default::default()
+        };
+
+        let supplier_product_id = supplier_product_model
+            .save(&txn)
+            .await
+            .map(|model| model.supplier_product_id.unwrap())
+            .map_err(|err| err.into_domain())?;
+
+        if event.preferred {
+            clear_preferred(&txn, event.product_id, supplier_product_id)
+                .await
+                .map_err(|err| err.into_domain())?;
+        }
+
+        txn.commit().await.map_err(|err| err.into_domain())?;
+
+        Ok(supplier_product_id)
+    }
+
+    async fn update_product(&self, event: &UpdateSupplierProduct) -> Result<(), SaveRepoFailure> {
+        let txn = self.db.begin().await.map_err(|err| err.into_domain())?;
+
+        let result = supplier_product::Entity::find_by_id(event.supplier_product_id)
+            .one(&txn)
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        let mut product_to_modify: supplier_product::ActiveModel = result.unwrap().into();
+        product_to_modify.supplier_code = ActiveValue::Set(event.supplier_code.to_owned());
+        product_to_modify.pack_size = ActiveValue::Set(event.pack_size);
+        product_to_modify.lead_time_days = ActiveValue::Set(event.lead_time_days);
+        product_to_modify.last_price =
+            ActiveValue::Set(event.last_price.map(|price| price.minor()));
+        product_to_modify.preferred = ActiveValue::Set(event.preferred.into());
+        product_to_modify
+            .save(&txn)
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        if event.preferred {
+            clear_preferred(&txn, event.product_id, event.supplier_product_id)
+                .await
+                .map_err(|err| err.into_domain())?;
+        }
+
+        txn.commit().await.map_err(|err| err.into_domain())
+    }
+}
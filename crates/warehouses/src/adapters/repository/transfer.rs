@@ -0,0 +1,303 @@
+use crate::adapters::repository::stock::{is_out_of_stock, lock_products};
+use crate::domain::changes::stock::AddStockMovement;
+use crate::domain::changes::transfer::{
+    AddStockTransfer, CancelStockTransfer, ReceiveStockTransfer,
+};
+use crate::domain::ports::spi::transfer::StockTransferRepository;
+use crate::domain::queries::transfer::StockTransferQuery;
+use crate::domain::selectors::transfer::StockTransferSelector;
+use crate::domain::valuables::stock::StockMovementKind;
+use crate::domain::valuables::transfer::StockTransferStatus;
+use ids_std_domain::pagination::{Page, PaginationQuery};
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+use ids_std_sea::convert::into::IntoDomain;
+use ids_std_sea::paginator;
+use lumx_sea_orm::sea_orm::sqlx::types::chrono::Utc;
+use lumx_sea_orm::sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseTransaction, DbConn, DbErr, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, TransactionTrait,
+};
+use portal_schema::{stock_movement, stock_transfer};
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct StockTransferSeaRepository {
+    db: Arc<DbConn>,
+}
+
+impl StockTransferSeaRepository {
+    pub fn new(db: &Arc<DbConn>) -> Self {
+        Self {
+            db: Arc::clone(&db),
+        }
+    }
+}
+
+fn transfer_movement(
+    stock_transfer_id: i32,
+    product_id: i32,
+    storage_location_id: i32,
+    kind: StockMovementKind,
+    quantity: i32,
+    lot_number: Option<String>,
+    serial_number: Option<String>,
+) -> stock_movement::ActiveModel {
+    stock_movement::ActiveModel {
+        product_id: ActiveValue::Set(product_id),
+        kind: ActiveValue::Set(kind.as_str().to_owned()),
+        quantity: ActiveValue::Set(quantity),
+        storage_location_id: ActiveValue::Set(Some(storage_location_id)),
+        stock_transfer_id: ActiveValue::Set(Some(stock_transfer_id)),
+        lot_number: ActiveValue::Set(lot_number),
+        serial_number: ActiveValue::Set(serial_number),
+        reason: ActiveValue::Set(None),
+        moved_at: ActiveValue::Set(Utc::now().naive_utc()),
+        ..Default::default()
+    }
+}
+
+async fn find_open_for_update(
+    txn: &DatabaseTransaction,
+    stock_transfer_id: i32,
+) -> Result<Option<stock_transfer::Model>, DbErr> {
+    let transfer = stock_transfer::Entity::find_by_id(stock_transfer_id)
+        .lock_exclusive()
+        .one(txn)
+        .await?;
+
+    Ok(transfer.filter(|transfer| {
+        StockTransferStatus::parse(&transfer.status).is_some_and(|status| status.is_open())
+    }))
+}
+
+#[async_trait::async_trait]
+impl StockTransferRepository for StockTransferSeaRepository {
+    async fn find_by_id(
+        &self,
+        id: i32,
+    ) -> Result<Option<StockTransferSelector>, SelectRepoFailure> {
+        let maybe_model = stock_transfer::Entity::find_by_id(id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(|model| StockTransferSelector::from(&model));
+
+        Ok(maybe_model)
+    }
+
+    async fn find_all(
+        &self,
+        query: &PaginationQuery,
+        criteria: &StockTransferQuery,
+    ) -> Result<Page<StockTransferSelector>, SelectRepoFailure> {
+        let mut select = stock_transfer::Entity::find();
+        if let Some(product_id) = criteria.product_id {
+            select = select.filter(stock_transfer::Column::ProductId.eq(product_id));
+        }
+        if let Some(status) = criteria.status {
+            select = select.filter(stock_transfer::Column::Status.eq(status.as_str()));
+        }
+
+        let paginator = select
+            .order_by_desc(stock_transfer::Column::StockTransferId)
+            .paginate(self.db.as_ref(), query.page_size);
+
+        paginator::fetch_page(&paginator, query, |model| {
+            StockTransferSelector::from(model)
+        })
+        .await
+    }
+
+    async fn find_in_transit(
+        &self,
+        product_id: i32,
+        destination_warehouse_id: Option<i32>,
+    ) -> Result<i32, SelectRepoFailure> {
+        let mut select = stock_transfer::Entity::find()
+            .filter(stock_transfer::Column::ProductId.eq(product_id))
+            .filter(stock_transfer::Column::Status.is_in([
+                StockTransferStatus::InTransit.as_str(),
+                StockTransferStatus::PartiallyReceived.as_str(),
+            ]));
+        if let Some(warehouse_id) = destination_warehouse_id {
+            select = select.filter(stock_transfer::Column::DestinationWarehouseId.eq(warehouse_id));
+        }
+
+        let transfers = select
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(transfers
+            .iter()
+            .map(|transfer| transfer.quantity - transfer.received_quantity)
+            .sum())
+    }
+
+    async fn save(&self, event: &AddStockTransfer) -> Result<Option<i32>, SaveRepoFailure> {
+        let txn = self.db.begin().await.map_err(|err| err.into_domain())?;
+
+        lock_products(&txn, &BTreeSet::from([event.product_id]))
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        let transfer_model = stock_transfer::ActiveModel {
+            product_id: ActiveValue::Set(event.product_id),
+            source_location_id: ActiveValue::Set(event.source_location_id),
+            source_warehouse_id: ActiveValue::Set(event.source_warehouse_id),
+            destination_warehouse_id: ActiveValue::Set(event.destination_warehouse_id),
+            quantity: ActiveValue::Set(event.quantity),
+            received_quantity: ActiveValue::Set(0),
+            status: ActiveValue::Set(StockTransferStatus::InTransit.as_str().to_owned()),
+            transferred_at: ActiveValue::Set(Utc::now().naive_utc()),
+            lot_number: ActiveValue::Set(event.lot_number.to_owned()),
+            serial_number: ActiveValue::Set(event.serial_number.to_owned()),
+            unit_cost: ActiveValue::Set(Some(event.unit_cost.minor())),
+            ..Default::default()
+        };
+        let stock_transfer_id = transfer_model
+            .save(&txn)
+            .await
+            .map(|model| model.stock_transfer_id.unwrap())
+            .map_err(|err| err.into_domain())?;
+
+        let mut transferred_movement = transfer_movement(
+            stock_transfer_id,
+            event.product_id,
+            event.source_location_id,
+            StockMovementKind::TransferOut,
+            -event.quantity,
+            event.lot_number.to_owned(),
+            event.serial_number.to_owned(),
+        );
+        transferred_movement.unit_cost = ActiveValue::Set(Some(event.unit_cost.minor()));
+        transferred_movement
+            .save(&txn)
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        let issue = AddStockMovement {
+            product_id: event.product_id,
+            kind: StockMovementKind::TransferOut,
+            quantity: -event.quantity,
+            storage_location_id: Some(event.source_location_id),
+            lot_number: event.lot_number.to_owned(),
+            serial_number: event.serial_number.to_owned(),
+            unit_cost: Some(event.unit_cost),
+            lot_expires_on: None,
+            reason: None,
+        };
+        if is_out_of_stock(&txn, &issue)
+            .await
+            .map_err(|err| err.into_domain())?
+        {
+            txn.rollback().await.map_err(|err| err.into_domain())?;
+
+            return Ok(None);
+        }
+
+        txn.commit()
+            .await
+            .map(|_| Some(stock_transfer_id))
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn receive(&self, event: &ReceiveStockTransfer) -> Result<bool, SaveRepoFailure> {
+        let txn = self.db.begin().await.map_err(|err| err.into_domain())?;
+
+        let transfer = find_open_for_update(&txn, event.stock_transfer_id)
+            .await
+            .map_err(|err| err.into_domain())?
+            .filter(|transfer| event.quantity <= transfer.quantity - transfer.received_quantity);
+        let Some(transfer) = transfer else {
+            txn.rollback().await.map_err(|err| err.into_domain())?;
+
+            return Ok(false);
+        };
+
+        let received_quantity = transfer.received_quantity + event.quantity;
+        let status = StockTransferStatus::after_receipt(transfer.quantity, received_quantity);
+        let lot_number = transfer.lot_number.to_owned();
+        let serial_number = transfer.serial_number.to_owned();
+
+        let mut transfer_to_receive: stock_transfer::ActiveModel = transfer.into();
+        transfer_to_receive.received_quantity = ActiveValue::Set(received_quantity);
+        transfer_to_receive.status = ActiveValue::Set(status.as_str().to_owned());
+        if !status.is_open() {
+            transfer_to_receive.closed_at = ActiveValue::Set(Some(Utc::now().naive_utc()));
+        }
+        transfer_to_receive
+            .save(&txn)
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        let mut received_movement = transfer_movement(
+            event.stock_transfer_id,
+            event.product_id,
+            event.storage_location_id,
+            StockMovementKind::TransferIn,
+            event.quantity,
+            lot_number,
+            serial_number,
+        );
+        received_movement.unit_cost = ActiveValue::Set(Some(event.unit_cost.minor()));
+        received_movement
+            .save(&txn)
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        txn.commit()
+            .await
+            .map(|_| true)
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn cancel(&self, event: &CancelStockTransfer) -> Result<bool, SaveRepoFailure> {
+        let txn = self.db.begin().await.map_err(|err| err.into_domain())?;
+
+        let transfer = find_open_for_update(&txn, event.stock_transfer_id)
+            .await
+            .map_err(|err| err.into_domain())?;
+        let Some(transfer) = transfer else {
+            txn.rollback().await.map_err(|err| err.into_domain())?;
+
+            return Ok(false);
+        };
+
+        let in_transit = transfer.quantity - transfer.received_quantity;
+        let lot_number = transfer.lot_number.to_owned();
+        let serial_number = transfer.serial_number.to_owned();
+
+        let mut transfer_to_cancel: stock_transfer::ActiveModel = transfer.into();
+        transfer_to_cancel.status =
+            ActiveValue::Set(StockTransferStatus::Cancelled.as_str().to_owned());
+        transfer_to_cancel.closed_at = ActiveValue::Set(Some(Utc::now().naive_utc()));
+        transfer_to_cancel
+            .save(&txn)
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        if in_transit > 0 {
+            let mut returned_movement = transfer_movement(
+                event.stock_transfer_id,
+                event.product_id,
+                event.source_location_id,
+                StockMovementKind::TransferIn,
+                in_transit,
+                lot_number,
+                serial_number,
+            );
+            returned_movement.unit_cost = ActiveValue::Set(Some(event.unit_cost.minor()));
+            returned_movement
+                .save(&txn)
+                .await
+                .map_err(|err| err.into_domain())?;
+        }
+
+        txn.commit()
+            .await
+            .map(|_| true)
+            .map_err(|err| err.into_domain())
+    }
+}
@@ -0,0 +1,124 @@
+use crate::domain::changes::unit::{AddUnitConversion, AddUnitOfMeasure};
+use crate::domain::ports::spi::unit::UnitOfMeasureRepository;
+use crate::domain::selectors::unit::{UnitConversionSelector, UnitOfMeasureSelector};
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+use ids_std_sea::convert::into::IntoDomain;
+use lumx_sea_orm::sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DbConn, EntityTrait, QueryFilter, QueryOrder,
+};
+use portal_schema::{unit_conversion, unit_of_measure};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct UnitOfMeasureSeaRepository {
+    db: Arc<DbConn>,
+}
+
+impl UnitOfMeasureSeaRepository {
+    pub fn new(db: &Arc<DbConn>) -> Self {
+        Self {
+            db: Arc::clone(&db),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl UnitOfMeasureRepository for UnitOfMeasureSeaRepository {
+    async fn find_by_id(
+        &self,
+        id: i32,
+    ) -> Result<Option<UnitOfMeasureSelector>, SelectRepoFailure> {
+        let maybe_model = unit_of_measure::Entity::find_by_id(id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(UnitOfMeasureSelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_by_code(
+        &self,
+        code: &str,
+    ) -> Result<Option<UnitOfMeasureSelector>, SelectRepoFailure> {
+        let maybe_model = unit_of_measure::Entity::find()
+            .filter(unit_of_measure::Column::Code.eq(code))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(UnitOfMeasureSelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_all(&self) -> Result<Vec<UnitOfMeasureSelector>, SelectRepoFailure> {
+        let models = unit_of_measure::Entity::find()
+            .order_by_asc(unit_of_measure::Column::Code)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(models
+            .into_iter()
+            .map(UnitOfMeasureSelector::from)
+            .collect())
+    }
+
+    async fn find_conversion(
+        &self,
+        from_unit_id: i32,
+        to_unit_id: i32,
+    ) -> Result<Option<UnitConversionSelector>, SelectRepoFailure> {
+        let maybe_model = unit_conversion::Entity::find()
+            .filter(unit_conversion::Column::FromUnitId.eq(from_unit_id))
+            .filter(unit_conversion::Column::ToUnitId.eq(to_unit_id))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(UnitConversionSelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_conversions(&self) -> Result<Vec<UnitConversionSelector>, SelectRepoFailure> {
+        let models = unit_conversion::Entity::find()
+            .order_by_asc(unit_conversion::Column::UnitConversionId)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        Ok(models
+            .into_iter()
+            .map(UnitConversionSelector::from)
+            .collect())
+    }
+
+    async fn save(&self, event: &AddUnitOfMeasure) -> Result<i32, SaveRepoFailure> {
+        let unit_model = unit_of_measure::ActiveModel {
+            code: ActiveValue::Set(event.code.to_owned()),
+            name: ActiveValue::Set(event.name.to_owned()),
+            ..Default::default()
+        };
+
+        unit_model
+            .save(self.db.as_ref())
+            .await
+            .map(|model| model.unit_of_measure_id.unwrap())
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn save_conversion(&self, event: &AddUnitConversion) -> Result<i32, SaveRepoFailure> {
+        let conversion_model = unit_conversion::ActiveModel {
+            from_unit_id: ActiveValue::Set(event.from_unit_id),
+            to_unit_id: ActiveValue::Set(event.to_unit_id),
+            factor: ActiveValue::Set(event.factor),
+            ..Default::default()
+        };
+
+        conversion_model
+            .save(self.db.as_ref())
+            .await
+            .map(|model| model.unit_conversion_id.unwrap())
+            .map_err(|err| err.into_domain())
+    }
+}
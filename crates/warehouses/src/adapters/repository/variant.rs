@@ -0,0 +1,160 @@
+use super::factories::variant::VariantValueAndAttribute;
+use crate::domain::changes::variant::AddProductVariant;
+use crate::domain::ports::spi::variant::ProductVariantRepository;
+use crate::domain::selectors::attribute::AttributeValueSelector;
+use crate::domain::selectors::variant::{ProductVariantDetailsSelector, ProductVariantSelector};
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+use ids_std_sea::convert::into::IntoDomain;
+use lumx_sea_orm::sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DbConn, EntityTrait, JoinType, QueryFilter,
+    QueryOrder, QuerySelect, RelationTrait, TransactionTrait,
+};
+use portal_schema::{
+    product_attribute, product_attribute_value, product_variant, product_variant_value,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct ProductVariantSeaRepository {
+    db: Arc<DbConn>,
+}
+
+impl ProductVariantSeaRepository {
+    pub fn new(db: &Arc<DbConn>) -> Self {
+        Self {
+            db: Arc::clone(&db),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ProductVariantRepository for ProductVariantSeaRepository {
+    async fn find_by_signature(
+        &self,
+        signature: &str,
+    ) -> Result<Option<ProductVariantSelector>, SelectRepoFailure> {
+        let maybe_model = product_variant::Entity::find()
+            .filter(product_variant::Column::Signature.eq(signature))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(ProductVariantSelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_by_sku(
+        &self,
+        sku: &str,
+    ) -> Result<Option<ProductVariantSelector>, SelectRepoFailure> {
+        let maybe_model = product_variant::Entity::find()
+            .filter(product_variant::Column::Sku.eq(sku))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(ProductVariantSelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_by_product(
+        &self,
+        product_id: i32,
+    ) -> Result<Vec<ProductVariantDetailsSelector>, SelectRepoFailure> {
+        let variants = product_variant::Entity::find()
+            .filter(product_variant::Column::ProductId.eq(product_id))
+            .order_by_asc(product_variant::Column::ProductVariantId)
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        let variant_values = product_variant_value::Entity::find()
+            .select_only()
+            .column(product_variant_value::Column::VariantId)
+            .column(product_attribute_value::Column::ProductAttributeValueId)
+            .column(product_attribute_value::Column::AttributeId)
+            .column_as(product_attribute::Column::Name, "attribute_name")
+            .column(product_attribute_value::Column::Value)
+            .join(
+                JoinType::InnerJoin,
+                product_variant_value::Relation::ProductAttributeValue.def(),
+            )
+            .join(
+                JoinType::InnerJoin,
+                product_attribute_value::Relation::ProductAttribute.def(),
+            )
+            .filter(
+                product_variant_value::Column::VariantId.is_in(
+                    variants
+                        .iter()
+                        .map(|variant| variant.product_variant_id)
+                        .collect::<Vec<i32>>(),
+                ),
+            )
+            .order_by_asc(product_attribute_value::Column::AttributeId)
+            .into_model::<VariantValueAndAttribute>()
+            .all(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        let mut values_by_variant: HashMap<i32, Vec<AttributeValueSelector>> = HashMap::new();
+        for variant_value in variant_values {
+            values_by_variant
+                .entry(variant_value.variant_id)
+                .or_default()
+                .push(AttributeValueSelector::from(variant_value));
+        }
+
+        Ok(variants
+            .into_iter()
+            .map(|variant| ProductVariantDetailsSelector {
+                product_variant_id: variant.product_variant_id,
+                product_id: variant.product_id,
+                name: variant.name,
+                sku: variant.sku,
+                values: values_by_variant
+                    .remove(&variant.product_variant_id)
+                    .unwrap_or_default(),
+            })
+            .collect())
+    }
+
+    async fn save_all(&self, events: &[AddProductVariant]) -> Result<Vec<i32>, SaveRepoFailure> {
+        let txn = self.db.begin().await.map_err(|err| err.into_domain())?;
+
+        let mut product_variant_ids = Vec::new();
+        for event in events {
+            let variant_model = product_variant::ActiveModel {
+                product_id: ActiveValue::Set(event.product_id),
+                name: ActiveValue::Set(event.name.to_owned()),
+                sku: ActiveValue::Set(event.sku.to_owned()),
+                signature: ActiveValue::Set(event.signature.to_owned()),
+                ..Default::default()
+            };
+            let product_variant_id = variant_model
+                .save(&txn)
+                .await
+                .map(|model| model.product_variant_id.unwrap())
+                .map_err(|err| err.into_domain())?;
+
+            for attribute_value_id in &event.attribute_value_ids {
+                let variant_value_model = product_variant_value::ActiveModel {
+                    variant_id: ActiveValue::Set(product_variant_id),
+                    attribute_value_id: ActiveValue::Set(*attribute_value_id),
+                    ..Default::default()
+                };
+                variant_value_model
+                    .save(&txn)
+                    .await
+                    .map_err(|err| err.into_domain())?;
+            }
+
+            product_variant_ids.push(product_variant_id);
+        }
+
+        txn.commit().await.map_err(|err| err.into_domain())?;
+
+        Ok(product_variant_ids)
+    }
+}
@@ -0,0 +1,123 @@
+use crate::domain::changes::warehouse::{AddWarehouse, UpdateWarehouse};
+use crate::domain::ports::spi::warehouse::WarehouseRepository;
+use crate::domain::queries::warehouse::WarehouseQuery;
+use crate::domain::selectors::warehouse::{WarehousePageSelector, WarehouseSelector};
+use ids_std_domain::pagination::{Page, PaginationQuery};
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+use ids_std_sea::convert::into::IntoDomain;
+use ids_std_sea::paginator;
+use lumx_sea_orm::sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DbConn, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder,
+};
+use portal_schema::warehouse;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct WarehouseSeaRepository {
+    db: Arc<DbConn>,
+}
+
+impl WarehouseSeaRepository {
+    pub fn new(db: &Arc<DbConn>) -> Self {
+        Self {
+            db: Arc::clone(&db),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl WarehouseRepository for WarehouseSeaRepository {
+    async fn find_by_id(&self, id: i32) -> Result<Option<WarehouseSelector>, SelectRepoFailure> {
+        let maybe_model = warehouse::Entity::find_by_id(id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(WarehouseSelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_by_code(
+        &self,
+        code: &str,
+    ) -> Result<Option<WarehouseSelector>, SelectRepoFailure> {
+        let maybe_model = warehouse::Entity::find()
+            .filter(warehouse::Column::Code.eq(code))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(WarehouseSelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_other_by_code(
+        &self,
+        self_id: i32,
+        code: &str,
+    ) -> Result<Option<WarehouseSelector>, SelectRepoFailure> {
+        let maybe_model = warehouse::Entity::find()
+            .filter(warehouse::Column::Code.eq(code))
+            .filter(warehouse::Column::WarehouseId.ne(self_id))
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?
+            .map(WarehouseSelector::from);
+
+        Ok(maybe_model)
+    }
+
+    async fn find_all(
+        &self,
+        query: &PaginationQuery,
+        criteria: &WarehouseQuery,
+    ) -> Result<Page<WarehousePageSelector>, SelectRepoFailure> {
+        let mut select = warehouse::Entity::find();
+        if let Some(name) = &criteria.name {
+            select = select.filter(warehouse::Column::Name.contains(name));
+        }
+
+        let paginator = select
+            .order_by_desc(warehouse::Column::WarehouseId)
+            .paginate(self.db.as_ref(), query.page_size);
+
+        paginator::fetch_page(&paginator, query, |model| {
+            WarehousePageSelector::from(model)
+        })
+        .await
+    }
+
+    async fn save(&self, event: &AddWarehouse) -> Result<i32, SaveRepoFailure> {
+        let warehouse_model = warehouse::ActiveModel {
+            code: ActiveValue::Set(event.code.to_owned()),
+            name: ActiveValue::Set(event.name.to_owned()),
+            address: ActiveValue::Set(event.address.to_owned()),
+            ..Default::default()
+        };
+
+        warehouse_model
+            .save(self.db.as_ref())
+            .await
+            .map(|model| model.warehouse_id.unwrap())
+            .map_err(|err| err.into_domain())
+    }
+
+    async fn update(&self, event: &UpdateWarehouse) -> Result<(), SaveRepoFailure> {
+        let result = warehouse::Entity::find_by_id(event.warehouse_id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(|err| err.into_domain())?;
+
+        let mut warehouse_to_modify: warehouse::ActiveModel = result.unwrap().into();
+        warehouse_to_modify.code = ActiveValue::Set(event.code.to_owned());
+        warehouse_to_modify.name = ActiveValue::Set(event.name.to_owned());
+        warehouse_to_modify.address = ActiveValue::Set(event.address.to_owned());
+
+        warehouse_to_modify
+            .save(self.db.as_ref())
+            .await
+            .map(|_| {})
+            .map_err(|err| err.into_domain())
+    }
+}
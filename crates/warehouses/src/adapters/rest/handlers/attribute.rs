@@ -0,0 +1,68 @@
+use crate::adapters::rest::types::attribute::{
+    AddAttributeValueParams, CreateProductAttributeParams, ProductAttributeDetailsQuery,
+};
+use crate::domain::commands::attribute::{AddAttributeValueCommand, CreateProductAttributeCommand};
+use crate::domain::ports::api::attribute::{
+    AddAttributeValueUseCase, CreateProductAttributeUseCase, FindAllProductAttributesUseCase,
+};
+use crate::domain::services::attribute::ProductAttributeService;
+use ids_std_rest_api::failure::ApiFailure;
+use ids_std_rest_api::replier::Replier;
+use ids_std_rest_api::types::created::Created;
+use ids_std_rest_api::types::result::ApiResult;
+use lumx_axum::axum::extract::Path;
+use lumx_axum::axum::Json;
+use lumx_axum::extractor::Component;
+
+pub async fn create_product_attribute(
+    Component(uc): Component<ProductAttributeService>,
+    Json(payload): Json<CreateProductAttributeParams>,
+) -> ApiResult<Created<i32>> {
+    tracing::info!("creating product attribute {:?}", payload);
+
+    let create_attribute_cmd = CreateProductAttributeCommand {
+        name: payload.name,
+        values: payload.values,
+    };
+    let product_attribute_id = uc.create_product_attribute(&create_attribute_cmd).await?;
+
+    Ok(Replier::ok(Created::new(product_attribute_id)))
+}
+
+pub async fn add_attribute_value(
+    Path(product_attribute_id): Path<i32>,
+    Component(uc): Component<ProductAttributeService>,
+    Json(payload): Json<AddAttributeValueParams>,
+) -> ApiResult<Created<i32>> {
+    tracing::info!(
+        product_attribute_id = product_attribute_id,
+        "adding attribute value {:?}",
+        payload
+    );
+
+    let add_value_cmd = AddAttributeValueCommand {
+        attribute_id: product_attribute_id,
+        value: payload.value,
+    };
+    let product_attribute_value_id = uc.add_attribute_value(&add_value_cmd).await?;
+
+    Ok(Replier::ok(Created::new(product_attribute_value_id)))
+}
+
+pub async fn find_all_product_attributes(
+    Component(uc): Component<ProductAttributeService>,
+) -> ApiResult<Vec<ProductAttributeDetailsQuery>> {
+    tracing::info!("finding all product attributes");
+
+    uc.find_all_product_attributes()
+        .await
+        .map(|attributes| {
+            Replier::ok(
+                attributes
+                    .iter()
+                    .map(ProductAttributeDetailsQuery::from)
+                    .collect(),
+            )
+        })
+        .map_err(ApiFailure::from)
+}
@@ -0,0 +1,78 @@
+use crate::adapters::rest::types::barcode::{
+    AddProductBarcodeParams, ProductBarcodeQuery, ScannedProductQuery, SetProductSkuParams,
+};
+use crate::domain::commands::barcode::{AddProductBarcodeCommand, SetProductSkuCommand};
+use crate::domain::ports::api::barcode::{
+    AddProductBarcodeUseCase, FindProductBarcodesUseCase, FindProductByBarcodeUseCase,
+    SetProductSkuUseCase,
+};
+use crate::domain::services::barcode::ProductBarcodeService;
+use ids_std_rest_api::failure::ApiFailure;
+use ids_std_rest_api::replier::Replier;
+use ids_std_rest_api::types::created::Created;
+use ids_std_rest_api::types::result::ApiResult;
+use lumx_axum::axum::extract::Path;
+use lumx_axum::axum::http::StatusCode;
+use lumx_axum::axum::Json;
+use lumx_axum::extractor::Component;
+
+pub async fn set_product_sku(
+    Path(product_id): Path<i32>,
+    Component(uc): Component<ProductBarcodeService>,
+    Json(payload): Json<SetProductSkuParams>,
+) -> Result<StatusCode, ApiFailure> {
+    tracing::info!(product_id = product_id, "setting product sku {:?}", payload);
+
+    let set_sku_cmd = SetProductSkuCommand {
+        product_id,
+        sku: payload.sku,
+    };
+    uc.set_product_sku(&set_sku_cmd)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiFailure::from)
+}
+
+pub async fn add_product_barcode(
+    Path(product_id): Path<i32>,
+    Component(uc): Component<ProductBarcodeService>,
+    Json(payload): Json<AddProductBarcodeParams>,
+) -> ApiResult<Created<i32>> {
+    tracing::info!(
+        product_id = product_id,
+        "adding product barcode {:?}",
+        payload
+    );
+
+    let add_barcode_cmd = AddProductBarcodeCommand {
+        product_id,
+        code: payload.code,
+    };
+    let product_barcode_id = uc.add_product_barcode(&add_barcode_cmd).await?;
+
+    Ok(Replier::ok(Created::new(product_barcode_id)))
+}
+
+pub async fn find_product_barcodes(
+    Path(product_id): Path<i32>,
+    Component(uc): Component<ProductBarcodeService>,
+) -> ApiResult<Vec<ProductBarcodeQuery>> {
+    tracing::info!(product_id = product_id, "finding product barcodes");
+
+    uc.find_product_barcodes(product_id)
+        .await
+        .map(|barcodes| Replier::ok(barcodes.iter().map(ProductBarcodeQuery::from).collect()))
+        .map_err(ApiFailure::from)
+}
+
+pub async fn find_product_by_barcode(
+    Path(code): Path<String>,
+    Component(uc): Component<ProductBarcodeService>,
+) -> ApiResult<ScannedProductQuery> {
+    tracing::info!(code = code.as_str(), "finding product by barcode");
+
+    uc.find_product_by_barcode(code.as_str())
+        .await
+        .map(|product| Replier::ok(ScannedProductQuery::from(&product)))
+        .map_err(ApiFailure::from)
+}
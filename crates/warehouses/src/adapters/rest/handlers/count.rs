@@ -0,0 +1,139 @@
+use crate::adapters::rest::types::count::{
+    CountInventoryParams, InventoryCountCriteriaParams, InventoryCountDetailsQuery,
+    InventoryCountPageQuery, OpenInventoryCountParams,
+};
+use crate::domain::commands::count::{CountInventoryCommand, OpenInventoryCountCommand};
+use crate::domain::ports::api::count::{
+    CountInventoryUseCase, FindAllInventoryCountsUseCase, FindInventoryCountUseCase,
+    OpenInventoryCountUseCase, PostInventoryCountUseCase, ReviewInventoryCountUseCase,
+    StartInventoryCountUseCase,
+};
+use crate::domain::services::count::InventoryCountService;
+use ids_std_rest_api::failure::ApiFailure;
+use ids_std_rest_api::replier::Replier;
+use ids_std_rest_api::types::created::Created;
+use ids_std_rest_api::types::pagination::{Paged, PaginationParams};
+use ids_std_rest_api::types::result::ApiResult;
+use lumx_axum::axum::extract::{Path, Query};
+use lumx_axum::axum::http::StatusCode;
+use lumx_axum::axum::Json;
+use lumx_axum::extractor::Component;
+
+pub async fn open_inventory_count(
+    Component(uc): Component<InventoryCountService>,
+    Json(payload): Json<OpenInventoryCountParams>,
+) -> ApiResult<Created<i32>> {
+    tracing::info!("opening inventory count {:?}", payload);
+
+    let open_count_cmd = OpenInventoryCountCommand {
+        warehouse_id: payload.warehouse_id,
+        family_id: payload.family_id,
+    };
+    let inventory_count_id = uc.open_inventory_count(&open_count_cmd).await?;
+
+    Ok(Replier::ok(Created::new(inventory_count_id)))
+}
+
+pub async fn start_inventory_count(
+    Path(inventory_count_id): Path<i32>,
+    Component(uc): Component<InventoryCountService>,
+) -> Result<StatusCode, ApiFailure> {
+    tracing::info!(
+        inventory_count_id = inventory_count_id,
+        "starting inventory count"
+    );
+
+    uc.start_inventory_count(inventory_count_id)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiFailure::from)
+}
+
+pub async fn count_inventory(
+    Path(inventory_count_id): Path<i32>,
+    Component(uc): Component<InventoryCountService>,
+    Json(payload): Json<CountInventoryParams>,
+) -> Result<StatusCode, ApiFailure> {
+    tracing::info!(
+        inventory_count_id = inventory_count_id,
+        "counting inventory {:?}",
+        payload
+    );
+
+    let count_inventory_cmd = CountInventoryCommand {
+        inventory_count_id,
+        product_id: payload.product_id,
+        storage_location_id: payload.storage_location_id,
+        counted_quantity: payload.counted_quantity,
+        unit_id: payload.unit_id,
+    };
+    uc.count_inventory(&count_inventory_cmd)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiFailure::from)
+}
+
+pub async fn review_inventory_count(
+    Path(inventory_count_id): Path<i32>,
+    Component(uc): Component<InventoryCountService>,
+) -> Result<StatusCode, ApiFailure> {
+    tracing::info!(
+        inventory_count_id = inventory_count_id,
+        "reviewing inventory count"
+    );
+
+    uc.review_inventory_count(inventory_count_id)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiFailure::from)
+}
+
+pub async fn post_inventory_count(
+    Path(inventory_count_id): Path<i32>,
+    Component(uc): Component<InventoryCountService>,
+) -> Result<StatusCode, ApiFailure> {
+    tracing::info!(
+        inventory_count_id = inventory_count_id,
+        "posting inventory count"
+    );
+
+    uc.post_inventory_count(inventory_count_id)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiFailure::from)
+}
+
+pub async fn find_all_inventory_counts(
+    Component(uc): Component<InventoryCountService>,
+    Query(payload): Query<PaginationParams>,
+    Query(criteria): Query<InventoryCountCriteriaParams>,
+) -> ApiResult<Paged<InventoryCountPageQuery>> {
+    tracing::info!(
+        "find and paginate all inventory counts {:?} {:?}",
+        payload,
+        criteria
+    );
+
+    let counts = uc
+        .find_all_inventory_counts(&payload.into(), &criteria.into())
+        .await?;
+
+    Ok(Replier::ok(Paged::from(&counts, |item| {
+        InventoryCountPageQuery::from(item)
+    })))
+}
+
+pub async fn find_inventory_count(
+    Path(inventory_count_id): Path<i32>,
+    Component(uc): Component<InventoryCountService>,
+) -> ApiResult<InventoryCountDetailsQuery> {
+    tracing::info!(
+        inventory_count_id = inventory_count_id,
+        "finding inventory count"
+    );
+
+    uc.find_inventory_count(inventory_count_id)
+        .await
+        .map(|count| Replier::ok(InventoryCountDetailsQuery::from(&count)))
+        .map_err(ApiFailure::from)
+}
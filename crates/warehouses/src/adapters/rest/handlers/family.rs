@@ -0,0 +1,126 @@
+use crate::adapters::rest::types::family::{
+    CreateProductFamilyParams, ProductFamilyBreadcrumbQuery, ProductFamilyCriteriaParams,
+    ProductFamilyDetailsQuery, ProductFamilyPageQuery, ProductFamilyTreeQuery,
+    UpdateProductFamilyParams,
+};
+use crate::domain::commands::family::{CreateProductFamilyCommand, UpdateProductFamilyCommand};
+use crate::domain::ports::api::family::{
+    CreateProductFamilyUseCase, FindAllProductFamiliesUseCase, FindProductFamilyBreadcrumbUseCase,
+    FindProductFamilySubtreeUseCase, FindProductFamilyUseCase, UpdateProductFamilyUseCase,
+};
+use crate::domain::services::family::ProductFamilyService;
+use ids_std_rest_api::failure::ApiFailure;
+use ids_std_rest_api::replier::Replier;
+use ids_std_rest_api::types::created::Created;
+use ids_std_rest_api::types::pagination::{Paged, PaginationParams};
+use ids_std_rest_api::types::result::ApiResult;
+use lumx_axum::axum::extract::{Path, Query};
+use lumx_axum::axum::http::StatusCode;
+use lumx_axum::axum::Json;
+use lumx_axum::extractor::Component;
+
+pub async fn create_product_family(
+    Component(uc): Component<ProductFamilyService>,
+    Json(payload): Json<CreateProductFamilyParams>,
+) -> ApiResult<Created<i32>> {
+    tracing::info!("creating product family {:?}", payload);
+
+    let create_family_cmd = CreateProductFamilyCommand {
+        name: payload.name,
+        summary: payload.summary,
+        parent_family_id: payload.parent_family_id,
+    };
+    let product_family_id = uc.create_product_family(&create_family_cmd).await?;
+
+    Ok(Replier::ok(Created::new(product_family_id)))
+}
+
+pub async fn update_product_family(
+    Path(product_family_id): Path<i32>,
+    Component(uc): Component<ProductFamilyService>,
+    Json(payload): Json<UpdateProductFamilyParams>,
+) -> Result<StatusCode, ApiFailure> {
+    tracing::info!("updating product family {:?}", payload);
+
+    let update_family_cmd = UpdateProductFamilyCommand {
+        product_family_id,
+        name: payload.name,
+        summary: payload.summary,
+        parent_family_id: payload.parent_family_id,
+    };
+    uc.update_product_family(&update_family_cmd)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiFailure::from)
+}
+
+pub async fn find_all_product_families(
+    Component(uc): Component<ProductFamilyService>,
+    Query(payload): Query<PaginationParams>,
+    Query(criteria): Query<ProductFamilyCriteriaParams>,
+) -> ApiResult<Paged<ProductFamilyPageQuery>> {
+    tracing::info!(
+        "find and paginate all product families {:?} {:?}",
+        payload,
+        criteria
+    );
+
+    let families = uc
+        .find_all_product_families(&payload.into(), &criteria.into())
+        .await?;
+
+    Ok(Replier::ok(Paged::from(&families, |item| {
+        ProductFamilyPageQuery::from(item)
+    })))
+}
+
+pub async fn find_product_family(
+    Path(product_family_id): Path<i32>,
+    Component(uc): Component<ProductFamilyService>,
+) -> ApiResult<ProductFamilyDetailsQuery> {
+    tracing::info!(
+        product_family_id = product_family_id,
+        "finding product family"
+    );
+
+    uc.find_product_family(product_family_id)
+        .await
+        .map(|family| Replier::ok(ProductFamilyDetailsQuery::from(&family)))
+        .map_err(ApiFailure::from)
+}
+
+pub async fn find_product_family_subtree(
+    Path(product_family_id): Path<i32>,
+    Component(uc): Component<ProductFamilyService>,
+) -> ApiResult<ProductFamilyTreeQuery> {
+    tracing::info!(
+        product_family_id = product_family_id,
+        "finding product family subtree"
+    );
+
+    uc.find_product_family_subtree(product_family_id)
+        .await
+        .map(|tree| Replier::ok(ProductFamilyTreeQuery::from(&tree)))
+        .map_err(ApiFailure::from)
+}
+
+pub async fn find_product_family_breadcrumb(
+    Path(product_family_id): Path<i32>,
+    Component(uc): Component<ProductFamilyService>,
+) -> ApiResult<Vec<ProductFamilyBreadcrumbQuery>> {
+    tracing::info!(
+        product_family_id = product_family_id,
+        "finding product family breadcrumb"
+    );
+
+    uc.find_product_family_breadcrumb(product_family_id)
+        .await
+        .map(|path| {
+            Replier::ok(
+                path.iter()
+                    .map(ProductFamilyBreadcrumbQuery::from)
+                    .collect(),
+            )
+        })
+        .map_err(ApiFailure::from)
+}
@@ -0,0 +1,78 @@
+use crate::adapters::rest::types::kit::{
+    AddKitComponentParams, AssembleKitParams, KitAvailabilityQuery, KitComponentQuery,
+};
+use crate::domain::commands::kit::{AddKitComponentCommand, AssembleKitCommand};
+use crate::domain::ports::api::kit::{
+    AddKitComponentUseCase, AssembleKitUseCase, FindKitAvailabilityUseCase,
+    FindKitComponentsUseCase,
+};
+use crate::domain::services::kit::KitService;
+use ids_std_rest_api::failure::ApiFailure;
+use ids_std_rest_api::replier::Replier;
+use ids_std_rest_api::types::created::Created;
+use ids_std_rest_api::types::result::ApiResult;
+use lumx_axum::axum::extract::Path;
+use lumx_axum::axum::Json;
+use lumx_axum::extractor::Component;
+
+pub async fn add_kit_component(
+    Path(product_id): Path<i32>,
+    Component(uc): Component<KitService>,
+    Json(payload): Json<AddKitComponentParams>,
+) -> ApiResult<Created<i32>> {
+    tracing::info!(
+        product_id = product_id,
+        "adding kit component {:?}",
+        payload
+    );
+
+    let add_component_cmd = AddKitComponentCommand {
+        kit_product_id: product_id,
+        component_product_id: payload.component_id,
+        quantity: payload.quantity,
+    };
+    let product_component_id = uc.add_kit_component(&add_component_cmd).await?;
+
+    Ok(Replier::ok(Created::new(product_component_id)))
+}
+
+pub async fn find_kit_components(
+    Path(product_id): Path<i32>,
+    Component(uc): Component<KitService>,
+) -> ApiResult<Vec<KitComponentQuery>> {
+    tracing::info!(product_id = product_id, "finding kit components");
+
+    uc.find_kit_components(product_id)
+        .await
+        .map(|components| Replier::ok(components.iter().map(KitComponentQuery::from).collect()))
+        .map_err(ApiFailure::from)
+}
+
+pub async fn find_kit_availability(
+    Path(product_id): Path<i32>,
+    Component(uc): Component<KitService>,
+) -> ApiResult<KitAvailabilityQuery> {
+    tracing::info!(product_id = product_id, "finding kit availability");
+
+    uc.find_kit_availability(product_id)
+        .await
+        .map(|availability| Replier::ok(KitAvailabilityQuery::from(&availability)))
+        .map_err(ApiFailure::from)
+}
+
+pub async fn assemble_kit(
+    Path(product_id): Path<i32>,
+    Component(uc): Component<KitService>,
+    Json(payload): Json<AssembleKitParams>,
+) -> ApiResult<Created<i32>> {
+    tracing::info!(product_id = product_id, "assembling kit {:?}", payload);
+
+    let assemble_kit_cmd = AssembleKitCommand {
+        kit_product_id: product_id,
+        quantity: payload.quantity,
+        storage_location_id: payload.storage_location_id,
+    };
+    let stock_movement_id = uc.assemble_kit(&assemble_kit_cmd).await?;
+
+    Ok(Replier::ok(Created::new(stock_movement_id)))
+}
@@ -0,0 +1,97 @@
+use crate::adapters::rest::types::location::{
+    CreateStorageLocationParams, StorageLocationDetailsQuery, StorageLocationPageQuery,
+    UpdateStorageLocationParams,
+};
+use crate::domain::commands::location::{
+    CreateStorageLocationCommand, UpdateStorageLocationCommand,
+};
+use crate::domain::ports::api::location::{
+    CreateStorageLocationUseCase, FindStorageLocationUseCase, FindWarehouseLocationsUseCase,
+    UpdateStorageLocationUseCase,
+};
+use crate::domain::services::location::StorageLocationService;
+use ids_std_rest_api::failure::ApiFailure;
+use ids_std_rest_api::replier::Replier;
+use ids_std_rest_api::types::created::Created;
+use ids_std_rest_api::types::pagination::{Paged, PaginationParams};
+use ids_std_rest_api::types::result::ApiResult;
+use lumx_axum::axum::extract::{Path, Query};
+use lumx_axum::axum::http::StatusCode;
+use lumx_axum::axum::Json;
+use lumx_axum::extractor::Component;
+
+pub async fn create_storage_location(
+    Path(warehouse_id): Path<i32>,
+    Component(uc): Component<StorageLocationService>,
+    Json(payload): Json<CreateStorageLocationParams>,
+) -> ApiResult<Created<i32>> {
+    tracing::info!(
+        warehouse_id = warehouse_id,
+        "creating storage location {:?}",
+        payload
+    );
+
+    let create_location_cmd = CreateStorageLocationCommand {
+        warehouse_id,
+        aisle: payload.aisle,
+        shelf: payload.shelf,
+        bin: payload.bin,
+    };
+    let storage_location_id = uc.create_storage_location(&create_location_cmd).await?;
+
+    Ok(Replier::ok(Created::new(storage_location_id)))
+}
+
+pub async fn update_storage_location(
+    Path(storage_location_id): Path<i32>,
+    Component(uc): Component<StorageLocationService>,
+    Json(payload): Json<UpdateStorageLocationParams>,
+) -> Result<StatusCode, ApiFailure> {
+    tracing::info!("updating storage location {:?}", payload);
+
+    let update_location_cmd = UpdateStorageLocationCommand {
+        storage_location_id,
+        aisle: payload.aisle,
+        shelf: payload.shelf,
+        bin: payload.bin,
+    };
+    uc.update_storage_location(&update_location_cmd)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiFailure::from)
+}
+
+pub async fn find_warehouse_locations(
+    Path(warehouse_id): Path<i32>,
+    Component(uc): Component<StorageLocationService>,
+    Query(payload): Query<PaginationParams>,
+) -> ApiResult<Paged<StorageLocationPageQuery>> {
+    tracing::info!(
+        warehouse_id = warehouse_id,
+        "find and paginate storage locations {:?}",
+        payload
+    );
+
+    let locations = uc
+        .find_warehouse_locations(&payload.into(), warehouse_id)
+        .await?;
+
+    Ok(Replier::ok(Paged::from(&locations, |item| {
+        StorageLocationPageQuery::from(item)
+    })))
+}
+
+pub async fn find_storage_location(
+    Path(storage_location_id): Path<i32>,
+    Component(uc): Component<StorageLocationService>,
+) -> ApiResult<StorageLocationDetailsQuery> {
+    tracing::info!(
+        storage_location_id = storage_location_id,
+        "finding storage location"
+    );
+
+    uc.find_storage_location(storage_location_id)
+        .await
+        .map(|location| Replier::ok(StorageLocationDetailsQuery::from(&location)))
+        .map_err(ApiFailure::from)
+}
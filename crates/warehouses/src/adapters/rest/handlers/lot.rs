@@ -0,0 +1,36 @@
+use crate::adapters::rest::types::lot::{
+    ExpiringLotCriteriaParams, ExpiringLotDetailsQuery, FefoPickCriteriaParams,
+    FefoPickDetailsQuery,
+};
+use crate::domain::ports::api::lot::{FindExpiringLotsUseCase, PlanFefoPickUseCase};
+use crate::domain::services::lot::StockLotService;
+use ids_std_rest_api::failure::ApiFailure;
+use ids_std_rest_api::replier::Replier;
+use ids_std_rest_api::types::result::ApiResult;
+use lumx_axum::axum::extract::{Path, Query};
+use lumx_axum::extractor::Component;
+
+pub async fn find_expiring_lots(
+    Component(uc): Component<StockLotService>,
+    Query(criteria): Query<ExpiringLotCriteriaParams>,
+) -> ApiResult<Vec<ExpiringLotDetailsQuery>> {
+    tracing::info!("finding expiring lots {:?}", criteria);
+
+    uc.find_expiring_lots(&criteria.into())
+        .await
+        .map(|lots| Replier::ok(lots.iter().map(ExpiringLotDetailsQuery::from).collect()))
+        .map_err(ApiFailure::from)
+}
+
+pub async fn plan_fefo_pick(
+    Path(product_id): Path<i32>,
+    Component(uc): Component<StockLotService>,
+    Query(criteria): Query<FefoPickCriteriaParams>,
+) -> ApiResult<FefoPickDetailsQuery> {
+    tracing::info!(product_id = product_id, "planning fefo pick {:?}", criteria);
+
+    uc.plan_fefo_pick(product_id, &criteria.into())
+        .await
+        .map(|plan| Replier::ok(FefoPickDetailsQuery::from(&plan)))
+        .map_err(ApiFailure::from)
+}
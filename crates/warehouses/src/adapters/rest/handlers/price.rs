@@ -0,0 +1,86 @@
+use crate::adapters::rest::types::price::{
+    AddProductPriceParams, CreatePriceListParams, PriceListDetailsQuery,
+    ProductPriceCriteriaParams, ResolvedPriceDetailsQuery,
+};
+use crate::domain::commands::price::{AddProductPriceCommand, CreatePriceListCommand};
+use crate::domain::ports::api::price::{
+    AddProductPriceUseCase, CreatePriceListUseCase, FindAllPriceListsUseCase,
+    ResolveProductPriceUseCase,
+};
+use crate::domain::services::price::PriceListService;
+use ids_std_rest_api::failure::ApiFailure;
+use ids_std_rest_api::replier::Replier;
+use ids_std_rest_api::types::created::Created;
+use ids_std_rest_api::types::result::ApiResult;
+use lumx_axum::axum::extract::{Path, Query};
+use lumx_axum::axum::Json;
+use lumx_axum::extractor::Component;
+
+pub async fn create_price_list(
+    Component(uc): Component<PriceListService>,
+    Json(payload): Json<CreatePriceListParams>,
+) -> ApiResult<Created<i32>> {
+    tracing::info!("creating price list {:?}", payload);
+
+    let create_price_list_cmd = CreatePriceListCommand {
+        name: payload.name,
+        kind: payload.kind.into(),
+        currency: payload.currency,
+        valid_from: payload.valid_from,
+        valid_to: payload.valid_to,
+    };
+    let price_list_id = uc.create_price_list(&create_price_list_cmd).await?;
+
+    Ok(Replier::ok(Created::new(price_list_id)))
+}
+
+pub async fn find_all_price_lists(
+    Component(uc): Component<PriceListService>,
+) -> ApiResult<Vec<PriceListDetailsQuery>> {
+    tracing::info!("finding all price lists");
+
+    uc.find_all_price_lists()
+        .await
+        .map(|lists| Replier::ok(lists.iter().map(PriceListDetailsQuery::from).collect()))
+        .map_err(ApiFailure::from)
+}
+
+pub async fn add_product_price(
+    Path(price_list_id): Path<i32>,
+    Component(uc): Component<PriceListService>,
+    Json(payload): Json<AddProductPriceParams>,
+) -> ApiResult<Created<i32>> {
+    tracing::info!(
+        price_list_id = price_list_id,
+        "adding product price {:?}",
+        payload
+    );
+
+    let add_price_cmd = AddProductPriceCommand {
+        price_list_id,
+        product_id: payload.product_id,
+        amount: payload.amount,
+        valid_from: payload.valid_from,
+        valid_to: payload.valid_to,
+    };
+    let product_price_id = uc.add_product_price(&add_price_cmd).await?;
+
+    Ok(Replier::ok(Created::new(product_price_id)))
+}
+
+pub async fn resolve_product_price(
+    Path(product_id): Path<i32>,
+    Component(uc): Component<PriceListService>,
+    Query(criteria): Query<ProductPriceCriteriaParams>,
+) -> ApiResult<ResolvedPriceDetailsQuery> {
+    tracing::info!(
+        product_id = product_id,
+        "resolving product price {:?}",
+        criteria
+    );
+
+    uc.resolve_product_price(product_id, &criteria.into())
+        .await
+        .map(|price| Replier::ok(ResolvedPriceDetailsQuery::from(&price)))
+        .map_err(ApiFailure::from)
+}
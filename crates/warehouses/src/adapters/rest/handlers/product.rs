@@ -1,10 +1,21 @@
-use crate::adapters::rest::types::product::CreateProductParams;
-use crate::domain::commands::product::CreateProductCommand;
-use crate::domain::ports::api::product::CreateProductUseCase;
+use crate::adapters::rest::types::product::{
+    CreateProductParams, ProductCriteriaParams, ProductDetailsQuery, ProductPageQuery,
+    UpdateProductParams,
+};
+use crate::domain::commands::product::{CreateProductCommand, UpdateProductCommand};
+use crate::domain::ports::api::product::{
+    CreateProductUseCase, DiscontinueProductUseCase, FindAllProductsUseCase,
+    FindProductDetailsUseCase, UpdateProductUseCase,
+};
 use crate::domain::services::product::ProductService;
+use crate::domain::valuables::tracking::TrackingMode;
+use ids_std_rest_api::failure::ApiFailure;
 use ids_std_rest_api::replier::Replier;
 use ids_std_rest_api::types::created::Created;
+use ids_std_rest_api::types::pagination::{Paged, PaginationParams};
 use ids_std_rest_api::types::result::ApiResult;
+use lumx_axum::axum::extract::{Path, Query};
+use lumx_axum::axum::http::StatusCode;
 use lumx_axum::axum::Json;
 use lumx_axum::extractor::Component;
 
@@ -21,8 +32,79 @@ pub async fn create_product(
         purchasable: payload.purchasable,
         saleable: payload.saleable,
         saleable_without_stock: payload.saleable_without_stock,
+        tracking_mode: payload
+            .tracking_mode
+            .map(TrackingMode::from)
+            .unwrap_or(TrackingMode::None),
     };
     let product_id = uc.create_product(&create_product_cmd).await?;
 
     Ok(Replier::ok(Created::new(product_id)))
 }
+
+pub async fn find_all_products(
+    Component(uc): Component<ProductService>,
+    Query(payload): Query<PaginationParams>,
+    Query(criteria): Query<ProductCriteriaParams>,
+) -> ApiResult<Paged<ProductPageQuery>> {
+    tracing::info!(
+        "find and paginate all products {:?} {:?}",
+        payload,
+        criteria
+    );
+
+    let products = uc
+        .find_all_products(&payload.into(), &criteria.into())
+        .await?;
+
+    Ok(Replier::ok(Paged::from(&products, |item| {
+        ProductPageQuery::from(item)
+    })))
+}
+
+pub async fn find_product(
+    Path(product_id): Path<i32>,
+    Component(uc): Component<ProductService>,
+) -> ApiResult<ProductDetailsQuery> {
+    tracing::info!(product_id = product_id, "finding product");
+
+    uc.find_product_details(product_id)
+        .await
+        .map(|product| Replier::ok(ProductDetailsQuery::from(&product)))
+        .map_err(ApiFailure::from)
+}
+
+pub async fn update_product(
+    Path(product_id): Path<i32>,
+    Component(uc): Component<ProductService>,
+    Json(payload): Json<UpdateProductParams>,
+) -> Result<StatusCode, ApiFailure> {
+    tracing::info!("updating product {:?}", payload);
+
+    let update_product_cmd = UpdateProductCommand {
+        product_id,
+        name: payload.name,
+        summary: payload.summary,
+        family_id: payload.family_id,
+        purchasable: payload.purchasable,
+        saleable: payload.saleable,
+        saleable_without_stock: payload.saleable_without_stock,
+        tracking_mode: payload.tracking_mode.map(TrackingMode::from),
+    };
+    uc.update_product(&update_product_cmd)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiFailure::from)
+}
+
+pub async fn discontinue_product(
+    Path(product_id): Path<i32>,
+    Component(uc): Component<ProductService>,
+) -> Result<StatusCode, ApiFailure> {
+    tracing::info!(product_id = product_id, "discontinuing product");
+
+    uc.discontinue_product(product_id)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiFailure::from)
+}
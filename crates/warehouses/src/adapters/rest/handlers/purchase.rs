@@ -0,0 +1,173 @@
+use crate::adapters::rest::types::purchase::{
+    AddPurchaseOrderLineParams, CreatePurchaseOrderParams, PurchaseOrderCriteriaParams,
+    PurchaseOrderDetailsQuery, PurchaseOrderPageQuery, ReceivePurchaseOrderParams,
+};
+use crate::domain::commands::purchase::{
+    AddPurchaseOrderLineCommand, CreatePurchaseOrderCommand, ReceivePurchaseOrderCommand,
+    ReceivePurchaseOrderLineCommand,
+};
+use crate::domain::ports::api::purchase::{
+    AddPurchaseOrderLineUseCase, ApprovePurchaseOrderUseCase, ClosePurchaseOrderUseCase,
+    CreatePurchaseOrderUseCase, FindAllPurchaseOrdersUseCase, FindPurchaseOrderUseCase,
+    ReceivePurchaseOrderUseCase, SendPurchaseOrderUseCase,
+};
+use crate::domain::services::purchase::PurchaseOrderService;
+use ids_std_rest_api::failure::ApiFailure;
+use ids_std_rest_api::replier::Replier;
+use ids_std_rest_api::types::created::Created;
+use ids_std_rest_api::types::pagination::{Paged, PaginationParams};
+use ids_std_rest_api::types::result::ApiResult;
+use lumx_axum::axum::extract::{Path, Query};
+use lumx_axum::axum::http::StatusCode;
+use lumx_axum::axum::Json;
+use lumx_axum::extractor::Component;
+
+pub async fn create_purchase_order(
+    Component(uc): Component<PurchaseOrderService>,
+    Json(payload): Json<CreatePurchaseOrderParams>,
+) -> ApiResult<Created<i32>> {
+    tracing::info!("creating purchase order {:?}", payload);
+
+    let create_order_cmd = CreatePurchaseOrderCommand {
+        supplier_id: payload.supplier_id,
+    };
+    let purchase_order_id = uc.create_purchase_order(&create_order_cmd).await?;
+
+    Ok(Replier::ok(Created::new(purchase_order_id)))
+}
+
+pub async fn add_purchase_order_line(
+    Path(purchase_order_id): Path<i32>,
+    Component(uc): Component<PurchaseOrderService>,
+    Json(payload): Json<AddPurchaseOrderLineParams>,
+) -> ApiResult<Created<i32>> {
+    tracing::info!(
+        purchase_order_id = purchase_order_id,
+        "adding purchase order line {:?}",
+        payload
+    );
+
+    let add_line_cmd = AddPurchaseOrderLineCommand {
+        purchase_order_id,
+        product_id: payload.product_id,
+        quantity: payload.quantity,
+        unit_id: payload.unit_id,
+        unit_price: payload.unit_price,
+    };
+    let purchase_order_line_id = uc.add_purchase_order_line(&add_line_cmd).await?;
+
+    Ok(Replier::ok(Created::new(purchase_order_line_id)))
+}
+
+pub async fn approve_purchase_order(
+    Path(purchase_order_id): Path<i32>,
+    Component(uc): Component<PurchaseOrderService>,
+) -> Result<StatusCode, ApiFailure> {
+    tracing::info!(
+        purchase_order_id = purchase_order_id,
+        "approving purchase order"
+    );
+
+    uc.approve_purchase_order(purchase_order_id)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiFailure::from)
+}
+
+pub async fn send_purchase_order(
+    Path(purchase_order_id): Path<i32>,
+    Component(uc): Component<PurchaseOrderService>,
+) -> Result<StatusCode, ApiFailure> {
+    tracing::info!(
+        purchase_order_id = purchase_order_id,
+        "sending purchase order"
+    );
+
+    uc.send_purchase_order(purchase_order_id)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiFailure::from)
+}
+
+pub async fn receive_purchase_order(
+    Path(purchase_order_id): Path<i32>,
+    Component(uc): Component<PurchaseOrderService>,
+    Json(payload): Json<ReceivePurchaseOrderParams>,
+) -> Result<StatusCode, ApiFailure> {
+    tracing::info!(
+        purchase_order_id = purchase_order_id,
+        "receiving purchase order {:?}",
+        payload
+    );
+
+    let receive_order_cmd = ReceivePurchaseOrderCommand {
+        purchase_order_id,
+        storage_location_id: payload.storage_location_id,
+        allow_over_receipt: payload.allow_over_receipt,
+        lines: payload
+            .lines
+            .into_iter()
+            .map(|line| ReceivePurchaseOrderLineCommand {
+                purchase_order_line_id: line.purchase_order_line_id,
+                quantity: line.quantity,
+                lot_number: line.lot_number,
+                serial_number: line.serial_number,
+                expires_on: line.expires_on,
+            })
+            .collect(),
+    };
+    uc.receive_purchase_order(&receive_order_cmd)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiFailure::from)
+}
+
+pub async fn close_purchase_order(
+    Path(purchase_order_id): Path<i32>,
+    Component(uc): Component<PurchaseOrderService>,
+) -> Result<StatusCode, ApiFailure> {
+    tracing::info!(
+        purchase_order_id = purchase_order_id,
+        "closing purchase order"
+    );
+
+    uc.close_purchase_order(purchase_order_id)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiFailure::from)
+}
+
+pub async fn find_all_purchase_orders(
+    Component(uc): Component<PurchaseOrderService>,
+    Query(payload): Query<PaginationParams>,
+    Query(criteria): Query<PurchaseOrderCriteriaParams>,
+) -> ApiResult<Paged<PurchaseOrderPageQuery>> {
+    tracing::info!(
+        "find and paginate all purchase orders {:?} {:?}",
+        payload,
+        criteria
+    );
+
+    let orders = uc
+        .find_all_purchase_orders(&payload.into(), &criteria.into())
+        .await?;
+
+    Ok(Replier::ok(Paged::from(&orders, |item| {
+        PurchaseOrderPageQuery::from(item)
+    })))
+}
+
+pub async fn find_purchase_order(
+    Path(purchase_order_id): Path<i32>,
+    Component(uc): Component<PurchaseOrderService>,
+) -> ApiResult<PurchaseOrderDetailsQuery> {
+    tracing::info!(
+        purchase_order_id = purchase_order_id,
+        "finding purchase order"
+    );
+
+    uc.find_purchase_order(purchase_order_id)
+        .await
+        .map(|order| Replier::ok(PurchaseOrderDetailsQuery::from(&order)))
+        .map_err(ApiFailure::from)
+}
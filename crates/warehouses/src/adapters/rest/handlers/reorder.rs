@@ -0,0 +1,61 @@
+use crate::adapters::rest::types::reorder::{
+    LowStockQuery, ReorderPointDetailsQuery, SetReorderPointParams,
+};
+use crate::domain::commands::reorder::SetReorderPointCommand;
+use crate::domain::ports::api::reorder::{
+    FindLowStockProductsUseCase, FindReorderPointUseCase, SetReorderPointUseCase,
+};
+use crate::domain::services::reorder::ReorderPointService;
+use ids_std_rest_api::failure::ApiFailure;
+use ids_std_rest_api::replier::Replier;
+use ids_std_rest_api::types::result::ApiResult;
+use lumx_axum::axum::extract::Path;
+use lumx_axum::axum::http::StatusCode;
+use lumx_axum::axum::Json;
+use lumx_axum::extractor::Component;
+
+pub async fn set_reorder_point(
+    Path(product_id): Path<i32>,
+    Component(uc): Component<ReorderPointService>,
+    Json(payload): Json<SetReorderPointParams>,
+) -> Result<StatusCode, ApiFailure> {
+    tracing::info!(
+        product_id = product_id,
+        "setting reorder point {:?}",
+        payload
+    );
+
+    let set_reorder_point_cmd = SetReorderPointCommand {
+        product_id,
+        min_quantity: payload.min_quantity,
+        max_quantity: payload.max_quantity,
+        unit_id: payload.unit_id,
+    };
+    uc.set_reorder_point(&set_reorder_point_cmd)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiFailure::from)
+}
+
+pub async fn find_reorder_point(
+    Path(product_id): Path<i32>,
+    Component(uc): Component<ReorderPointService>,
+) -> ApiResult<ReorderPointDetailsQuery> {
+    tracing::info!(product_id = product_id, "finding reorder point");
+
+    uc.find_reorder_point(product_id)
+        .await
+        .map(|reorder_point| Replier::ok(ReorderPointDetailsQuery::from(&reorder_point)))
+        .map_err(ApiFailure::from)
+}
+
+pub async fn find_low_stock_products(
+    Component(uc): Component<ReorderPointService>,
+) -> ApiResult<Vec<LowStockQuery>> {
+    tracing::info!("finding low stock products");
+
+    uc.find_low_stock_products()
+        .await
+        .map(|products| Replier::ok(products.iter().map(LowStockQuery::from).collect()))
+        .map_err(ApiFailure::from)
+}
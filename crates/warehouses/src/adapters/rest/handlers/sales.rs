@@ -0,0 +1,147 @@
+use crate::adapters::rest::types::sales::{
+    AddSalesOrderLineParams, AvailableToPromiseQuery, CreateSalesOrderParams,
+    FulfilSalesOrderParams, SalesOrderCriteriaParams, SalesOrderDetailsQuery, SalesOrderPageQuery,
+};
+use crate::domain::commands::sales::{
+    AddSalesOrderLineCommand, CreateSalesOrderCommand, FulfilSalesOrderCommand,
+};
+use crate::domain::ports::api::sales::{
+    AddSalesOrderLineUseCase, CancelSalesOrderUseCase, ConfirmSalesOrderUseCase,
+    CreateSalesOrderUseCase, FindAllSalesOrdersUseCase, FindAvailableToPromiseUseCase,
+    FindSalesOrderUseCase, FulfilSalesOrderUseCase,
+};
+use crate::domain::services::sales::SalesOrderService;
+use ids_std_rest_api::failure::ApiFailure;
+use ids_std_rest_api::replier::Replier;
+use ids_std_rest_api::types::created::Created;
+use ids_std_rest_api::types::pagination::{Paged, PaginationParams};
+use ids_std_rest_api::types::result::ApiResult;
+use lumx_axum::axum::extract::{Path, Query};
+use lumx_axum::axum::http::StatusCode;
+use lumx_axum::axum::Json;
+use lumx_axum::extractor::Component;
+
+pub async fn create_sales_order(
+    Component(uc): Component<SalesOrderService>,
+    Json(payload): Json<CreateSalesOrderParams>,
+) -> ApiResult<Created<i32>> {
+    tracing::info!("creating sales order {:?}", payload);
+
+    let create_order_cmd = CreateSalesOrderCommand {
+        customer_id: payload.customer_id,
+    };
+    let sales_order_id = uc.create_sales_order(&create_order_cmd).await?;
+
+    Ok(Replier::ok(Created::new(sales_order_id)))
+}
+
+pub async fn add_sales_order_line(
+    Path(sales_order_id): Path<i32>,
+    Component(uc): Component<SalesOrderService>,
+    Json(payload): Json<AddSalesOrderLineParams>,
+) -> ApiResult<Created<i32>> {
+    tracing::info!(
+        sales_order_id = sales_order_id,
+        "adding sales order line {:?}",
+        payload
+    );
+
+    let add_line_cmd = AddSalesOrderLineCommand {
+        sales_order_id,
+        product_id: payload.product_id,
+        quantity: payload.quantity,
+        unit_id: payload.unit_id,
+    };
+    let sales_order_line_id = uc.add_sales_order_line(&add_line_cmd).await?;
+
+    Ok(Replier::ok(Created::new(sales_order_line_id)))
+}
+
+pub async fn confirm_sales_order(
+    Path(sales_order_id): Path<i32>,
+    Component(uc): Component<SalesOrderService>,
+) -> Result<StatusCode, ApiFailure> {
+    tracing::info!(sales_order_id = sales_order_id, "confirming sales order");
+
+    uc.confirm_sales_order(sales_order_id)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiFailure::from)
+}
+
+pub async fn cancel_sales_order(
+    Path(sales_order_id): Path<i32>,
+    Component(uc): Component<SalesOrderService>,
+) -> Result<StatusCode, ApiFailure> {
+    tracing::info!(sales_order_id = sales_order_id, "cancelling sales order");
+
+    uc.cancel_sales_order(sales_order_id)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiFailure::from)
+}
+
+pub async fn fulfil_sales_order(
+    Path(sales_order_id): Path<i32>,
+    Component(uc): Component<SalesOrderService>,
+    Json(payload): Json<FulfilSalesOrderParams>,
+) -> Result<StatusCode, ApiFailure> {
+    tracing::info!(
+        sales_order_id = sales_order_id,
+        "fulfilling sales order {:?}",
+        payload
+    );
+
+    let fulfil_order_cmd = FulfilSalesOrderCommand {
+        sales_order_id,
+        storage_location_id: payload.storage_location_id,
+    };
+    uc.fulfil_sales_order(&fulfil_order_cmd)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiFailure::from)
+}
+
+pub async fn find_all_sales_orders(
+    Component(uc): Component<SalesOrderService>,
+    Query(payload): Query<PaginationParams>,
+    Query(criteria): Query<SalesOrderCriteriaParams>,
+) -> ApiResult<Paged<SalesOrderPageQuery>> {
+    tracing::info!(
+        "find and paginate all sales orders {:?} {:?}",
+        payload,
+        criteria
+    );
+
+    let orders = uc
+        .find_all_sales_orders(&payload.into(), &criteria.into())
+        .await?;
+
+    Ok(Replier::ok(Paged::from(&orders, |item| {
+        SalesOrderPageQuery::from(item)
+    })))
+}
+
+pub async fn find_sales_order(
+    Path(sales_order_id): Path<i32>,
+    Component(uc): Component<SalesOrderService>,
+) -> ApiResult<SalesOrderDetailsQuery> {
+    tracing::info!(sales_order_id = sales_order_id, "finding sales order");
+
+    uc.find_sales_order(sales_order_id)
+        .await
+        .map(|order| Replier::ok(SalesOrderDetailsQuery::from(&order)))
+        .map_err(ApiFailure::from)
+}
+
+pub async fn find_available_to_promise(
+    Path(product_id): Path<i32>,
+    Component(uc): Component<SalesOrderService>,
+) -> ApiResult<AvailableToPromiseQuery> {
+    tracing::info!(product_id = product_id, "finding available to promise");
+
+    uc.find_available_to_promise(product_id)
+        .await
+        .map(|available| Replier::ok(AvailableToPromiseQuery::from(&available)))
+        .map_err(ApiFailure::from)
+}
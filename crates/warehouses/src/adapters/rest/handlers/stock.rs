@@ -0,0 +1,114 @@
+use crate::adapters::rest::types::stock::{
+    ProductLocationQuery, ProductOnHandQuery, RecordStockMovementParams, StockCriteriaParams,
+    StockMovementPageQuery,
+};
+use crate::domain::commands::stock::RecordStockMovementCommand;
+use crate::domain::ports::api::stock::{
+    FindProductLocationsUseCase, FindProductOnHandUseCase, FindStockMovementsUseCase,
+    RecordStockMovementUseCase, TraceStockMovementsUseCase,
+};
+use crate::domain::services::stock::StockService;
+use ids_std_rest_api::failure::ApiFailure;
+use ids_std_rest_api::replier::Replier;
+use ids_std_rest_api::types::created::Created;
+use ids_std_rest_api::types::pagination::{Paged, PaginationParams};
+use ids_std_rest_api::types::result::ApiResult;
+use lumx_axum::axum::extract::{Path, Query};
+use lumx_axum::axum::Json;
+use lumx_axum::extractor::Component;
+
+pub async fn record_stock_movement(
+    Path(product_id): Path<i32>,
+    Component(uc): Component<StockService>,
+    Json(payload): Json<RecordStockMovementParams>,
+) -> ApiResult<Created<i32>> {
+    tracing::info!(
+        product_id = product_id,
+        "recording stock movement {:?}",
+        payload
+    );
+
+    let record_movement_cmd = RecordStockMovementCommand {
+        product_id,
+        kind: payload.kind.into(),
+        quantity: payload.quantity,
+        unit_id: payload.unit_id,
+        storage_location_id: payload.storage_location_id,
+        lot_number: payload.lot_number,
+        serial_number: payload.serial_number,
+        expires_on: payload.expires_on,
+        unit_cost: payload.unit_cost,
+        override_expiry: payload.override_expiry,
+        reason: payload.reason,
+    };
+    let stock_movement_id = uc.record_stock_movement(&record_movement_cmd).await?;
+
+    Ok(Replier::ok(Created::new(stock_movement_id)))
+}
+
+pub async fn find_stock_movements(
+    Path(product_id): Path<i32>,
+    Component(uc): Component<StockService>,
+    Query(payload): Query<PaginationParams>,
+) -> ApiResult<Paged<StockMovementPageQuery>> {
+    tracing::info!(
+        product_id = product_id,
+        "find and paginate stock movements {:?}",
+        payload
+    );
+
+    let movements = uc.find_stock_movements(&payload.into(), product_id).await?;
+
+    Ok(Replier::ok(Paged::from(&movements, |item| {
+        StockMovementPageQuery::from(item)
+    })))
+}
+
+pub async fn trace_stock_movements(
+    Path(tracking_code): Path<String>,
+    Component(uc): Component<StockService>,
+    Query(payload): Query<PaginationParams>,
+) -> ApiResult<Paged<StockMovementPageQuery>> {
+    tracing::info!(
+        tracking_code = tracking_code.as_str(),
+        "find and paginate traced stock movements {:?}",
+        payload
+    );
+
+    let movements = uc
+        .trace_stock_movements(&payload.into(), tracking_code.as_str())
+        .await?;
+
+    Ok(Replier::ok(Paged::from(&movements, |item| {
+        StockMovementPageQuery::from(item)
+    })))
+}
+
+pub async fn find_product_on_hand(
+    Path(product_id): Path<i32>,
+    Component(uc): Component<StockService>,
+    Query(criteria): Query<StockCriteriaParams>,
+) -> ApiResult<ProductOnHandQuery> {
+    tracing::info!(
+        product_id = product_id,
+        "finding product on hand {:?}",
+        criteria
+    );
+
+    uc.find_product_on_hand(product_id, &criteria.into())
+        .await
+        .map(|on_hand| Replier::ok(ProductOnHandQuery::from(&on_hand)))
+        .map_err(ApiFailure::from)
+}
+
+pub async fn find_product_locations(
+    Path(product_id): Path<i32>,
+    Component(uc): Component<StockService>,
+) -> ApiResult<Vec<ProductLocationQuery>> {
+    tracing::info!(product_id = product_id, "finding product locations");
+
+    uc.find_product_locations(product_id)
+        .await
+        .map(|locations| Replier::ok(locations.iter().map(ProductLocationQuery::from).collect()))
+        .map_err(ApiFailure::from)
+}
@@ -0,0 +1,123 @@
+use crate::adapters::rest::types::supplier::{
+    AddSupplierProductParams, CreateSupplierParams, PreferredSupplierQuery, SupplierProductQuery,
+    SupplierQuery, UpdateSupplierProductParams,
+};
+use crate::domain::commands::supplier::{
+    AddSupplierProductCommand, CreateSupplierCommand, UpdateSupplierProductCommand,
+};
+use crate::domain::ports::api::supplier::{
+    AddSupplierProductUseCase, CreateSupplierUseCase, FindAllSuppliersUseCase,
+    FindPreferredSupplierUseCase, FindSupplierProductsUseCase, UpdateSupplierProductUseCase,
+};
+use crate::domain::services::supplier::SupplierService;
+use ids_std_rest_api::failure::ApiFailure;
+use ids_std_rest_api::replier::Replier;
+use ids_std_rest_api::types::created::Created;
+use ids_std_rest_api::types::result::ApiResult;
+use lumx_axum::axum::extract::Path;
+use lumx_axum::axum::http::StatusCode;
+use lumx_axum::axum::Json;
+use lumx_axum::extractor::Component;
+
+pub async fn create_supplier(
+    Component(uc): Component<SupplierService>,
+    Json(payload): Json<CreateSupplierParams>,
+) -> ApiResult<Created<i32>> {
+    tracing::info!("creating supplier {:?}", payload);
+
+    let create_supplier_cmd = CreateSupplierCommand {
+        name: payload.name,
+        contact_person_id: payload.contact_person_id,
+        currency: payload.currency,
+    };
+    let supplier_id = uc.create_supplier(&create_supplier_cmd).await?;
+
+    Ok(Replier::ok(Created::new(supplier_id)))
+}
+
+pub async fn find_all_suppliers(
+    Component(uc): Component<SupplierService>,
+) -> ApiResult<Vec<SupplierQuery>> {
+    tracing::info!("finding all suppliers");
+
+    uc.find_all_suppliers()
+        .await
+        .map(|suppliers| Replier::ok(suppliers.iter().map(SupplierQuery::from).collect()))
+        .map_err(ApiFailure::from)
+}
+
+pub async fn add_supplier_product(
+    Path(supplier_id): Path<i32>,
+    Component(uc): Component<SupplierService>,
+    Json(payload): Json<AddSupplierProductParams>,
+) -> ApiResult<Created<i32>> {
+    tracing::info!(
+        supplier_id = supplier_id,
+        "adding supplier product {:?}",
+        payload
+    );
+
+    let add_supplier_product_cmd = AddSupplierProductCommand {
+        supplier_id,
+        product_id: payload.product_id,
+        supplier_code: payload.supplier_code,
+        pack_size: payload.pack_size,
+        lead_time_days: payload.lead_time_days,
+        last_price: payload.last_price,
+        preferred: payload.preferred,
+    };
+    let supplier_product_id = uc.add_supplier_product(&add_supplier_product_cmd).await?;
+
+    Ok(Replier::ok(Created::new(supplier_product_id)))
+}
+
+pub async fn update_supplier_product(
+    Path((supplier_id, product_id)): Path<(i32, i32)>,
+    Component(uc): Component<SupplierService>,
+    Json(payload): Json<UpdateSupplierProductParams>,
+) -> Result<StatusCode, ApiFailure> {
+    tracing::info!(
+        supplier_id = supplier_id,
+        product_id = product_id,
+        "updating supplier product {:?}",
+        payload
+    );
+
+    let update_supplier_product_cmd = UpdateSupplierProductCommand {
+        supplier_id,
+        product_id,
+        supplier_code: payload.supplier_code,
+        pack_size: payload.pack_size,
+        lead_time_days: payload.lead_time_days,
+        last_price: payload.last_price,
+        preferred: payload.preferred,
+    };
+    uc.update_supplier_product(&update_supplier_product_cmd)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiFailure::from)
+}
+
+pub async fn find_supplier_products(
+    Path(supplier_id): Path<i32>,
+    Component(uc): Component<SupplierService>,
+) -> ApiResult<Vec<SupplierProductQuery>> {
+    tracing::info!(supplier_id = supplier_id, "finding supplier products");
+
+    uc.find_supplier_products(supplier_id)
+        .await
+        .map(|products| Replier::ok(products.iter().map(SupplierProductQuery::from).collect()))
+        .map_err(ApiFailure::from)
+}
+
+pub async fn find_preferred_supplier(
+    Path(product_id): Path<i32>,
+    Component(uc): Component<SupplierService>,
+) -> ApiResult<PreferredSupplierQuery> {
+    tracing::info!(product_id = product_id, "finding preferred supplier");
+
+    uc.find_preferred_supplier(product_id)
+        .await
+        .map(|offer| Replier::ok(PreferredSupplierQuery::from(&offer)))
+        .map_err(ApiFailure::from)
+}
@@ -0,0 +1,112 @@
+use crate::adapters::rest::types::transfer::{
+    ReceiveStockTransferParams, StockTransferCriteriaParams, StockTransferDetailsQuery,
+    TransferStockParams,
+};
+use crate::domain::commands::transfer::{ReceiveStockTransferCommand, TransferStockCommand};
+use crate::domain::ports::api::transfer::{
+    CancelStockTransferUseCase, FindAllStockTransfersUseCase, FindStockTransferUseCase,
+    ReceiveStockTransferUseCase, TransferStockUseCase,
+};
+use crate::domain::services::transfer::StockTransferService;
+use ids_std_rest_api::failure::ApiFailure;
+use ids_std_rest_api::replier::Replier;
+use ids_std_rest_api::types::created::Created;
+use ids_std_rest_api::types::pagination::{Paged, PaginationParams};
+use ids_std_rest_api::types::result::ApiResult;
+use lumx_axum::axum::extract::{Path, Query};
+use lumx_axum::axum::http::StatusCode;
+use lumx_axum::axum::Json;
+use lumx_axum::extractor::Component;
+
+pub async fn transfer_stock(
+    Component(uc): Component<StockTransferService>,
+    Json(payload): Json<TransferStockParams>,
+) -> ApiResult<Created<i32>> {
+    tracing::info!("transferring stock {:?}", payload);
+
+    let transfer_stock_cmd = TransferStockCommand {
+        product_id: payload.product_id,
+        source_location_id: payload.source_location_id,
+        destination_warehouse_id: payload.destination_warehouse_id,
+        quantity: payload.quantity,
+        unit_id: payload.unit_id,
+        lot_number: payload.lot_number,
+        serial_number: payload.serial_number,
+    };
+    let stock_transfer_id = uc.transfer_stock(&transfer_stock_cmd).await?;
+
+    Ok(Replier::ok(Created::new(stock_transfer_id)))
+}
+
+pub async fn receive_stock_transfer(
+    Path(stock_transfer_id): Path<i32>,
+    Component(uc): Component<StockTransferService>,
+    Json(payload): Json<ReceiveStockTransferParams>,
+) -> Result<StatusCode, ApiFailure> {
+    tracing::info!(
+        stock_transfer_id = stock_transfer_id,
+        "receiving stock transfer {:?}",
+        payload
+    );
+
+    let receive_transfer_cmd = ReceiveStockTransferCommand {
+        stock_transfer_id,
+        storage_location_id: payload.storage_location_id,
+        quantity: payload.quantity,
+        unit_id: payload.unit_id,
+    };
+    uc.receive_stock_transfer(&receive_transfer_cmd)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiFailure::from)
+}
+
+pub async fn cancel_stock_transfer(
+    Path(stock_transfer_id): Path<i32>,
+    Component(uc): Component<StockTransferService>,
+) -> Result<StatusCode, ApiFailure> {
+    tracing::info!(
+        stock_transfer_id = stock_transfer_id,
+        "cancelling stock transfer"
+    );
+
+    uc.cancel_stock_transfer(stock_transfer_id)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiFailure::from)
+}
+
+pub async fn find_all_stock_transfers(
+    Component(uc): Component<StockTransferService>,
+    Query(payload): Query<PaginationParams>,
+    Query(criteria): Query<StockTransferCriteriaParams>,
+) -> ApiResult<Paged<StockTransferDetailsQuery>> {
+    tracing::info!(
+        "find and paginate all stock transfers {:?} {:?}",
+        payload,
+        criteria
+    );
+
+    let transfers = uc
+        .find_all_stock_transfers(&payload.into(), &criteria.into())
+        .await?;
+
+    Ok(Replier::ok(Paged::from(&transfers, |item| {
+        StockTransferDetailsQuery::from(item)
+    })))
+}
+
+pub async fn find_stock_transfer(
+    Path(stock_transfer_id): Path<i32>,
+    Component(uc): Component<StockTransferService>,
+) -> ApiResult<StockTransferDetailsQuery> {
+    tracing::info!(
+        stock_transfer_id = stock_transfer_id,
+        "finding stock transfer"
+    );
+
+    uc.find_stock_transfer(stock_transfer_id)
+        .await
+        .map(|transfer| Replier::ok(StockTransferDetailsQuery::from(&transfer)))
+        .map_err(ApiFailure::from)
+}
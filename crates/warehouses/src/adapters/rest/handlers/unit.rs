@@ -0,0 +1,102 @@
+use crate::adapters::rest::types::unit::{
+    AddUnitConversionParams, CreateUnitOfMeasureParams, ProductUnitsQuery, SetProductUnitsParams,
+    UnitOfMeasureDetailsQuery,
+};
+use crate::domain::commands::unit::{
+    AddUnitConversionCommand, CreateUnitOfMeasureCommand, SetProductUnitsCommand,
+};
+use crate::domain::ports::api::unit::{
+    AddUnitConversionUseCase, CreateUnitOfMeasureUseCase, FindAllUnitsOfMeasureUseCase,
+    FindProductUnitsUseCase, SetProductUnitsUseCase,
+};
+use crate::domain::services::unit::UnitOfMeasureService;
+use ids_std_rest_api::failure::ApiFailure;
+use ids_std_rest_api::replier::Replier;
+use ids_std_rest_api::types::created::Created;
+use ids_std_rest_api::types::result::ApiResult;
+use lumx_axum::axum::extract::Path;
+use lumx_axum::axum::http::StatusCode;
+use lumx_axum::axum::Json;
+use lumx_axum::extractor::Component;
+
+pub async fn create_unit_of_measure(
+    Component(uc): Component<UnitOfMeasureService>,
+    Json(payload): Json<CreateUnitOfMeasureParams>,
+) -> ApiResult<Created<i32>> {
+    tracing::info!("creating unit of measure {:?}", payload);
+
+    let create_unit_cmd = CreateUnitOfMeasureCommand {
+        code: payload.code,
+        name: payload.name,
+    };
+    let unit_of_measure_id = uc.create_unit_of_measure(&create_unit_cmd).await?;
+
+    Ok(Replier::ok(Created::new(unit_of_measure_id)))
+}
+
+pub async fn add_unit_conversion(
+    Path(unit_of_measure_id): Path<i32>,
+    Component(uc): Component<UnitOfMeasureService>,
+    Json(payload): Json<AddUnitConversionParams>,
+) -> ApiResult<Created<i32>> {
+    tracing::info!(
+        unit_of_measure_id = unit_of_measure_id,
+        "adding unit conversion {:?}",
+        payload
+    );
+
+    let add_conversion_cmd = AddUnitConversionCommand {
+        from_unit_id: unit_of_measure_id,
+        to_unit_id: payload.to_unit_id,
+        factor: payload.factor,
+    };
+    let unit_conversion_id = uc.add_unit_conversion(&add_conversion_cmd).await?;
+
+    Ok(Replier::ok(Created::new(unit_conversion_id)))
+}
+
+pub async fn find_all_units_of_measure(
+    Component(uc): Component<UnitOfMeasureService>,
+) -> ApiResult<Vec<UnitOfMeasureDetailsQuery>> {
+    tracing::info!("finding all units of measure");
+
+    uc.find_all_units_of_measure()
+        .await
+        .map(|units| Replier::ok(units.iter().map(UnitOfMeasureDetailsQuery::from).collect()))
+        .map_err(ApiFailure::from)
+}
+
+pub async fn set_product_units(
+    Path(product_id): Path<i32>,
+    Component(uc): Component<UnitOfMeasureService>,
+    Json(payload): Json<SetProductUnitsParams>,
+) -> Result<StatusCode, ApiFailure> {
+    tracing::info!(
+        product_id = product_id,
+        "setting product units {:?}",
+        payload
+    );
+
+    let set_units_cmd = SetProductUnitsCommand {
+        product_id,
+        base_unit_id: payload.base_unit_id,
+        purchase_unit_id: payload.purchase_unit_id,
+        sale_unit_id: payload.sale_unit_id,
+    };
+    uc.set_product_units(&set_units_cmd)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiFailure::from)
+}
+
+pub async fn find_product_units(
+    Path(product_id): Path<i32>,
+    Component(uc): Component<UnitOfMeasureService>,
+) -> ApiResult<ProductUnitsQuery> {
+    tracing::info!(product_id = product_id, "finding product units");
+
+    uc.find_product_units(product_id)
+        .await
+        .map(|units| Replier::ok(ProductUnitsQuery::from(&units)))
+        .map_err(ApiFailure::from)
+}
@@ -0,0 +1,66 @@
+use crate::adapters::rest::types::valuation::{
+    ProductValuationQuery, SetCostingMethodParams, StockValuationCriteriaParams,
+    StockValuationDetailsQuery,
+};
+use crate::domain::commands::valuation::SetCostingMethodCommand;
+use crate::domain::ports::api::valuation::{
+    FindProductValuationUseCase, FindStockValuationUseCase, SetCostingMethodUseCase,
+};
+use crate::domain::services::valuation::ValuationService;
+use ids_std_rest_api::failure::ApiFailure;
+use ids_std_rest_api::replier::Replier;
+use ids_std_rest_api::types::result::ApiResult;
+use lumx_axum::axum::extract::{Path, Query};
+use lumx_axum::axum::http::StatusCode;
+use lumx_axum::axum::Json;
+use lumx_axum::extractor::Component;
+
+pub async fn set_costing_method(
+    Path(product_id): Path<i32>,
+    Component(uc): Component<ValuationService>,
+    Json(payload): Json<SetCostingMethodParams>,
+) -> Result<StatusCode, ApiFailure> {
+    tracing::info!(
+        product_id = product_id,
+        "setting costing method {:?}",
+        payload
+    );
+
+    let set_costing_method_cmd = SetCostingMethodCommand {
+        product_id,
+        costing_method: payload.costing_method.into(),
+    };
+    uc.set_costing_method(&set_costing_method_cmd)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiFailure::from)
+}
+
+pub async fn find_stock_valuation(
+    Component(uc): Component<ValuationService>,
+    Query(criteria): Query<StockValuationCriteriaParams>,
+) -> ApiResult<StockValuationDetailsQuery> {
+    tracing::info!("finding stock valuation {:?}", criteria);
+
+    uc.find_stock_valuation(&criteria.into())
+        .await
+        .map(|valuation| Replier::ok(StockValuationDetailsQuery::from(&valuation)))
+        .map_err(ApiFailure::from)
+}
+
+pub async fn find_product_valuation(
+    Path(product_id): Path<i32>,
+    Component(uc): Component<ValuationService>,
+    Query(criteria): Query<StockValuationCriteriaParams>,
+) -> ApiResult<ProductValuationQuery> {
+    tracing::info!(
+        product_id = product_id,
+        "finding product valuation {:?}",
+        criteria
+    );
+
+    uc.find_product_valuation(product_id, &criteria.into())
+        .await
+        .map(|valuation| Replier::ok(ProductValuationQuery::from(&valuation)))
+        .map_err(ApiFailure::from)
+}
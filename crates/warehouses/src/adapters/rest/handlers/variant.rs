@@ -0,0 +1,77 @@
+use crate::adapters::rest::types::variant::{
+    CreateProductVariantParams, GenerateVariantMatrixParams, ProductVariantDetailsQuery,
+};
+use crate::domain::commands::variant::{CreateProductVariantCommand, GenerateVariantMatrixCommand};
+use crate::domain::ports::api::variant::{
+    CreateProductVariantUseCase, FindProductVariantsUseCase, GenerateVariantMatrixUseCase,
+};
+use crate::domain::services::variant::ProductVariantService;
+use ids_std_rest_api::failure::ApiFailure;
+use ids_std_rest_api::replier::Replier;
+use ids_std_rest_api::types::created::Created;
+use ids_std_rest_api::types::result::ApiResult;
+use lumx_axum::axum::extract::Path;
+use lumx_axum::axum::Json;
+use lumx_axum::extractor::Component;
+
+pub async fn create_product_variant(
+    Path(product_id): Path<i32>,
+    Component(uc): Component<ProductVariantService>,
+    Json(payload): Json<CreateProductVariantParams>,
+) -> ApiResult<Created<i32>> {
+    tracing::info!(
+        product_id = product_id,
+        "creating product variant {:?}",
+        payload
+    );
+
+    let create_variant_cmd = CreateProductVariantCommand {
+        product_id,
+        attribute_value_ids: payload.attribute_value_ids,
+        sku: payload.sku,
+    };
+    let product_variant_id = uc.create_product_variant(&create_variant_cmd).await?;
+
+    Ok(Replier::ok(Created::new(product_variant_id)))
+}
+
+pub async fn generate_variant_matrix(
+    Path(product_id): Path<i32>,
+    Component(uc): Component<ProductVariantService>,
+    Json(payload): Json<GenerateVariantMatrixParams>,
+) -> ApiResult<Vec<Created<i32>>> {
+    tracing::info!(
+        product_id = product_id,
+        "generating variant matrix {:?}",
+        payload
+    );
+
+    let generate_matrix_cmd = GenerateVariantMatrixCommand {
+        product_id,
+        attribute_value_ids: payload.attribute_value_ids,
+    };
+    let product_variant_ids = uc.generate_variant_matrix(&generate_matrix_cmd).await?;
+
+    Ok(Replier::ok(
+        product_variant_ids.into_iter().map(Created::new).collect(),
+    ))
+}
+
+pub async fn find_product_variants(
+    Path(product_id): Path<i32>,
+    Component(uc): Component<ProductVariantService>,
+) -> ApiResult<Vec<ProductVariantDetailsQuery>> {
+    tracing::info!(product_id = product_id, "finding product variants");
+
+    uc.find_product_variants(product_id)
+        .await
+        .map(|variants| {
+            Replier::ok(
+                variants
+                    .iter()
+                    .map(ProductVariantDetailsQuery::from)
+                    .collect(),
+            )
+        })
+        .map_err(ApiFailure::from)
+}
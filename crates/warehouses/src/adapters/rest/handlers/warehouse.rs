@@ -0,0 +1,85 @@
+use crate::adapters::rest::types::warehouse::{
+    CreateWarehouseParams, UpdateWarehouseParams, WarehouseCriteriaParams, WarehouseDetailsQuery,
+    WarehousePageQuery,
+};
+use crate::domain::commands::warehouse::{CreateWarehouseCommand, UpdateWarehouseCommand};
+use crate::domain::ports::api::warehouse::{
+    CreateWarehouseUseCase, FindAllWarehousesUseCase, FindWarehouseUseCase, UpdateWarehouseUseCase,
+};
+use crate::domain::services::warehouse::WarehouseService;
+use ids_std_rest_api::failure::ApiFailure;
+use ids_std_rest_api::replier::Replier;
+use ids_std_rest_api::types::created::Created;
+use ids_std_rest_api::types::pagination::{Paged, PaginationParams};
+use ids_std_rest_api::types::result::ApiResult;
+use lumx_axum::axum::extract::{Path, Query};
+use lumx_axum::axum::http::StatusCode;
+use lumx_axum::axum::Json;
+use lumx_axum::extractor::Component;
+
+pub async fn create_warehouse(
+    Component(uc): Component<WarehouseService>,
+    Json(payload): Json<CreateWarehouseParams>,
+) -> ApiResult<Created<i32>> {
+    tracing::info!("creating warehouse {:?}", payload);
+
+    let create_warehouse_cmd = CreateWarehouseCommand {
+        code: payload.code,
+        name: payload.name,
+        address: payload.address,
+    };
+    let warehouse_id = uc.create_warehouse(&create_warehouse_cmd).await?;
+
+    Ok(Replier::ok(Created::new(warehouse_id)))
+}
+
+pub async fn update_warehouse(
+    Path(warehouse_id): Path<i32>,
+    Component(uc): Component<WarehouseService>,
+    Json(payload): Json<UpdateWarehouseParams>,
+) -> Result<StatusCode, ApiFailure> {
+    tracing::info!("updating warehouse {:?}", payload);
+
+    let update_warehouse_cmd = UpdateWarehouseCommand {
+        warehouse_id,
+        code: payload.code,
+        name: payload.name,
+        address: payload.address,
+    };
+    uc.update_warehouse(&update_warehouse_cmd)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiFailure::from)
+}
+
+pub async fn find_all_warehouses(
+    Component(uc): Component<WarehouseService>,
+    Query(payload): Query<PaginationParams>,
+    Query(criteria): Query<WarehouseCriteriaParams>,
+) -> ApiResult<Paged<WarehousePageQuery>> {
+    tracing::info!(
+        "find and paginate all warehouses {:?} {:?}",
+        payload,
+        criteria
+    );
+
+    let warehouses = uc
+        .find_all_warehouses(&payload.into(), &criteria.into())
+        .await?;
+
+    Ok(Replier::ok(Paged::from(&warehouses, |item| {
+        WarehousePageQuery::from(item)
+    })))
+}
+
+pub async fn find_warehouse(
+    Path(warehouse_id): Path<i32>,
+    Component(uc): Component<WarehouseService>,
+) -> ApiResult<WarehouseDetailsQuery> {
+    tracing::info!(warehouse_id = warehouse_id, "finding warehouse");
+
+    uc.find_warehouse(warehouse_id)
+        .await
+        .map(|warehouse| Replier::ok(WarehouseDetailsQuery::from(&warehouse)))
+        .map_err(ApiFailure::from)
+}
@@ -0,0 +1,15 @@
+use crate::adapters::rest::handlers;
+use lumx_axum::axum::{routing, Router};
+
+pub fn router() -> Router {
+    Router::new()
+        .route(
+            "/api/v1/product-attributes",
+            routing::post(handlers::attribute::create_product_attribute)
+                .get(handlers::attribute::find_all_product_attributes),
+        )
+        .route(
+            "/api/v1/product-attributes/:product_attribute_id/values",
+            routing::post(handlers::attribute::add_attribute_value),
+        )
+}
@@ -0,0 +1,19 @@
+use crate::adapters::rest::handlers;
+use lumx_axum::axum::{routing, Router};
+
+pub fn router() -> Router {
+    Router::new()
+        .route(
+            "/api/v1/products/by-barcode/:code",
+            routing::get(handlers::barcode::find_product_by_barcode),
+        )
+        .route(
+            "/api/v1/products/:product_id/sku",
+            routing::put(handlers::barcode::set_product_sku),
+        )
+        .route(
+            "/api/v1/products/:product_id/barcodes",
+            routing::post(handlers::barcode::add_product_barcode)
+                .get(handlers::barcode::find_product_barcodes),
+        )
+}
@@ -0,0 +1,31 @@
+use crate::adapters::rest::handlers;
+use lumx_axum::axum::{routing, Router};
+
+pub fn router() -> Router {
+    Router::new()
+        .route(
+            "/api/v1/inventory-counts",
+            routing::post(handlers::count::open_inventory_count)
+                .get(handlers::count::find_all_inventory_counts),
+        )
+        .route(
+            "/api/v1/inventory-counts/:inventory_count_id",
+            routing::get(handlers::count::find_inventory_count),
+        )
+        .route(
+            "/api/v1/inventory-counts/:inventory_count_id/start",
+            routing::post(handlers::count::start_inventory_count),
+        )
+        .route(
+            "/api/v1/inventory-counts/:inventory_count_id/counts",
+            routing::post(handlers::count::count_inventory),
+        )
+        .route(
+            "/api/v1/inventory-counts/:inventory_count_id/review",
+            routing::post(handlers::count::review_inventory_count),
+        )
+        .route(
+            "/api/v1/inventory-counts/:inventory_count_id/post",
+            routing::post(handlers::count::post_inventory_count),
+        )
+}
@@ -0,0 +1,24 @@
+use crate::adapters::rest::handlers;
+use lumx_axum::axum::{routing, Router};
+
+pub fn router() -> Router {
+    Router::new()
+        .route(
+            "/api/v1/product-families",
+            routing::post(handlers::family::create_product_family)
+                .get(handlers::family::find_all_product_families),
+        )
+        .route(
+            "/api/v1/product-families/:product_family_id",
+            routing::get(handlers::family::find_product_family)
+                .put(handlers::family::update_product_family),
+        )
+        .route(
+            "/api/v1/product-families/:product_family_id/subtree",
+            routing::get(handlers::family::find_product_family_subtree),
+        )
+        .route(
+            "/api/v1/product-families/:product_family_id/breadcrumb",
+            routing::get(handlers::family::find_product_family_breadcrumb),
+        )
+}
@@ -0,0 +1,18 @@
+use crate::adapters::rest::handlers;
+use lumx_axum::axum::{routing, Router};
+
+pub fn router() -> Router {
+    Router::new()
+        .route(
+            "/api/v1/products/:product_id/components",
+            routing::post(handlers::kit::add_kit_component).get(handlers::kit::find_kit_components),
+        )
+        .route(
+            "/api/v1/products/:product_id/kit-availability",
+            routing::get(handlers::kit::find_kit_availability),
+        )
+        .route(
+            "/api/v1/products/:product_id/kit-assemblies",
+            routing::post(handlers::kit::assemble_kit),
+        )
+}
@@ -0,0 +1,16 @@
+use crate::adapters::rest::handlers;
+use lumx_axum::axum::{routing, Router};
+
+pub fn router() -> Router {
+    Router::new()
+        .route(
+            "/api/v1/warehouses/:warehouse_id/locations",
+            routing::post(handlers::location::create_storage_location)
+                .get(handlers::location::find_warehouse_locations),
+        )
+        .route(
+            "/api/v1/storage-locations/:storage_location_id",
+            routing::get(handlers::location::find_storage_location)
+                .put(handlers::location::update_storage_location),
+        )
+}
@@ -0,0 +1,14 @@
+use crate::adapters::rest::handlers;
+use lumx_axum::axum::{routing, Router};
+
+pub fn router() -> Router {
+    Router::new()
+        .route(
+            "/api/v1/stock-lots/expiring",
+            routing::get(handlers::lot::find_expiring_lots),
+        )
+        .route(
+            "/api/v1/products/:product_id/fefo-pick",
+            routing::get(handlers::lot::plan_fefo_pick),
+        )
+}
@@ -0,0 +1,19 @@
+use crate::adapters::rest::handlers;
+use lumx_axum::axum::{routing, Router};
+
+pub fn router() -> Router {
+    Router::new()
+        .route(
+            "/api/v1/price-lists",
+            routing::post(handlers::price::create_price_list)
+                .get(handlers::price::find_all_price_lists),
+        )
+        .route(
+            "/api/v1/price-lists/:price_list_id/prices",
+            routing::post(handlers::price::add_product_price),
+        )
+        .route(
+            "/api/v1/products/:product_id/price",
+            routing::get(handlers::price::resolve_product_price),
+        )
+}
@@ -2,8 +2,18 @@ use crate::adapters::rest::handlers;
 use lumx_axum::axum::{routing, Router};
 
 pub fn router() -> Router {
-    Router::new().route(
-        "/api/v1/products",
-        routing::post(handlers::product::create_product),
-    )
+    Router::new()
+        .route(
+            "/api/v1/products",
+            routing::post(handlers::product::create_product)
+                .get(handlers::product::find_all_products),
+        )
+        .route(
+            "/api/v1/products/:product_id",
+            routing::get(handlers::product::find_product).put(handlers::product::update_product),
+        )
+        .route(
+            "/api/v1/products/:product_id/discontinue",
+            routing::post(handlers::product::discontinue_product),
+        )
 }
@@ -0,0 +1,38 @@
+use crate::adapters::rest::handlers;
+use lumx_axum::axum::{middleware, routing, Router};
+
+pub fn router() -> Router {
+    Router::new()
+        .route(
+            "/api/v1/purchase-orders",
+            routing::post(handlers::purchase::create_purchase_order)
+                .get(handlers::purchase::find_all_purchase_orders),
+        )
+        .route(
+            "/api/v1/purchase-orders/:purchase_order_id",
+            routing::get(handlers::purchase::find_purchase_order),
+        )
+        .route(
+            "/api/v1/purchase-orders/:purchase_order_id/lines",
+            routing::post(handlers::purchase::add_purchase_order_line),
+        )
+        .route(
+            "/api/v1/purchase-orders/:purchase_order_id/approve",
+            routing::post(handlers::purchase::approve_purchase_order),
+        )
+        .route(
+            "/api/v1/purchase-orders/:purchase_order_id/send",
+            routing::post(handlers::purchase::send_purchase_order),
+        )
+        .route(
+            "/api/v1/purchase-orders/:purchase_order_id/receive",
+            routing::post(handlers::purchase::receive_purchase_order),
+        )
+        .route(
+            "/api/v1/purchase-orders/:purchase_order_id/close",
+            routing::post(handlers::purchase::close_purchase_order),
+        )
+        .route_layer(middleware::from_fn(
+            lumx_passport::middleware::auth::jwt_auth,
+        ))
+}
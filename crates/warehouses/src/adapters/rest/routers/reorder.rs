@@ -0,0 +1,15 @@
+use crate::adapters::rest::handlers;
+use lumx_axum::axum::{routing, Router};
+
+pub fn router() -> Router {
+    Router::new()
+        .route(
+            "/api/v1/products/low-stock",
+            routing::get(handlers::reorder::find_low_stock_products),
+        )
+        .route(
+            "/api/v1/products/:product_id/reorder-point",
+            routing::get(handlers::reorder::find_reorder_point)
+                .put(handlers::reorder::set_reorder_point),
+        )
+}
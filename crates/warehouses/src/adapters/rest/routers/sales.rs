@@ -0,0 +1,35 @@
+use crate::adapters::rest::handlers;
+use lumx_axum::axum::{routing, Router};
+
+pub fn router() -> Router {
+    Router::new()
+        .route(
+            "/api/v1/sales-orders",
+            routing::post(handlers::sales::create_sales_order)
+                .get(handlers::sales::find_all_sales_orders),
+        )
+        .route(
+            "/api/v1/sales-orders/:sales_order_id",
+            routing::get(handlers::sales::find_sales_order),
+        )
+        .route(
+            "/api/v1/sales-orders/:sales_order_id/lines",
+            routing::post(handlers::sales::add_sales_order_line),
+        )
+        .route(
+            "/api/v1/sales-orders/:sales_order_id/confirm",
+            routing::post(handlers::sales::confirm_sales_order),
+        )
+        .route(
+            "/api/v1/sales-orders/:sales_order_id/cancel",
+            routing::post(handlers::sales::cancel_sales_order),
+        )
+        .route(
+            "/api/v1/sales-orders/:sales_order_id/fulfil",
+            routing::post(handlers::sales::fulfil_sales_order),
+        )
+        .route(
+            "/api/v1/products/:product_id/available-to-promise",
+            routing::get(handlers::sales::find_available_to_promise),
+        )
+}
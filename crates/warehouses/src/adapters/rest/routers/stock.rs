@@ -0,0 +1,23 @@
+use crate::adapters::rest::handlers;
+use lumx_axum::axum::{routing, Router};
+
+pub fn router() -> Router {
+    Router::new()
+        .route(
+            "/api/v1/products/:product_id/stock-movements",
+            routing::post(handlers::stock::record_stock_movement)
+                .get(handlers::stock::find_stock_movements),
+        )
+        .route(
+            "/api/v1/products/:product_id/stock",
+            routing::get(handlers::stock::find_product_on_hand),
+        )
+        .route(
+            "/api/v1/products/:product_id/locations",
+            routing::get(handlers::stock::find_product_locations),
+        )
+        .route(
+            "/api/v1/stock-movements/trace/:tracking_code",
+            routing::get(handlers::stock::trace_stock_movements),
+        )
+}
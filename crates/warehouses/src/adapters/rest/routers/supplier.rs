@@ -0,0 +1,24 @@
+use crate::adapters::rest::handlers;
+use lumx_axum::axum::{routing, Router};
+
+pub fn router() -> Router {
+    Router::new()
+        .route(
+            "/api/v1/suppliers",
+            routing::post(handlers::supplier::create_supplier)
+                .get(handlers::supplier::find_all_suppliers),
+        )
+        .route(
+            "/api/v1/suppliers/:supplier_id/products",
+            routing::post(handlers::supplier::add_supplier_product)
+                .get(handlers::supplier::find_supplier_products),
+        )
+        .route(
+            "/api/v1/suppliers/:supplier_id/products/:product_id",
+            routing::put(handlers::supplier::update_supplier_product),
+        )
+        .route(
+            "/api/v1/products/:product_id/preferred-supplier",
+            routing::get(handlers::supplier::find_preferred_supplier),
+        )
+}
@@ -0,0 +1,23 @@
+use crate::adapters::rest::handlers;
+use lumx_axum::axum::{routing, Router};
+
+pub fn router() -> Router {
+    Router::new()
+        .route(
+            "/api/v1/stock-transfers",
+            routing::post(handlers::transfer::transfer_stock)
+                .get(handlers::transfer::find_all_stock_transfers),
+        )
+        .route(
+            "/api/v1/stock-transfers/:stock_transfer_id",
+            routing::get(handlers::transfer::find_stock_transfer),
+        )
+        .route(
+            "/api/v1/stock-transfers/:stock_transfer_id/receive",
+            routing::post(handlers::transfer::receive_stock_transfer),
+        )
+        .route(
+            "/api/v1/stock-transfers/:stock_transfer_id/cancel",
+            routing::post(handlers::transfer::cancel_stock_transfer),
+        )
+}
@@ -0,0 +1,19 @@
+use crate::adapters::rest::handlers;
+use lumx_axum::axum::{routing, Router};
+
+pub fn router() -> Router {
+    Router::new()
+        .route(
+            "/api/v1/units-of-measure",
+            routing::post(handlers::unit::create_unit_of_measure)
+                .get(handlers::unit::find_all_units_of_measure),
+        )
+        .route(
+            "/api/v1/units-of-measure/:unit_of_measure_id/conversions",
+            routing::post(handlers::unit::add_unit_conversion),
+        )
+        .route(
+            "/api/v1/products/:product_id/units",
+            routing::get(handlers::unit::find_product_units).put(handlers::unit::set_product_units),
+        )
+}
@@ -0,0 +1,18 @@
+use crate::adapters::rest::handlers;
+use lumx_axum::axum::{routing, Router};
+
+pub fn router() -> Router {
+    Router::new()
+        .route(
+            "/api/v1/stock-valuation",
+            routing::get(handlers::valuation::find_stock_valuation),
+        )
+        .route(
+            "/api/v1/products/:product_id/valuation",
+            routing::get(handlers::valuation::find_product_valuation),
+        )
+        .route(
+            "/api/v1/products/:product_id/costing-method",
+            routing::put(handlers::valuation::set_costing_method),
+        )
+}
@@ -0,0 +1,15 @@
+use crate::adapters::rest::handlers;
+use lumx_axum::axum::{routing, Router};
+
+pub fn router() -> Router {
+    Router::new()
+        .route(
+            "/api/v1/products/:product_id/variants",
+            routing::post(handlers::variant::create_product_variant)
+                .get(handlers::variant::find_product_variants),
+        )
+        .route(
+            "/api/v1/products/:product_id/variants/matrix",
+            routing::post(handlers::variant::generate_variant_matrix),
+        )
+}
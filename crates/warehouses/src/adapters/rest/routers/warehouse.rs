@@ -0,0 +1,16 @@
+use crate::adapters::rest::handlers;
+use lumx_axum::axum::{routing, Router};
+
+pub fn router() -> Router {
+    Router::new()
+        .route(
+            "/api/v1/warehouses",
+            routing::post(handlers::warehouse::create_warehouse)
+                .get(handlers::warehouse::find_all_warehouses),
+        )
+        .route(
+            "/api/v1/warehouses/:warehouse_id",
+            routing::get(handlers::warehouse::find_warehouse)
+                .put(handlers::warehouse::update_warehouse),
+        )
+}
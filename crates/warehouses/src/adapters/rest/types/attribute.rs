@@ -0,0 +1,57 @@
+use crate::domain::selectors::attribute::{
+    AttributeValueSelector, ProductAttributeDetailsSelector,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateProductAttributeParams {
+    pub name: String,
+
+    #[serde(default)]
+    pub values: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddAttributeValueParams {
+    pub value: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttributeValueQuery {
+    pub attribute_value_id: i32,
+    pub attribute_id: i32,
+    pub attribute_name: String,
+    pub value: String,
+}
+
+impl From<&AttributeValueSelector> for AttributeValueQuery {
+    fn from(value: &AttributeValueSelector) -> Self {
+        Self {
+            attribute_value_id: value.product_attribute_value_id,
+            attribute_id: value.attribute_id,
+            attribute_name: value.attribute_name.to_owned(),
+            value: value.value.to_owned(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductAttributeDetailsQuery {
+    pub product_attribute_id: i32,
+    pub name: String,
+    pub values: Vec<AttributeValueQuery>,
+}
+
+impl From<&ProductAttributeDetailsSelector> for ProductAttributeDetailsQuery {
+    fn from(value: &ProductAttributeDetailsSelector) -> Self {
+        Self {
+            product_attribute_id: value.product_attribute_id,
+            name: value.name.to_owned(),
+            values: value.values.iter().map(AttributeValueQuery::from).collect(),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::domain::selectors::barcode::{ProductBarcodeSelector, ScannedProductSelector};
+use crate::domain::valuables::barcode::BarcodeSymbology;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BarcodeSymbologyParams {
+    Ean13,
+    UpcA,
+    Gtin14,
+}
+
+impl From<BarcodeSymbology> for BarcodeSymbologyParams {
+    fn from(value: BarcodeSymbology) -> Self {
+        match value {
+            BarcodeSymbology::Ean13 => Self::Ean13,
+            BarcodeSymbology::UpcA => Self::UpcA,
+            BarcodeSymbology::Gtin14 => Self::Gtin14,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetProductSkuParams {
+    pub sku: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddProductBarcodeParams {
+    pub code: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductBarcodeQuery {
+    pub product_barcode_id: i32,
+    pub code: String,
+    pub gtin: String,
+    pub symbology: BarcodeSymbologyParams,
+}
+
+impl From<&ProductBarcodeSelector> for ProductBarcodeQuery {
+    fn from(value: &ProductBarcodeSelector) -> Self {
+        Self {
+            product_barcode_id: value.product_barcode_id,
+            code: value.code.to_owned(),
+            gtin: value.gtin.to_owned(),
+            symbology: value.symbology.into(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScannedProductQuery {
+    pub product_id: i32,
+    pub product_name: String,
+    pub sku: Option<String>,
+    pub code: String,
+    pub symbology: BarcodeSymbologyParams,
+    pub discontinued: bool,
+}
+
+impl From<&ScannedProductSelector> for ScannedProductQuery {
+    fn from(value: &ScannedProductSelector) -> Self {
+        Self {
+            product_id: value.product_id,
+            product_name: value.product_name.to_owned(),
+            sku: value.sku.to_owned(),
+            code: value.code.to_owned(),
+            symbology: value.symbology.into(),
+            discontinued: value.discontinued,
+        }
+    }
+}
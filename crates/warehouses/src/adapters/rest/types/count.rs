@@ -0,0 +1,153 @@
+use crate::domain::queries::count::InventoryCountQuery;
+use crate::domain::selectors::count::{
+    InventoryCountDetailsSelector, InventoryCountLineSelector, InventoryCountSelector,
+};
+use crate::domain::valuables::count::{CountVariance, InventoryCountStatus};
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum InventoryCountStatusParams {
+    Open,
+    Counting,
+    Reviewed,
+    Posted,
+}
+
+impl From<InventoryCountStatusParams> for InventoryCountStatus {
+    fn from(value: InventoryCountStatusParams) -> Self {
+        match value {
+            InventoryCountStatusParams::Open => Self::Open,
+            InventoryCountStatusParams::Counting => Self::Counting,
+            InventoryCountStatusParams::Reviewed => Self::Reviewed,
+            InventoryCountStatusParams::Posted => Self::Posted,
+        }
+    }
+}
+
+impl From<InventoryCountStatus> for InventoryCountStatusParams {
+    fn from(value: InventoryCountStatus) -> Self {
+        match value {
+            InventoryCountStatus::Open => Self::Open,
+            InventoryCountStatus::Counting => Self::Counting,
+            InventoryCountStatus::Reviewed => Self::Reviewed,
+            InventoryCountStatus::Posted => Self::Posted,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInventoryCountParams {
+    pub warehouse_id: Option<i32>,
+
+    pub family_id: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CountInventoryParams {
+    pub product_id: i32,
+
+    pub storage_location_id: Option<i32>,
+
+    pub counted_quantity: i32,
+
+    pub unit_id: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InventoryCountCriteriaParams {
+    pub status: Option<InventoryCountStatusParams>,
+
+    pub warehouse_id: Option<i32>,
+}
+
+impl From<InventoryCountCriteriaParams> for InventoryCountQuery {
+    fn from(value: InventoryCountCriteriaParams) -> Self {
+        Self {
+            status: value.status.map(InventoryCountStatus::from),
+            warehouse_id: value.warehouse_id,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InventoryCountPageQuery {
+    pub inventory_count_id: i32,
+    pub warehouse_id: Option<i32>,
+    pub family_id: Option<i32>,
+    pub status: InventoryCountStatusParams,
+    pub opened_at: NaiveDateTime,
+    pub posted_at: Option<NaiveDateTime>,
+}
+
+impl From<&InventoryCountSelector> for InventoryCountPageQuery {
+    fn from(value: &InventoryCountSelector) -> Self {
+        Self {
+            inventory_count_id: value.inventory_count_id,
+            warehouse_id: value.warehouse_id,
+            family_id: value.family_id,
+            status: value.status.into(),
+            opened_at: value.opened_at,
+            posted_at: value.posted_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InventoryCountLineQuery {
+    pub inventory_count_line_id: i32,
+    pub product_id: i32,
+    pub storage_location_id: Option<i32>,
+    pub book_quantity: i32,
+    pub counted_quantity: Option<i32>,
+    pub variance: Option<i32>,
+}
+
+impl From<&InventoryCountLineSelector> for InventoryCountLineQuery {
+    fn from(value: &InventoryCountLineSelector) -> Self {
+        Self {
+            inventory_count_line_id: value.inventory_count_line_id,
+            product_id: value.product_id,
+            storage_location_id: value.storage_location_id,
+            book_quantity: value.book_quantity,
+            counted_quantity: value.counted_quantity,
+            variance: CountVariance::new(value.book_quantity, value.counted_quantity).get(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InventoryCountDetailsQuery {
+    pub inventory_count_id: i32,
+    pub warehouse_id: Option<i32>,
+    pub family_id: Option<i32>,
+    pub status: InventoryCountStatusParams,
+    pub opened_at: NaiveDateTime,
+    pub posted_at: Option<NaiveDateTime>,
+    pub lines: Vec<InventoryCountLineQuery>,
+}
+
+impl From<&InventoryCountDetailsSelector> for InventoryCountDetailsQuery {
+    fn from(value: &InventoryCountDetailsSelector) -> Self {
+        Self {
+            inventory_count_id: value.inventory_count_id,
+            warehouse_id: value.warehouse_id,
+            family_id: value.family_id,
+            status: value.status.into(),
+            opened_at: value.opened_at,
+            posted_at: value.posted_at,
+            lines: value
+                .lines
+                .iter()
+                .map(InventoryCountLineQuery::from)
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use crate::domain::queries::family::ProductFamilyQuery;
+use crate::domain::selectors::family::{
+    ProductFamilyPageSelector, ProductFamilySelector, ProductFamilyTreeSelector,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateProductFamilyParams {
+    pub name: String,
+
+    pub summary: Option<String>,
+
+    pub parent_family_id: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateProductFamilyParams {
+    pub name: String,
+
+    pub summary: Option<String>,
+
+    pub parent_family_id: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductFamilyCriteriaParams {
+    pub name: Option<String>,
+}
+
+impl From<ProductFamilyCriteriaParams> for ProductFamilyQuery {
+    fn from(value: ProductFamilyCriteriaParams) -> Self {
+        Self { name: value.name }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductFamilyPageQuery {
+    pub product_family_id: i32,
+    pub name: String,
+    pub summary: Option<String>,
+    pub parent_family_id: Option<i32>,
+}
+
+impl From<&ProductFamilyPageSelector> for ProductFamilyPageQuery {
+    fn from(value: &ProductFamilyPageSelector) -> Self {
+        Self {
+            product_family_id: value.product_family_id,
+            name: value.name.to_string(),
+            summary: value.summary.clone(),
+            parent_family_id: value.parent_family_id,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductFamilyDetailsQuery {
+    pub product_family_id: i32,
+    pub name: String,
+    pub summary: Option<String>,
+    pub parent_family_id: Option<i32>,
+}
+
+impl From<&ProductFamilySelector> for ProductFamilyDetailsQuery {
+    fn from(value: &ProductFamilySelector) -> Self {
+        Self {
+            product_family_id: value.product_family_id,
+            name: value.name.to_string(),
+            summary: value.summary.clone(),
+            parent_family_id: value.parent_family_id,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductFamilyTreeQuery {
+    pub product_family_id: i32,
+    pub name: String,
+    pub summary: Option<String>,
+    pub children: Vec<ProductFamilyTreeQuery>,
+}
+
+impl From<&ProductFamilyTreeSelector> for ProductFamilyTreeQuery {
+    fn from(value: &ProductFamilyTreeSelector) -> Self {
+        Self {
+            product_family_id: value.product_family_id,
+            name: value.name.to_string(),
+            summary: value.summary.clone(),
+            children: value
+                .children
+                .iter()
+                .map(ProductFamilyTreeQuery::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductFamilyBreadcrumbQuery {
+    pub product_family_id: i32,
+    pub name: String,
+}
+
+impl From<&ProductFamilySelector> for ProductFamilyBreadcrumbQuery {
+    fn from(value: &ProductFamilySelector) -> Self {
+        Self {
+            product_family_id: value.product_family_id,
+            name: value.name.to_string(),
+        }
+    }
+}
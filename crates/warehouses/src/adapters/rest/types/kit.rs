@@ -0,0 +1,80 @@
+use crate::domain::selectors::kit::{
+    ComponentAvailabilitySelector, KitAvailabilitySelector, KitComponentSelector,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddKitComponentParams {
+    pub component_id: i32,
+    pub quantity: i32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssembleKitParams {
+    pub quantity: i32,
+    pub storage_location_id: Option<i32>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KitComponentQuery {
+    pub product_component_id: i32,
+    pub component_id: i32,
+    pub quantity: i32,
+}
+
+impl From<&KitComponentSelector> for KitComponentQuery {
+    fn from(value: &KitComponentSelector) -> Self {
+        Self {
+            product_component_id: value.product_component_id,
+            component_id: value.component_product_id,
+            quantity: value.quantity,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentAvailabilityQuery {
+    pub component_id: i32,
+    pub quantity: i32,
+    pub available: i32,
+}
+
+impl From<&ComponentAvailabilitySelector> for ComponentAvailabilityQuery {
+    fn from(value: &ComponentAvailabilitySelector) -> Self {
+        Self {
+            component_id: value.component_product_id,
+            quantity: value.quantity,
+            available: value.available,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KitAvailabilityQuery {
+    pub product_id: i32,
+    pub on_hand: i32,
+    pub buildable: i32,
+    pub available: i32,
+    pub components: Vec<ComponentAvailabilityQuery>,
+}
+
+impl From<&KitAvailabilitySelector> for KitAvailabilityQuery {
+    fn from(value: &KitAvailabilitySelector) -> Self {
+        Self {
+            product_id: value.product_id,
+            on_hand: value.on_hand,
+            buildable: value.buildable,
+            available: value.available,
+            components: value
+                .components
+                .iter()
+                .map(ComponentAvailabilityQuery::from)
+                .collect(),
+        }
+    }
+}
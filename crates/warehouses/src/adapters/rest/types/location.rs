@@ -0,0 +1,68 @@
+use crate::domain::selectors::location::{StorageLocationPageSelector, StorageLocationSelector};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateStorageLocationParams {
+    pub aisle: String,
+
+    pub shelf: String,
+
+    pub bin: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateStorageLocationParams {
+    pub aisle: String,
+
+    pub shelf: String,
+
+    pub bin: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageLocationPageQuery {
+    pub storage_location_id: i32,
+    pub warehouse_id: i32,
+    pub aisle: String,
+    pub shelf: String,
+    pub bin: String,
+}
+
+impl From<&StorageLocationPageSelector> for StorageLocationPageQuery {
+    fn from(value: &StorageLocationPageSelector) -> Self {
+        Self {
+            storage_location_id: value.storage_location_id,
+            warehouse_id: value.warehouse_id,
+            aisle: value.aisle.to_string(),
+            shelf: value.shelf.to_string(),
+            bin: value.bin.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageLocationDetailsQuery {
+    pub storage_location_id: i32,
+    pub warehouse_id: i32,
+    pub warehouse_code: String,
+    pub aisle: String,
+    pub shelf: String,
+    pub bin: String,
+}
+
+impl From<&StorageLocationSelector> for StorageLocationDetailsQuery {
+    fn from(value: &StorageLocationSelector) -> Self {
+        Self {
+            storage_location_id: value.storage_location_id,
+            warehouse_id: value.warehouse_id,
+            warehouse_code: value.warehouse_code.to_string(),
+            aisle: value.aisle.to_string(),
+            shelf: value.shelf.to_string(),
+            bin: value.bin.to_string(),
+        }
+    }
+}
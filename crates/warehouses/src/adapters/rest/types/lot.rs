@@ -0,0 +1,95 @@
+use crate::domain::queries::lot::{ExpiringLotQuery, FefoPickQuery};
+use crate::domain::selectors::lot::{ExpiringLotSelector, FefoPickSelector, LotPickSelector};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpiringLotCriteriaParams {
+    pub within_days: u32,
+}
+
+impl From<ExpiringLotCriteriaParams> for ExpiringLotQuery {
+    fn from(value: ExpiringLotCriteriaParams) -> Self {
+        Self {
+            within_days: value.within_days.into(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpiringLotDetailsQuery {
+    pub product_id: i32,
+    pub lot_number: String,
+    pub expires_on: NaiveDate,
+    pub on_hand: i32,
+    pub expired: bool,
+}
+
+impl From<&ExpiringLotSelector> for ExpiringLotDetailsQuery {
+    fn from(value: &ExpiringLotSelector) -> Self {
+        Self {
+            product_id: value.product_id,
+            lot_number: value.lot_number.to_string(),
+            expires_on: value.expires_on,
+            on_hand: value.on_hand,
+            expired: value.expired,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FefoPickCriteriaParams {
+    pub quantity: u16,
+
+    pub override_reason: Option<String>,
+}
+
+impl From<FefoPickCriteriaParams> for FefoPickQuery {
+    fn from(value: FefoPickCriteriaParams) -> Self {
+        Self {
+            quantity: value.quantity.into(),
+            override_reason: value.override_reason,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LotPickQuery {
+    pub lot_number: String,
+    pub expires_on: Option<NaiveDate>,
+    pub quantity: i32,
+}
+
+impl From<&LotPickSelector> for LotPickQuery {
+    fn from(value: &LotPickSelector) -> Self {
+        Self {
+            lot_number: value.lot_number.to_string(),
+            expires_on: value.expires_on,
+            quantity: value.quantity,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FefoPickDetailsQuery {
+    pub product_id: i32,
+    pub quantity: i32,
+    pub picks: Vec<LotPickQuery>,
+    pub shortage: i32,
+}
+
+impl From<&FefoPickSelector> for FefoPickDetailsQuery {
+    fn from(value: &FefoPickSelector) -> Self {
+        Self {
+            product_id: value.product_id,
+            quantity: value.quantity,
+            picks: value.picks.iter().map(LotPickQuery::from).collect(),
+            shortage: value.shortage,
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use crate::domain::queries::price::ProductPriceQuery;
+use crate::domain::selectors::price::{PriceListSelector, ResolvedPriceSelector};
+use crate::domain::valuables::price::PriceListKind;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PriceListKindParams {
+    Retail,
+    Wholesale,
+}
+
+impl From<PriceListKindParams> for PriceListKind {
+    fn from(value: PriceListKindParams) -> Self {
+        match value {
+            PriceListKindParams::Retail => Self::Retail,
+            PriceListKindParams::Wholesale => Self::Wholesale,
+        }
+    }
+}
+
+impl From<PriceListKind> for PriceListKindParams {
+    fn from(value: PriceListKind) -> Self {
+        match value {
+            PriceListKind::Retail => Self::Retail,
+            PriceListKind::Wholesale => Self::Wholesale,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePriceListParams {
+    pub name: String,
+
+    pub kind: PriceListKindParams,
+
+    pub currency: String,
+
+    pub valid_from: NaiveDate,
+
+    pub valid_to: Option<NaiveDate>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddProductPriceParams {
+    pub product_id: i32,
+
+    pub amount: String,
+
+    pub valid_from: NaiveDate,
+
+    pub valid_to: Option<NaiveDate>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductPriceCriteriaParams {
+    pub price_list_id: i32,
+
+    pub date: Option<NaiveDate>,
+}
+
+impl From<ProductPriceCriteriaParams> for ProductPriceQuery {
+    fn from(value: ProductPriceCriteriaParams) -> Self {
+        Self {
+            price_list_id: value.price_list_id,
+            date: value.date,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceListDetailsQuery {
+    pub price_list_id: i32,
+    pub name: String,
+    pub kind: PriceListKindParams,
+    pub currency: String,
+    pub valid_from: NaiveDate,
+    pub valid_to: Option<NaiveDate>,
+}
+
+impl From<&PriceListSelector> for PriceListDetailsQuery {
+    fn from(value: &PriceListSelector) -> Self {
+        Self {
+            price_list_id: value.price_list_id,
+            name: value.name.to_owned(),
+            kind: value.kind.into(),
+            currency: value.currency.to_owned(),
+            valid_from: value.valid_from,
+            valid_to: value.valid_to,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedPriceDetailsQuery {
+    pub product_id: i32,
+    pub price_list_id: i32,
+    pub price_list_name: String,
+    pub kind: PriceListKindParams,
+    pub currency: String,
+    pub amount: String,
+    pub valid_from: NaiveDate,
+    pub valid_to: Option<NaiveDate>,
+}
+
+impl From<&ResolvedPriceSelector> for ResolvedPriceDetailsQuery {
+    fn from(value: &ResolvedPriceSelector) -> Self {
+        Self {
+            product_id: value.product_id,
+            price_list_id: value.price_list_id,
+            price_list_name: value.price_list_name.to_owned(),
+            kind: value.kind.into(),
+            currency: value.currency.to_owned(),
+            amount: value.amount.format(),
+            valid_from: value.valid_from,
+            valid_to: value.valid_to,
+        }
+    }
+}
@@ -1,4 +1,35 @@
-use serde::Deserialize;
+use crate::domain::queries::product::ProductQuery;
+use crate::domain::selectors::product::{ProductDetailsSelector, ProductPageSelector};
+use crate::domain::valuables::tracking::TrackingMode;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TrackingModeParams {
+    None,
+    Lot,
+    Serial,
+}
+
+impl From<TrackingModeParams> for TrackingMode {
+    fn from(value: TrackingModeParams) -> Self {
+        match value {
+            TrackingModeParams::None => Self::None,
+            TrackingModeParams::Lot => Self::Lot,
+            TrackingModeParams::Serial => Self::Serial,
+        }
+    }
+}
+
+impl From<TrackingMode> for TrackingModeParams {
+    fn from(value: TrackingMode) -> Self {
+        match value {
+            TrackingMode::None => Self::None,
+            TrackingMode::Lot => Self::Lot,
+            TrackingMode::Serial => Self::Serial,
+        }
+    }
+}
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -14,4 +45,114 @@ pub struct CreateProductParams {
     pub saleable: bool,
 
     pub saleable_without_stock: bool,
+
+    pub tracking_mode: Option<TrackingModeParams>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateProductParams {
+    pub name: String,
+
+    pub summary: Option<String>,
+
+    pub family_id: i32,
+
+    pub purchasable: bool,
+
+    pub saleable: bool,
+
+    pub saleable_without_stock: bool,
+
+    pub tracking_mode: Option<TrackingModeParams>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductCriteriaParams {
+    pub family_id: Option<i32>,
+
+    pub include_descendants: Option<bool>,
+
+    pub name: Option<String>,
+
+    pub purchasable: Option<bool>,
+
+    pub saleable: Option<bool>,
+}
+
+impl From<ProductCriteriaParams> for ProductQuery {
+    fn from(value: ProductCriteriaParams) -> Self {
+        Self {
+            family_ids: value.family_id.into_iter().collect(),
+            include_descendants: value.include_descendants.unwrap_or_default(),
+            name: value.name,
+            purchasable: value.purchasable,
+            saleable: value.saleable,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductPageQuery {
+    pub product_id: i32,
+    pub family_id: i32,
+    pub family_name: String,
+    pub name: String,
+    pub summary: Option<String>,
+    pub purchasable: bool,
+    pub saleable: bool,
+    pub saleable_without_stock: bool,
+    pub discontinued: bool,
+    pub tracking_mode: TrackingModeParams,
+}
+
+impl From<&ProductPageSelector> for ProductPageQuery {
+    fn from(value: &ProductPageSelector) -> Self {
+        Self {
+            product_id: value.product_id,
+            family_id: value.family_id,
+            family_name: value.family_name.to_string(),
+            name: value.name.to_string(),
+            summary: value.summary.clone(),
+            purchasable: value.purchasable,
+            saleable: value.saleable,
+            saleable_without_stock: value.saleable_without_stock,
+            discontinued: value.discontinued,
+            tracking_mode: value.tracking_mode.into(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductDetailsQuery {
+    pub product_id: i32,
+    pub family_id: i32,
+    pub family_name: String,
+    pub name: String,
+    pub summary: Option<String>,
+    pub purchasable: bool,
+    pub saleable: bool,
+    pub saleable_without_stock: bool,
+    pub discontinued: bool,
+    pub tracking_mode: TrackingModeParams,
+}
+
+impl From<&ProductDetailsSelector> for ProductDetailsQuery {
+    fn from(value: &ProductDetailsSelector) -> Self {
+        Self {
+            product_id: value.product_id,
+            family_id: value.family_id,
+            family_name: value.family_name.to_string(),
+            name: value.name.to_string(),
+            summary: value.summary.clone(),
+            purchasable: value.purchasable,
+            saleable: value.saleable,
+            saleable_without_stock: value.saleable_without_stock,
+            discontinued: value.discontinued,
+            tracking_mode: value.tracking_mode.into(),
+        }
+    }
 }
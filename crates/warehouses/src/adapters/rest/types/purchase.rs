@@ -0,0 +1,194 @@
+use crate::domain::queries::purchase::PurchaseOrderQuery;
+use crate::domain::selectors::purchase::{
+    PurchaseOrderDetailsSelector, PurchaseOrderLineSelector, PurchaseOrderSelector,
+};
+use crate::domain::valuables::purchase::{LineReceipt, PurchaseOrderStatus};
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PurchaseOrderStatusParams {
+    Draft,
+    Approved,
+    Sent,
+    PartiallyReceived,
+    Closed,
+}
+
+impl From<PurchaseOrderStatusParams> for PurchaseOrderStatus {
+    fn from(value: PurchaseOrderStatusParams) -> Self {
+        match value {
+            PurchaseOrderStatusParams::Draft => Self::Draft,
+            PurchaseOrderStatusParams::Approved => Self::Approved,
+            PurchaseOrderStatusParams::Sent => Self::Sent,
+            PurchaseOrderStatusParams::PartiallyReceived => Self::PartiallyReceived,
+            PurchaseOrderStatusParams::Closed => Self::Closed,
+        }
+    }
+}
+
+impl From<PurchaseOrderStatus> for PurchaseOrderStatusParams {
+    fn from(value: PurchaseOrderStatus) -> Self {
+        match value {
+            PurchaseOrderStatus::Draft => Self::Draft,
+            PurchaseOrderStatus::Approved => Self::Approved,
+            PurchaseOrderStatus::Sent => Self::Sent,
+            PurchaseOrderStatus::PartiallyReceived => Self::PartiallyReceived,
+            PurchaseOrderStatus::Closed => Self::Closed,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePurchaseOrderParams {
+    pub supplier_id: i32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddPurchaseOrderLineParams {
+    pub product_id: i32,
+
+    pub quantity: i32,
+
+    pub unit_id: Option<i32>,
+
+    pub unit_price: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReceivePurchaseOrderLineParams {
+    pub purchase_order_line_id: i32,
+
+    pub quantity: i32,
+
+    pub lot_number: Option<String>,
+
+    pub serial_number: Option<String>,
+
+    pub expires_on: Option<NaiveDate>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReceivePurchaseOrderParams {
+    pub storage_location_id: Option<i32>,
+
+    #[serde(default)]
+    pub allow_over_receipt: bool,
+
+    pub lines: Vec<ReceivePurchaseOrderLineParams>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PurchaseOrderCriteriaParams {
+    pub status: Option<PurchaseOrderStatusParams>,
+
+    pub supplier_id: Option<i32>,
+}
+
+impl From<PurchaseOrderCriteriaParams> for PurchaseOrderQuery {
+    fn from(value: PurchaseOrderCriteriaParams) -> Self {
+        Self {
+            status: value.status.map(PurchaseOrderStatus::from),
+            supplier_id: value.supplier_id,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PurchaseOrderPageQuery {
+    pub purchase_order_id: i32,
+    pub supplier_id: i32,
+    pub status: PurchaseOrderStatusParams,
+    pub currency: String,
+    pub created_at: NaiveDateTime,
+    pub approved_at: Option<NaiveDateTime>,
+    pub sent_at: Option<NaiveDateTime>,
+    pub closed_at: Option<NaiveDateTime>,
+}
+
+impl From<&PurchaseOrderSelector> for PurchaseOrderPageQuery {
+    fn from(value: &PurchaseOrderSelector) -> Self {
+        Self {
+            purchase_order_id: value.purchase_order_id,
+            supplier_id: value.supplier_id,
+            status: value.status.into(),
+            currency: value.currency.to_owned(),
+            created_at: value.created_at,
+            approved_at: value.approved_at,
+            sent_at: value.sent_at,
+            closed_at: value.closed_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PurchaseOrderLineQuery {
+    pub purchase_order_line_id: i32,
+    pub product_id: i32,
+    pub ordered_quantity: i32,
+    pub received_quantity: i32,
+    pub outstanding_quantity: i32,
+    pub over_received_quantity: i32,
+    pub over_received: bool,
+    pub unit_id: Option<i32>,
+    pub unit_price: String,
+}
+
+impl From<&PurchaseOrderLineSelector> for PurchaseOrderLineQuery {
+    fn from(value: &PurchaseOrderLineSelector) -> Self {
+        let receipt = LineReceipt::new(value.ordered_quantity, value.received_quantity);
+        Self {
+            purchase_order_line_id: value.purchase_order_line_id,
+            product_id: value.product_id,
+            ordered_quantity: value.ordered_quantity,
+            received_quantity: value.received_quantity,
+            outstanding_quantity: receipt.outstanding_quantity(),
+            over_received_quantity: receipt.over_received_quantity(),
+            over_received: receipt.over_received_quantity() > 0,
+            unit_id: value.unit_id,
+            unit_price: value.unit_price.format(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PurchaseOrderDetailsQuery {
+    pub purchase_order_id: i32,
+    pub supplier_id: i32,
+    pub status: PurchaseOrderStatusParams,
+    pub currency: String,
+    pub created_at: NaiveDateTime,
+    pub approved_at: Option<NaiveDateTime>,
+    pub sent_at: Option<NaiveDateTime>,
+    pub closed_at: Option<NaiveDateTime>,
+    pub lines: Vec<PurchaseOrderLineQuery>,
+}
+
+impl From<&PurchaseOrderDetailsSelector> for PurchaseOrderDetailsQuery {
+    fn from(value: &PurchaseOrderDetailsSelector) -> Self {
+        Self {
+            purchase_order_id: value.purchase_order_id,
+            supplier_id: value.supplier_id,
+            status: value.status.into(),
+            currency: value.currency.to_owned(),
+            created_at: value.created_at,
+            approved_at: value.approved_at,
+            sent_at: value.sent_at,
+            closed_at: value.closed_at,
+            lines: value
+                .lines
+                .iter()
+                .map(PurchaseOrderLineQuery::from)
+                .collect(),
+        }
+    }
+}
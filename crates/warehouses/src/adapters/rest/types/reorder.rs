@@ -0,0 +1,56 @@
+use crate::domain::selectors::reorder::{LowStockSelector, ReorderPointSelector};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetReorderPointParams {
+    pub min_quantity: i32,
+
+    pub max_quantity: Option<i32>,
+
+    pub unit_id: Option<i32>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReorderPointDetailsQuery {
+    pub product_id: i32,
+    pub product_name: String,
+    pub min_quantity: i32,
+    pub max_quantity: Option<i32>,
+}
+
+impl From<&ReorderPointSelector> for ReorderPointDetailsQuery {
+    fn from(value: &ReorderPointSelector) -> Self {
+        Self {
+            product_id: value.product_id,
+            product_name: value.product_name.to_owned(),
+            min_quantity: value.min_quantity,
+            max_quantity: value.max_quantity,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LowStockQuery {
+    pub product_id: i32,
+    pub product_name: String,
+    pub on_hand: i32,
+    pub min_quantity: i32,
+    pub max_quantity: Option<i32>,
+    pub suggested_quantity: i32,
+}
+
+impl From<&LowStockSelector> for LowStockQuery {
+    fn from(value: &LowStockSelector) -> Self {
+        Self {
+            product_id: value.product_id,
+            product_name: value.product_name.to_owned(),
+            on_hand: value.on_hand,
+            min_quantity: value.min_quantity,
+            max_quantity: value.max_quantity,
+            suggested_quantity: value.suggested_quantity,
+        }
+    }
+}
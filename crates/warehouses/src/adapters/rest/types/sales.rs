@@ -0,0 +1,176 @@
+use crate::domain::queries::sales::SalesOrderQuery;
+use crate::domain::selectors::sales::{
+    AvailableToPromiseSelector, SalesOrderDetailsSelector, SalesOrderLineSelector,
+    SalesOrderSelector,
+};
+use crate::domain::valuables::sales::SalesOrderStatus;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SalesOrderStatusParams {
+    Draft,
+    Confirmed,
+    Cancelled,
+    Fulfilled,
+}
+
+impl From<SalesOrderStatusParams> for SalesOrderStatus {
+    fn from(value: SalesOrderStatusParams) -> Self {
+        match value {
+            SalesOrderStatusParams::Draft => Self::Draft,
+            SalesOrderStatusParams::Confirmed => Self::Confirmed,
+            SalesOrderStatusParams::Cancelled => Self::Cancelled,
+            SalesOrderStatusParams::Fulfilled => Self::Fulfilled,
+        }
+    }
+}
+
+impl From<SalesOrderStatus> for SalesOrderStatusParams {
+    fn from(value: SalesOrderStatus) -> Self {
+        match value {
+            SalesOrderStatus::Draft => Self::Draft,
+            SalesOrderStatus::Confirmed => Self::Confirmed,
+            SalesOrderStatus::Cancelled => Self::Cancelled,
+            SalesOrderStatus::Fulfilled => Self::Fulfilled,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateSalesOrderParams {
+    pub customer_id: i32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddSalesOrderLineParams {
+    pub product_id: i32,
+
+    pub quantity: i32,
+
+    pub unit_id: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FulfilSalesOrderParams {
+    pub storage_location_id: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SalesOrderCriteriaParams {
+    pub status: Option<SalesOrderStatusParams>,
+
+    pub customer_id: Option<i32>,
+}
+
+impl From<SalesOrderCriteriaParams> for SalesOrderQuery {
+    fn from(value: SalesOrderCriteriaParams) -> Self {
+        Self {
+            status: value.status.map(SalesOrderStatus::from),
+            customer_id: value.customer_id,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SalesOrderPageQuery {
+    pub sales_order_id: i32,
+    pub customer_id: i32,
+    pub status: SalesOrderStatusParams,
+    pub created_at: NaiveDateTime,
+    pub confirmed_at: Option<NaiveDateTime>,
+    pub cancelled_at: Option<NaiveDateTime>,
+    pub fulfilled_at: Option<NaiveDateTime>,
+}
+
+impl From<&SalesOrderSelector> for SalesOrderPageQuery {
+    fn from(value: &SalesOrderSelector) -> Self {
+        Self {
+            sales_order_id: value.sales_order_id,
+            customer_id: value.customer_id,
+            status: value.status.into(),
+            created_at: value.created_at,
+            confirmed_at: value.confirmed_at,
+            cancelled_at: value.cancelled_at,
+            fulfilled_at: value.fulfilled_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SalesOrderLineQuery {
+    pub sales_order_line_id: i32,
+    pub product_id: i32,
+    pub ordered_quantity: i32,
+    pub reserved_quantity: i32,
+    pub backordered_quantity: i32,
+}
+
+impl From<&SalesOrderLineSelector> for SalesOrderLineQuery {
+    fn from(value: &SalesOrderLineSelector) -> Self {
+        Self {
+            sales_order_line_id: value.sales_order_line_id,
+            product_id: value.product_id,
+            ordered_quantity: value.ordered_quantity,
+            reserved_quantity: value.reserved_quantity,
+            backordered_quantity: value.backordered_quantity,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SalesOrderDetailsQuery {
+    pub sales_order_id: i32,
+    pub customer_id: i32,
+    pub status: SalesOrderStatusParams,
+    pub created_at: NaiveDateTime,
+    pub confirmed_at: Option<NaiveDateTime>,
+    pub cancelled_at: Option<NaiveDateTime>,
+    pub fulfilled_at: Option<NaiveDateTime>,
+    pub lines: Vec<SalesOrderLineQuery>,
+}
+
+impl From<&SalesOrderDetailsSelector> for SalesOrderDetailsQuery {
+    fn from(value: &SalesOrderDetailsSelector) -> Self {
+        Self {
+            sales_order_id: value.sales_order_id,
+            customer_id: value.customer_id,
+            status: value.status.into(),
+            created_at: value.created_at,
+            confirmed_at: value.confirmed_at,
+            cancelled_at: value.cancelled_at,
+            fulfilled_at: value.fulfilled_at,
+            lines: value.lines.iter().map(SalesOrderLineQuery::from).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AvailableToPromiseQuery {
+    pub product_id: i32,
+    pub on_hand: i32,
+    pub reserved: i32,
+    pub backordered: i32,
+    pub available_to_promise: i32,
+}
+
+impl From<&AvailableToPromiseSelector> for AvailableToPromiseQuery {
+    fn from(value: &AvailableToPromiseSelector) -> Self {
+        Self {
+            product_id: value.product_id,
+            on_hand: value.on_hand,
+            reserved: value.reserved,
+            backordered: value.backordered,
+            available_to_promise: value.available_to_promise,
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use crate::domain::queries::stock::StockQuery;
+use crate::domain::selectors::stock::{
+    ProductLocationSelector, ProductOnHandSelector, StockMovementPageSelector,
+};
+use crate::domain::valuables::stock::StockMovementKind;
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StockMovementKindParams {
+    Receipt,
+    Issue,
+    Adjustment,
+    TransferOut,
+    TransferIn,
+}
+
+impl From<StockMovementKindParams> for StockMovementKind {
+    fn from(value: StockMovementKindParams) -> Self {
+        match value {
+            StockMovementKindParams::Receipt => Self::Receipt,
+            StockMovementKindParams::Issue => Self::Issue,
+            StockMovementKindParams::Adjustment => Self::Adjustment,
+            StockMovementKindParams::TransferOut => Self::TransferOut,
+            StockMovementKindParams::TransferIn => Self::TransferIn,
+        }
+    }
+}
+
+impl From<StockMovementKind> for StockMovementKindParams {
+    fn from(value: StockMovementKind) -> Self {
+        match value {
+            StockMovementKind::Receipt => Self::Receipt,
+            StockMovementKind::Issue => Self::Issue,
+            StockMovementKind::Adjustment => Self::Adjustment,
+            StockMovementKind::TransferOut => Self::TransferOut,
+            StockMovementKind::TransferIn => Self::TransferIn,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordStockMovementParams {
+    pub kind: StockMovementKindParams,
+
+    pub quantity: i32,
+
+    pub unit_id: Option<i32>,
+
+    pub storage_location_id: Option<i32>,
+
+    pub lot_number: Option<String>,
+
+    pub serial_number: Option<String>,
+
+    pub expires_on: Option<NaiveDate>,
+
+    pub unit_cost: Option<String>,
+
+    #[serde(default)]
+    pub override_expiry: bool,
+
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StockMovementPageQuery {
+    pub stock_movement_id: i32,
+    pub product_id: i32,
+    pub kind: StockMovementKindParams,
+    pub quantity: i32,
+    pub storage_location_id: Option<i32>,
+    pub stock_transfer_id: Option<i32>,
+    pub lot_number: Option<String>,
+    pub serial_number: Option<String>,
+    pub unit_cost: Option<String>,
+    pub reason: Option<String>,
+    pub moved_at: NaiveDateTime,
+}
+
+impl From<&StockMovementPageSelector> for StockMovementPageQuery {
+    fn from(value: &StockMovementPageSelector) -> Self {
+        Self {
+            stock_movement_id: value.stock_movement_id,
+            product_id: value.product_id,
+            kind: value.kind.into(),
+            quantity: value.quantity,
+            storage_location_id: value.storage_location_id,
+            stock_transfer_id: value.stock_transfer_id,
+            lot_number: value.lot_number.clone(),
+            serial_number: value.serial_number.clone(),
+            unit_cost: value.unit_cost.map(|unit_cost| unit_cost.format()),
+            reason: value.reason.clone(),
+            moved_at: value.moved_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StockCriteriaParams {
+    pub warehouse_id: Option<i32>,
+}
+
+impl From<StockCriteriaParams> for StockQuery {
+    fn from(value: StockCriteriaParams) -> Self {
+        Self {
+            warehouse_id: value.warehouse_id,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductOnHandQuery {
+    pub product_id: i32,
+    pub warehouse_id: Option<i32>,
+    pub on_hand: i32,
+    pub in_transit: i32,
+}
+
+impl From<&ProductOnHandSelector> for ProductOnHandQuery {
+    fn from(value: &ProductOnHandSelector) -> Self {
+        Self {
+            product_id: value.product_id,
+            warehouse_id: value.warehouse_id,
+            on_hand: value.on_hand,
+            in_transit: value.in_transit,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductLocationQuery {
+    pub storage_location_id: i32,
+    pub warehouse_id: i32,
+    pub warehouse_code: String,
+    pub aisle: String,
+    pub shelf: String,
+    pub bin: String,
+    pub on_hand: i32,
+}
+
+impl From<&ProductLocationSelector> for ProductLocationQuery {
+    fn from(value: &ProductLocationSelector) -> Self {
+        Self {
+            storage_location_id: value.storage_location_id,
+            warehouse_id: value.warehouse_id,
+            warehouse_code: value.warehouse_code.to_string(),
+            aisle: value.aisle.to_string(),
+            shelf: value.shelf.to_string(),
+            bin: value.bin.to_string(),
+            on_hand: value.on_hand,
+        }
+    }
+}
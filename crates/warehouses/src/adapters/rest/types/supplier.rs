@@ -0,0 +1,122 @@
+use crate::domain::selectors::supplier::{
+    SupplierOfferSelector, SupplierProductSelector, SupplierSelector,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateSupplierParams {
+    pub name: String,
+
+    pub contact_person_id: Option<i32>,
+
+    pub currency: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddSupplierProductParams {
+    pub product_id: i32,
+
+    pub supplier_code: String,
+
+    pub pack_size: i32,
+
+    pub lead_time_days: i32,
+
+    pub last_price: Option<String>,
+
+    #[serde(default)]
+    pub preferred: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateSupplierProductParams {
+    pub supplier_code: String,
+
+    pub pack_size: i32,
+
+    pub lead_time_days: i32,
+
+    pub last_price: Option<String>,
+
+    #[serde(default)]
+    pub preferred: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SupplierQuery {
+    pub supplier_id: i32,
+    pub name: String,
+    pub contact_person_id: Option<i32>,
+    pub currency: String,
+}
+
+impl From<&SupplierSelector> for SupplierQuery {
+    fn from(value: &SupplierSelector) -> Self {
+        Self {
+            supplier_id: value.supplier_id,
+            name: value.name.to_owned(),
+            contact_person_id: value.contact_person_id,
+            currency: value.currency.to_owned(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SupplierProductQuery {
+    pub supplier_product_id: i32,
+    pub product_id: i32,
+    pub supplier_code: String,
+    pub pack_size: i32,
+    pub lead_time_days: i32,
+    pub last_price: Option<String>,
+    pub preferred: bool,
+}
+
+impl From<&SupplierProductSelector> for SupplierProductQuery {
+    fn from(value: &SupplierProductSelector) -> Self {
+        Self {
+            supplier_product_id: value.supplier_product_id,
+            product_id: value.product_id,
+            supplier_code: value.supplier_code.to_owned(),
+            pack_size: value.pack_size,
+            lead_time_days: value.lead_time_days,
+            last_price: value.last_price.map(|price| price.format()),
+            preferred: value.preferred,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreferredSupplierQuery {
+    pub product_id: i32,
+    pub supplier_id: i32,
+    pub supplier_name: String,
+    pub currency: String,
+    pub supplier_code: String,
+    pub pack_size: i32,
+    pub lead_time_days: i32,
+    pub last_price: Option<String>,
+    pub preferred: bool,
+}
+
+impl From<&SupplierOfferSelector> for PreferredSupplierQuery {
+    fn from(value: &SupplierOfferSelector) -> Self {
+        Self {
+            product_id: value.product_id,
+            supplier_id: value.supplier_id,
+            supplier_name: value.supplier_name.to_owned(),
+            currency: value.currency.to_owned(),
+            supplier_code: value.supplier_code.to_owned(),
+            pack_size: value.pack_size,
+            lead_time_days: value.lead_time_days,
+            last_price: value.last_price.map(|price| price.format()),
+            preferred: value.preferred,
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use crate::domain::queries::transfer::StockTransferQuery;
+use crate::domain::selectors::transfer::StockTransferSelector;
+use crate::domain::valuables::transfer::StockTransferStatus;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StockTransferStatusParams {
+    InTransit,
+    PartiallyReceived,
+    Received,
+    Cancelled,
+}
+
+impl From<StockTransferStatusParams> for StockTransferStatus {
+    fn from(value: StockTransferStatusParams) -> Self {
+        match value {
+            StockTransferStatusParams::InTransit => Self::InTransit,
+            StockTransferStatusParams::PartiallyReceived => Self::PartiallyReceived,
+            StockTransferStatusParams::Received => Self::Received,
+            StockTransferStatusParams::Cancelled => Self::Cancelled,
+        }
+    }
+}
+
+impl From<StockTransferStatus> for StockTransferStatusParams {
+    fn from(value: StockTransferStatus) -> Self {
+        match value {
+            StockTransferStatus::InTransit => Self::InTransit,
+            StockTransferStatus::PartiallyReceived => Self::PartiallyReceived,
+            StockTransferStatus::Received => Self::Received,
+            StockTransferStatus::Cancelled => Self::Cancelled,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferStockParams {
+    pub product_id: i32,
+
+    pub source_location_id: i32,
+
+    pub destination_warehouse_id: i32,
+
+    pub quantity: i32,
+
+    pub unit_id: Option<i32>,
+
+    pub lot_number: Option<String>,
+
+    pub serial_number: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReceiveStockTransferParams {
+    pub storage_location_id: i32,
+
+    pub quantity: i32,
+
+    pub unit_id: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StockTransferCriteriaParams {
+    pub product_id: Option<i32>,
+
+    pub status: Option<StockTransferStatusParams>,
+}
+
+impl From<StockTransferCriteriaParams> for StockTransferQuery {
+    fn from(value: StockTransferCriteriaParams) -> Self {
+        Self {
+            product_id: value.product_id,
+            status: value.status.map(StockTransferStatus::from),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StockTransferDetailsQuery {
+    pub stock_transfer_id: i32,
+    pub product_id: i32,
+    pub source_location_id: i32,
+    pub source_warehouse_id: i32,
+    pub destination_warehouse_id: i32,
+    pub quantity: i32,
+    pub received_quantity: i32,
+    pub in_transit: i32,
+    pub status: StockTransferStatusParams,
+    pub transferred_at: NaiveDateTime,
+    pub closed_at: Option<NaiveDateTime>,
+    pub lot_number: Option<String>,
+    pub serial_number: Option<String>,
+}
+
+impl From<&StockTransferSelector> for StockTransferDetailsQuery {
+    fn from(value: &StockTransferSelector) -> Self {
+        let in_transit = if value.status.is_open() {
+            value.quantity - value.received_quantity
+        } else {
+            0
+        };
+
+        Self {
+            stock_transfer_id: value.stock_transfer_id,
+            product_id: value.product_id,
+            source_location_id: value.source_location_id,
+            source_warehouse_id: value.source_warehouse_id,
+            destination_warehouse_id: value.destination_warehouse_id,
+            quantity: value.quantity,
+            received_quantity: value.received_quantity,
+            in_transit,
+            status: value.status.into(),
+            transferred_at: value.transferred_at,
+            closed_at: value.closed_at,
+            lot_number: value.lot_number.clone(),
+            serial_number: value.serial_number.clone(),
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use crate::domain::selectors::unit::{
+    ProductUnitsSelector, UnitConversionSelector, UnitOfMeasureDetailsSelector,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateUnitOfMeasureParams {
+    pub code: String,
+
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddUnitConversionParams {
+    pub to_unit_id: i32,
+
+    pub factor: i32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetProductUnitsParams {
+    pub base_unit_id: i32,
+
+    pub purchase_unit_id: Option<i32>,
+
+    pub sale_unit_id: Option<i32>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnitConversionQuery {
+    pub unit_conversion_id: i32,
+    pub to_unit_id: i32,
+    pub factor: i32,
+}
+
+impl From<&UnitConversionSelector> for UnitConversionQuery {
+    fn from(value: &UnitConversionSelector) -> Self {
+        Self {
+            unit_conversion_id: value.unit_conversion_id,
+            to_unit_id: value.to_unit_id,
+            factor: value.factor,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnitOfMeasureDetailsQuery {
+    pub unit_of_measure_id: i32,
+    pub code: String,
+    pub name: String,
+    pub conversions: Vec<UnitConversionQuery>,
+}
+
+impl From<&UnitOfMeasureDetailsSelector> for UnitOfMeasureDetailsQuery {
+    fn from(value: &UnitOfMeasureDetailsSelector) -> Self {
+        Self {
+            unit_of_measure_id: value.unit_of_measure_id,
+            code: value.code.to_owned(),
+            name: value.name.to_owned(),
+            conversions: value
+                .conversions
+                .iter()
+                .map(UnitConversionQuery::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductUnitsQuery {
+    pub product_id: i32,
+    pub base_unit_id: Option<i32>,
+    pub purchase_unit_id: Option<i32>,
+    pub sale_unit_id: Option<i32>,
+}
+
+impl From<&ProductUnitsSelector> for ProductUnitsQuery {
+    fn from(value: &ProductUnitsSelector) -> Self {
+        Self {
+            product_id: value.product_id,
+            base_unit_id: value.base_unit_id,
+            purchase_unit_id: value.purchase_unit_id,
+            sale_unit_id: value.sale_unit_id,
+        }
+    }
+}
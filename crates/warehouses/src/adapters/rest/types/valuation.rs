@@ -0,0 +1,124 @@
+use crate::domain::queries::valuation::StockValuationQuery;
+use crate::domain::selectors::valuation::{
+    FamilyValuationSelector, ProductValuationSelector, StockValuationSelector,
+};
+use crate::domain::valuables::valuation::CostingMethod;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CostingMethodParams {
+    MovingAverage,
+    Fifo,
+}
+
+impl From<CostingMethodParams> for CostingMethod {
+    fn from(value: CostingMethodParams) -> Self {
+        match value {
+            CostingMethodParams::MovingAverage => Self::MovingAverage,
+            CostingMethodParams::Fifo => Self::Fifo,
+        }
+    }
+}
+
+impl From<CostingMethod> for CostingMethodParams {
+    fn from(value: CostingMethod) -> Self {
+        match value {
+            CostingMethod::MovingAverage => Self::MovingAverage,
+            CostingMethod::Fifo => Self::Fifo,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetCostingMethodParams {
+    pub costing_method: CostingMethodParams,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StockValuationCriteriaParams {
+    pub as_of: Option<NaiveDate>,
+}
+
+impl From<StockValuationCriteriaParams> for StockValuationQuery {
+    fn from(value: StockValuationCriteriaParams) -> Self {
+        Self { as_of: value.as_of }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductValuationQuery {
+    pub product_id: i32,
+    pub family_id: i32,
+    pub costing_method: CostingMethodParams,
+    pub quantity: i32,
+    pub unit_cost: String,
+    pub value: String,
+    pub issued_quantity: i32,
+    pub issued_cost: String,
+}
+
+impl From<&ProductValuationSelector> for ProductValuationQuery {
+    fn from(value: &ProductValuationSelector) -> Self {
+        Self {
+            product_id: value.product_id,
+            family_id: value.family_id,
+            costing_method: value.costing_method.into(),
+            quantity: value.quantity,
+            unit_cost: value.unit_cost.format(),
+            value: value.value.format(),
+            issued_quantity: value.issued_quantity,
+            issued_cost: value.issued_cost.format(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FamilyValuationQuery {
+    pub family_id: i32,
+    pub quantity: i32,
+    pub value: String,
+}
+
+impl From<&FamilyValuationSelector> for FamilyValuationQuery {
+    fn from(value: &FamilyValuationSelector) -> Self {
+        Self {
+            family_id: value.family_id,
+            quantity: value.quantity,
+            value: value.value.format(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StockValuationDetailsQuery {
+    pub as_of: NaiveDate,
+    pub value: String,
+    pub families: Vec<FamilyValuationQuery>,
+    pub products: Vec<ProductValuationQuery>,
+}
+
+impl From<&StockValuationSelector> for StockValuationDetailsQuery {
+    fn from(value: &StockValuationSelector) -> Self {
+        Self {
+            as_of: value.as_of,
+            value: value.value.format(),
+            families: value
+                .families
+                .iter()
+                .map(FamilyValuationQuery::from)
+                .collect(),
+            products: value
+                .products
+                .iter()
+                .map(ProductValuationQuery::from)
+                .collect(),
+        }
+    }
+}
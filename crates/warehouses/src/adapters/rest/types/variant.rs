@@ -0,0 +1,39 @@
+use crate::adapters::rest::types::attribute::AttributeValueQuery;
+use crate::domain::selectors::variant::ProductVariantDetailsSelector;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateProductVariantParams {
+    pub attribute_value_ids: Vec<i32>,
+
+    pub sku: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateVariantMatrixParams {
+    pub attribute_value_ids: Vec<i32>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductVariantDetailsQuery {
+    pub product_variant_id: i32,
+    pub product_id: i32,
+    pub name: String,
+    pub sku: Option<String>,
+    pub values: Vec<AttributeValueQuery>,
+}
+
+impl From<&ProductVariantDetailsSelector> for ProductVariantDetailsQuery {
+    fn from(value: &ProductVariantDetailsSelector) -> Self {
+        Self {
+            product_variant_id: value.product_variant_id,
+            product_id: value.product_id,
+            name: value.name.to_owned(),
+            sku: value.sku.to_owned(),
+            values: value.values.iter().map(AttributeValueQuery::from).collect(),
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::domain::queries::warehouse::WarehouseQuery;
+use crate::domain::selectors::warehouse::{WarehousePageSelector, WarehouseSelector};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateWarehouseParams {
+    pub code: String,
+
+    pub name: String,
+
+    pub address: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateWarehouseParams {
+    pub code: String,
+
+    pub name: String,
+
+    pub address: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WarehouseCriteriaParams {
+    pub name: Option<String>,
+}
+
+impl From<WarehouseCriteriaParams> for WarehouseQuery {
+    fn from(value: WarehouseCriteriaParams) -> Self {
+        Self { name: value.name }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WarehousePageQuery {
+    pub warehouse_id: i32,
+    pub code: String,
+    pub name: String,
+    pub address: Option<String>,
+}
+
+impl From<&WarehousePageSelector> for WarehousePageQuery {
+    fn from(value: &WarehousePageSelector) -> Self {
+        Self {
+            warehouse_id: value.warehouse_id,
+            code: value.code.to_string(),
+            name: value.name.to_string(),
+            address: value.address.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WarehouseDetailsQuery {
+    pub warehouse_id: i32,
+    pub code: String,
+    pub name: String,
+    pub address: Option<String>,
+}
+
+impl From<&WarehouseSelector> for WarehouseDetailsQuery {
+    fn from(value: &WarehouseSelector) -> Self {
+        Self {
+            warehouse_id: value.warehouse_id,
+            code: value.code.to_string(),
+            name: value.name.to_string(),
+            address: value.address.clone(),
+        }
+    }
+}
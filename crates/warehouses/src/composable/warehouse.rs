@@ -1,6 +1,40 @@
+use crate::adapters::repository::attribute::ProductAttributeSeaRepository;
+use crate::adapters::repository::barcode::ProductBarcodeSeaRepository;
+use crate::adapters::repository::count::InventoryCountSeaRepository;
 use crate::adapters::repository::family::ProductFamilySeaRepository;
+use crate::adapters::repository::kit::KitComponentSeaRepository;
+use crate::adapters::repository::location::StorageLocationSeaRepository;
+use crate::adapters::repository::lot::StockLotSeaRepository;
+use crate::adapters::repository::price::PriceListSeaRepository;
 use crate::adapters::repository::product::ProductSeaRepository;
+use crate::adapters::repository::purchase::PurchaseOrderSeaRepository;
+use crate::adapters::repository::reorder::ReorderPointSeaRepository;
+use crate::adapters::repository::sales::SalesOrderSeaRepository;
+use crate::adapters::repository::stock::StockMovementSeaRepository;
+use crate::adapters::repository::supplier::SupplierSeaRepository;
+use crate::adapters::repository::transfer::StockTransferSeaRepository;
+use crate::adapters::repository::unit::UnitOfMeasureSeaRepository;
+use crate::adapters::repository::variant::ProductVariantSeaRepository;
+use crate::adapters::repository::warehouse::WarehouseSeaRepository;
+use crate::domain::services::attribute::ProductAttributeService;
+use crate::domain::services::barcode::ProductBarcodeService;
+use crate::domain::services::count::InventoryCountService;
+use crate::domain::services::family::ProductFamilyService;
+use crate::domain::services::kit::KitService;
+use crate::domain::services::location::StorageLocationService;
+use crate::domain::services::lot::StockLotService;
+use crate::domain::services::price::PriceListService;
 use crate::domain::services::product::ProductService;
+use crate::domain::services::purchase::PurchaseOrderService;
+use crate::domain::services::reorder::ReorderPointService;
+use crate::domain::services::sales::SalesOrderService;
+use crate::domain::services::stock::StockService;
+use crate::domain::services::supplier::SupplierService;
+use crate::domain::services::transfer::StockTransferService;
+use crate::domain::services::unit::UnitOfMeasureService;
+use crate::domain::services::valuation::ValuationService;
+use crate::domain::services::variant::ProductVariantService;
+use crate::domain::services::warehouse::WarehouseService;
 use lumx_core::plugable::plugin::Plugin;
 use lumx_core::program::ProgramBuilder;
 use lumx_sea_orm::plugin::SeaOrmPlugin;
@@ -14,18 +48,155 @@ impl WarehousePlugin {
 
         let product_repo = ProductSeaRepository::new(&db_conn);
         let family_repo = ProductFamilySeaRepository::new(&db_conn);
+        let stock_repo = StockMovementSeaRepository::new(&db_conn);
+        let warehouse_repo = WarehouseSeaRepository::new(&db_conn);
+        let location_repo = StorageLocationSeaRepository::new(&db_conn);
+        let transfer_repo = StockTransferSeaRepository::new(&db_conn);
+        let lot_repo = StockLotSeaRepository::new(&db_conn);
+        let count_repo = InventoryCountSeaRepository::new(&db_conn);
+        let reorder_repo = ReorderPointSeaRepository::new(&db_conn);
+        let unit_repo = UnitOfMeasureSeaRepository::new(&db_conn);
+        let barcode_repo = ProductBarcodeSeaRepository::new(&db_conn);
+        let attribute_repo = ProductAttributeSeaRepository::new(&db_conn);
+        let variant_repo = ProductVariantSeaRepository::new(&db_conn);
+        let price_repo = PriceListSeaRepository::new(&db_conn);
+        let supplier_repo = SupplierSeaRepository::new(&db_conn);
+        let purchase_repo = PurchaseOrderSeaRepository::new(&db_conn);
+        let sales_repo = SalesOrderSeaRepository::new(&db_conn);
+        let component_repo = KitComponentSeaRepository::new(&db_conn);
 
         app.add_component(product_repo);
         app.add_component(family_repo);
+        app.add_component(stock_repo);
+        app.add_component(warehouse_repo);
+        app.add_component(location_repo);
+        app.add_component(transfer_repo);
+        app.add_component(lot_repo);
+        app.add_component(count_repo);
+        app.add_component(reorder_repo);
+        app.add_component(unit_repo);
+        app.add_component(barcode_repo);
+        app.add_component(attribute_repo);
+        app.add_component(variant_repo);
+        app.add_component(price_repo);
+        app.add_component(supplier_repo);
+        app.add_component(purchase_repo);
+        app.add_component(sales_repo);
+        app.add_component(component_repo);
     }
 
     fn expose_services(&self, app: &mut ProgramBuilder) {
         let product_repo = app.get_expect_component::<ProductSeaRepository>();
         let family_repo = app.get_expect_component::<ProductFamilySeaRepository>();
+        let stock_repo = app.get_expect_component::<StockMovementSeaRepository>();
+        let warehouse_repo = app.get_expect_component::<WarehouseSeaRepository>();
+        let location_repo = app.get_expect_component::<StorageLocationSeaRepository>();
+        let transfer_repo = app.get_expect_component::<StockTransferSeaRepository>();
+        let lot_repo = app.get_expect_component::<StockLotSeaRepository>();
+        let count_repo = app.get_expect_component::<InventoryCountSeaRepository>();
+        let reorder_repo = app.get_expect_component::<ReorderPointSeaRepository>();
+        let unit_repo = app.get_expect_component::<UnitOfMeasureSeaRepository>();
+        let barcode_repo = app.get_expect_component::<ProductBarcodeSeaRepository>();
+        let attribute_repo = app.get_expect_component::<ProductAttributeSeaRepository>();
+        let variant_repo = app.get_expect_component::<ProductVariantSeaRepository>();
+        let price_repo = app.get_expect_component::<PriceListSeaRepository>();
+        let supplier_repo = app.get_expect_component::<SupplierSeaRepository>();
+        let purchase_repo = app.get_expect_component::<PurchaseOrderSeaRepository>();
+        let sales_repo = app.get_expect_component::<SalesOrderSeaRepository>();
+        let component_repo = app.get_expect_component::<KitComponentSeaRepository>();
 
-        let product_service = ProductService::new(product_repo, family_repo);
+        let product_service = ProductService::new(
+            product_repo.clone(),
+            family_repo.clone(),
+            stock_repo.clone(),
+            component_repo.clone(),
+        );
+        let family_service = ProductFamilyService::new(family_repo.clone());
+        let stock_service = StockService::new(
+            stock_repo.clone(),
+            product_repo.clone(),
+            location_repo.clone(),
+            transfer_repo.clone(),
+            lot_repo.clone(),
+            unit_repo.clone(),
+            component_repo.clone(),
+        );
+        let warehouse_service = WarehouseService::new(warehouse_repo.clone());
+        let location_service =
+            StorageLocationService::new(location_repo.clone(), warehouse_repo.clone());
+        let transfer_service = StockTransferService::new(
+            transfer_repo,
+            stock_repo.clone(),
+            product_repo.clone(),
+            location_repo.clone(),
+            warehouse_repo.clone(),
+            unit_repo.clone(),
+        );
+        let lot_service =
+            StockLotService::new(lot_repo.clone(), stock_repo.clone(), product_repo.clone());
+        let count_service = InventoryCountService::new(
+            count_repo,
+            stock_repo.clone(),
+            product_repo.clone(),
+            family_repo,
+            location_repo.clone(),
+            warehouse_repo,
+            unit_repo.clone(),
+        );
+        let reorder_service = ReorderPointService::new(
+            reorder_repo,
+            product_repo.clone(),
+            stock_repo.clone(),
+            unit_repo.clone(),
+        );
+        let unit_service =
+            UnitOfMeasureService::new(unit_repo.clone(), product_repo.clone(), stock_repo.clone());
+        let barcode_service =
+            ProductBarcodeService::new(barcode_repo, product_repo.clone(), variant_repo.clone());
+        let attribute_service = ProductAttributeService::new(attribute_repo.clone());
+        let variant_service =
+            ProductVariantService::new(variant_repo, attribute_repo, product_repo.clone());
+        let price_service = PriceListService::new(price_repo, product_repo.clone());
+        let supplier_service = SupplierService::new(supplier_repo.clone(), product_repo.clone());
+        let purchase_service = PurchaseOrderService::new(
+            purchase_repo,
+            supplier_repo,
+            product_repo.clone(),
+            location_repo.clone(),
+            unit_repo.clone(),
+            stock_repo.clone(),
+            lot_repo,
+        );
+        let sales_service = SalesOrderService::new(
+            sales_repo,
+            product_repo.clone(),
+            stock_repo.clone(),
+            component_repo.clone(),
+            location_repo.clone(),
+            unit_repo,
+        );
+        let valuation_service = ValuationService::new(product_repo.clone(), stock_repo.clone());
+        let kit_service = KitService::new(component_repo, product_repo, stock_repo, location_repo);
 
         app.add_component(product_service);
+        app.add_component(family_service);
+        app.add_component(stock_service);
+        app.add_component(warehouse_service);
+        app.add_component(location_service);
+        app.add_component(transfer_service);
+        app.add_component(lot_service);
+        app.add_component(count_service);
+        app.add_component(reorder_service);
+        app.add_component(unit_service);
+        app.add_component(barcode_service);
+        app.add_component(attribute_service);
+        app.add_component(variant_service);
+        app.add_component(price_service);
+        app.add_component(supplier_service);
+        app.add_component(purchase_service);
+        app.add_component(sales_service);
+        app.add_component(valuation_service);
+        app.add_component(kit_service);
     }
 }
 
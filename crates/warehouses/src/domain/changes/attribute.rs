@@ -0,0 +1,9 @@
+pub struct AddProductAttribute {
+    pub name: String,
+    pub values: Vec<String>,
+}
+
+pub struct AddAttributeValue {
+    pub attribute_id: i32,
+    pub value: String,
+}
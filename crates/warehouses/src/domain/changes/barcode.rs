@@ -0,0 +1,8 @@
+use crate::domain::valuables::barcode::BarcodeSymbology;
+
+pub struct AddProductBarcode {
+    pub product_id: i32,
+    pub code: String,
+    pub gtin: String,
+    pub symbology: BarcodeSymbology,
+}
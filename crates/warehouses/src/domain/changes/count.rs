@@ -0,0 +1,29 @@
+use crate::domain::changes::stock::AddStockMovement;
+
+pub struct AddInventoryCount {
+    pub warehouse_id: Option<i32>,
+    pub family_id: Option<i32>,
+}
+
+pub struct AddInventoryCountLine {
+    pub inventory_count_id: i32,
+    pub product_id: i32,
+    pub storage_location_id: Option<i32>,
+    pub book_quantity: i32,
+    pub counted_quantity: Option<i32>,
+}
+
+pub struct StartInventoryCount {
+    pub inventory_count_id: i32,
+    pub lines: Vec<AddInventoryCountLine>,
+}
+
+pub struct CountInventoryLine {
+    pub inventory_count_line_id: i32,
+    pub counted_quantity: i32,
+}
+
+pub struct PostInventoryCount {
+    pub inventory_count_id: i32,
+    pub adjustments: Vec<AddStockMovement>,
+}
@@ -0,0 +1,14 @@
+pub struct AddProductFamily {
+    pub name: String,
+    pub summary: Option<String>,
+    pub parent_family_id: Option<i32>,
+    pub signature: String,
+}
+
+pub struct UpdateProductFamily {
+    pub product_family_id: i32,
+    pub name: String,
+    pub summary: Option<String>,
+    pub parent_family_id: Option<i32>,
+    pub signature: String,
+}
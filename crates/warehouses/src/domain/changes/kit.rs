@@ -0,0 +1,5 @@
+pub struct AddKitComponent {
+    pub kit_product_id: i32,
+    pub component_product_id: i32,
+    pub quantity: i32,
+}
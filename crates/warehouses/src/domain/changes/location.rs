@@ -0,0 +1,13 @@
+pub struct AddStorageLocation {
+    pub warehouse_id: i32,
+    pub aisle: String,
+    pub shelf: String,
+    pub bin: String,
+}
+
+pub struct UpdateStorageLocation {
+    pub storage_location_id: i32,
+    pub aisle: String,
+    pub shelf: String,
+    pub bin: String,
+}
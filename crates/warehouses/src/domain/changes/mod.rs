@@ -1 +1,17 @@
+pub mod attribute;
+pub mod barcode;
+pub mod count;
+pub mod family;
+pub mod kit;
+pub mod location;
+pub mod price;
 pub mod product;
+pub mod purchase;
+pub mod reorder;
+pub mod sales;
+pub mod stock;
+pub mod supplier;
+pub mod transfer;
+pub mod unit;
+pub mod variant;
+pub mod warehouse;
@@ -0,0 +1,19 @@
+use crate::domain::valuables::money::Money;
+use crate::domain::valuables::price::PriceListKind;
+use chrono::NaiveDate;
+
+pub struct AddPriceList {
+    pub name: String,
+    pub kind: PriceListKind,
+    pub currency: String,
+    pub valid_from: NaiveDate,
+    pub valid_to: Option<NaiveDate>,
+}
+
+pub struct AddProductPrice {
+    pub price_list_id: i32,
+    pub product_id: i32,
+    pub amount: Money,
+    pub valid_from: NaiveDate,
+    pub valid_to: Option<NaiveDate>,
+}
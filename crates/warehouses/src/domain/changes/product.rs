@@ -1,3 +1,6 @@
+use crate::domain::valuables::tracking::TrackingMode;
+use crate::domain::valuables::valuation::CostingMethod;
+
 pub struct AddProduct {
     pub name: String,
     pub summary: Option<String>,
@@ -6,4 +9,34 @@ pub struct AddProduct {
     pub saleable: bool,
     pub saleable_without_stock: bool,
     pub signature: String,
+    pub tracking_mode: TrackingMode,
+}
+
+pub struct UpdateProduct {
+    pub product_id: i32,
+    pub name: String,
+    pub summary: Option<String>,
+    pub family_id: i32,
+    pub purchasable: bool,
+    pub saleable: bool,
+    pub saleable_without_stock: bool,
+    pub signature: String,
+    pub tracking_mode: TrackingMode,
+}
+
+pub struct UpdateProductUnits {
+    pub product_id: i32,
+    pub base_unit_id: i32,
+    pub purchase_unit_id: Option<i32>,
+    pub sale_unit_id: Option<i32>,
+}
+
+pub struct UpdateProductSku {
+    pub product_id: i32,
+    pub sku: String,
+}
+
+pub struct UpdateProductCostingMethod {
+    pub product_id: i32,
+    pub costing_method: CostingMethod,
 }
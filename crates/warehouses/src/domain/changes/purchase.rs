@@ -0,0 +1,41 @@
+use crate::domain::changes::stock::AddStockMovement;
+use crate::domain::valuables::money::Money;
+use crate::domain::valuables::purchase::PurchaseOrderStatus;
+
+pub struct AddPurchaseOrder {
+    pub supplier_id: i32,
+    pub currency: String,
+}
+
+pub struct AddPurchaseOrderLine {
+    pub purchase_order_id: i32,
+    pub product_id: i32,
+    pub ordered_quantity: i32,
+    pub unit_id: Option<i32>,
+    pub unit_price: Money,
+}
+
+pub struct ChangePurchaseOrderStatus {
+    pub purchase_order_id: i32,
+    pub current_status: PurchaseOrderStatus,
+    pub status: PurchaseOrderStatus,
+}
+
+pub struct ReceivePurchaseOrderLine {
+    pub purchase_order_line_id: i32,
+    pub quantity: i32,
+}
+
+pub struct UpdateLastPurchasePrice {
+    pub supplier_id: i32,
+    pub product_id: i32,
+    pub last_price: Money,
+}
+
+pub struct ReceivePurchaseOrder {
+    pub purchase_order_id: i32,
+    pub allow_over_receipt: bool,
+    pub lines: Vec<ReceivePurchaseOrderLine>,
+    pub receipts: Vec<AddStockMovement>,
+    pub last_prices: Vec<UpdateLastPurchasePrice>,
+}
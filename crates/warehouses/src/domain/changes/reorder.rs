@@ -0,0 +1,5 @@
+pub struct SetReorderPoint {
+    pub product_id: i32,
+    pub min_quantity: i32,
+    pub max_quantity: Option<i32>,
+}
@@ -0,0 +1,40 @@
+use crate::domain::changes::stock::AddStockMovement;
+use crate::domain::valuables::sales::SalesOrderStatus;
+
+pub struct AddSalesOrder {
+    pub customer_id: i32,
+}
+
+pub struct AddSalesOrderLine {
+    pub sales_order_id: i32,
+    pub product_id: i32,
+    pub ordered_quantity: i32,
+}
+
+pub struct ReserveSalesOrderLine {
+    pub sales_order_line_id: i32,
+    pub reserved_quantity: i32,
+    pub backordered_quantity: i32,
+}
+
+pub struct CommittedStock {
+    pub product_id: i32,
+    pub on_hand: i32,
+    pub reserved: i32,
+    pub backordered: i32,
+}
+
+pub struct ChangeSalesOrderStatus {
+    pub sales_order_id: i32,
+    pub current_status: SalesOrderStatus,
+    pub status: SalesOrderStatus,
+    pub lines: Vec<ReserveSalesOrderLine>,
+    pub committed_stock: Vec<CommittedStock>,
+}
+
+pub struct FulfilSalesOrder {
+    pub sales_order_id: i32,
+    pub lines: Vec<ReserveSalesOrderLine>,
+    pub issues: Vec<AddStockMovement>,
+    pub in_stock_product_ids: Vec<i32>,
+}
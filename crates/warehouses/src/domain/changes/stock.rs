@@ -0,0 +1,15 @@
+use crate::domain::valuables::money::Money;
+use crate::domain::valuables::stock::StockMovementKind;
+use chrono::NaiveDate;
+
+pub struct AddStockMovement {
+    pub product_id: i32,
+    pub kind: StockMovementKind,
+    pub quantity: i32,
+    pub storage_location_id: Option<i32>,
+    pub lot_number: Option<String>,
+    pub serial_number: Option<String>,
+    pub unit_cost: Option<Money>,
+    pub lot_expires_on: Option<NaiveDate>,
+    pub reason: Option<String>,
+}
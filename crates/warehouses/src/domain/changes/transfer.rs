@@ -0,0 +1,27 @@
+use crate::domain::valuables::money::Money;
+
+pub struct AddStockTransfer {
+    pub product_id: i32,
+    pub source_location_id: i32,
+    pub source_warehouse_id: i32,
+    pub destination_warehouse_id: i32,
+    pub quantity: i32,
+    pub lot_number: Option<String>,
+    pub serial_number: Option<String>,
+    pub unit_cost: Money,
+}
+
+pub struct ReceiveStockTransfer {
+    pub stock_transfer_id: i32,
+    pub product_id: i32,
+    pub storage_location_id: i32,
+    pub quantity: i32,
+    pub unit_cost: Money,
+}
+
+pub struct CancelStockTransfer {
+    pub stock_transfer_id: i32,
+    pub product_id: i32,
+    pub source_location_id: i32,
+    pub unit_cost: Money,
+}
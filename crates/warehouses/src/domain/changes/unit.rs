@@ -0,0 +1,10 @@
+pub struct AddUnitOfMeasure {
+    pub code: String,
+    pub name: String,
+}
+
+pub struct AddUnitConversion {
+    pub from_unit_id: i32,
+    pub to_unit_id: i32,
+    pub factor: i32,
+}
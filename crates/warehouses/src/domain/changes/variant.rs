@@ -0,0 +1,7 @@
+pub struct AddProductVariant {
+    pub product_id: i32,
+    pub name: String,
+    pub sku: Option<String>,
+    pub signature: String,
+    pub attribute_value_ids: Vec<i32>,
+}
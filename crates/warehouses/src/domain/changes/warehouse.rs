@@ -0,0 +1,12 @@
+pub struct AddWarehouse {
+    pub code: String,
+    pub name: String,
+    pub address: Option<String>,
+}
+
+pub struct UpdateWarehouse {
+    pub warehouse_id: i32,
+    pub code: String,
+    pub name: String,
+    pub address: Option<String>,
+}
@@ -0,0 +1,17 @@
+use validator::Validate;
+
+#[derive(Validate, Debug, Clone)]
+pub struct CreateProductAttributeCommand {
+    #[validate(length(min = 1, max = 50))]
+    pub name: String,
+
+    pub values: Vec<String>,
+}
+
+#[derive(Validate, Debug, Clone)]
+pub struct AddAttributeValueCommand {
+    pub attribute_id: i32,
+
+    #[validate(length(min = 1, max = 50))]
+    pub value: String,
+}
@@ -0,0 +1,17 @@
+use validator::Validate;
+
+#[derive(Validate, Debug, Clone)]
+pub struct SetProductSkuCommand {
+    pub product_id: i32,
+
+    #[validate(length(min = 1, max = 50))]
+    pub sku: String,
+}
+
+#[derive(Validate, Debug, Clone)]
+pub struct AddProductBarcodeCommand {
+    pub product_id: i32,
+
+    #[validate(length(min = 1, max = 14))]
+    pub code: String,
+}
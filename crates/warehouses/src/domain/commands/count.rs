@@ -0,0 +1,22 @@
+use validator::Validate;
+
+#[derive(Validate, Debug, Clone)]
+pub struct OpenInventoryCountCommand {
+    pub warehouse_id: Option<i32>,
+
+    pub family_id: Option<i32>,
+}
+
+#[derive(Validate, Debug, Clone)]
+pub struct CountInventoryCommand {
+    pub inventory_count_id: i32,
+
+    pub product_id: i32,
+
+    pub storage_location_id: Option<i32>,
+
+    #[validate(range(min = 0))]
+    pub counted_quantity: i32,
+
+    pub unit_id: Option<i32>,
+}
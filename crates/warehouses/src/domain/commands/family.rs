@@ -0,0 +1,23 @@
+use validator::Validate;
+
+#[derive(Validate, Debug, Clone)]
+pub struct CreateProductFamilyCommand {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+
+    pub summary: Option<String>,
+
+    pub parent_family_id: Option<i32>,
+}
+
+#[derive(Validate, Debug, Clone)]
+pub struct UpdateProductFamilyCommand {
+    pub product_family_id: i32,
+
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+
+    pub summary: Option<String>,
+
+    pub parent_family_id: Option<i32>,
+}
@@ -0,0 +1,21 @@
+use validator::Validate;
+
+#[derive(Validate, Debug, Clone)]
+pub struct AddKitComponentCommand {
+    pub kit_product_id: i32,
+
+    pub component_product_id: i32,
+
+    #[validate(range(min = 1))]
+    pub quantity: i32,
+}
+
+#[derive(Validate, Debug, Clone)]
+pub struct AssembleKitCommand {
+    pub kit_product_id: i32,
+
+    #[validate(range(min = 1))]
+    pub quantity: i32,
+
+    pub storage_location_id: Option<i32>,
+}
@@ -0,0 +1,29 @@
+use validator::Validate;
+
+#[derive(Validate, Debug, Clone)]
+pub struct CreateStorageLocationCommand {
+    pub warehouse_id: i32,
+
+    #[validate(length(min = 1, max = 20))]
+    pub aisle: String,
+
+    #[validate(length(min = 1, max = 20))]
+    pub shelf: String,
+
+    #[validate(length(min = 1, max = 20))]
+    pub bin: String,
+}
+
+#[derive(Validate, Debug, Clone)]
+pub struct UpdateStorageLocationCommand {
+    pub storage_location_id: i32,
+
+    #[validate(length(min = 1, max = 20))]
+    pub aisle: String,
+
+    #[validate(length(min = 1, max = 20))]
+    pub shelf: String,
+
+    #[validate(length(min = 1, max = 20))]
+    pub bin: String,
+}
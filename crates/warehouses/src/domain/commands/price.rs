@@ -0,0 +1,30 @@
+use crate::domain::valuables::price::PriceListKind;
+use chrono::NaiveDate;
+use validator::Validate;
+
+#[derive(Validate, Debug, Clone)]
+pub struct CreatePriceListCommand {
+    #[validate(length(min = 1, max = 50))]
+    pub name: String,
+
+    pub kind: PriceListKind,
+
+    pub currency: String,
+
+    pub valid_from: NaiveDate,
+
+    pub valid_to: Option<NaiveDate>,
+}
+
+#[derive(Validate, Debug, Clone)]
+pub struct AddProductPriceCommand {
+    pub price_list_id: i32,
+
+    pub product_id: i32,
+
+    pub amount: String,
+
+    pub valid_from: NaiveDate,
+
+    pub valid_to: Option<NaiveDate>,
+}
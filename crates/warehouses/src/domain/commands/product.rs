@@ -1,3 +1,4 @@
+use crate::domain::valuables::tracking::TrackingMode;
 use validator::Validate;
 
 #[derive(Validate, Debug, Clone)]
@@ -14,4 +15,26 @@ pub struct CreateProductCommand {
     pub saleable: bool,
 
     pub saleable_without_stock: bool,
+
+    pub tracking_mode: TrackingMode,
+}
+
+#[derive(Validate, Debug, Clone)]
+pub struct UpdateProductCommand {
+    pub product_id: i32,
+
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+
+    pub summary: Option<String>,
+
+    pub family_id: i32,
+
+    pub purchasable: bool,
+
+    pub saleable: bool,
+
+    pub saleable_without_stock: bool,
+
+    pub tracking_mode: Option<TrackingMode>,
 }
@@ -0,0 +1,46 @@
+use chrono::NaiveDate;
+use validator::Validate;
+
+#[derive(Validate, Debug, Clone)]
+pub struct CreatePurchaseOrderCommand {
+    pub supplier_id: i32,
+}
+
+#[derive(Validate, Debug, Clone)]
+pub struct AddPurchaseOrderLineCommand {
+    pub purchase_order_id: i32,
+
+    pub product_id: i32,
+
+    #[validate(range(min = 1))]
+    pub quantity: i32,
+
+    pub unit_id: Option<i32>,
+
+    pub unit_price: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReceivePurchaseOrderLineCommand {
+    pub purchase_order_line_id: i32,
+
+    pub quantity: i32,
+
+    pub lot_number: Option<String>,
+
+    pub serial_number: Option<String>,
+
+    pub expires_on: Option<NaiveDate>,
+}
+
+#[derive(Validate, Debug, Clone)]
+pub struct ReceivePurchaseOrderCommand {
+    pub purchase_order_id: i32,
+
+    pub storage_location_id: Option<i32>,
+
+    pub allow_over_receipt: bool,
+
+    #[validate(length(min = 1))]
+    pub lines: Vec<ReceivePurchaseOrderLineCommand>,
+}
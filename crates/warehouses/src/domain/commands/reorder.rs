@@ -0,0 +1,14 @@
+use validator::Validate;
+
+#[derive(Validate, Debug, Clone)]
+pub struct SetReorderPointCommand {
+    pub product_id: i32,
+
+    #[validate(range(min = 0))]
+    pub min_quantity: i32,
+
+    #[validate(range(min = 1))]
+    pub max_quantity: Option<i32>,
+
+    pub unit_id: Option<i32>,
+}
@@ -0,0 +1,25 @@
+use validator::Validate;
+
+#[derive(Validate, Debug, Clone)]
+pub struct CreateSalesOrderCommand {
+    pub customer_id: i32,
+}
+
+#[derive(Validate, Debug, Clone)]
+pub struct AddSalesOrderLineCommand {
+    pub sales_order_id: i32,
+
+    pub product_id: i32,
+
+    #[validate(range(min = 1))]
+    pub quantity: i32,
+
+    pub unit_id: Option<i32>,
+}
+
+#[derive(Validate, Debug, Clone)]
+pub struct FulfilSalesOrderCommand {
+    pub sales_order_id: i32,
+
+    pub storage_location_id: Option<i32>,
+}
@@ -0,0 +1,31 @@
+use crate::domain::valuables::stock::StockMovementKind;
+use chrono::NaiveDate;
+use validator::Validate;
+
+#[derive(Validate, Debug, Clone)]
+pub struct RecordStockMovementCommand {
+    pub product_id: i32,
+
+    pub kind: StockMovementKind,
+
+    pub quantity: i32,
+
+    pub unit_id: Option<i32>,
+
+    pub storage_location_id: Option<i32>,
+
+    #[validate(length(min = 1, max = 50))]
+    pub lot_number: Option<String>,
+
+    #[validate(length(min = 1, max = 50))]
+    pub serial_number: Option<String>,
+
+    pub expires_on: Option<NaiveDate>,
+
+    pub unit_cost: Option<String>,
+
+    pub override_expiry: bool,
+
+    #[validate(length(max = 255))]
+    pub reason: Option<String>,
+}
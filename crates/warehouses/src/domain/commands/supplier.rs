@@ -0,0 +1,51 @@
+use validator::Validate;
+
+#[derive(Validate, Debug, Clone)]
+pub struct CreateSupplierCommand {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+
+    pub contact_person_id: Option<i32>,
+
+    pub currency: String,
+}
+
+#[derive(Validate, Debug, Clone)]
+pub struct AddSupplierProductCommand {
+    pub supplier_id: i32,
+
+    pub product_id: i32,
+
+    #[validate(length(min = 1, max = 50))]
+    pub supplier_code: String,
+
+    #[validate(range(min = 1))]
+    pub pack_size: i32,
+
+    #[validate(range(min = 0, max = 365))]
+    pub lead_time_days: i32,
+
+    pub last_price: Option<String>,
+
+    pub preferred: bool,
+}
+
+#[derive(Validate, Debug, Clone)]
+pub struct UpdateSupplierProductCommand {
+    pub supplier_id: i32,
+
+    pub product_id: i32,
+
+    #[validate(length(min = 1, max = 50))]
+    pub supplier_code: String,
+
+    #[validate(range(min = 1))]
+    pub pack_size: i32,
+
+    #[validate(range(min = 0, max = 365))]
+    pub lead_time_days: i32,
+
+    pub last_price: Option<String>,
+
+    pub preferred: bool,
+}
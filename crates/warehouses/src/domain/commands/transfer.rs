@@ -0,0 +1,31 @@
+use validator::Validate;
+
+#[derive(Validate, Debug, Clone)]
+pub struct TransferStockCommand {
+    pub product_id: i32,
+
+    pub source_location_id: i32,
+
+    pub destination_warehouse_id: i32,
+
+    pub quantity: i32,
+
+    pub unit_id: Option<i32>,
+
+    #[validate(length(min = 1, max = 50))]
+    pub lot_number: Option<String>,
+
+    #[validate(length(min = 1, max = 50))]
+    pub serial_number: Option<String>,
+}
+
+#[derive(Validate, Debug, Clone)]
+pub struct ReceiveStockTransferCommand {
+    pub stock_transfer_id: i32,
+
+    pub storage_location_id: i32,
+
+    pub quantity: i32,
+
+    pub unit_id: Option<i32>,
+}
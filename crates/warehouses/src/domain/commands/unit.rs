@@ -0,0 +1,31 @@
+use validator::Validate;
+
+#[derive(Validate, Debug, Clone)]
+pub struct CreateUnitOfMeasureCommand {
+    #[validate(length(min = 1, max = 10))]
+    pub code: String,
+
+    #[validate(length(min = 1, max = 50))]
+    pub name: String,
+}
+
+#[derive(Validate, Debug, Clone)]
+pub struct AddUnitConversionCommand {
+    pub from_unit_id: i32,
+
+    pub to_unit_id: i32,
+
+    #[validate(range(min = 1))]
+    pub factor: i32,
+}
+
+#[derive(Validate, Debug, Clone)]
+pub struct SetProductUnitsCommand {
+    pub product_id: i32,
+
+    pub base_unit_id: i32,
+
+    pub purchase_unit_id: Option<i32>,
+
+    pub sale_unit_id: Option<i32>,
+}
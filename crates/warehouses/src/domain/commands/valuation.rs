@@ -0,0 +1,9 @@
+use crate::domain::valuables::valuation::CostingMethod;
+use validator::Validate;
+
+#[derive(Validate, Debug, Clone)]
+pub struct SetCostingMethodCommand {
+    pub product_id: i32,
+
+    pub costing_method: CostingMethod,
+}
@@ -0,0 +1,20 @@
+use validator::Validate;
+
+#[derive(Validate, Debug, Clone)]
+pub struct CreateProductVariantCommand {
+    pub product_id: i32,
+
+    #[validate(length(min = 1))]
+    pub attribute_value_ids: Vec<i32>,
+
+    #[validate(length(min = 1, max = 50))]
+    pub sku: Option<String>,
+}
+
+#[derive(Validate, Debug, Clone)]
+pub struct GenerateVariantMatrixCommand {
+    pub product_id: i32,
+
+    #[validate(length(min = 1))]
+    pub attribute_value_ids: Vec<i32>,
+}
@@ -0,0 +1,27 @@
+use validator::Validate;
+
+#[derive(Validate, Debug, Clone)]
+pub struct CreateWarehouseCommand {
+    #[validate(length(min = 1, max = 20))]
+    pub code: String,
+
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+
+    #[validate(length(max = 255))]
+    pub address: Option<String>,
+}
+
+#[derive(Validate, Debug, Clone)]
+pub struct UpdateWarehouseCommand {
+    pub warehouse_id: i32,
+
+    #[validate(length(min = 1, max = 20))]
+    pub code: String,
+
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+
+    #[validate(length(max = 255))]
+    pub address: Option<String>,
+}
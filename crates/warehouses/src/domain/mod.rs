@@ -1,6 +1,7 @@
 pub mod changes;
 pub mod commands;
 pub mod ports;
+pub mod queries;
 pub mod selectors;
 pub mod services;
 pub mod valuables;
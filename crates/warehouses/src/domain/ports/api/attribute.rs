@@ -0,0 +1,27 @@
+use crate::domain::commands::attribute::{AddAttributeValueCommand, CreateProductAttributeCommand};
+use crate::domain::selectors::attribute::ProductAttributeDetailsSelector;
+use async_trait::async_trait;
+use ids_std_domain::api::failure::{CreateDomainFailure, FindManyFailure};
+
+#[async_trait]
+pub trait CreateProductAttributeUseCase: Send + Sync + 'static {
+    async fn create_product_attribute(
+        &self,
+        command: &CreateProductAttributeCommand,
+    ) -> Result<i32, CreateDomainFailure>;
+}
+
+#[async_trait]
+pub trait AddAttributeValueUseCase: Send + Sync + 'static {
+    async fn add_attribute_value(
+        &self,
+        command: &AddAttributeValueCommand,
+    ) -> Result<i32, CreateDomainFailure>;
+}
+
+#[async_trait]
+pub trait FindAllProductAttributesUseCase: Send + Sync + 'static {
+    async fn find_all_product_attributes(
+        &self,
+    ) -> Result<Vec<ProductAttributeDetailsSelector>, FindManyFailure>;
+}
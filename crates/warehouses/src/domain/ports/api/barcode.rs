@@ -0,0 +1,38 @@
+use crate::domain::commands::barcode::{AddProductBarcodeCommand, SetProductSkuCommand};
+use crate::domain::selectors::barcode::{ProductBarcodeSelector, ScannedProductSelector};
+use async_trait::async_trait;
+use ids_std_domain::api::failure::{
+    CreateDomainFailure, FindManyFailure, FindOneFailure, UpdateDomainFailure,
+};
+
+#[async_trait]
+pub trait SetProductSkuUseCase: Send + Sync + 'static {
+    async fn set_product_sku(
+        &self,
+        command: &SetProductSkuCommand,
+    ) -> Result<(), UpdateDomainFailure>;
+}
+
+#[async_trait]
+pub trait AddProductBarcodeUseCase: Send + Sync + 'static {
+    async fn add_product_barcode(
+        &self,
+        command: &AddProductBarcodeCommand,
+    ) -> Result<i32, CreateDomainFailure>;
+}
+
+#[async_trait]
+pub trait FindProductBarcodesUseCase: Send + Sync + 'static {
+    async fn find_product_barcodes(
+        &self,
+        product_id: i32,
+    ) -> Result<Vec<ProductBarcodeSelector>, FindManyFailure>;
+}
+
+#[async_trait]
+pub trait FindProductByBarcodeUseCase: Send + Sync + 'static {
+    async fn find_product_by_barcode(
+        &self,
+        code: &str,
+    ) -> Result<ScannedProductSelector, FindOneFailure>;
+}
@@ -0,0 +1,65 @@
+use crate::domain::commands::count::{CountInventoryCommand, OpenInventoryCountCommand};
+use crate::domain::queries::count::InventoryCountQuery;
+use crate::domain::selectors::count::{InventoryCountDetailsSelector, InventoryCountSelector};
+use async_trait::async_trait;
+use ids_std_domain::api::failure::{
+    CreateDomainFailure, FindManyFailure, FindOneFailure, UpdateDomainFailure,
+};
+use ids_std_domain::pagination::{Page, PaginationQuery};
+
+#[async_trait]
+pub trait OpenInventoryCountUseCase: Send + Sync + 'static {
+    async fn open_inventory_count(
+        &self,
+        command: &OpenInventoryCountCommand,
+    ) -> Result<i32, CreateDomainFailure>;
+}
+
+#[async_trait]
+pub trait StartInventoryCountUseCase: Send + Sync + 'static {
+    async fn start_inventory_count(
+        &self,
+        inventory_count_id: i32,
+    ) -> Result<(), UpdateDomainFailure>;
+}
+
+#[async_trait]
+pub trait CountInventoryUseCase: Send + Sync + 'static {
+    async fn count_inventory(
+        &self,
+        command: &CountInventoryCommand,
+    ) -> Result<(), UpdateDomainFailure>;
+}
+
+#[async_trait]
+pub trait ReviewInventoryCountUseCase: Send + Sync + 'static {
+    async fn review_inventory_count(
+        &self,
+        inventory_count_id: i32,
+    ) -> Result<(), UpdateDomainFailure>;
+}
+
+#[async_trait]
+pub trait PostInventoryCountUseCase: Send + Sync + 'static {
+    async fn post_inventory_count(
+        &self,
+        inventory_count_id: i32,
+    ) -> Result<(), UpdateDomainFailure>;
+}
+
+#[async_trait]
+pub trait FindAllInventoryCountsUseCase: Send + Sync + 'static {
+    async fn find_all_inventory_counts(
+        &self,
+        query: &PaginationQuery,
+        criteria: &InventoryCountQuery,
+    ) -> Result<Page<InventoryCountSelector>, FindManyFailure>;
+}
+
+#[async_trait]
+pub trait FindInventoryCountUseCase: Send + Sync + 'static {
+    async fn find_inventory_count(
+        &self,
+        inventory_count_id: i32,
+    ) -> Result<InventoryCountDetailsSelector, FindOneFailure>;
+}
@@ -0,0 +1,59 @@
+use crate::domain::commands::family::{CreateProductFamilyCommand, UpdateProductFamilyCommand};
+use crate::domain::queries::family::ProductFamilyQuery;
+use crate::domain::selectors::family::{
+    ProductFamilyPageSelector, ProductFamilySelector, ProductFamilyTreeSelector,
+};
+use async_trait::async_trait;
+use ids_std_domain::api::failure::{
+    CreateDomainFailure, FindManyFailure, FindOneFailure, UpdateDomainFailure,
+};
+use ids_std_domain::pagination::{Page, PaginationQuery};
+
+#[async_trait]
+pub trait CreateProductFamilyUseCase: Send + Sync + 'static {
+    async fn create_product_family(
+        &self,
+        command: &CreateProductFamilyCommand,
+    ) -> Result<i32, CreateDomainFailure>;
+}
+
+#[async_trait]
+pub trait UpdateProductFamilyUseCase: Send + Sync + 'static {
+    async fn update_product_family(
+        &self,
+        command: &UpdateProductFamilyCommand,
+    ) -> Result<(), UpdateDomainFailure>;
+}
+
+#[async_trait]
+pub trait FindAllProductFamiliesUseCase: Send + Sync + 'static {
+    async fn find_all_product_families(
+        &self,
+        query: &PaginationQuery,
+        criteria: &ProductFamilyQuery,
+    ) -> Result<Page<ProductFamilyPageSelector>, FindManyFailure>;
+}
+
+#[async_trait]
+pub trait FindProductFamilyUseCase: Send + Sync + 'static {
+    async fn find_product_family(
+        &self,
+        product_family_id: i32,
+    ) -> Result<ProductFamilySelector, FindOneFailure>;
+}
+
+#[async_trait]
+pub trait FindProductFamilySubtreeUseCase: Send + Sync + 'static {
+    async fn find_product_family_subtree(
+        &self,
+        product_family_id: i32,
+    ) -> Result<ProductFamilyTreeSelector, FindOneFailure>;
+}
+
+#[async_trait]
+pub trait FindProductFamilyBreadcrumbUseCase: Send + Sync + 'static {
+    async fn find_product_family_breadcrumb(
+        &self,
+        product_family_id: i32,
+    ) -> Result<Vec<ProductFamilySelector>, FindOneFailure>;
+}
@@ -0,0 +1,33 @@
+use crate::domain::commands::kit::{AddKitComponentCommand, AssembleKitCommand};
+use crate::domain::selectors::kit::{KitAvailabilitySelector, KitComponentSelector};
+use async_trait::async_trait;
+use ids_std_domain::api::failure::{CreateDomainFailure, FindManyFailure, FindOneFailure};
+
+#[async_trait]
+pub trait AddKitComponentUseCase: Send + Sync + 'static {
+    async fn add_kit_component(
+        &self,
+        command: &AddKitComponentCommand,
+    ) -> Result<i32, CreateDomainFailure>;
+}
+
+#[async_trait]
+pub trait FindKitComponentsUseCase: Send + Sync + 'static {
+    async fn find_kit_components(
+        &self,
+        kit_product_id: i32,
+    ) -> Result<Vec<KitComponentSelector>, FindManyFailure>;
+}
+
+#[async_trait]
+pub trait FindKitAvailabilityUseCase: Send + Sync + 'static {
+    async fn find_kit_availability(
+        &self,
+        kit_product_id: i32,
+    ) -> Result<KitAvailabilitySelector, FindOneFailure>;
+}
+
+#[async_trait]
+pub trait AssembleKitUseCase: Send + Sync + 'static {
+    async fn assemble_kit(&self, command: &AssembleKitCommand) -> Result<i32, CreateDomainFailure>;
+}
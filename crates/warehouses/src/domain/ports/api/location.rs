@@ -0,0 +1,42 @@
+use crate::domain::commands::location::{
+    CreateStorageLocationCommand, UpdateStorageLocationCommand,
+};
+use crate::domain::selectors::location::{StorageLocationPageSelector, StorageLocationSelector};
+use async_trait::async_trait;
+use ids_std_domain::api::failure::{
+    CreateDomainFailure, FindManyFailure, FindOneFailure, UpdateDomainFailure,
+};
+use ids_std_domain::pagination::{Page, PaginationQuery};
+
+#[async_trait]
+pub trait CreateStorageLocationUseCase: Send + Sync + 'static {
+    async fn create_storage_location(
+        &self,
+        command: &CreateStorageLocationCommand,
+    ) -> Result<i32, CreateDomainFailure>;
+}
+
+#[async_trait]
+pub trait UpdateStorageLocationUseCase: Send + Sync + 'static {
+    async fn update_storage_location(
+        &self,
+        command: &UpdateStorageLocationCommand,
+    ) -> Result<(), UpdateDomainFailure>;
+}
+
+#[async_trait]
+pub trait FindWarehouseLocationsUseCase: Send + Sync + 'static {
+    async fn find_warehouse_locations(
+        &self,
+        query: &PaginationQuery,
+        warehouse_id: i32,
+    ) -> Result<Page<StorageLocationPageSelector>, FindManyFailure>;
+}
+
+#[async_trait]
+pub trait FindStorageLocationUseCase: Send + Sync + 'static {
+    async fn find_storage_location(
+        &self,
+        storage_location_id: i32,
+    ) -> Result<StorageLocationSelector, FindOneFailure>;
+}
@@ -0,0 +1,21 @@
+use crate::domain::queries::lot::{ExpiringLotQuery, FefoPickQuery};
+use crate::domain::selectors::lot::{ExpiringLotSelector, FefoPickSelector};
+use async_trait::async_trait;
+use ids_std_domain::api::failure::FindOneFailure;
+
+#[async_trait]
+pub trait FindExpiringLotsUseCase: Send + Sync + 'static {
+    async fn find_expiring_lots(
+        &self,
+        criteria: &ExpiringLotQuery,
+    ) -> Result<Vec<ExpiringLotSelector>, FindOneFailure>;
+}
+
+#[async_trait]
+pub trait PlanFefoPickUseCase: Send + Sync + 'static {
+    async fn plan_fefo_pick(
+        &self,
+        product_id: i32,
+        criteria: &FefoPickQuery,
+    ) -> Result<FefoPickSelector, FindOneFailure>;
+}
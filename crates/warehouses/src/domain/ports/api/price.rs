@@ -0,0 +1,35 @@
+use crate::domain::commands::price::{AddProductPriceCommand, CreatePriceListCommand};
+use crate::domain::queries::price::ProductPriceQuery;
+use crate::domain::selectors::price::{PriceListSelector, ResolvedPriceSelector};
+use async_trait::async_trait;
+use ids_std_domain::api::failure::{CreateDomainFailure, FindManyFailure, FindOneFailure};
+
+#[async_trait]
+pub trait CreatePriceListUseCase: Send + Sync + 'static {
+    async fn create_price_list(
+        &self,
+        command: &CreatePriceListCommand,
+    ) -> Result<i32, CreateDomainFailure>;
+}
+
+#[async_trait]
+pub trait FindAllPriceListsUseCase: Send + Sync + 'static {
+    async fn find_all_price_lists(&self) -> Result<Vec<PriceListSelector>, FindManyFailure>;
+}
+
+#[async_trait]
+pub trait AddProductPriceUseCase: Send + Sync + 'static {
+    async fn add_product_price(
+        &self,
+        command: &AddProductPriceCommand,
+    ) -> Result<i32, CreateDomainFailure>;
+}
+
+#[async_trait]
+pub trait ResolveProductPriceUseCase: Send + Sync + 'static {
+    async fn resolve_product_price(
+        &self,
+        product_id: i32,
+        query: &ProductPriceQuery,
+    ) -> Result<ResolvedPriceSelector, FindOneFailure>;
+}
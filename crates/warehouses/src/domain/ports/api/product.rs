@@ -1,6 +1,11 @@
-use crate::domain::commands::product::CreateProductCommand;
+use crate::domain::commands::product::{CreateProductCommand, UpdateProductCommand};
+use crate::domain::queries::product::ProductQuery;
+use crate::domain::selectors::product::{ProductDetailsSelector, ProductPageSelector};
 use async_trait::async_trait;
-use ids_std_domain::api::failure::CreateDomainFailure;
+use ids_std_domain::api::failure::{
+    CreateDomainFailure, FindManyFailure, FindOneFailure, UpdateDomainFailure,
+};
+use ids_std_domain::pagination::{Page, PaginationQuery};
 
 #[async_trait]
 pub trait CreateProductUseCase: Send + Sync + 'static {
@@ -9,3 +14,33 @@ pub trait CreateProductUseCase: Send + Sync + 'static {
         command: &CreateProductCommand,
     ) -> Result<i32, CreateDomainFailure>;
 }
+
+#[async_trait]
+pub trait FindAllProductsUseCase: Send + Sync + 'static {
+    async fn find_all_products(
+        &self,
+        query: &PaginationQuery,
+        criteria: &ProductQuery,
+    ) -> Result<Page<ProductPageSelector>, FindManyFailure>;
+}
+
+#[async_trait]
+pub trait FindProductDetailsUseCase: Send + Sync + 'static {
+    async fn find_product_details(
+        &self,
+        product_id: i32,
+    ) -> Result<ProductDetailsSelector, FindOneFailure>;
+}
+
+#[async_trait]
+pub trait UpdateProductUseCase: Send + Sync + 'static {
+    async fn update_product(
+        &self,
+        command: &UpdateProductCommand,
+    ) -> Result<(), UpdateDomainFailure>;
+}
+
+#[async_trait]
+pub trait DiscontinueProductUseCase: Send + Sync + 'static {
+    async fn discontinue_product(&self, product_id: i32) -> Result<(), UpdateDomainFailure>;
+}
@@ -0,0 +1,70 @@
+use crate::domain::commands::purchase::{
+    AddPurchaseOrderLineCommand, CreatePurchaseOrderCommand, ReceivePurchaseOrderCommand,
+};
+use crate::domain::queries::purchase::PurchaseOrderQuery;
+use crate::domain::selectors::purchase::{PurchaseOrderDetailsSelector, PurchaseOrderSelector};
+use async_trait::async_trait;
+use ids_std_domain::api::failure::{
+    CreateDomainFailure, FindManyFailure, FindOneFailure, UpdateDomainFailure,
+};
+use ids_std_domain::pagination::{Page, PaginationQuery};
+
+#[async_trait]
+pub trait CreatePurchaseOrderUseCase: Send + Sync + 'static {
+    async fn create_purchase_order(
+        &self,
+        command: &CreatePurchaseOrderCommand,
+    ) -> Result<i32, CreateDomainFailure>;
+}
+
+#[async_trait]
+pub trait AddPurchaseOrderLineUseCase: Send + Sync + 'static {
+    async fn add_purchase_order_line(
+        &self,
+        command: &AddPurchaseOrderLineCommand,
+    ) -> Result<i32, CreateDomainFailure>;
+}
+
+#[async_trait]
+pub trait ApprovePurchaseOrderUseCase: Send + Sync + 'static {
+    async fn approve_purchase_order(
+        &self,
+        purchase_order_id: i32,
+    ) -> Result<(), UpdateDomainFailure>;
+}
+
+#[async_trait]
+pub trait SendPurchaseOrderUseCase: Send + Sync + 'static {
+    async fn send_purchase_order(&self, purchase_order_id: i32) -> Result<(), UpdateDomainFailure>;
+}
+
+#[async_trait]
+pub trait ReceivePurchaseOrderUseCase: Send + Sync + 'static {
+    async fn receive_purchase_order(
+        &self,
+        command: &ReceivePurchaseOrderCommand,
+    ) -> Result<(), UpdateDomainFailure>;
+}
+
+#[async_trait]
+pub trait ClosePurchaseOrderUseCase: Send + Sync + 'static {
+    async fn close_purchase_order(&self, purchase_order_id: i32)
+        -> Result<(), UpdateDomainFailure>;
+}
+
+#[async_trait]
+pub trait FindAllPurchaseOrdersUseCase: Send + Sync + 'static {
+    async fn find_all_purchase_orders(
+        &self,
+        query: &PaginationQuery,
+        criteria: &PurchaseOrderQuery,
+    ) -> Result<Page<PurchaseOrderSelector>, FindManyFailure>;
+}
+
+#[async_trait]
+pub trait FindPurchaseOrderUseCase: Send + Sync + 'static {
+    async fn find_purchase_order(
+        &self,
+        purchase_order_id: i32,
+    ) -> Result<PurchaseOrderDetailsSelector, FindOneFailure>;
+}
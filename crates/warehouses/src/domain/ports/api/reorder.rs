@@ -0,0 +1,25 @@
+use crate::domain::commands::reorder::SetReorderPointCommand;
+use crate::domain::selectors::reorder::{LowStockSelector, ReorderPointSelector};
+use async_trait::async_trait;
+use ids_std_domain::api::failure::{FindManyFailure, FindOneFailure, UpdateDomainFailure};
+
+#[async_trait]
+pub trait SetReorderPointUseCase: Send + Sync + 'static {
+    async fn set_reorder_point(
+        &self,
+        command: &SetReorderPointCommand,
+    ) -> Result<(), UpdateDomainFailure>;
+}
+
+#[async_trait]
+pub trait FindReorderPointUseCase: Send + Sync + 'static {
+    async fn find_reorder_point(
+        &self,
+        product_id: i32,
+    ) -> Result<ReorderPointSelector, FindOneFailure>;
+}
+
+#[async_trait]
+pub trait FindLowStockProductsUseCase: Send + Sync + 'static {
+    async fn find_low_stock_products(&self) -> Result<Vec<LowStockSelector>, FindManyFailure>;
+}
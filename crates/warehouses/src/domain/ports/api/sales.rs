@@ -0,0 +1,71 @@
+use crate::domain::commands::sales::{
+    AddSalesOrderLineCommand, CreateSalesOrderCommand, FulfilSalesOrderCommand,
+};
+use crate::domain::queries::sales::SalesOrderQuery;
+use crate::domain::selectors::sales::{
+    AvailableToPromiseSelector, SalesOrderDetailsSelector, SalesOrderSelector,
+};
+use async_trait::async_trait;
+use ids_std_domain::api::failure::{
+    CreateDomainFailure, FindManyFailure, FindOneFailure, UpdateDomainFailure,
+};
+use ids_std_domain::pagination::{Page, PaginationQuery};
+
+#[async_trait]
+pub trait CreateSalesOrderUseCase: Send + Sync + 'static {
+    async fn create_sales_order(
+        &self,
+        command: &CreateSalesOrderCommand,
+    ) -> Result<i32, CreateDomainFailure>;
+}
+
+#[async_trait]
+pub trait AddSalesOrderLineUseCase: Send + Sync + 'static {
+    async fn add_sales_order_line(
+        &self,
+        command: &AddSalesOrderLineCommand,
+    ) -> Result<i32, CreateDomainFailure>;
+}
+
+#[async_trait]
+pub trait ConfirmSalesOrderUseCase: Send + Sync + 'static {
+    async fn confirm_sales_order(&self, sales_order_id: i32) -> Result<(), UpdateDomainFailure>;
+}
+
+#[async_trait]
+pub trait CancelSalesOrderUseCase: Send + Sync + 'static {
+    async fn cancel_sales_order(&self, sales_order_id: i32) -> Result<(), UpdateDomainFailure>;
+}
+
+#[async_trait]
+pub trait FulfilSalesOrderUseCase: Send + Sync + 'static {
+    async fn fulfil_sales_order(
+        &self,
+        command: &FulfilSalesOrderCommand,
+    ) -> Result<(), UpdateDomainFailure>;
+}
+
+#[async_trait]
+pub trait FindAllSalesOrdersUseCase: Send + Sync + 'static {
+    async fn find_all_sales_orders(
+        &self,
+        query: &PaginationQuery,
+        criteria: &SalesOrderQuery,
+    ) -> Result<Page<SalesOrderSelector>, FindManyFailure>;
+}
+
+#[async_trait]
+pub trait FindSalesOrderUseCase: Send + Sync + 'static {
+    async fn find_sales_order(
+        &self,
+        sales_order_id: i32,
+    ) -> Result<SalesOrderDetailsSelector, FindOneFailure>;
+}
+
+#[async_trait]
+pub trait FindAvailableToPromiseUseCase: Send + Sync + 'static {
+    async fn find_available_to_promise(
+        &self,
+        product_id: i32,
+    ) -> Result<AvailableToPromiseSelector, FindOneFailure>;
+}
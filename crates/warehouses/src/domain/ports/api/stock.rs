@@ -0,0 +1,51 @@
+use crate::domain::commands::stock::RecordStockMovementCommand;
+use crate::domain::queries::stock::StockQuery;
+use crate::domain::selectors::stock::{
+    ProductLocationSelector, ProductOnHandSelector, StockMovementPageSelector,
+};
+use async_trait::async_trait;
+use ids_std_domain::api::failure::{CreateDomainFailure, FindManyFailure, FindOneFailure};
+use ids_std_domain::pagination::{Page, PaginationQuery};
+
+#[async_trait]
+pub trait RecordStockMovementUseCase: Send + Sync + 'static {
+    async fn record_stock_movement(
+        &self,
+        command: &RecordStockMovementCommand,
+    ) -> Result<i32, CreateDomainFailure>;
+}
+
+#[async_trait]
+pub trait FindProductOnHandUseCase: Send + Sync + 'static {
+    async fn find_product_on_hand(
+        &self,
+        product_id: i32,
+        criteria: &StockQuery,
+    ) -> Result<ProductOnHandSelector, FindOneFailure>;
+}
+
+#[async_trait]
+pub trait FindStockMovementsUseCase: Send + Sync + 'static {
+    async fn find_stock_movements(
+        &self,
+        query: &PaginationQuery,
+        product_id: i32,
+    ) -> Result<Page<StockMovementPageSelector>, FindManyFailure>;
+}
+
+#[async_trait]
+pub trait FindProductLocationsUseCase: Send + Sync + 'static {
+    async fn find_product_locations(
+        &self,
+        product_id: i32,
+    ) -> Result<Vec<ProductLocationSelector>, FindOneFailure>;
+}
+
+#[async_trait]
+pub trait TraceStockMovementsUseCase: Send + Sync + 'static {
+    async fn trace_stock_movements(
+        &self,
+        query: &PaginationQuery,
+        tracking_code: &str,
+    ) -> Result<Page<StockMovementPageSelector>, FindManyFailure>;
+}
@@ -0,0 +1,55 @@
+use crate::domain::commands::supplier::{
+    AddSupplierProductCommand, CreateSupplierCommand, UpdateSupplierProductCommand,
+};
+use crate::domain::selectors::supplier::{
+    SupplierOfferSelector, SupplierProductSelector, SupplierSelector,
+};
+use async_trait::async_trait;
+use ids_std_domain::api::failure::{
+    CreateDomainFailure, FindManyFailure, FindOneFailure, UpdateDomainFailure,
+};
+
+#[async_trait]
+pub trait CreateSupplierUseCase: Send + Sync + 'static {
+    async fn create_supplier(
+        &self,
+        command: &CreateSupplierCommand,
+    ) -> Result<i32, CreateDomainFailure>;
+}
+
+#[async_trait]
+pub trait FindAllSuppliersUseCase: Send + Sync + 'static {
+    async fn find_all_suppliers(&self) -> Result<Vec<SupplierSelector>, FindManyFailure>;
+}
+
+#[async_trait]
+pub trait AddSupplierProductUseCase: Send + Sync + 'static {
+    async fn add_supplier_product(
+        &self,
+        command: &AddSupplierProductCommand,
+    ) -> Result<i32, CreateDomainFailure>;
+}
+
+#[async_trait]
+pub trait UpdateSupplierProductUseCase: Send + Sync + 'static {
+    async fn update_supplier_product(
+        &self,
+        command: &UpdateSupplierProductCommand,
+    ) -> Result<(), UpdateDomainFailure>;
+}
+
+#[async_trait]
+pub trait FindSupplierProductsUseCase: Send + Sync + 'static {
+    async fn find_supplier_products(
+        &self,
+        supplier_id: i32,
+    ) -> Result<Vec<SupplierProductSelector>, FindManyFailure>;
+}
+
+#[async_trait]
+pub trait FindPreferredSupplierUseCase: Send + Sync + 'static {
+    async fn find_preferred_supplier(
+        &self,
+        product_id: i32,
+    ) -> Result<SupplierOfferSelector, FindOneFailure>;
+}
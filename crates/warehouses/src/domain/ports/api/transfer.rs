@@ -0,0 +1,49 @@
+use crate::domain::commands::transfer::{ReceiveStockTransferCommand, TransferStockCommand};
+use crate::domain::queries::transfer::StockTransferQuery;
+use crate::domain::selectors::transfer::StockTransferSelector;
+use async_trait::async_trait;
+use ids_std_domain::api::failure::{
+    CreateDomainFailure, FindManyFailure, FindOneFailure, UpdateDomainFailure,
+};
+use ids_std_domain::pagination::{Page, PaginationQuery};
+
+#[async_trait]
+pub trait TransferStockUseCase: Send + Sync + 'static {
+    async fn transfer_stock(
+        &self,
+        command: &TransferStockCommand,
+    ) -> Result<i32, CreateDomainFailure>;
+}
+
+#[async_trait]
+pub trait ReceiveStockTransferUseCase: Send + Sync + 'static {
+    async fn receive_stock_transfer(
+        &self,
+        command: &ReceiveStockTransferCommand,
+    ) -> Result<(), UpdateDomainFailure>;
+}
+
+#[async_trait]
+pub trait CancelStockTransferUseCase: Send + Sync + 'static {
+    async fn cancel_stock_transfer(
+        &self,
+        stock_transfer_id: i32,
+    ) -> Result<(), UpdateDomainFailure>;
+}
+
+#[async_trait]
+pub trait FindAllStockTransfersUseCase: Send + Sync + 'static {
+    async fn find_all_stock_transfers(
+        &self,
+        query: &PaginationQuery,
+        criteria: &StockTransferQuery,
+    ) -> Result<Page<StockTransferSelector>, FindManyFailure>;
+}
+
+#[async_trait]
+pub trait FindStockTransferUseCase: Send + Sync + 'static {
+    async fn find_stock_transfer(
+        &self,
+        stock_transfer_id: i32,
+    ) -> Result<StockTransferSelector, FindOneFailure>;
+}
@@ -0,0 +1,47 @@
+use crate::domain::commands::unit::{
+    AddUnitConversionCommand, CreateUnitOfMeasureCommand, SetProductUnitsCommand,
+};
+use crate::domain::selectors::unit::{ProductUnitsSelector, UnitOfMeasureDetailsSelector};
+use async_trait::async_trait;
+use ids_std_domain::api::failure::{
+    CreateDomainFailure, FindManyFailure, FindOneFailure, UpdateDomainFailure,
+};
+
+#[async_trait]
+pub trait CreateUnitOfMeasureUseCase: Send + Sync + 'static {
+    async fn create_unit_of_measure(
+        &self,
+        command: &CreateUnitOfMeasureCommand,
+    ) -> Result<i32, CreateDomainFailure>;
+}
+
+#[async_trait]
+pub trait AddUnitConversionUseCase: Send + Sync + 'static {
+    async fn add_unit_conversion(
+        &self,
+        command: &AddUnitConversionCommand,
+    ) -> Result<i32, CreateDomainFailure>;
+}
+
+#[async_trait]
+pub trait FindAllUnitsOfMeasureUseCase: Send + Sync + 'static {
+    async fn find_all_units_of_measure(
+        &self,
+    ) -> Result<Vec<UnitOfMeasureDetailsSelector>, FindManyFailure>;
+}
+
+#[async_trait]
+pub trait SetProductUnitsUseCase: Send + Sync + 'static {
+    async fn set_product_units(
+        &self,
+        command: &SetProductUnitsCommand,
+    ) -> Result<(), UpdateDomainFailure>;
+}
+
+#[async_trait]
+pub trait FindProductUnitsUseCase: Send + Sync + 'static {
+    async fn find_product_units(
+        &self,
+        product_id: i32,
+    ) -> Result<ProductUnitsSelector, FindOneFailure>;
+}
@@ -0,0 +1,30 @@
+use crate::domain::commands::valuation::SetCostingMethodCommand;
+use crate::domain::queries::valuation::StockValuationQuery;
+use crate::domain::selectors::valuation::{ProductValuationSelector, StockValuationSelector};
+use async_trait::async_trait;
+use ids_std_domain::api::failure::{FindManyFailure, FindOneFailure, UpdateDomainFailure};
+
+#[async_trait]
+pub trait SetCostingMethodUseCase: Send + Sync + 'static {
+    async fn set_costing_method(
+        &self,
+        command: &SetCostingMethodCommand,
+    ) -> Result<(), UpdateDomainFailure>;
+}
+
+#[async_trait]
+pub trait FindStockValuationUseCase: Send + Sync + 'static {
+    async fn find_stock_valuation(
+        &self,
+        criteria: &StockValuationQuery,
+    ) -> Result<StockValuationSelector, FindManyFailure>;
+}
+
+#[async_trait]
+pub trait FindProductValuationUseCase: Send + Sync + 'static {
+    async fn find_product_valuation(
+        &self,
+        product_id: i32,
+        criteria: &StockValuationQuery,
+    ) -> Result<ProductValuationSelector, FindOneFailure>;
+}
@@ -0,0 +1,28 @@
+use crate::domain::commands::variant::{CreateProductVariantCommand, GenerateVariantMatrixCommand};
+use crate::domain::selectors::variant::ProductVariantDetailsSelector;
+use async_trait::async_trait;
+use ids_std_domain::api::failure::{CreateDomainFailure, FindManyFailure};
+
+#[async_trait]
+pub trait CreateProductVariantUseCase: Send + Sync + 'static {
+    async fn create_product_variant(
+        &self,
+        command: &CreateProductVariantCommand,
+    ) -> Result<i32, CreateDomainFailure>;
+}
+
+#[async_trait]
+pub trait GenerateVariantMatrixUseCase: Send + Sync + 'static {
+    async fn generate_variant_matrix(
+        &self,
+        command: &GenerateVariantMatrixCommand,
+    ) -> Result<Vec<i32>, CreateDomainFailure>;
+}
+
+#[async_trait]
+pub trait FindProductVariantsUseCase: Send + Sync + 'static {
+    async fn find_product_variants(
+        &self,
+        product_id: i32,
+    ) -> Result<Vec<ProductVariantDetailsSelector>, FindManyFailure>;
+}
@@ -0,0 +1,38 @@
+use crate::domain::commands::warehouse::{CreateWarehouseCommand, UpdateWarehouseCommand};
+use crate::domain::queries::warehouse::WarehouseQuery;
+use crate::domain::selectors::warehouse::{WarehousePageSelector, WarehouseSelector};
+use async_trait::async_trait;
+use ids_std_domain::api::failure::{
+    CreateDomainFailure, FindManyFailure, FindOneFailure, UpdateDomainFailure,
+};
+use ids_std_domain::pagination::{Page, PaginationQuery};
+
+#[async_trait]
+pub trait CreateWarehouseUseCase: Send + Sync + 'static {
+    async fn create_warehouse(
+        &self,
+        command: &CreateWarehouseCommand,
+    ) -> Result<i32, CreateDomainFailure>;
+}
+
+#[async_trait]
+pub trait UpdateWarehouseUseCase: Send + Sync + 'static {
+    async fn update_warehouse(
+        &self,
+        command: &UpdateWarehouseCommand,
+    ) -> Result<(), UpdateDomainFailure>;
+}
+
+#[async_trait]
+pub trait FindAllWarehousesUseCase: Send + Sync + 'static {
+    async fn find_all_warehouses(
+        &self,
+        query: &PaginationQuery,
+        criteria: &WarehouseQuery,
+    ) -> Result<Page<WarehousePageSelector>, FindManyFailure>;
+}
+
+#[async_trait]
+pub trait FindWarehouseUseCase: Send + Sync + 'static {
+    async fn find_warehouse(&self, warehouse_id: i32) -> Result<WarehouseSelector, FindOneFailure>;
+}
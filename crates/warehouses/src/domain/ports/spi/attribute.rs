@@ -0,0 +1,33 @@
+use crate::domain::changes::attribute::{AddAttributeValue, AddProductAttribute};
+use crate::domain::selectors::attribute::{AttributeValueSelector, ProductAttributeSelector};
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+
+#[async_trait::async_trait]
+pub trait ProductAttributeRepository: Send + Sync + 'static {
+    async fn find_by_id(
+        &self,
+        id: i32,
+    ) -> Result<Option<ProductAttributeSelector>, SelectRepoFailure>;
+
+    async fn find_by_name(
+        &self,
+        name: &str,
+    ) -> Result<Option<ProductAttributeSelector>, SelectRepoFailure>;
+
+    async fn find_all(&self) -> Result<Vec<ProductAttributeSelector>, SelectRepoFailure>;
+
+    async fn find_value(
+        &self,
+        attribute_id: i32,
+        value: &str,
+    ) -> Result<Option<AttributeValueSelector>, SelectRepoFailure>;
+
+    async fn find_values(
+        &self,
+        value_ids: Option<Vec<i32>>,
+    ) -> Result<Vec<AttributeValueSelector>, SelectRepoFailure>;
+
+    async fn save(&self, event: &AddProductAttribute) -> Result<i32, SaveRepoFailure>;
+
+    async fn save_value(&self, event: &AddAttributeValue) -> Result<i32, SaveRepoFailure>;
+}
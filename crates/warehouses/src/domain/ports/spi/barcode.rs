@@ -0,0 +1,23 @@
+use crate::domain::changes::barcode::AddProductBarcode;
+use crate::domain::selectors::barcode::{ProductBarcodeSelector, ScannedProductSelector};
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+
+#[async_trait::async_trait]
+pub trait ProductBarcodeRepository: Send + Sync + 'static {
+    async fn find_by_gtin(
+        &self,
+        gtin: &str,
+    ) -> Result<Option<ProductBarcodeSelector>, SelectRepoFailure>;
+
+    async fn find_by_product(
+        &self,
+        product_id: i32,
+    ) -> Result<Vec<ProductBarcodeSelector>, SelectRepoFailure>;
+
+    async fn find_scanned(
+        &self,
+        gtin: &str,
+    ) -> Result<Option<ScannedProductSelector>, SelectRepoFailure>;
+
+    async fn save(&self, event: &AddProductBarcode) -> Result<i32, SaveRepoFailure>;
+}
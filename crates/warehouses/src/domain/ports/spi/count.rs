@@ -0,0 +1,39 @@
+use crate::domain::changes::count::{
+    AddInventoryCount, AddInventoryCountLine, CountInventoryLine, PostInventoryCount,
+    StartInventoryCount,
+};
+use crate::domain::queries::count::InventoryCountQuery;
+use crate::domain::selectors::count::{InventoryCountLineSelector, InventoryCountSelector};
+use ids_std_domain::pagination::{Page, PaginationQuery};
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+
+#[async_trait::async_trait]
+pub trait InventoryCountRepository: Send + Sync + 'static {
+    async fn find_by_id(
+        &self,
+        id: i32,
+    ) -> Result<Option<InventoryCountSelector>, SelectRepoFailure>;
+
+    async fn find_all(
+        &self,
+        query: &PaginationQuery,
+        criteria: &InventoryCountQuery,
+    ) -> Result<Page<InventoryCountSelector>, SelectRepoFailure>;
+
+    async fn find_lines(
+        &self,
+        inventory_count_id: i32,
+    ) -> Result<Vec<InventoryCountLineSelector>, SelectRepoFailure>;
+
+    async fn save(&self, event: &AddInventoryCount) -> Result<i32, SaveRepoFailure>;
+
+    async fn start(&self, event: &StartInventoryCount) -> Result<(), SaveRepoFailure>;
+
+    async fn add_line(&self, event: &AddInventoryCountLine) -> Result<i32, SaveRepoFailure>;
+
+    async fn count_line(&self, event: &CountInventoryLine) -> Result<(), SaveRepoFailure>;
+
+    async fn review(&self, inventory_count_id: i32) -> Result<(), SaveRepoFailure>;
+
+    async fn post(&self, event: &PostInventoryCount) -> Result<(), SaveRepoFailure>;
+}
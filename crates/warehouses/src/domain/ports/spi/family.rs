@@ -1,8 +1,37 @@
-use crate::domain::selectors::family::ProductFamilySelector;
-use ids_std_domain::spi::failure::SelectRepoFailure;
+use crate::domain::changes::family::{AddProductFamily, UpdateProductFamily};
+use crate::domain::queries::family::ProductFamilyQuery;
+use crate::domain::selectors::family::{ProductFamilyPageSelector, ProductFamilySelector};
+use ids_std_domain::pagination::{Page, PaginationQuery};
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
 
 #[async_trait::async_trait]
 pub trait ProductFamilyRepository: Send + Sync + 'static {
     async fn find_by_id(&self, id: i32)
         -> Result<Option<ProductFamilySelector>, SelectRepoFailure>;
+
+    async fn find_by_signature(
+        &self,
+        signature: &str,
+    ) -> Result<Option<ProductFamilySelector>, SelectRepoFailure>;
+
+    async fn find_other_by_signature(
+        &self,
+        self_id: i32,
+        signature: &str,
+    ) -> Result<Option<ProductFamilySelector>, SelectRepoFailure>;
+
+    async fn find_children(
+        &self,
+        parent_family_ids: &[i32],
+    ) -> Result<Vec<ProductFamilySelector>, SelectRepoFailure>;
+
+    async fn find_all(
+        &self,
+        query: &PaginationQuery,
+        criteria: &ProductFamilyQuery,
+    ) -> Result<Page<ProductFamilyPageSelector>, SelectRepoFailure>;
+
+    async fn save(&self, event: &AddProductFamily) -> Result<i32, SaveRepoFailure>;
+
+    async fn update(&self, event: &UpdateProductFamily) -> Result<(), SaveRepoFailure>;
 }
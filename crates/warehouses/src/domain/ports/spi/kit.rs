@@ -0,0 +1,24 @@
+use crate::domain::changes::kit::AddKitComponent;
+use crate::domain::selectors::kit::KitComponentSelector;
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+
+#[async_trait::async_trait]
+pub trait KitComponentRepository: Send + Sync + 'static {
+    async fn find_component(
+        &self,
+        kit_product_id: i32,
+        component_product_id: i32,
+    ) -> Result<Option<KitComponentSelector>, SelectRepoFailure>;
+
+    async fn find_by_kits(
+        &self,
+        kit_product_ids: &[i32],
+    ) -> Result<Vec<KitComponentSelector>, SelectRepoFailure>;
+
+    async fn find_by_components(
+        &self,
+        component_product_ids: &[i32],
+    ) -> Result<Vec<KitComponentSelector>, SelectRepoFailure>;
+
+    async fn save(&self, event: &AddKitComponent) -> Result<i32, SaveRepoFailure>;
+}
@@ -0,0 +1,35 @@
+use crate::domain::changes::location::{AddStorageLocation, UpdateStorageLocation};
+use crate::domain::selectors::location::{StorageLocationPageSelector, StorageLocationSelector};
+use ids_std_domain::pagination::{Page, PaginationQuery};
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+
+#[async_trait::async_trait]
+pub trait StorageLocationRepository: Send + Sync + 'static {
+    async fn find_by_id(
+        &self,
+        id: i32,
+    ) -> Result<Option<StorageLocationSelector>, SelectRepoFailure>;
+
+    async fn find_by_ids(
+        &self,
+        ids: &[i32],
+    ) -> Result<Vec<StorageLocationSelector>, SelectRepoFailure>;
+
+    async fn find_by_address(
+        &self,
+        warehouse_id: i32,
+        aisle: &str,
+        shelf: &str,
+        bin: &str,
+    ) -> Result<Option<StorageLocationSelector>, SelectRepoFailure>;
+
+    async fn find_by_warehouse(
+        &self,
+        query: &PaginationQuery,
+        warehouse_id: i32,
+    ) -> Result<Page<StorageLocationPageSelector>, SelectRepoFailure>;
+
+    async fn save(&self, event: &AddStorageLocation) -> Result<i32, SaveRepoFailure>;
+
+    async fn update(&self, event: &UpdateStorageLocation) -> Result<(), SaveRepoFailure>;
+}
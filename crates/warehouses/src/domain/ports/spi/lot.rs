@@ -0,0 +1,22 @@
+use crate::domain::selectors::lot::StockLotSelector;
+use chrono::NaiveDate;
+use ids_std_domain::spi::failure::SelectRepoFailure;
+
+#[async_trait::async_trait]
+pub trait StockLotRepository: Send + Sync + 'static {
+    async fn find_by_lot(
+        &self,
+        product_id: i32,
+        lot_number: &str,
+    ) -> Result<Option<StockLotSelector>, SelectRepoFailure>;
+
+    async fn find_by_product(
+        &self,
+        product_id: i32,
+    ) -> Result<Vec<StockLotSelector>, SelectRepoFailure>;
+
+    async fn find_expiring(
+        &self,
+        until: NaiveDate,
+    ) -> Result<Vec<StockLotSelector>, SelectRepoFailure>;
+}
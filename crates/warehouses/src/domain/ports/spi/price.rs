@@ -0,0 +1,25 @@
+use crate::domain::changes::price::{AddPriceList, AddProductPrice};
+use crate::domain::selectors::price::{PriceListSelector, ProductPriceSelector};
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+
+#[async_trait::async_trait]
+pub trait PriceListRepository: Send + Sync + 'static {
+    async fn find_by_id(&self, id: i32) -> Result<Option<PriceListSelector>, SelectRepoFailure>;
+
+    async fn find_by_name(
+        &self,
+        name: &str,
+    ) -> Result<Option<PriceListSelector>, SelectRepoFailure>;
+
+    async fn find_all(&self) -> Result<Vec<PriceListSelector>, SelectRepoFailure>;
+
+    async fn find_prices(
+        &self,
+        price_list_id: i32,
+        product_id: i32,
+    ) -> Result<Vec<ProductPriceSelector>, SelectRepoFailure>;
+
+    async fn save(&self, event: &AddPriceList) -> Result<i32, SaveRepoFailure>;
+
+    async fn save_price(&self, event: &AddProductPrice) -> Result<i32, SaveRepoFailure>;
+}
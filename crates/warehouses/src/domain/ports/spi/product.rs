@@ -1,13 +1,66 @@
-use crate::domain::changes::product::AddProduct;
-use crate::domain::selectors::product::ProductSelector;
+use crate::domain::changes::product::{
+    AddProduct, UpdateProduct, UpdateProductCostingMethod, UpdateProductSku, UpdateProductUnits,
+};
+use crate::domain::queries::product::ProductQuery;
+use crate::domain::selectors::product::{
+    ProductDetailsSelector, ProductPageSelector, ProductSelector,
+};
+use ids_std_domain::pagination::{Page, PaginationQuery};
 use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
 
 #[async_trait::async_trait]
 pub trait ProductRepository: Send + Sync + 'static {
     async fn save(&self, event: &AddProduct) -> Result<i32, SaveRepoFailure>;
 
+    async fn update(&self, event: &UpdateProduct) -> Result<(), SaveRepoFailure>;
+
+    async fn discontinue(&self, product_id: i32) -> Result<(), SaveRepoFailure>;
+
+    async fn update_units(&self, event: &UpdateProductUnits) -> Result<(), SaveRepoFailure>;
+
+    async fn update_sku(&self, event: &UpdateProductSku) -> Result<(), SaveRepoFailure>;
+
+    async fn update_costing_method(
+        &self,
+        event: &UpdateProductCostingMethod,
+    ) -> Result<(), SaveRepoFailure>;
+
+    async fn find_by_id(&self, id: i32) -> Result<Option<ProductSelector>, SelectRepoFailure>;
+
     async fn find_by_signature(
         &self,
         signature: &str,
     ) -> Result<Option<ProductSelector>, SelectRepoFailure>;
+
+    async fn find_other_by_signature(
+        &self,
+        self_id: i32,
+        signature: &str,
+    ) -> Result<Option<ProductSelector>, SelectRepoFailure>;
+
+    async fn find_by_sku(&self, sku: &str) -> Result<Option<ProductSelector>, SelectRepoFailure>;
+
+    async fn find_other_by_sku(
+        &self,
+        self_id: i32,
+        sku: &str,
+    ) -> Result<Option<ProductSelector>, SelectRepoFailure>;
+
+    async fn find_untracked(
+        &self,
+        family_ids: Option<Vec<i32>>,
+    ) -> Result<Vec<ProductSelector>, SelectRepoFailure>;
+
+    async fn find_by_ids(&self, ids: Vec<i32>) -> Result<Vec<ProductSelector>, SelectRepoFailure>;
+
+    async fn find_details_by_id(
+        &self,
+        id: i32,
+    ) -> Result<Option<ProductDetailsSelector>, SelectRepoFailure>;
+
+    async fn find_all(
+        &self,
+        query: &PaginationQuery,
+        criteria: &ProductQuery,
+    ) -> Result<Page<ProductPageSelector>, SelectRepoFailure>;
 }
@@ -0,0 +1,35 @@
+use crate::domain::changes::purchase::{
+    AddPurchaseOrder, AddPurchaseOrderLine, ChangePurchaseOrderStatus, ReceivePurchaseOrder,
+};
+use crate::domain::queries::purchase::PurchaseOrderQuery;
+use crate::domain::selectors::purchase::{PurchaseOrderLineSelector, PurchaseOrderSelector};
+use ids_std_domain::pagination::{Page, PaginationQuery};
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+
+#[async_trait::async_trait]
+pub trait PurchaseOrderRepository: Send + Sync + 'static {
+    async fn find_by_id(&self, id: i32)
+        -> Result<Option<PurchaseOrderSelector>, SelectRepoFailure>;
+
+    async fn find_all(
+        &self,
+        query: &PaginationQuery,
+        criteria: &PurchaseOrderQuery,
+    ) -> Result<Page<PurchaseOrderSelector>, SelectRepoFailure>;
+
+    async fn find_lines(
+        &self,
+        purchase_order_id: i32,
+    ) -> Result<Vec<PurchaseOrderLineSelector>, SelectRepoFailure>;
+
+    async fn save(&self, event: &AddPurchaseOrder) -> Result<i32, SaveRepoFailure>;
+
+    async fn add_line(&self, event: &AddPurchaseOrderLine) -> Result<i32, SaveRepoFailure>;
+
+    async fn change_status(
+        &self,
+        event: &ChangePurchaseOrderStatus,
+    ) -> Result<bool, SaveRepoFailure>;
+
+    async fn receive(&self, event: &ReceivePurchaseOrder) -> Result<bool, SaveRepoFailure>;
+}
@@ -0,0 +1,15 @@
+use crate::domain::changes::reorder::SetReorderPoint;
+use crate::domain::selectors::reorder::ReorderPointSelector;
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+
+#[async_trait::async_trait]
+pub trait ReorderPointRepository: Send + Sync + 'static {
+    async fn find_by_product(
+        &self,
+        product_id: i32,
+    ) -> Result<Option<ReorderPointSelector>, SelectRepoFailure>;
+
+    async fn find_purchasable(&self) -> Result<Vec<ReorderPointSelector>, SelectRepoFailure>;
+
+    async fn save(&self, event: &SetReorderPoint) -> Result<(), SaveRepoFailure>;
+}
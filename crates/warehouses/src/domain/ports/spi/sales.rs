@@ -0,0 +1,40 @@
+use crate::domain::changes::sales::{
+    AddSalesOrder, AddSalesOrderLine, ChangeSalesOrderStatus, FulfilSalesOrder,
+};
+use crate::domain::queries::sales::SalesOrderQuery;
+use crate::domain::selectors::sales::{
+    ProductCommitmentSelector, SalesOrderLineSelector, SalesOrderSelector,
+};
+use ids_std_domain::pagination::{Page, PaginationQuery};
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+
+#[async_trait::async_trait]
+pub trait SalesOrderRepository: Send + Sync + 'static {
+    async fn find_by_id(&self, id: i32) -> Result<Option<SalesOrderSelector>, SelectRepoFailure>;
+
+    async fn find_all(
+        &self,
+        query: &PaginationQuery,
+        criteria: &SalesOrderQuery,
+    ) -> Result<Page<SalesOrderSelector>, SelectRepoFailure>;
+
+    async fn find_lines(
+        &self,
+        sales_order_id: i32,
+    ) -> Result<Vec<SalesOrderLineSelector>, SelectRepoFailure>;
+
+    async fn find_commitments(
+        &self,
+        product_ids: &[i32],
+    ) -> Result<Vec<ProductCommitmentSelector>, SelectRepoFailure>;
+
+    async fn exists_customer(&self, person_id: i32) -> Result<bool, SelectRepoFailure>;
+
+    async fn save(&self, event: &AddSalesOrder) -> Result<i32, SaveRepoFailure>;
+
+    async fn add_line(&self, event: &AddSalesOrderLine) -> Result<i32, SaveRepoFailure>;
+
+    async fn change_status(&self, event: &ChangeSalesOrderStatus) -> Result<bool, SaveRepoFailure>;
+
+    async fn fulfil(&self, event: &FulfilSalesOrder) -> Result<bool, SaveRepoFailure>;
+}
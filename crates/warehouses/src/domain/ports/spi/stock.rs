@@ -0,0 +1,92 @@
+use crate::domain::changes::stock::AddStockMovement;
+use crate::domain::selectors::stock::{
+    CostedMovementSelector, LocationOnHandSelector, LotOnHandSelector, StockMovementPageSelector,
+    StockedLocationSelector,
+};
+use crate::domain::valuables::tracking::TrackedUnit;
+use chrono::NaiveDateTime;
+use ids_std_domain::pagination::{Page, PaginationQuery};
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+
+#[async_trait::async_trait]
+pub trait StockMovementRepository: Send + Sync + 'static {
+    async fn save(&self, event: &AddStockMovement) -> Result<i32, SaveRepoFailure>;
+
+    async fn save_all(&self, events: &[AddStockMovement]) -> Result<Vec<i32>, SaveRepoFailure>;
+
+    async fn save_all_in_stock(
+        &self,
+        events: &[AddStockMovement],
+    ) -> Result<Option<Vec<i32>>, SaveRepoFailure>;
+
+    async fn find_on_hand(&self, product_id: i32) -> Result<i32, SelectRepoFailure>;
+
+    async fn find_on_hand_at(
+        &self,
+        product_id: i32,
+        storage_location_id: i32,
+    ) -> Result<i32, SelectRepoFailure>;
+
+    async fn find_on_hand_in_warehouse(
+        &self,
+        product_id: i32,
+        warehouse_id: i32,
+    ) -> Result<i32, SelectRepoFailure>;
+
+    async fn find_on_hand_of_unit(
+        &self,
+        product_id: i32,
+        unit: &TrackedUnit,
+    ) -> Result<i32, SelectRepoFailure>;
+
+    async fn find_on_hand_of_unit_at(
+        &self,
+        product_id: i32,
+        unit: &TrackedUnit,
+        storage_location_id: i32,
+    ) -> Result<i32, SelectRepoFailure>;
+
+    async fn find_on_hand_by_location(
+        &self,
+        product_id: i32,
+    ) -> Result<Vec<LocationOnHandSelector>, SelectRepoFailure>;
+
+    async fn find_stocked_locations_in_warehouse(
+        &self,
+        warehouse_id: i32,
+    ) -> Result<Vec<StockedLocationSelector>, SelectRepoFailure>;
+
+    async fn find_on_hand_by_lot(
+        &self,
+        product_id: i32,
+    ) -> Result<Vec<LotOnHandSelector>, SelectRepoFailure>;
+
+    async fn find_stocked_products(
+        &self,
+        moved_before: NaiveDateTime,
+    ) -> Result<Vec<i32>, SelectRepoFailure>;
+
+    async fn find_by_product(
+        &self,
+        query: &PaginationQuery,
+        product_id: i32,
+    ) -> Result<Page<StockMovementPageSelector>, SelectRepoFailure>;
+
+    async fn find_by_tracking_code(
+        &self,
+        query: &PaginationQuery,
+        tracking_code: &str,
+    ) -> Result<Page<StockMovementPageSelector>, SelectRepoFailure>;
+
+    async fn find_costed_movements(
+        &self,
+        product_id: i32,
+        moved_before: Option<NaiveDateTime>,
+    ) -> Result<Vec<CostedMovementSelector>, SelectRepoFailure>;
+
+    async fn find_costed_movements_of_products(
+        &self,
+        product_ids: &[i32],
+        moved_before: NaiveDateTime,
+    ) -> Result<Vec<CostedMovementSelector>, SelectRepoFailure>;
+}
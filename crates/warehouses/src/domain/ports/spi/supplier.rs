@@ -0,0 +1,45 @@
+use crate::domain::changes::supplier::{AddSupplier, AddSupplierProduct, UpdateSupplierProduct};
+use crate::domain::selectors::supplier::{
+    SupplierOfferSelector, SupplierProductSelector, SupplierSelector,
+};
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+
+#[async_trait::async_trait]
+pub trait SupplierRepository: Send + Sync + 'static {
+    async fn find_by_id(&self, id: i32) -> Result<Option<SupplierSelector>, SelectRepoFailure>;
+
+    async fn find_by_name(&self, name: &str)
+        -> Result<Option<SupplierSelector>, SelectRepoFailure>;
+
+    async fn find_all(&self) -> Result<Vec<SupplierSelector>, SelectRepoFailure>;
+
+    async fn exists_contact(&self, person_id: i32) -> Result<bool, SelectRepoFailure>;
+
+    async fn find_product(
+        &self,
+        supplier_id: i32,
+        product_id: i32,
+    ) -> Result<Option<SupplierProductSelector>, SelectRepoFailure>;
+
+    async fn find_product_by_code(
+        &self,
+        supplier_id: i32,
+        supplier_code: &str,
+    ) -> Result<Option<SupplierProductSelector>, SelectRepoFailure>;
+
+    async fn find_products(
+        &self,
+        supplier_id: i32,
+    ) -> Result<Vec<SupplierProductSelector>, SelectRepoFailure>;
+
+    async fn find_offers(
+        &self,
+        product_id: i32,
+    ) -> Result<Vec<SupplierOfferSelector>, SelectRepoFailure>;
+
+    async fn save(&self, event: &AddSupplier) -> Result<i32, SaveRepoFailure>;
+
+    async fn save_product(&self, event: &AddSupplierProduct) -> Result<i32, SaveRepoFailure>;
+
+    async fn update_product(&self, event: &UpdateSupplierProduct) -> Result<(), SaveRepoFailure>;
+}
@@ -0,0 +1,31 @@
+use crate::domain::changes::transfer::{
+    AddStockTransfer, CancelStockTransfer, ReceiveStockTransfer,
+};
+use crate::domain::queries::transfer::StockTransferQuery;
+use crate::domain::selectors::transfer::StockTransferSelector;
+use ids_std_domain::pagination::{Page, PaginationQuery};
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+
+#[async_trait::async_trait]
+pub trait StockTransferRepository: Send + Sync + 'static {
+    async fn find_by_id(&self, id: i32)
+        -> Result<Option<StockTransferSelector>, SelectRepoFailure>;
+
+    async fn find_all(
+        &self,
+        query: &PaginationQuery,
+        criteria: &StockTransferQuery,
+    ) -> Result<Page<StockTransferSelector>, SelectRepoFailure>;
+
+    async fn find_in_transit(
+        &self,
+        product_id: i32,
+        destination_warehouse_id: Option<i32>,
+    ) -> Result<i32, SelectRepoFailure>;
+
+    async fn save(&self, event: &AddStockTransfer) -> Result<Option<i32>, SaveRepoFailure>;
+
+    async fn receive(&self, event: &ReceiveStockTransfer) -> Result<bool, SaveRepoFailure>;
+
+    async fn cancel(&self, event: &CancelStockTransfer) -> Result<bool, SaveRepoFailure>;
+}
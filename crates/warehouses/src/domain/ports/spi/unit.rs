@@ -0,0 +1,28 @@
+use crate::domain::changes::unit::{AddUnitConversion, AddUnitOfMeasure};
+use crate::domain::selectors::unit::{UnitConversionSelector, UnitOfMeasureSelector};
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+
+#[async_trait::async_trait]
+pub trait UnitOfMeasureRepository: Send + Sync + 'static {
+    async fn find_by_id(&self, id: i32)
+        -> Result<Option<UnitOfMeasureSelector>, SelectRepoFailure>;
+
+    async fn find_by_code(
+        &self,
+        code: &str,
+    ) -> Result<Option<UnitOfMeasureSelector>, SelectRepoFailure>;
+
+    async fn find_all(&self) -> Result<Vec<UnitOfMeasureSelector>, SelectRepoFailure>;
+
+    async fn find_conversion(
+        &self,
+        from_unit_id: i32,
+        to_unit_id: i32,
+    ) -> Result<Option<UnitConversionSelector>, SelectRepoFailure>;
+
+    async fn find_conversions(&self) -> Result<Vec<UnitConversionSelector>, SelectRepoFailure>;
+
+    async fn save(&self, event: &AddUnitOfMeasure) -> Result<i32, SaveRepoFailure>;
+
+    async fn save_conversion(&self, event: &AddUnitConversion) -> Result<i32, SaveRepoFailure>;
+}
@@ -0,0 +1,23 @@
+use crate::domain::changes::variant::AddProductVariant;
+use crate::domain::selectors::variant::{ProductVariantDetailsSelector, ProductVariantSelector};
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+
+#[async_trait::async_trait]
+pub trait ProductVariantRepository: Send + Sync + 'static {
+    async fn find_by_signature(
+        &self,
+        signature: &str,
+    ) -> Result<Option<ProductVariantSelector>, SelectRepoFailure>;
+
+    async fn find_by_sku(
+        &self,
+        sku: &str,
+    ) -> Result<Option<ProductVariantSelector>, SelectRepoFailure>;
+
+    async fn find_by_product(
+        &self,
+        product_id: i32,
+    ) -> Result<Vec<ProductVariantDetailsSelector>, SelectRepoFailure>;
+
+    async fn save_all(&self, events: &[AddProductVariant]) -> Result<Vec<i32>, SaveRepoFailure>;
+}
@@ -0,0 +1,31 @@
+use crate::domain::changes::warehouse::{AddWarehouse, UpdateWarehouse};
+use crate::domain::queries::warehouse::WarehouseQuery;
+use crate::domain::selectors::warehouse::{WarehousePageSelector, WarehouseSelector};
+use ids_std_domain::pagination::{Page, PaginationQuery};
+use ids_std_domain::spi::failure::{SaveRepoFailure, SelectRepoFailure};
+
+#[async_trait::async_trait]
+pub trait WarehouseRepository: Send + Sync + 'static {
+    async fn find_by_id(&self, id: i32) -> Result<Option<WarehouseSelector>, SelectRepoFailure>;
+
+    async fn find_by_code(
+        &self,
+        code: &str,
+    ) -> Result<Option<WarehouseSelector>, SelectRepoFailure>;
+
+    async fn find_other_by_code(
+        &self,
+        self_id: i32,
+        code: &str,
+    ) -> Result<Option<WarehouseSelector>, SelectRepoFailure>;
+
+    async fn find_all(
+        &self,
+        query: &PaginationQuery,
+        criteria: &WarehouseQuery,
+    ) -> Result<Page<WarehousePageSelector>, SelectRepoFailure>;
+
+    async fn save(&self, event: &AddWarehouse) -> Result<i32, SaveRepoFailure>;
+
+    async fn update(&self, event: &UpdateWarehouse) -> Result<(), SaveRepoFailure>;
+}
@@ -0,0 +1,7 @@
+use crate::domain::valuables::count::InventoryCountStatus;
+
+#[derive(Debug, Clone, Default)]
+pub struct InventoryCountQuery {
+    pub status: Option<InventoryCountStatus>,
+    pub warehouse_id: Option<i32>,
+}
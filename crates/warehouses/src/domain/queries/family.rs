@@ -0,0 +1,4 @@
+#[derive(Debug, Clone, Default)]
+pub struct ProductFamilyQuery {
+    pub name: Option<String>,
+}
@@ -0,0 +1,10 @@
+#[derive(Debug, Clone, Default)]
+pub struct ExpiringLotQuery {
+    pub within_days: i64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct FefoPickQuery {
+    pub quantity: i32,
+    pub override_reason: Option<String>,
+}
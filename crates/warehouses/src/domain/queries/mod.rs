@@ -0,0 +1,11 @@
+pub mod count;
+pub mod family;
+pub mod lot;
+pub mod price;
+pub mod product;
+pub mod purchase;
+pub mod sales;
+pub mod stock;
+pub mod transfer;
+pub mod valuation;
+pub mod warehouse;
@@ -0,0 +1,7 @@
+use chrono::NaiveDate;
+
+#[derive(Debug, Clone, Default)]
+pub struct ProductPriceQuery {
+    pub price_list_id: i32,
+    pub date: Option<NaiveDate>,
+}
@@ -0,0 +1,8 @@
+#[derive(Debug, Clone, Default)]
+pub struct ProductQuery {
+    pub family_ids: Vec<i32>,
+    pub include_descendants: bool,
+    pub name: Option<String>,
+    pub purchasable: Option<bool>,
+    pub saleable: Option<bool>,
+}
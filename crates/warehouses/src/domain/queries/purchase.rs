@@ -0,0 +1,7 @@
+use crate::domain::valuables::purchase::PurchaseOrderStatus;
+
+#[derive(Debug, Clone, Default)]
+pub struct PurchaseOrderQuery {
+    pub status: Option<PurchaseOrderStatus>,
+    pub supplier_id: Option<i32>,
+}
@@ -0,0 +1,7 @@
+use crate::domain::valuables::sales::SalesOrderStatus;
+
+#[derive(Debug, Clone, Default)]
+pub struct SalesOrderQuery {
+    pub status: Option<SalesOrderStatus>,
+    pub customer_id: Option<i32>,
+}
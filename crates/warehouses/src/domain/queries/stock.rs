@@ -0,0 +1,4 @@
+#[derive(Debug, Clone, Default)]
+pub struct StockQuery {
+    pub warehouse_id: Option<i32>,
+}
@@ -0,0 +1,7 @@
+use crate::domain::valuables::transfer::StockTransferStatus;
+
+#[derive(Debug, Clone, Default)]
+pub struct StockTransferQuery {
+    pub product_id: Option<i32>,
+    pub status: Option<StockTransferStatus>,
+}
@@ -0,0 +1,6 @@
+use chrono::NaiveDate;
+
+#[derive(Debug, Clone, Default)]
+pub struct StockValuationQuery {
+    pub as_of: Option<NaiveDate>,
+}
@@ -0,0 +1,4 @@
+#[derive(Debug, Clone, Default)]
+pub struct WarehouseQuery {
+    pub name: Option<String>,
+}
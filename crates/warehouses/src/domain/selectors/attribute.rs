@@ -0,0 +1,17 @@
+pub struct ProductAttributeSelector {
+    pub product_attribute_id: i32,
+    pub name: String,
+}
+
+pub struct AttributeValueSelector {
+    pub product_attribute_value_id: i32,
+    pub attribute_id: i32,
+    pub attribute_name: String,
+    pub value: String,
+}
+
+pub struct ProductAttributeDetailsSelector {
+    pub product_attribute_id: i32,
+    pub name: String,
+    pub values: Vec<AttributeValueSelector>,
+}
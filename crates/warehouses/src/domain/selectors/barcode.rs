@@ -0,0 +1,18 @@
+use crate::domain::valuables::barcode::BarcodeSymbology;
+
+pub struct ProductBarcodeSelector {
+    pub product_barcode_id: i32,
+    pub product_id: i32,
+    pub code: String,
+    pub gtin: String,
+    pub symbology: BarcodeSymbology,
+}
+
+pub struct ScannedProductSelector {
+    pub product_id: i32,
+    pub product_name: String,
+    pub sku: Option<String>,
+    pub code: String,
+    pub symbology: BarcodeSymbology,
+    pub discontinued: bool,
+}
@@ -0,0 +1,29 @@
+use crate::domain::valuables::count::InventoryCountStatus;
+use chrono::NaiveDateTime;
+
+pub struct InventoryCountSelector {
+    pub inventory_count_id: i32,
+    pub warehouse_id: Option<i32>,
+    pub family_id: Option<i32>,
+    pub status: InventoryCountStatus,
+    pub opened_at: NaiveDateTime,
+    pub posted_at: Option<NaiveDateTime>,
+}
+
+pub struct InventoryCountLineSelector {
+    pub inventory_count_line_id: i32,
+    pub product_id: i32,
+    pub storage_location_id: Option<i32>,
+    pub book_quantity: i32,
+    pub counted_quantity: Option<i32>,
+}
+
+pub struct InventoryCountDetailsSelector {
+    pub inventory_count_id: i32,
+    pub warehouse_id: Option<i32>,
+    pub family_id: Option<i32>,
+    pub status: InventoryCountStatus,
+    pub opened_at: NaiveDateTime,
+    pub posted_at: Option<NaiveDateTime>,
+    pub lines: Vec<InventoryCountLineSelector>,
+}
@@ -1,3 +1,21 @@
 pub struct ProductFamilySelector {
     pub product_family_id: i32,
+    pub name: String,
+    pub summary: Option<String>,
+    pub parent_family_id: Option<i32>,
+    pub signature: String,
+}
+
+pub struct ProductFamilyPageSelector {
+    pub product_family_id: i32,
+    pub name: String,
+    pub summary: Option<String>,
+    pub parent_family_id: Option<i32>,
+}
+
+pub struct ProductFamilyTreeSelector {
+    pub product_family_id: i32,
+    pub name: String,
+    pub summary: Option<String>,
+    pub children: Vec<ProductFamilyTreeSelector>,
 }
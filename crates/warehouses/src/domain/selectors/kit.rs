@@ -0,0 +1,20 @@
+pub struct KitComponentSelector {
+    pub product_component_id: i32,
+    pub kit_product_id: i32,
+    pub component_product_id: i32,
+    pub quantity: i32,
+}
+
+pub struct ComponentAvailabilitySelector {
+    pub component_product_id: i32,
+    pub quantity: i32,
+    pub available: i32,
+}
+
+pub struct KitAvailabilitySelector {
+    pub product_id: i32,
+    pub on_hand: i32,
+    pub buildable: i32,
+    pub available: i32,
+    pub components: Vec<ComponentAvailabilitySelector>,
+}
@@ -0,0 +1,16 @@
+pub struct StorageLocationSelector {
+    pub storage_location_id: i32,
+    pub warehouse_id: i32,
+    pub warehouse_code: String,
+    pub aisle: String,
+    pub shelf: String,
+    pub bin: String,
+}
+
+pub struct StorageLocationPageSelector {
+    pub storage_location_id: i32,
+    pub warehouse_id: i32,
+    pub aisle: String,
+    pub shelf: String,
+    pub bin: String,
+}
@@ -0,0 +1,29 @@
+use chrono::NaiveDate;
+
+pub struct StockLotSelector {
+    pub stock_lot_id: i32,
+    pub product_id: i32,
+    pub lot_number: String,
+    pub expires_on: NaiveDate,
+}
+
+pub struct ExpiringLotSelector {
+    pub product_id: i32,
+    pub lot_number: String,
+    pub expires_on: NaiveDate,
+    pub on_hand: i32,
+    pub expired: bool,
+}
+
+pub struct LotPickSelector {
+    pub lot_number: String,
+    pub expires_on: Option<NaiveDate>,
+    pub quantity: i32,
+}
+
+pub struct FefoPickSelector {
+    pub product_id: i32,
+    pub quantity: i32,
+    pub picks: Vec<LotPickSelector>,
+    pub shortage: i32,
+}
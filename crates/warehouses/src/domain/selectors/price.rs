@@ -0,0 +1,32 @@
+use crate::domain::valuables::money::Money;
+use crate::domain::valuables::price::PriceListKind;
+use chrono::NaiveDate;
+
+pub struct PriceListSelector {
+    pub price_list_id: i32,
+    pub name: String,
+    pub kind: PriceListKind,
+    pub currency: String,
+    pub valid_from: NaiveDate,
+    pub valid_to: Option<NaiveDate>,
+}
+
+pub struct ProductPriceSelector {
+    pub product_price_id: i32,
+    pub price_list_id: i32,
+    pub product_id: i32,
+    pub amount: Money,
+    pub valid_from: NaiveDate,
+    pub valid_to: Option<NaiveDate>,
+}
+
+pub struct ResolvedPriceSelector {
+    pub product_id: i32,
+    pub price_list_id: i32,
+    pub price_list_name: String,
+    pub kind: PriceListKind,
+    pub currency: String,
+    pub amount: Money,
+    pub valid_from: NaiveDate,
+    pub valid_to: Option<NaiveDate>,
+}
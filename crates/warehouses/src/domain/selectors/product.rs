@@ -1,5 +1,57 @@
+use crate::domain::valuables::tracking::TrackingMode;
+use crate::domain::valuables::valuation::CostingMethod;
+
 pub struct ProductSelector {
     pub product_id: i32,
 
+    pub family_id: i32,
+
+    pub signature: String,
+
+    pub purchasable: bool,
+
+    pub saleable: bool,
+
+    pub saleable_without_stock: bool,
+
+    pub discontinued: bool,
+
+    pub tracking_mode: TrackingMode,
+
+    pub base_unit_id: Option<i32>,
+
+    pub purchase_unit_id: Option<i32>,
+
+    pub sale_unit_id: Option<i32>,
+
+    pub sku: Option<String>,
+
+    pub costing_method: CostingMethod,
+}
+
+pub struct ProductPageSelector {
+    pub product_id: i32,
+    pub family_id: i32,
+    pub family_name: String,
+    pub name: String,
+    pub summary: Option<String>,
+    pub purchasable: bool,
+    pub saleable: bool,
+    pub saleable_without_stock: bool,
+    pub discontinued: bool,
+    pub tracking_mode: TrackingMode,
+}
+
+pub struct ProductDetailsSelector {
+    pub product_id: i32,
+    pub family_id: i32,
+    pub family_name: String,
+    pub name: String,
+    pub summary: Option<String>,
+    pub purchasable: bool,
+    pub saleable: bool,
+    pub saleable_without_stock: bool,
+    pub discontinued: bool,
+    pub tracking_mode: TrackingMode,
     pub signature: String,
 }
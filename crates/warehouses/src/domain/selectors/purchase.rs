@@ -0,0 +1,35 @@
+use crate::domain::valuables::money::Money;
+use crate::domain::valuables::purchase::PurchaseOrderStatus;
+use chrono::NaiveDateTime;
+
+pub struct PurchaseOrderSelector {
+    pub purchase_order_id: i32,
+    pub supplier_id: i32,
+    pub status: PurchaseOrderStatus,
+    pub currency: String,
+    pub created_at: NaiveDateTime,
+    pub approved_at: Option<NaiveDateTime>,
+    pub sent_at: Option<NaiveDateTime>,
+    pub closed_at: Option<NaiveDateTime>,
+}
+
+pub struct PurchaseOrderLineSelector {
+    pub purchase_order_line_id: i32,
+    pub product_id: i32,
+    pub ordered_quantity: i32,
+    pub received_quantity: i32,
+    pub unit_id: Option<i32>,
+    pub unit_price: Money,
+}
+
+pub struct PurchaseOrderDetailsSelector {
+    pub purchase_order_id: i32,
+    pub supplier_id: i32,
+    pub status: PurchaseOrderStatus,
+    pub currency: String,
+    pub created_at: NaiveDateTime,
+    pub approved_at: Option<NaiveDateTime>,
+    pub sent_at: Option<NaiveDateTime>,
+    pub closed_at: Option<NaiveDateTime>,
+    pub lines: Vec<PurchaseOrderLineSelector>,
+}
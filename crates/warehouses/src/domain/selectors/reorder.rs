@@ -0,0 +1,15 @@
+pub struct ReorderPointSelector {
+    pub product_id: i32,
+    pub product_name: String,
+    pub min_quantity: i32,
+    pub max_quantity: Option<i32>,
+}
+
+pub struct LowStockSelector {
+    pub product_id: i32,
+    pub product_name: String,
+    pub on_hand: i32,
+    pub min_quantity: i32,
+    pub max_quantity: Option<i32>,
+    pub suggested_quantity: i32,
+}
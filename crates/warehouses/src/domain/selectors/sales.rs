@@ -0,0 +1,45 @@
+use crate::domain::valuables::sales::SalesOrderStatus;
+use chrono::NaiveDateTime;
+
+pub struct SalesOrderSelector {
+    pub sales_order_id: i32,
+    pub customer_id: i32,
+    pub status: SalesOrderStatus,
+    pub created_at: NaiveDateTime,
+    pub confirmed_at: Option<NaiveDateTime>,
+    pub cancelled_at: Option<NaiveDateTime>,
+    pub fulfilled_at: Option<NaiveDateTime>,
+}
+
+pub struct SalesOrderLineSelector {
+    pub sales_order_line_id: i32,
+    pub product_id: i32,
+    pub ordered_quantity: i32,
+    pub reserved_quantity: i32,
+    pub backordered_quantity: i32,
+}
+
+pub struct SalesOrderDetailsSelector {
+    pub sales_order_id: i32,
+    pub customer_id: i32,
+    pub status: SalesOrderStatus,
+    pub created_at: NaiveDateTime,
+    pub confirmed_at: Option<NaiveDateTime>,
+    pub cancelled_at: Option<NaiveDateTime>,
+    pub fulfilled_at: Option<NaiveDateTime>,
+    pub lines: Vec<SalesOrderLineSelector>,
+}
+
+pub struct ProductCommitmentSelector {
+    pub product_id: i32,
+    pub reserved: i32,
+    pub backordered: i32,
+}
+
+pub struct AvailableToPromiseSelector {
+    pub product_id: i32,
+    pub on_hand: i32,
+    pub reserved: i32,
+    pub backordered: i32,
+    pub available_to_promise: i32,
+}
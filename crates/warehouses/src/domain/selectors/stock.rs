@@ -0,0 +1,57 @@
+use crate::domain::valuables::money::Money;
+use crate::domain::valuables::stock::StockMovementKind;
+use chrono::NaiveDateTime;
+
+pub struct StockMovementPageSelector {
+    pub stock_movement_id: i32,
+    pub product_id: i32,
+    pub kind: StockMovementKind,
+    pub quantity: i32,
+    pub storage_location_id: Option<i32>,
+    pub stock_transfer_id: Option<i32>,
+    pub lot_number: Option<String>,
+    pub serial_number: Option<String>,
+    pub unit_cost: Option<Money>,
+    pub reason: Option<String>,
+    pub moved_at: NaiveDateTime,
+}
+
+pub struct ProductOnHandSelector {
+    pub product_id: i32,
+    pub warehouse_id: Option<i32>,
+    pub on_hand: i32,
+    pub in_transit: i32,
+}
+
+pub struct LocationOnHandSelector {
+    pub storage_location_id: i32,
+    pub on_hand: i32,
+}
+
+pub struct StockedLocationSelector {
+    pub product_id: i32,
+    pub storage_location_id: i32,
+    pub on_hand: i32,
+}
+
+pub struct LotOnHandSelector {
+    pub lot_number: String,
+    pub on_hand: i32,
+}
+
+pub struct ProductLocationSelector {
+    pub storage_location_id: i32,
+    pub warehouse_id: i32,
+    pub warehouse_code: String,
+    pub aisle: String,
+    pub shelf: String,
+    pub bin: String,
+    pub on_hand: i32,
+}
+
+pub struct CostedMovementSelector {
+    pub product_id: i32,
+    pub kind: StockMovementKind,
+    pub quantity: i32,
+    pub unit_cost: Option<Money>,
+}
@@ -0,0 +1,32 @@
+use crate::domain::valuables::money::Money;
+
+pub struct SupplierSelector {
+    pub supplier_id: i32,
+    pub name: String,
+    pub contact_person_id: Option<i32>,
+    pub currency: String,
+}
+
+pub struct SupplierProductSelector {
+    pub supplier_product_id: i32,
+    pub supplier_id: i32,
+    pub product_id: i32,
+    pub supplier_code: String,
+    pub pack_size: i32,
+    pub lead_time_days: i32,
+    pub last_price: Option<Money>,
+    pub preferred: bool,
+}
+
+pub struct SupplierOfferSelector {
+    pub supplier_product_id: i32,
+    pub supplier_id: i32,
+    pub supplier_name: String,
+    pub currency: String,
+    pub product_id: i32,
+    pub supplier_code: String,
+    pub pack_size: i32,
+    pub lead_time_days: i32,
+    pub last_price: Option<Money>,
+    pub preferred: bool,
+}
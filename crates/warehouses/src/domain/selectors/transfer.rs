@@ -0,0 +1,19 @@
+use crate::domain::valuables::money::Money;
+use crate::domain::valuables::transfer::StockTransferStatus;
+use chrono::NaiveDateTime;
+
+pub struct StockTransferSelector {
+    pub stock_transfer_id: i32,
+    pub product_id: i32,
+    pub source_location_id: i32,
+    pub source_warehouse_id: i32,
+    pub destination_warehouse_id: i32,
+    pub quantity: i32,
+    pub received_quantity: i32,
+    pub status: StockTransferStatus,
+    pub transferred_at: NaiveDateTime,
+    pub closed_at: Option<NaiveDateTime>,
+    pub lot_number: Option<String>,
+    pub serial_number: Option<String>,
+    pub unit_cost: Option<Money>,
+}
@@ -0,0 +1,26 @@
+pub struct UnitOfMeasureSelector {
+    pub unit_of_measure_id: i32,
+    pub code: String,
+    pub name: String,
+}
+
+pub struct UnitConversionSelector {
+    pub unit_conversion_id: i32,
+    pub from_unit_id: i32,
+    pub to_unit_id: i32,
+    pub factor: i32,
+}
+
+pub struct UnitOfMeasureDetailsSelector {
+    pub unit_of_measure_id: i32,
+    pub code: String,
+    pub name: String,
+    pub conversions: Vec<UnitConversionSelector>,
+}
+
+pub struct ProductUnitsSelector {
+    pub product_id: i32,
+    pub base_unit_id: Option<i32>,
+    pub purchase_unit_id: Option<i32>,
+    pub sale_unit_id: Option<i32>,
+}
@@ -0,0 +1,27 @@
+use crate::domain::valuables::money::Money;
+use crate::domain::valuables::valuation::CostingMethod;
+use chrono::NaiveDate;
+
+pub struct ProductValuationSelector {
+    pub product_id: i32,
+    pub family_id: i32,
+    pub costing_method: CostingMethod,
+    pub quantity: i32,
+    pub unit_cost: Money,
+    pub value: Money,
+    pub issued_quantity: i32,
+    pub issued_cost: Money,
+}
+
+pub struct FamilyValuationSelector {
+    pub family_id: i32,
+    pub quantity: i32,
+    pub value: Money,
+}
+
+pub struct StockValuationSelector {
+    pub as_of: NaiveDate,
+    pub value: Money,
+    pub families: Vec<FamilyValuationSelector>,
+    pub products: Vec<ProductValuationSelector>,
+}
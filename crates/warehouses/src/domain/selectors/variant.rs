@@ -0,0 +1,16 @@
+use crate::domain::selectors::attribute::AttributeValueSelector;
+
+pub struct ProductVariantSelector {
+    pub product_variant_id: i32,
+    pub product_id: i32,
+    pub sku: Option<String>,
+    pub signature: String,
+}
+
+pub struct ProductVariantDetailsSelector {
+    pub product_variant_id: i32,
+    pub product_id: i32,
+    pub name: String,
+    pub sku: Option<String>,
+    pub values: Vec<AttributeValueSelector>,
+}
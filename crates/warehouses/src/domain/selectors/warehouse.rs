@@ -0,0 +1,13 @@
+pub struct WarehouseSelector {
+    pub warehouse_id: i32,
+    pub code: String,
+    pub name: String,
+    pub address: Option<String>,
+}
+
+pub struct WarehousePageSelector {
+    pub warehouse_id: i32,
+    pub code: String,
+    pub name: String,
+    pub address: Option<String>,
+}
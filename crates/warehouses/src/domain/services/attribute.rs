@@ -0,0 +1,167 @@
+use crate::domain::changes::attribute::{AddAttributeValue, AddProductAttribute};
+use crate::domain::commands::attribute::{AddAttributeValueCommand, CreateProductAttributeCommand};
+use crate::domain::ports::api::attribute::{
+    AddAttributeValueUseCase, CreateProductAttributeUseCase, FindAllProductAttributesUseCase,
+};
+use crate::domain::ports::spi::attribute::ProductAttributeRepository;
+use crate::domain::selectors::attribute::{
+    AttributeValueSelector, ProductAttributeDetailsSelector,
+};
+use ids_std_domain::api::failure::{CreateDomainFailure, FindManyFailure, InvalidField};
+use ids_std_domain::validation;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct ProductAttributeService {
+    attribute_repo: Arc<dyn ProductAttributeRepository>,
+}
+
+impl ProductAttributeService {
+    pub fn new(attribute_repo: Arc<dyn ProductAttributeRepository>) -> Self {
+        Self { attribute_repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl CreateProductAttributeUseCase for ProductAttributeService {
+    async fn create_product_attribute(
+        &self,
+        command: &CreateProductAttributeCommand,
+    ) -> Result<i32, CreateDomainFailure> {
+        tracing::info!("creating product attribute {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let values = command
+            .values
+            .iter()
+            .map(|value| value.trim().to_owned())
+            .collect::<Vec<String>>();
+
+        if values
+            .iter()
+            .any(|value| value.is_empty() || value.len() > 50)
+        {
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "values".into(),
+                "attribute values must have between 1 and 50 characters".into(),
+            )))?
+        }
+
+        let distinct_values = values
+            .iter()
+            .map(|value| value.to_lowercase())
+            .collect::<HashSet<String>>();
+        if distinct_values.len() != values.len() {
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "values".into(),
+                "attribute values must be unique".into(),
+            )))?
+        }
+
+        let attribute_name = command.name.trim().to_owned();
+        let another_attribute = self
+            .attribute_repo
+            .find_by_name(attribute_name.as_str())
+            .await?;
+
+        if let Some(attr) = another_attribute {
+            tracing::info!(
+                product_attribute_id = &attr.product_attribute_id,
+                "product attribute already exist"
+            );
+
+            Err(CreateDomainFailure::Conflict(
+                "product attribute already exist".to_string(),
+            ))?;
+        }
+
+        let attribute_created_event = AddProductAttribute {
+            name: attribute_name,
+            values,
+        };
+        let product_attribute_id = self.attribute_repo.save(&attribute_created_event).await?;
+
+        Ok(product_attribute_id)
+    }
+}
+
+#[async_trait::async_trait]
+impl AddAttributeValueUseCase for ProductAttributeService {
+    async fn add_attribute_value(
+        &self,
+        command: &AddAttributeValueCommand,
+    ) -> Result<i32, CreateDomainFailure> {
+        tracing::info!("adding attribute value {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let attribute = self.attribute_repo.find_by_id(command.attribute_id).await?;
+        if attribute.is_none() {
+            tracing::info!(
+                product_attribute_id = &command.attribute_id,
+                "product attribute does not exist"
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "attribute_id".into(),
+                "product attribute does not exist".into(),
+            )))?
+        }
+
+        let value = command.value.trim().to_owned();
+        let another_value = self
+            .attribute_repo
+            .find_value(command.attribute_id, value.as_str())
+            .await?;
+
+        if let Some(val) = another_value {
+            tracing::info!(
+                product_attribute_value_id = &val.product_attribute_value_id,
+                "attribute value already exist"
+            );
+
+            Err(CreateDomainFailure::Conflict(
+                "attribute value already exist".to_string(),
+            ))?;
+        }
+
+        let value_added_event = AddAttributeValue {
+            attribute_id: command.attribute_id,
+            value,
+        };
+        let product_attribute_value_id = self.attribute_repo.save_value(&value_added_event).await?;
+
+        Ok(product_attribute_value_id)
+    }
+}
+
+#[async_trait::async_trait]
+impl FindAllProductAttributesUseCase for ProductAttributeService {
+    async fn find_all_product_attributes(
+        &self,
+    ) -> Result<Vec<ProductAttributeDetailsSelector>, FindManyFailure> {
+        let attributes = self.attribute_repo.find_all().await?;
+        let values = self.attribute_repo.find_values(None).await?;
+
+        let mut values_by_attribute: HashMap<i32, Vec<AttributeValueSelector>> = HashMap::new();
+        for value in values {
+            values_by_attribute
+                .entry(value.attribute_id)
+                .or_default()
+                .push(value);
+        }
+
+        Ok(attributes
+            .into_iter()
+            .map(|attribute| ProductAttributeDetailsSelector {
+                product_attribute_id: attribute.product_attribute_id,
+                name: attribute.name,
+                values: values_by_attribute
+                    .remove(&attribute.product_attribute_id)
+                    .unwrap_or_default(),
+            })
+            .collect())
+    }
+}
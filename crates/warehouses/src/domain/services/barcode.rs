@@ -0,0 +1,193 @@
+use crate::domain::changes::barcode::AddProductBarcode;
+use crate::domain::changes::product::UpdateProductSku;
+use crate::domain::commands::barcode::{AddProductBarcodeCommand, SetProductSkuCommand};
+use crate::domain::ports::api::barcode::{
+    AddProductBarcodeUseCase, FindProductBarcodesUseCase, FindProductByBarcodeUseCase,
+    SetProductSkuUseCase,
+};
+use crate::domain::ports::spi::barcode::ProductBarcodeRepository;
+use crate::domain::ports::spi::product::ProductRepository;
+use crate::domain::ports::spi::variant::ProductVariantRepository;
+use crate::domain::selectors::barcode::{ProductBarcodeSelector, ScannedProductSelector};
+use crate::domain::valuables::barcode::Barcode;
+use crate::domain::valuables::product::ProductSku;
+use ids_std_domain::api::failure::{
+    CreateDomainFailure, FindManyFailure, FindOneFailure, InvalidField, UpdateDomainFailure,
+};
+use ids_std_domain::validation;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct ProductBarcodeService {
+    barcode_repo: Arc<dyn ProductBarcodeRepository>,
+    product_repo: Arc<dyn ProductRepository>,
+    variant_repo: Arc<dyn ProductVariantRepository>,
+}
+
+impl ProductBarcodeService {
+    pub fn new(
+        barcode_repo: Arc<dyn ProductBarcodeRepository>,
+        product_repo: Arc<dyn ProductRepository>,
+        variant_repo: Arc<dyn ProductVariantRepository>,
+    ) -> Self {
+        Self {
+            barcode_repo,
+            product_repo,
+            variant_repo,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl SetProductSkuUseCase for ProductBarcodeService {
+    async fn set_product_sku(
+        &self,
+        command: &SetProductSkuCommand,
+    ) -> Result<(), UpdateDomainFailure> {
+        tracing::info!("setting product sku {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let product = self.product_repo.find_by_id(command.product_id).await?;
+        if product.is_none() {
+            tracing::info!(product_id = &command.product_id, "product does not exist");
+
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "product_id".into(),
+                "product does not exist".into(),
+            )))?
+        }
+
+        let product_sku = ProductSku::new(command.sku.as_str()).get();
+        if product_sku.is_empty() {
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "sku".into(),
+                "sku must not be blank".into(),
+            )))?
+        }
+
+        let another_product = self
+            .product_repo
+            .find_other_by_sku(command.product_id, product_sku.as_str())
+            .await?;
+        let another_variant = self.variant_repo.find_by_sku(product_sku.as_str()).await?;
+
+        if another_product.is_some() || another_variant.is_some() {
+            tracing::info!(product_sku = &product_sku, "product sku already exist");
+
+            Err(UpdateDomainFailure::Conflict(
+                "product sku already exist".to_string(),
+            ))?;
+        }
+
+        let sku_updated_event = UpdateProductSku {
+            product_id: command.product_id,
+            sku: product_sku,
+        };
+        self.product_repo.update_sku(&sku_updated_event).await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl AddProductBarcodeUseCase for ProductBarcodeService {
+    async fn add_product_barcode(
+        &self,
+        command: &AddProductBarcodeCommand,
+    ) -> Result<i32, CreateDomainFailure> {
+        tracing::info!("adding product barcode {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let product = self.product_repo.find_by_id(command.product_id).await?;
+        if product.is_none() {
+            tracing::info!(product_id = &command.product_id, "product does not exist");
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "product_id".into(),
+                "product does not exist".into(),
+            )))?
+        }
+
+        let barcode = match Barcode::parse(command.code.as_str()) {
+            Ok(barcode) => barcode,
+            Err(violation) => {
+                tracing::info!(code = &command.code, "barcode is not valid");
+
+                Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                    "code".into(),
+                    violation.message().into(),
+                )))?
+            }
+        };
+
+        let another_barcode = self
+            .barcode_repo
+            .find_by_gtin(barcode.gtin().as_str())
+            .await?;
+
+        if let Some(brc) = another_barcode {
+            tracing::info!(
+                product_id = &brc.product_id,
+                gtin = &brc.gtin,
+                "product barcode already exist"
+            );
+
+            Err(CreateDomainFailure::Conflict(
+                "product barcode already exist".to_string(),
+            ))?;
+        }
+
+        let barcode_added_event = AddProductBarcode {
+            product_id: command.product_id,
+            code: barcode.code(),
+            gtin: barcode.gtin(),
+            symbology: barcode.symbology(),
+        };
+        let product_barcode_id = self.barcode_repo.save(&barcode_added_event).await?;
+
+        Ok(product_barcode_id)
+    }
+}
+
+#[async_trait::async_trait]
+impl FindProductBarcodesUseCase for ProductBarcodeService {
+    async fn find_product_barcodes(
+        &self,
+        product_id: i32,
+    ) -> Result<Vec<ProductBarcodeSelector>, FindManyFailure> {
+        tracing::info!(product_id = &product_id, "finding product barcodes");
+
+        Ok(self.barcode_repo.find_by_product(product_id).await?)
+    }
+}
+
+#[async_trait::async_trait]
+impl FindProductByBarcodeUseCase for ProductBarcodeService {
+    async fn find_product_by_barcode(
+        &self,
+        code: &str,
+    ) -> Result<ScannedProductSelector, FindOneFailure> {
+        tracing::info!(code = &code, "finding product by barcode");
+
+        let scanned_product = match Barcode::parse(code) {
+            Ok(barcode) => {
+                self.barcode_repo
+                    .find_scanned(barcode.gtin().as_str())
+                    .await?
+            }
+            Err(_) => None,
+        };
+
+        if scanned_product.is_none() {
+            tracing::info!(code = &code, "product barcode does not exist");
+
+            Err(FindOneFailure::NotFound(
+                "product barcode does not exist".to_string(),
+            ))?;
+        }
+
+        Ok(scanned_product.unwrap())
+    }
+}
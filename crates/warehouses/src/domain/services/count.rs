@@ -0,0 +1,524 @@
+use crate::domain::changes::count::{
+    AddInventoryCount, AddInventoryCountLine, CountInventoryLine, PostInventoryCount,
+    StartInventoryCount,
+};
+use crate::domain::changes::stock::AddStockMovement;
+use crate::domain::commands::count::{CountInventoryCommand, OpenInventoryCountCommand};
+use crate::domain::ports::api::count::{
+    CountInventoryUseCase, FindAllInventoryCountsUseCase, FindInventoryCountUseCase,
+    OpenInventoryCountUseCase, PostInventoryCountUseCase, ReviewInventoryCountUseCase,
+    StartInventoryCountUseCase,
+};
+use crate::domain::ports::spi::count::InventoryCountRepository;
+use crate::domain::ports::spi::family::ProductFamilyRepository;
+use crate::domain::ports::spi::location::StorageLocationRepository;
+use crate::domain::ports::spi::product::ProductRepository;
+use crate::domain::ports::spi::stock::StockMovementRepository;
+use crate::domain::ports::spi::unit::UnitOfMeasureRepository;
+use crate::domain::ports::spi::warehouse::WarehouseRepository;
+use crate::domain::queries::count::InventoryCountQuery;
+use crate::domain::selectors::count::{InventoryCountDetailsSelector, InventoryCountSelector};
+use crate::domain::services::family::find_family_descendants;
+use crate::domain::services::unit::normalise_quantity;
+use crate::domain::services::valuation::find_unit_cost;
+use crate::domain::valuables::count::{CountVariance, InventoryCountStatus};
+use crate::domain::valuables::stock::StockMovementKind;
+use crate::domain::valuables::tracking::TrackingMode;
+use ids_std_domain::api::failure::{
+    CreateDomainFailure, FindManyFailure, FindOneFailure, InvalidField, UpdateDomainFailure,
+};
+use ids_std_domain::pagination::{Page, PaginationQuery};
+use ids_std_domain::spi::failure::SelectRepoFailure;
+use ids_std_domain::validation;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct InventoryCountService {
+    count_repo: Arc<dyn InventoryCountRepository>,
+    stock_repo: Arc<dyn StockMovementRepository>,
+    product_repo: Arc<dyn ProductRepository>,
+    family_repo: Arc<dyn ProductFamilyRepository>,
+    location_repo: Arc<dyn StorageLocationRepository>,
+    warehouse_repo: Arc<dyn WarehouseRepository>,
+    unit_repo: Arc<dyn UnitOfMeasureRepository>,
+}
+
+impl InventoryCountService {
+    pub fn new(
+        count_repo: Arc<dyn InventoryCountRepository>,
+        stock_repo: Arc<dyn StockMovementRepository>,
+        product_repo: Arc<dyn ProductRepository>,
+        family_repo: Arc<dyn ProductFamilyRepository>,
+        location_repo: Arc<dyn StorageLocationRepository>,
+        warehouse_repo: Arc<dyn WarehouseRepository>,
+        unit_repo: Arc<dyn UnitOfMeasureRepository>,
+    ) -> Self {
+        Self {
+            count_repo,
+            stock_repo,
+            product_repo,
+            family_repo,
+            location_repo,
+            warehouse_repo,
+            unit_repo,
+        }
+    }
+
+    async fn find_counted_family_ids(
+        &self,
+        family_id: Option<i32>,
+    ) -> Result<Option<HashSet<i32>>, SelectRepoFailure> {
+        let Some(family_id) = family_id else {
+            return Ok(None);
+        };
+
+        let descendants = find_family_descendants(self.family_repo.as_ref(), &[family_id]).await?;
+
+        Ok(Some(
+            descendants
+                .iter()
+                .map(|family| family.product_family_id)
+                .chain([family_id])
+                .collect(),
+        ))
+    }
+
+    async fn find_count_in_status(
+        &self,
+        inventory_count_id: i32,
+        status: InventoryCountStatus,
+    ) -> Result<InventoryCountSelector, UpdateDomainFailure> {
+        let inventory_count = self.count_repo.find_by_id(inventory_count_id).await?;
+        if inventory_count.is_none() {
+            tracing::info!(
+                inventory_count_id = &inventory_count_id,
+                "inventory count does not exist"
+            );
+
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "inventory_count_id".into(),
+                "inventory count does not exist".into(),
+            )))?
+        }
+        let inventory_count = inventory_count.unwrap();
+
+        if inventory_count.status != status {
+            tracing::info!(
+                inventory_count_id = &inventory_count_id,
+                status = inventory_count.status.as_str(),
+                "inventory count is not {}",
+                status.as_str()
+            );
+
+            Err(UpdateDomainFailure::Conflict(format!(
+                "inventory count is not {}",
+                status.as_str()
+            )))?;
+        }
+
+        Ok(inventory_count)
+    }
+}
+
+#[async_trait::async_trait]
+impl OpenInventoryCountUseCase for InventoryCountService {
+    async fn open_inventory_count(
+        &self,
+        command: &OpenInventoryCountCommand,
+    ) -> Result<i32, CreateDomainFailure> {
+        tracing::info!("opening inventory count {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        if command.warehouse_id.is_none() && command.family_id.is_none() {
+            tracing::info!("inventory count without scope");
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "warehouse_id".into(),
+                "inventory count must be scoped to a warehouse or a product family".into(),
+            )))?
+        }
+
+        if let Some(warehouse_id) = command.warehouse_id {
+            let warehouse = self.warehouse_repo.find_by_id(warehouse_id).await?;
+
+            if warehouse.is_none() {
+                tracing::info!(warehouse_id = &warehouse_id, "warehouse does not exist");
+
+                Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                    "warehouse_id".into(),
+                    "warehouse does not exist".into(),
+                )))?
+            }
+        }
+
+        if let Some(family_id) = command.family_id {
+            let product_family = self.family_repo.find_by_id(family_id).await?;
+
+            if product_family.is_none() {
+                tracing::info!(
+                    product_family_id = &family_id,
+                    "product family does not exist"
+                );
+
+                Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                    "product_family_id".into(),
+                    "product family does not exist".into(),
+                )))?
+            }
+        }
+
+        let count_opened_event = AddInventoryCount {
+            warehouse_id: command.warehouse_id,
+            family_id: command.family_id,
+        };
+        let inventory_count_id = self.count_repo.save(&count_opened_event).await?;
+
+        Ok(inventory_count_id)
+    }
+}
+
+#[async_trait::async_trait]
+impl StartInventoryCountUseCase for InventoryCountService {
+    async fn start_inventory_count(
+        &self,
+        inventory_count_id: i32,
+    ) -> Result<(), UpdateDomainFailure> {
+        tracing::info!(
+            inventory_count_id = &inventory_count_id,
+            "starting inventory count"
+        );
+
+        let inventory_count = self
+            .find_count_in_status(inventory_count_id, InventoryCountStatus::Open)
+            .await?;
+
+        let family_ids = self
+            .find_counted_family_ids(inventory_count.family_id)
+            .await?;
+        let products = self
+            .product_repo
+            .find_untracked(family_ids.map(|ids| ids.into_iter().collect()))
+            .await?;
+
+        let lines = match inventory_count.warehouse_id {
+            Some(warehouse_id) => {
+                let product_ids: HashSet<i32> =
+                    products.iter().map(|product| product.product_id).collect();
+
+                self.stock_repo
+                    .find_stocked_locations_in_warehouse(warehouse_id)
+                    .await?
+                    .into_iter()
+                    .filter(|stocked| product_ids.contains(&stocked.product_id))
+                    .map(|stocked| AddInventoryCountLine {
+                        inventory_count_id,
+                        product_id: stocked.product_id,
+                        storage_location_id: Some(stocked.storage_location_id),
+                        book_quantity: stocked.on_hand,
+                        counted_quantity: None,
+                    })
+                    .collect()
+            }
+            None => {
+                let mut lines = Vec::new();
+                for product in products {
+                    let on_hand = self.stock_repo.find_on_hand(product.product_id).await?;
+
+                    if on_hand != 0 {
+                        lines.push(AddInventoryCountLine {
+                            inventory_count_id,
+                            product_id: product.product_id,
+                            storage_location_id: None,
+                            book_quantity: on_hand,
+                            counted_quantity: None,
+                        });
+                    }
+                }
+                lines
+            }
+        };
+
+        let count_started_event = StartInventoryCount {
+            inventory_count_id,
+            lines,
+        };
+        self.count_repo.start(&count_started_event).await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl CountInventoryUseCase for InventoryCountService {
+    async fn count_inventory(
+        &self,
+        command: &CountInventoryCommand,
+    ) -> Result<(), UpdateDomainFailure> {
+        tracing::info!("counting inventory {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let inventory_count = self
+            .find_count_in_status(command.inventory_count_id, InventoryCountStatus::Counting)
+            .await?;
+
+        let product = self.product_repo.find_by_id(command.product_id).await?;
+        if product.is_none() {
+            tracing::info!(product_id = &command.product_id, "product does not exist");
+
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "product_id".into(),
+                "product does not exist".into(),
+            )))?
+        }
+        let product = product.unwrap();
+
+        if product.tracking_mode != TrackingMode::None {
+            tracing::info!(
+                product_id = &command.product_id,
+                tracking_mode = product.tracking_mode.as_str(),
+                "tracked product counted"
+            );
+
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "product_id".into(),
+                "lot and serial tracked products are not counted in inventory counts".into(),
+            )))?
+        }
+
+        let family_ids = self
+            .find_counted_family_ids(inventory_count.family_id)
+            .await?;
+        if family_ids.is_some_and(|ids| !ids.contains(&product.family_id)) {
+            tracing::info!(
+                product_id = &command.product_id,
+                "product is outside of the counted family"
+            );
+
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "product_id".into(),
+                "product is outside of the counted product family".into(),
+            )))?
+        }
+
+        match (inventory_count.warehouse_id, command.storage_location_id) {
+            (Some(warehouse_id), Some(storage_location_id)) => {
+                let location = self.location_repo.find_by_id(storage_location_id).await?;
+
+                if !location.is_some_and(|location| location.warehouse_id == warehouse_id) {
+                    tracing::info!(
+                        storage_location_id = &storage_location_id,
+                        "storage location is not in the counted warehouse"
+                    );
+
+                    Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                        "storage_location_id".into(),
+                        "storage location is not in the counted warehouse".into(),
+                    )))?
+                }
+            }
+            (Some(_), None) => {
+                tracing::info!("warehouse count without storage location");
+
+                Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                    "storage_location_id".into(),
+                    "storage location is required to count a warehouse".into(),
+                )))?
+            }
+            (None, Some(_)) => {
+                tracing::info!("storage location counted outside of a warehouse count");
+
+                Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                    "storage_location_id".into(),
+                    "storage location is only counted in warehouse counts".into(),
+                )))?
+            }
+            (None, None) => {}
+        }
+
+        let counted_quantity = normalise_quantity(
+            self.unit_repo.as_ref(),
+            &product,
+            command.unit_id,
+            command.counted_quantity,
+            "counted_quantity",
+        )
+        .await?
+        .map_err(UpdateDomainFailure::InvalidField)?;
+
+        let counted_line = self
+            .count_repo
+            .find_lines(command.inventory_count_id)
+            .await?
+            .into_iter()
+            .find(|line| {
+                line.product_id == command.product_id
+                    && line.storage_location_id == command.storage_location_id
+            });
+
+        match counted_line {
+            Some(line) => {
+                let line_counted_event = CountInventoryLine {
+                    inventory_count_line_id: line.inventory_count_line_id,
+                    counted_quantity,
+                };
+                self.count_repo.count_line(&line_counted_event).await?;
+            }
+            None => {
+                let book_quantity = match command.storage_location_id {
+                    Some(storage_location_id) => {
+                        self.stock_repo
+                            .find_on_hand_at(command.product_id, storage_location_id)
+                            .await?
+                    }
+                    None => self.stock_repo.find_on_hand(command.product_id).await?,
+                };
+
+                let line_added_event = AddInventoryCountLine {
+                    inventory_count_id: command.inventory_count_id,
+                    product_id: command.product_id,
+                    storage_location_id: command.storage_location_id,
+                    book_quantity,
+                    counted_quantity: Some(counted_quantity),
+                };
+                self.count_repo.add_line(&line_added_event).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ReviewInventoryCountUseCase for InventoryCountService {
+    async fn review_inventory_count(
+        &self,
+        inventory_count_id: i32,
+    ) -> Result<(), UpdateDomainFailure> {
+        tracing::info!(
+            inventory_count_id = &inventory_count_id,
+            "reviewing inventory count"
+        );
+
+        self.find_count_in_status(inventory_count_id, InventoryCountStatus::Counting)
+            .await?;
+
+        let lines = self.count_repo.find_lines(inventory_count_id).await?;
+        if lines.iter().any(|line| line.counted_quantity.is_none()) {
+            tracing::info!(
+                inventory_count_id = &inventory_count_id,
+                "inventory count has uncounted lines"
+            );
+
+            Err(UpdateDomainFailure::Conflict(
+                "inventory count has uncounted lines".to_string(),
+            ))?;
+        }
+
+        self.count_repo.review(inventory_count_id).await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl PostInventoryCountUseCase for InventoryCountService {
+    async fn post_inventory_count(
+        &self,
+        inventory_count_id: i32,
+    ) -> Result<(), UpdateDomainFailure> {
+        tracing::info!(
+            inventory_count_id = &inventory_count_id,
+            "posting inventory count"
+        );
+
+        self.find_count_in_status(inventory_count_id, InventoryCountStatus::Reviewed)
+            .await?;
+
+        let lines = self.count_repo.find_lines(inventory_count_id).await?;
+
+        let mut adjustments = Vec::new();
+        for line in lines {
+            let Some(variance) = CountVariance::new(line.book_quantity, line.counted_quantity)
+                .get()
+                .filter(|variance| *variance != 0)
+            else {
+                continue;
+            };
+
+            let mut unit_cost = None;
+            if variance > 0 {
+                if let Some(product) = self.product_repo.find_by_id(line.product_id).await? {
+                    unit_cost = Some(find_unit_cost(self.stock_repo.as_ref(), &product).await?);
+                }
+            }
+
+            adjustments.push(AddStockMovement {
+                product_id: line.product_id,
+                kind: StockMovementKind::Adjustment,
+                quantity: variance,
+                storage_location_id: line.storage_location_id,
+                lot_number: None,
+                serial_number: None,
+                unit_cost,
+                lot_expires_on: None,
+                reason: Some(format!("inventory count {inventory_count_id}")),
+            });
+        }
+
+        let count_posted_event = PostInventoryCount {
+            inventory_count_id,
+            adjustments,
+        };
+        self.count_repo.post(&count_posted_event).await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl FindAllInventoryCountsUseCase for InventoryCountService {
+    async fn find_all_inventory_counts(
+        &self,
+        query: &PaginationQuery,
+        criteria: &InventoryCountQuery,
+    ) -> Result<Page<InventoryCountSelector>, FindManyFailure> {
+        Ok(self.count_repo.find_all(query, criteria).await?)
+    }
+}
+
+#[async_trait::async_trait]
+impl FindInventoryCountUseCase for InventoryCountService {
+    async fn find_inventory_count(
+        &self,
+        inventory_count_id: i32,
+    ) -> Result<InventoryCountDetailsSelector, FindOneFailure> {
+        let inventory_count = self.count_repo.find_by_id(inventory_count_id).await?;
+
+        if inventory_count.is_none() {
+            tracing::info!(
+                inventory_count_id = &inventory_count_id,
+                "inventory count does not exist"
+            );
+
+            Err(FindOneFailure::NotFound(
+                "inventory count does not exist".to_string(),
+            ))?;
+        }
+        let inventory_count = inventory_count.unwrap();
+
+        let lines = self.count_repo.find_lines(inventory_count_id).await?;
+
+        Ok(InventoryCountDetailsSelector {
+            inventory_count_id: inventory_count.inventory_count_id,
+            warehouse_id: inventory_count.warehouse_id,
+            family_id: inventory_count.family_id,
+            status: inventory_count.status,
+            opened_at: inventory_count.opened_at,
+            posted_at: inventory_count.posted_at,
+            lines,
+        })
+    }
+}
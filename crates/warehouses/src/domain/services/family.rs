@@ -0,0 +1,344 @@
+use crate::domain::changes::family::{AddProductFamily, UpdateProductFamily};
+use crate::domain::commands::family::{CreateProductFamilyCommand, UpdateProductFamilyCommand};
+use crate::domain::ports::api::family::{
+    CreateProductFamilyUseCase, FindAllProductFamiliesUseCase, FindProductFamilyBreadcrumbUseCase,
+    FindProductFamilySubtreeUseCase, FindProductFamilyUseCase, UpdateProductFamilyUseCase,
+};
+use crate::domain::ports::spi::family::ProductFamilyRepository;
+use crate::domain::queries::family::ProductFamilyQuery;
+use crate::domain::selectors::family::{
+    ProductFamilyPageSelector, ProductFamilySelector, ProductFamilyTreeSelector,
+};
+use crate::domain::valuables::family::{ProductFamilyName, ProductFamilySignature};
+use ids_std_domain::api::failure::{
+    CreateDomainFailure, FindManyFailure, FindOneFailure, InvalidField, UpdateDomainFailure,
+};
+use ids_std_domain::pagination::{Page, PaginationQuery};
+use ids_std_domain::spi::failure::SelectRepoFailure;
+use ids_std_domain::validation;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct ProductFamilyService {
+    family_repo: Arc<dyn ProductFamilyRepository>,
+}
+
+impl ProductFamilyService {
+    pub fn new(family_repo: Arc<dyn ProductFamilyRepository>) -> Self {
+        Self { family_repo }
+    }
+}
+
+pub(crate) async fn find_family_path(
+    family_repo: &dyn ProductFamilyRepository,
+    product_family_id: i32,
+) -> Result<Vec<ProductFamilySelector>, SelectRepoFailure> {
+    let mut path = Vec::new();
+    let mut visited = HashSet::new();
+    let mut current = Some(product_family_id);
+
+    while let Some(family_id) = current {
+        if !visited.insert(family_id) {
+            break;
+        }
+
+        match family_repo.find_by_id(family_id).await? {
+            Some(family) => {
+                current = family.parent_family_id;
+                path.push(family);
+            }
+            None => break,
+        }
+    }
+    path.reverse();
+
+    Ok(path)
+}
+
+pub(crate) async fn find_family_descendants(
+    family_repo: &dyn ProductFamilyRepository,
+    product_family_ids: &[i32],
+) -> Result<Vec<ProductFamilySelector>, SelectRepoFailure> {
+    let mut descendants = Vec::new();
+    let mut visited: HashSet<i32> = product_family_ids.iter().copied().collect();
+    let mut frontier = product_family_ids.to_vec();
+
+    while !frontier.is_empty() {
+        let children = family_repo.find_children(&frontier).await?;
+
+        frontier = Vec::new();
+        for child in children {
+            if visited.insert(child.product_family_id) {
+                frontier.push(child.product_family_id);
+                descendants.push(child);
+            }
+        }
+    }
+
+    Ok(descendants)
+}
+
+fn build_family_tree(
+    family: ProductFamilySelector,
+    children_by_parent: &mut HashMap<i32, Vec<ProductFamilySelector>>,
+) -> ProductFamilyTreeSelector {
+    let children = children_by_parent
+        .remove(&family.product_family_id)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|child| build_family_tree(child, children_by_parent))
+        .collect();
+
+    ProductFamilyTreeSelector {
+        product_family_id: family.product_family_id,
+        name: family.name,
+        summary: family.summary,
+        children,
+    }
+}
+
+#[async_trait::async_trait]
+impl CreateProductFamilyUseCase for ProductFamilyService {
+    async fn create_product_family(
+        &self,
+        command: &CreateProductFamilyCommand,
+    ) -> Result<i32, CreateDomainFailure> {
+        tracing::info!("creating product family {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        if let Some(parent_family_id) = command.parent_family_id {
+            let parent_family = self.family_repo.find_by_id(parent_family_id).await?;
+
+            if parent_family.is_none() {
+                tracing::info!(
+                    parent_family_id = &parent_family_id,
+                    "parent product family does not exist"
+                );
+
+                Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                    "parent_family_id".into(),
+                    "product family does not exist".into(),
+                )))?
+            }
+        }
+
+        let family_name = ProductFamilyName::new(command.name.as_str()).get();
+        let family_signature = ProductFamilySignature::new(command.name.as_str()).get();
+
+        let another_similar_family = self
+            .family_repo
+            .find_by_signature(family_signature.as_str())
+            .await?;
+
+        if let Some(family) = another_similar_family {
+            tracing::info!(
+                family_signature = &family.signature,
+                "product family signature already exist"
+            );
+
+            Err(CreateDomainFailure::Conflict(
+                "product family signature already exist".to_string(),
+            ))?;
+        }
+
+        let family_created_event = AddProductFamily {
+            name: family_name,
+            summary: command.summary.to_owned(),
+            parent_family_id: command.parent_family_id,
+            signature: family_signature,
+        };
+        let product_family_id = self.family_repo.save(&family_created_event).await?;
+
+        Ok(product_family_id)
+    }
+}
+
+#[async_trait::async_trait]
+impl UpdateProductFamilyUseCase for ProductFamilyService {
+    async fn update_product_family(
+        &self,
+        command: &UpdateProductFamilyCommand,
+    ) -> Result<(), UpdateDomainFailure> {
+        tracing::info!("updating product family {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let product_family = self
+            .family_repo
+            .find_by_id(command.product_family_id)
+            .await?;
+
+        if product_family.is_none() {
+            tracing::info!(
+                product_family_id = &command.product_family_id,
+                "product family does not exist"
+            );
+
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "product_family_id".into(),
+                "product family does not exist".into(),
+            )))?
+        }
+
+        if let Some(parent_family_id) = command.parent_family_id {
+            let parent_path = find_family_path(self.family_repo.as_ref(), parent_family_id).await?;
+
+            if parent_path.is_empty() {
+                tracing::info!(
+                    parent_family_id = &parent_family_id,
+                    "parent product family does not exist"
+                );
+
+                Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                    "parent_family_id".into(),
+                    "product family does not exist".into(),
+                )))?
+            }
+
+            let creates_cycle = parent_path
+                .iter()
+                .any(|family| family.product_family_id == command.product_family_id);
+
+            if creates_cycle {
+                tracing::info!(
+                    product_family_id = &command.product_family_id,
+                    parent_family_id = &parent_family_id,
+                    "product family cannot be nested under itself"
+                );
+
+                Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                    "parent_family_id".into(),
+                    "product family cannot be nested under itself".into(),
+                )))?
+            }
+        }
+
+        let family_name = ProductFamilyName::new(command.name.as_str()).get();
+        let family_signature = ProductFamilySignature::new(command.name.as_str()).get();
+
+        let another_similar_family = self
+            .family_repo
+            .find_other_by_signature(command.product_family_id, family_signature.as_str())
+            .await?;
+
+        if let Some(family) = another_similar_family {
+            tracing::info!(
+                family_signature = &family.signature,
+                "product family signature already exist"
+            );
+
+            Err(UpdateDomainFailure::Conflict(
+                "product family signature already exist".to_string(),
+            ))?;
+        }
+
+        let family_updated_event = UpdateProductFamily {
+            product_family_id: command.product_family_id,
+            name: family_name,
+            summary: command.summary.to_owned(),
+            parent_family_id: command.parent_family_id,
+            signature: family_signature,
+        };
+        self.family_repo.update(&family_updated_event).await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl FindAllProductFamiliesUseCase for ProductFamilyService {
+    async fn find_all_product_families(
+        &self,
+        query: &PaginationQuery,
+        criteria: &ProductFamilyQuery,
+    ) -> Result<Page<ProductFamilyPageSelector>, FindManyFailure> {
+        Ok(self.family_repo.find_all(query, criteria).await?)
+    }
+}
+
+#[async_trait::async_trait]
+impl FindProductFamilyUseCase for ProductFamilyService {
+    async fn find_product_family(
+        &self,
+        product_family_id: i32,
+    ) -> Result<ProductFamilySelector, FindOneFailure> {
+        let product_family = self.family_repo.find_by_id(product_family_id).await?;
+
+        if product_family.is_none() {
+            tracing::info!(
+                product_family_id = &product_family_id,
+                "product family does not exist"
+            );
+
+            Err(FindOneFailure::NotFound(
+                "product family does not exist".to_string(),
+            ))?;
+        }
+
+        Ok(product_family.unwrap())
+    }
+}
+
+#[async_trait::async_trait]
+impl FindProductFamilySubtreeUseCase for ProductFamilyService {
+    async fn find_product_family_subtree(
+        &self,
+        product_family_id: i32,
+    ) -> Result<ProductFamilyTreeSelector, FindOneFailure> {
+        let product_family = self.family_repo.find_by_id(product_family_id).await?;
+
+        if product_family.is_none() {
+            tracing::info!(
+                product_family_id = &product_family_id,
+                "product family does not exist"
+            );
+
+            Err(FindOneFailure::NotFound(
+                "product family does not exist".to_string(),
+            ))?;
+        }
+
+        let descendants =
+            find_family_descendants(self.family_repo.as_ref(), &[product_family_id]).await?;
+
+        let mut children_by_parent: HashMap<i32, Vec<ProductFamilySelector>> = HashMap::new();
+        for family in descendants {
+            if let Some(parent_family_id) = family.parent_family_id {
+                children_by_parent
+                    .entry(parent_family_id)
+                    .or_default()
+                    .push(family);
+            }
+        }
+
+        Ok(build_family_tree(
+            product_family.unwrap(),
+            &mut children_by_parent,
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl FindProductFamilyBreadcrumbUseCase for ProductFamilyService {
+    async fn find_product_family_breadcrumb(
+        &self,
+        product_family_id: i32,
+    ) -> Result<Vec<ProductFamilySelector>, FindOneFailure> {
+        let path = find_family_path(self.family_repo.as_ref(), product_family_id).await?;
+
+        if path.is_empty() {
+            tracing::info!(
+                product_family_id = &product_family_id,
+                "product family does not exist"
+            );
+
+            Err(FindOneFailure::NotFound(
+                "product family does not exist".to_string(),
+            ))?;
+        }
+
+        Ok(path)
+    }
+}
@@ -0,0 +1,475 @@
+use crate::domain::changes::kit::AddKitComponent;
+use crate::domain::changes::stock::AddStockMovement;
+use crate::domain::commands::kit::{AddKitComponentCommand, AssembleKitCommand};
+use crate::domain::ports::api::kit::{
+    AddKitComponentUseCase, AssembleKitUseCase, FindKitAvailabilityUseCase,
+    FindKitComponentsUseCase,
+};
+use crate::domain::ports::spi::kit::KitComponentRepository;
+use crate::domain::ports::spi::location::StorageLocationRepository;
+use crate::domain::ports::spi::product::ProductRepository;
+use crate::domain::ports::spi::stock::StockMovementRepository;
+use crate::domain::selectors::kit::{
+    ComponentAvailabilitySelector, KitAvailabilitySelector, KitComponentSelector,
+};
+use crate::domain::services::valuation::find_issue_cost;
+use crate::domain::valuables::kit::{KitIssue, KitStructure};
+use crate::domain::valuables::money::Money;
+use crate::domain::valuables::stock::StockMovementKind;
+use crate::domain::valuables::tracking::TrackingMode;
+use ids_std_domain::api::failure::{
+    CreateDomainFailure, FindManyFailure, FindOneFailure, InvalidField,
+};
+use ids_std_domain::spi::failure::SelectRepoFailure;
+use ids_std_domain::validation;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct KitService {
+    component_repo: Arc<dyn KitComponentRepository>,
+    product_repo: Arc<dyn ProductRepository>,
+    stock_repo: Arc<dyn StockMovementRepository>,
+    location_repo: Arc<dyn StorageLocationRepository>,
+}
+
+impl KitService {
+    pub fn new(
+        component_repo: Arc<dyn KitComponentRepository>,
+        product_repo: Arc<dyn ProductRepository>,
+        stock_repo: Arc<dyn StockMovementRepository>,
+        location_repo: Arc<dyn StorageLocationRepository>,
+    ) -> Self {
+        Self {
+            component_repo,
+            product_repo,
+            stock_repo,
+            location_repo,
+        }
+    }
+}
+
+pub(crate) async fn find_kit_structure(
+    component_repo: &dyn KitComponentRepository,
+    product_id: i32,
+) -> Result<KitStructure, SelectRepoFailure> {
+    find_kit_structure_of(component_repo, &[product_id]).await
+}
+
+async fn find_kit_structure_of(
+    component_repo: &dyn KitComponentRepository,
+    product_ids: &[i32],
+) -> Result<KitStructure, SelectRepoFailure> {
+    let mut components: HashMap<i32, Vec<(i32, i32)>> = HashMap::new();
+    let mut visited: HashSet<i32> = product_ids.iter().copied().collect();
+    let mut frontier = product_ids.to_vec();
+
+    while !frontier.is_empty() {
+        let kit_components = component_repo.find_by_kits(&frontier).await?;
+
+        frontier = Vec::new();
+        for component in kit_components {
+            if visited.insert(component.component_product_id) {
+                frontier.push(component.component_product_id);
+            }
+            components
+                .entry(component.kit_product_id)
+                .or_default()
+                .push((component.component_product_id, component.quantity));
+        }
+    }
+
+    Ok(KitStructure::new(components))
+}
+
+pub(crate) async fn check_kit_structure(
+    product_repo: &dyn ProductRepository,
+    structure: &KitStructure,
+    product_id: i32,
+) -> Result<Result<(), String>, SelectRepoFailure> {
+    // Components are only validated when added, the structure or their
+    // tracking may have changed since, so re-check before moving stock.
+    if structure.is_cyclic() {
+        tracing::info!(product_id = &product_id, "kit contains itself");
+
+        return Ok(Err("kit cannot contain itself".to_string()));
+    }
+
+    let products = product_repo.find_by_ids(structure.product_ids()).await?;
+    let tracked = products
+        .iter()
+        .find(|product| product.tracking_mode != TrackingMode::None);
+    if let Some(tracked) = tracked {
+        tracing::info!(
+            product_id = &product_id,
+            component_id = &tracked.product_id,
+            tracking_mode = tracked.tracking_mode.as_str(),
+            "tracked product used in a kit"
+        );
+
+        return Ok(Err(
+            "kit components must not be tracked by lot or serial".to_string()
+        ));
+    }
+
+    Ok(Ok(()))
+}
+
+pub(crate) async fn find_on_hand_of_products(
+    stock_repo: &dyn StockMovementRepository,
+    product_ids: &[i32],
+    storage_location_id: Option<i32>,
+) -> Result<HashMap<i32, i32>, SelectRepoFailure> {
+    let mut on_hand = HashMap::new();
+    for product_id in product_ids {
+        let quantity = match storage_location_id {
+            Some(storage_location_id) => {
+                stock_repo
+                    .find_on_hand_at(*product_id, storage_location_id)
+                    .await?
+            }
+            None => stock_repo.find_on_hand(*product_id).await?,
+        };
+        on_hand.insert(*product_id, quantity);
+    }
+
+    Ok(on_hand)
+}
+
+pub(crate) async fn find_shared_kit_structure(
+    component_repo: &dyn KitComponentRepository,
+    product_id: i32,
+) -> Result<KitStructure, SelectRepoFailure> {
+    // Kits built from the same components compete for their stock, so walk up
+    // from every product in the structure to the kits that consume it.
+    let structure = find_kit_structure(component_repo, product_id).await?;
+    let mut visited: HashSet<i32> = structure.product_ids().into_iter().collect();
+    visited.insert(product_id);
+    let mut kit_product_ids = vec![product_id];
+    let mut frontier: Vec<i32> = visited.iter().copied().collect();
+
+    while !frontier.is_empty() {
+        let kit_components = component_repo.find_by_components(&frontier).await?;
+
+        frontier = Vec::new();
+        for component in kit_components {
+            if visited.insert(component.kit_product_id) {
+                kit_product_ids.push(component.kit_product_id);
+                frontier.push(component.kit_product_id);
+            }
+        }
+    }
+
+    find_kit_structure_of(component_repo, &kit_product_ids).await
+}
+
+pub(crate) fn kit_issue_movement(
+    issue: &KitIssue,
+    storage_location_id: Option<i32>,
+    reason: Option<String>,
+) -> AddStockMovement {
+    AddStockMovement {
+        product_id: issue.product_id,
+        kind: StockMovementKind::Issue,
+        quantity: -issue.quantity,
+        storage_location_id,
+        lot_number: None,
+        serial_number: None,
+        unit_cost: None,
+        lot_expires_on: None,
+        reason,
+    }
+}
+
+#[async_trait::async_trait]
+impl AddKitComponentUseCase for KitService {
+    async fn add_kit_component(
+        &self,
+        command: &AddKitComponentCommand,
+    ) -> Result<i32, CreateDomainFailure> {
+        tracing::info!("adding kit component {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let kit = self.product_repo.find_by_id(command.kit_product_id).await?;
+        let Some(kit) = kit else {
+            tracing::info!(
+                product_id = &command.kit_product_id,
+                "product does not exist"
+            );
+
+            return Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "product_id".into(),
+                "product does not exist".into(),
+            )));
+        };
+
+        if kit.tracking_mode != TrackingMode::None {
+            tracing::info!(
+                product_id = &command.kit_product_id,
+                tracking_mode = kit.tracking_mode.as_str(),
+                "tracked product used as a kit"
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "product_id".into(),
+                "kit must not be tracked by lot or serial".into(),
+            )))?
+        }
+
+        let component = self
+            .product_repo
+            .find_by_id(command.component_product_id)
+            .await?;
+        let Some(component) = component else {
+            tracing::info!(
+                component_id = &command.component_product_id,
+                "component does not exist"
+            );
+
+            return Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "component_id".into(),
+                "component does not exist".into(),
+            )));
+        };
+
+        if component.tracking_mode != TrackingMode::None {
+            tracing::info!(
+                component_id = &command.component_product_id,
+                tracking_mode = component.tracking_mode.as_str(),
+                "tracked product used as a component"
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "component_id".into(),
+                "component must not be tracked by lot or serial".into(),
+            )))?
+        }
+
+        let component_structure =
+            find_kit_structure(self.component_repo.as_ref(), command.component_product_id).await?;
+        let creates_cycle = command.component_product_id == command.kit_product_id
+            || component_structure.contains(command.kit_product_id);
+
+        if creates_cycle {
+            tracing::info!(
+                product_id = &command.kit_product_id,
+                component_id = &command.component_product_id,
+                "kit cannot contain itself"
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "component_id".into(),
+                "kit cannot contain itself".into(),
+            )))?
+        }
+
+        let kit_component = self
+            .component_repo
+            .find_component(command.kit_product_id, command.component_product_id)
+            .await?;
+
+        if let Some(cmp) = kit_component {
+            tracing::info!(
+                product_component_id = &cmp.product_component_id,
+                "kit component already exist"
+            );
+
+            Err(CreateDomainFailure::Conflict(
+                "kit component already exist".to_string(),
+            ))?;
+        }
+
+        let kit_component_added_event = AddKitComponent {
+            kit_product_id: command.kit_product_id,
+            component_product_id: command.component_product_id,
+            quantity: command.quantity,
+        };
+        let product_component_id = self.component_repo.save(&kit_component_added_event).await?;
+
+        Ok(product_component_id)
+    }
+}
+
+#[async_trait::async_trait]
+impl FindKitComponentsUseCase for KitService {
+    async fn find_kit_components(
+        &self,
+        kit_product_id: i32,
+    ) -> Result<Vec<KitComponentSelector>, FindManyFailure> {
+        Ok(self.component_repo.find_by_kits(&[kit_product_id]).await?)
+    }
+}
+
+#[async_trait::async_trait]
+impl FindKitAvailabilityUseCase for KitService {
+    async fn find_kit_availability(
+        &self,
+        kit_product_id: i32,
+    ) -> Result<KitAvailabilitySelector, FindOneFailure> {
+        tracing::info!(product_id = &kit_product_id, "finding kit availability");
+
+        let product = self.product_repo.find_by_id(kit_product_id).await?;
+        if product.is_none() {
+            tracing::info!(product_id = &kit_product_id, "product does not exist");
+
+            Err(FindOneFailure::NotFound(
+                "product does not exist".to_string(),
+            ))?;
+        }
+
+        let structure = find_kit_structure(self.component_repo.as_ref(), kit_product_id).await?;
+        let mut product_ids = structure.product_ids();
+        if !product_ids.contains(&kit_product_id) {
+            product_ids.push(kit_product_id);
+        }
+        let on_hand =
+            find_on_hand_of_products(self.stock_repo.as_ref(), &product_ids, None).await?;
+
+        let components = structure
+            .components(kit_product_id)
+            .iter()
+            .map(
+                |(component_product_id, quantity)| ComponentAvailabilitySelector {
+                    component_product_id: *component_product_id,
+                    quantity: *quantity,
+                    available: structure.available(*component_product_id, &on_hand),
+                },
+            )
+            .collect();
+
+        Ok(KitAvailabilitySelector {
+            product_id: kit_product_id,
+            on_hand: on_hand[&kit_product_id],
+            buildable: structure.buildable(kit_product_id, &on_hand),
+            available: structure.available(kit_product_id, &on_hand),
+            components,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AssembleKitUseCase for KitService {
+    async fn assemble_kit(&self, command: &AssembleKitCommand) -> Result<i32, CreateDomainFailure> {
+        tracing::info!("assembling kit {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let kit = self.product_repo.find_by_id(command.kit_product_id).await?;
+        if kit.is_none() {
+            tracing::info!(
+                product_id = &command.kit_product_id,
+                "product does not exist"
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "product_id".into(),
+                "product does not exist".into(),
+            )))?
+        }
+
+        if let Some(storage_location_id) = command.storage_location_id {
+            let location = self.location_repo.find_by_id(storage_location_id).await?;
+
+            if location.is_none() {
+                tracing::info!(
+                    storage_location_id = &storage_location_id,
+                    "storage location does not exist"
+                );
+
+                Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                    "storage_location_id".into(),
+                    "storage location does not exist".into(),
+                )))?
+            }
+        }
+
+        let structure =
+            find_kit_structure(self.component_repo.as_ref(), command.kit_product_id).await?;
+        if !structure.is_kit(command.kit_product_id) {
+            tracing::info!(
+                product_id = &command.kit_product_id,
+                "product has no components"
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "product_id".into(),
+                "product has no components".into(),
+            )))?
+        }
+
+        check_kit_structure(
+            self.product_repo.as_ref(),
+            &structure,
+            command.kit_product_id,
+        )
+        .await?
+        .map_err(CreateDomainFailure::Conflict)?;
+
+        let on_hand = find_on_hand_of_products(
+            self.stock_repo.as_ref(),
+            &structure.product_ids(),
+            command.storage_location_id,
+        )
+        .await?;
+
+        let buildable = structure.buildable(command.kit_product_id, &on_hand);
+        if buildable < command.quantity {
+            tracing::info!(
+                product_id = &command.kit_product_id,
+                buildable = &buildable,
+                "insufficient component stock"
+            );
+
+            Err(CreateDomainFailure::Conflict(
+                "insufficient component stock".to_string(),
+            ))?;
+        }
+
+        let issues = structure.assemble(command.kit_product_id, command.quantity, &on_hand);
+        let reason = format!("kit assembly {}", command.kit_product_id);
+
+        let mut issued_quantities: BTreeMap<i32, i32> = BTreeMap::new();
+        for issue in &issues {
+            *issued_quantities.entry(issue.product_id).or_default() += issue.quantity;
+        }
+
+        let issued_products = self
+            .product_repo
+            .find_by_ids(issued_quantities.keys().copied().collect())
+            .await?;
+        let mut assembly_cost = 0;
+        for product in &issued_products {
+            let issued_cost = find_issue_cost(
+                self.stock_repo.as_ref(),
+                product,
+                issued_quantities[&product.product_id],
+            )
+            .await?;
+            assembly_cost += issued_cost.minor();
+        }
+
+        let mut kit_assembled_events: Vec<AddStockMovement> = issues
+            .iter()
+            .map(|issue| {
+                kit_issue_movement(issue, command.storage_location_id, Some(reason.to_owned()))
+            })
+            .collect();
+        kit_assembled_events.push(AddStockMovement {
+            product_id: command.kit_product_id,
+            kind: StockMovementKind::Receipt,
+            quantity: command.quantity,
+            storage_location_id: command.storage_location_id,
+            lot_number: None,
+            serial_number: None,
+            unit_cost: Some(Money::from_minor(
+                assembly_cost / i64::from(command.quantity),
+            )),
+            lot_expires_on: None,
+            reason: Some(reason),
+        });
+        let stock_movement_ids = self.stock_repo.save_all(&kit_assembled_events).await?;
+
+        Ok(stock_movement_ids[stock_movement_ids.len() - 1])
+    }
+}
@@ -0,0 +1,190 @@
+use crate::domain::changes::location::{AddStorageLocation, UpdateStorageLocation};
+use crate::domain::commands::location::{
+    CreateStorageLocationCommand, UpdateStorageLocationCommand,
+};
+use crate::domain::ports::api::location::{
+    CreateStorageLocationUseCase, FindStorageLocationUseCase, FindWarehouseLocationsUseCase,
+    UpdateStorageLocationUseCase,
+};
+use crate::domain::ports::spi::location::StorageLocationRepository;
+use crate::domain::ports::spi::warehouse::WarehouseRepository;
+use crate::domain::selectors::location::{StorageLocationPageSelector, StorageLocationSelector};
+use crate::domain::valuables::warehouse::StorageCode;
+use ids_std_domain::api::failure::{
+    CreateDomainFailure, FindManyFailure, FindOneFailure, InvalidField, UpdateDomainFailure,
+};
+use ids_std_domain::pagination::{Page, PaginationQuery};
+use ids_std_domain::validation;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct StorageLocationService {
+    location_repo: Arc<dyn StorageLocationRepository>,
+    warehouse_repo: Arc<dyn WarehouseRepository>,
+}
+
+impl StorageLocationService {
+    pub fn new(
+        location_repo: Arc<dyn StorageLocationRepository>,
+        warehouse_repo: Arc<dyn WarehouseRepository>,
+    ) -> Self {
+        Self {
+            location_repo,
+            warehouse_repo,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl CreateStorageLocationUseCase for StorageLocationService {
+    async fn create_storage_location(
+        &self,
+        command: &CreateStorageLocationCommand,
+    ) -> Result<i32, CreateDomainFailure> {
+        tracing::info!("creating storage location {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let warehouse = self.warehouse_repo.find_by_id(command.warehouse_id).await?;
+
+        if warehouse.is_none() {
+            tracing::info!(
+                warehouse_id = &command.warehouse_id,
+                "warehouse does not exist"
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "warehouse_id".into(),
+                "warehouse does not exist".into(),
+            )))?
+        }
+
+        let aisle = StorageCode::new(command.aisle.as_str()).get();
+        let shelf = StorageCode::new(command.shelf.as_str()).get();
+        let bin = StorageCode::new(command.bin.as_str()).get();
+
+        let another_location = self
+            .location_repo
+            .find_by_address(command.warehouse_id, &aisle, &shelf, &bin)
+            .await?;
+
+        if let Some(location) = another_location {
+            tracing::info!(
+                storage_location_id = &location.storage_location_id,
+                "storage location already exist"
+            );
+
+            Err(CreateDomainFailure::Conflict(
+                "storage location already exist".to_string(),
+            ))?;
+        }
+
+        let location_created_event = AddStorageLocation {
+            warehouse_id: command.warehouse_id,
+            aisle,
+            shelf,
+            bin,
+        };
+        let storage_location_id = self.location_repo.save(&location_created_event).await?;
+
+        Ok(storage_location_id)
+    }
+}
+
+#[async_trait::async_trait]
+impl UpdateStorageLocationUseCase for StorageLocationService {
+    async fn update_storage_location(
+        &self,
+        command: &UpdateStorageLocationCommand,
+    ) -> Result<(), UpdateDomainFailure> {
+        tracing::info!("updating storage location {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let location = self
+            .location_repo
+            .find_by_id(command.storage_location_id)
+            .await?;
+
+        if location.is_none() {
+            tracing::info!(
+                storage_location_id = &command.storage_location_id,
+                "storage location does not exist"
+            );
+
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "storage_location_id".into(),
+                "storage location does not exist".into(),
+            )))?
+        }
+
+        let aisle = StorageCode::new(command.aisle.as_str()).get();
+        let shelf = StorageCode::new(command.shelf.as_str()).get();
+        let bin = StorageCode::new(command.bin.as_str()).get();
+
+        let another_location = self
+            .location_repo
+            .find_by_address(location.unwrap().warehouse_id, &aisle, &shelf, &bin)
+            .await?;
+
+        if let Some(location) = another_location {
+            if location.storage_location_id != command.storage_location_id {
+                tracing::info!(
+                    storage_location_id = &location.storage_location_id,
+                    "storage location already exist"
+                );
+
+                Err(UpdateDomainFailure::Conflict(
+                    "storage location already exist".to_string(),
+                ))?;
+            }
+        }
+
+        let location_updated_event = UpdateStorageLocation {
+            storage_location_id: command.storage_location_id,
+            aisle,
+            shelf,
+            bin,
+        };
+        self.location_repo.update(&location_updated_event).await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl FindWarehouseLocationsUseCase for StorageLocationService {
+    async fn find_warehouse_locations(
+        &self,
+        query: &PaginationQuery,
+        warehouse_id: i32,
+    ) -> Result<Page<StorageLocationPageSelector>, FindManyFailure> {
+        Ok(self
+            .location_repo
+            .find_by_warehouse(query, warehouse_id)
+            .await?)
+    }
+}
+
+#[async_trait::async_trait]
+impl FindStorageLocationUseCase for StorageLocationService {
+    async fn find_storage_location(
+        &self,
+        storage_location_id: i32,
+    ) -> Result<StorageLocationSelector, FindOneFailure> {
+        let location = self.location_repo.find_by_id(storage_location_id).await?;
+
+        if location.is_none() {
+            tracing::info!(
+                storage_location_id = &storage_location_id,
+                "storage location does not exist"
+            );
+
+            Err(FindOneFailure::NotFound(
+                "storage location does not exist".to_string(),
+            ))?;
+        }
+
+        Ok(location.unwrap())
+    }
+}
@@ -0,0 +1,135 @@
+use crate::domain::ports::api::lot::{FindExpiringLotsUseCase, PlanFefoPickUseCase};
+use crate::domain::ports::spi::lot::StockLotRepository;
+use crate::domain::ports::spi::product::ProductRepository;
+use crate::domain::ports::spi::stock::StockMovementRepository;
+use crate::domain::queries::lot::{ExpiringLotQuery, FefoPickQuery};
+use crate::domain::selectors::lot::{ExpiringLotSelector, FefoPickSelector, LotPickSelector};
+use crate::domain::valuables::lot::{FefoPlan, LotExpiry, LotStock};
+use crate::domain::valuables::tracking::TrackingMode;
+use ids_std_domain::api::failure::FindOneFailure;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct StockLotService {
+    lot_repo: Arc<dyn StockLotRepository>,
+    stock_repo: Arc<dyn StockMovementRepository>,
+    product_repo: Arc<dyn ProductRepository>,
+}
+
+impl StockLotService {
+    pub fn new(
+        lot_repo: Arc<dyn StockLotRepository>,
+        stock_repo: Arc<dyn StockMovementRepository>,
+        product_repo: Arc<dyn ProductRepository>,
+    ) -> Self {
+        Self {
+            lot_repo,
+            stock_repo,
+            product_repo,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl FindExpiringLotsUseCase for StockLotService {
+    async fn find_expiring_lots(
+        &self,
+        criteria: &ExpiringLotQuery,
+    ) -> Result<Vec<ExpiringLotSelector>, FindOneFailure> {
+        let today = chrono::Utc::now().date_naive();
+        let until = today + chrono::Duration::days(criteria.within_days.max(0));
+
+        let lots = self.lot_repo.find_expiring(until).await?;
+
+        let product_ids: BTreeSet<i32> = lots.iter().map(|lot| lot.product_id).collect();
+        let mut on_hand_by_lot: HashMap<(i32, String), i32> = HashMap::new();
+        for product_id in product_ids {
+            for lot in self.stock_repo.find_on_hand_by_lot(product_id).await? {
+                on_hand_by_lot.insert((product_id, lot.lot_number), lot.on_hand);
+            }
+        }
+
+        Ok(lots
+            .into_iter()
+            .filter_map(|lot| {
+                let on_hand = on_hand_by_lot
+                    .get(&(lot.product_id, lot.lot_number.to_owned()))
+                    .copied()
+                    .unwrap_or_default();
+
+                (on_hand > 0).then(|| ExpiringLotSelector {
+                    expired: LotExpiry::new(lot.expires_on).is_expired_on(today),
+                    product_id: lot.product_id,
+                    lot_number: lot.lot_number,
+                    expires_on: lot.expires_on,
+                    on_hand,
+                })
+            })
+            .collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl PlanFefoPickUseCase for StockLotService {
+    async fn plan_fefo_pick(
+        &self,
+        product_id: i32,
+        criteria: &FefoPickQuery,
+    ) -> Result<FefoPickSelector, FindOneFailure> {
+        let product = self.product_repo.find_by_id(product_id).await?;
+
+        if !product.is_some_and(|product| product.tracking_mode == TrackingMode::Lot) {
+            tracing::info!(
+                product_id = &product_id,
+                "lot tracked product does not exist"
+            );
+
+            Err(FindOneFailure::NotFound(
+                "lot tracked product does not exist".to_string(),
+            ))?;
+        }
+
+        let expiry_by_lot: HashMap<String, chrono::NaiveDate> = self
+            .lot_repo
+            .find_by_product(product_id)
+            .await?
+            .into_iter()
+            .map(|lot| (lot.lot_number, lot.expires_on))
+            .collect();
+
+        let lots: Vec<LotStock> = self
+            .stock_repo
+            .find_on_hand_by_lot(product_id)
+            .await?
+            .into_iter()
+            .map(|lot| LotStock {
+                expires_on: expiry_by_lot.get(&lot.lot_number).copied(),
+                lot_number: lot.lot_number,
+                on_hand: lot.on_hand,
+            })
+            .collect();
+
+        let plan = FefoPlan::new(
+            &lots,
+            criteria.quantity,
+            chrono::Utc::now().date_naive(),
+            criteria.override_reason.is_some(),
+        );
+
+        Ok(FefoPickSelector {
+            product_id,
+            quantity: criteria.quantity,
+            picks: plan
+                .picks()
+                .iter()
+                .map(|pick| LotPickSelector {
+                    lot_number: pick.lot_number.to_owned(),
+                    expires_on: pick.expires_on,
+                    quantity: pick.quantity,
+                })
+                .collect(),
+            shortage: plan.shortage(),
+        })
+    }
+}
@@ -0,0 +1,254 @@
+use crate::domain::changes::price::{AddPriceList, AddProductPrice};
+use crate::domain::commands::price::{AddProductPriceCommand, CreatePriceListCommand};
+use crate::domain::ports::api::price::{
+    AddProductPriceUseCase, CreatePriceListUseCase, FindAllPriceListsUseCase,
+    ResolveProductPriceUseCase,
+};
+use crate::domain::ports::spi::price::PriceListRepository;
+use crate::domain::ports::spi::product::ProductRepository;
+use crate::domain::queries::price::ProductPriceQuery;
+use crate::domain::selectors::price::{PriceListSelector, ResolvedPriceSelector};
+use crate::domain::valuables::money::{CurrencyCode, Money};
+use crate::domain::valuables::price::PriceValidity;
+use ids_std_domain::api::failure::{
+    CreateDomainFailure, FindManyFailure, FindOneFailure, InvalidField,
+};
+use ids_std_domain::validation;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct PriceListService {
+    price_repo: Arc<dyn PriceListRepository>,
+    product_repo: Arc<dyn ProductRepository>,
+}
+
+impl PriceListService {
+    pub fn new(
+        price_repo: Arc<dyn PriceListRepository>,
+        product_repo: Arc<dyn ProductRepository>,
+    ) -> Self {
+        Self {
+            price_repo,
+            product_repo,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl CreatePriceListUseCase for PriceListService {
+    async fn create_price_list(
+        &self,
+        command: &CreatePriceListCommand,
+    ) -> Result<i32, CreateDomainFailure> {
+        tracing::info!("creating price list {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let currency = CurrencyCode::parse(command.currency.as_str());
+        if currency.is_none() {
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "currency".into(),
+                "currency must be a three letter ISO 4217 code".into(),
+            )))?
+        }
+
+        if PriceValidity::new(command.valid_from, command.valid_to).is_none() {
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "valid_to".into(),
+                "validity cannot end before it starts".into(),
+            )))?
+        }
+
+        let another_price_list = self.price_repo.find_by_name(command.name.as_str()).await?;
+
+        if let Some(lst) = another_price_list {
+            tracing::info!(
+                price_list_id = &lst.price_list_id,
+                "price list already exist"
+            );
+
+            Err(CreateDomainFailure::Conflict(
+                "price list already exist".to_string(),
+            ))?;
+        }
+
+        let price_list_created_event = AddPriceList {
+            name: command.name.to_owned(),
+            kind: command.kind,
+            currency: currency.unwrap().get(),
+            valid_from: command.valid_from,
+            valid_to: command.valid_to,
+        };
+        let price_list_id = self.price_repo.save(&price_list_created_event).await?;
+
+        Ok(price_list_id)
+    }
+}
+
+#[async_trait::async_trait]
+impl FindAllPriceListsUseCase for PriceListService {
+    async fn find_all_price_lists(&self) -> Result<Vec<PriceListSelector>, FindManyFailure> {
+        Ok(self.price_repo.find_all().await?)
+    }
+}
+
+#[async_trait::async_trait]
+impl AddProductPriceUseCase for PriceListService {
+    async fn add_product_price(
+        &self,
+        command: &AddProductPriceCommand,
+    ) -> Result<i32, CreateDomainFailure> {
+        tracing::info!("adding product price {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let price_list = self.price_repo.find_by_id(command.price_list_id).await?;
+        if price_list.is_none() {
+            tracing::info!(
+                price_list_id = &command.price_list_id,
+                "price list does not exist"
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "price_list_id".into(),
+                "price list does not exist".into(),
+            )))?
+        }
+
+        let product = self.product_repo.find_by_id(command.product_id).await?;
+        if product.is_none() {
+            tracing::info!(product_id = &command.product_id, "product does not exist");
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "product_id".into(),
+                "product does not exist".into(),
+            )))?
+        }
+
+        let amount = match Money::parse(command.amount.as_str()) {
+            Some(amount) if !amount.is_negative() => amount,
+            _ => Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "amount".into(),
+                "amount must be a positive decimal with up to four decimal places".into(),
+            )))?,
+        };
+
+        let validity = match PriceValidity::new(command.valid_from, command.valid_to) {
+            Some(validity) => validity,
+            None => Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "valid_to".into(),
+                "validity cannot end before it starts".into(),
+            )))?,
+        };
+
+        let prices = self
+            .price_repo
+            .find_prices(command.price_list_id, command.product_id)
+            .await?;
+
+        let overlapping_price = prices.iter().find(|price| {
+            PriceValidity::new(price.valid_from, price.valid_to)
+                .is_some_and(|other| validity.overlaps(&other))
+        });
+
+        if let Some(prc) = overlapping_price {
+            tracing::info!(
+                product_price_id = &prc.product_price_id,
+                "product price overlaps an existing price"
+            );
+
+            Err(CreateDomainFailure::Conflict(
+                "product price overlaps an existing price".to_string(),
+            ))?;
+        }
+
+        let price_added_event = AddProductPrice {
+            price_list_id: command.price_list_id,
+            product_id: command.product_id,
+            amount,
+            valid_from: command.valid_from,
+            valid_to: command.valid_to,
+        };
+        let product_price_id = self.price_repo.save_price(&price_added_event).await?;
+
+        Ok(product_price_id)
+    }
+}
+
+#[async_trait::async_trait]
+impl ResolveProductPriceUseCase for PriceListService {
+    async fn resolve_product_price(
+        &self,
+        product_id: i32,
+        query: &ProductPriceQuery,
+    ) -> Result<ResolvedPriceSelector, FindOneFailure> {
+        tracing::info!(
+            product_id = &product_id,
+            "resolving product price {:?}",
+            query
+        );
+
+        let date = query
+            .date
+            .unwrap_or_else(|| chrono::Utc::now().date_naive());
+
+        let price_list = self.price_repo.find_by_id(query.price_list_id).await?;
+        if price_list.is_none() {
+            tracing::info!(
+                price_list_id = &query.price_list_id,
+                "price list does not exist"
+            );
+
+            Err(FindOneFailure::NotFound(
+                "price list does not exist".to_string(),
+            ))?;
+        }
+        let price_list = price_list.unwrap();
+
+        let list_is_valid = PriceValidity::new(price_list.valid_from, price_list.valid_to)
+            .is_some_and(|validity| validity.contains(date));
+        if !list_is_valid {
+            tracing::info!(
+                price_list_id = &query.price_list_id,
+                "price list is not valid on the date"
+            );
+
+            Err(FindOneFailure::NotFound(
+                "price list is not valid on the date".to_string(),
+            ))?;
+        }
+
+        let prices = self
+            .price_repo
+            .find_prices(query.price_list_id, product_id)
+            .await?;
+
+        let applicable_price = prices
+            .into_iter()
+            .filter(|price| {
+                PriceValidity::new(price.valid_from, price.valid_to)
+                    .is_some_and(|validity| validity.contains(date))
+            })
+            .max_by_key(|price| price.valid_from);
+
+        if applicable_price.is_none() {
+            tracing::info!(product_id = &product_id, "product price does not exist");
+
+            Err(FindOneFailure::NotFound(
+                "product price does not exist".to_string(),
+            ))?;
+        }
+        let applicable_price = applicable_price.unwrap();
+
+        Ok(ResolvedPriceSelector {
+            product_id,
+            price_list_id: price_list.price_list_id,
+            price_list_name: price_list.name,
+            kind: price_list.kind,
+            currency: price_list.currency,
+            amount: applicable_price.amount,
+            valid_from: applicable_price.valid_from,
+            valid_to: applicable_price.valid_to,
+        })
+    }
+}
@@ -1,10 +1,21 @@
-use crate::domain::changes::product::AddProduct;
-use crate::domain::commands::product::CreateProductCommand;
-use crate::domain::ports::api::product::CreateProductUseCase;
+use crate::domain::changes::product::{AddProduct, UpdateProduct};
+use crate::domain::commands::product::{CreateProductCommand, UpdateProductCommand};
+use crate::domain::ports::api::product::{
+    CreateProductUseCase, DiscontinueProductUseCase, FindAllProductsUseCase,
+    FindProductDetailsUseCase, UpdateProductUseCase,
+};
 use crate::domain::ports::spi::family::ProductFamilyRepository;
+use crate::domain::ports::spi::kit::KitComponentRepository;
 use crate::domain::ports::spi::product::ProductRepository;
+use crate::domain::ports::spi::stock::StockMovementRepository;
+use crate::domain::queries::product::ProductQuery;
+use crate::domain::selectors::product::{ProductDetailsSelector, ProductPageSelector};
+use crate::domain::services::family::find_family_descendants;
 use crate::domain::valuables::product::ProductSignature;
-use ids_std_domain::api::failure::{CreateDomainFailure, InvalidField};
+use ids_std_domain::api::failure::{
+    CreateDomainFailure, FindManyFailure, FindOneFailure, InvalidField, UpdateDomainFailure,
+};
+use ids_std_domain::pagination::{Page, PaginationQuery};
 use ids_std_domain::validation;
 use std::sync::Arc;
 
@@ -12,16 +23,22 @@ use std::sync::Arc;
 pub struct ProductService {
     product_repo: Arc<dyn ProductRepository>,
     family_repo: Arc<dyn ProductFamilyRepository>,
+    stock_repo: Arc<dyn StockMovementRepository>,
+    component_repo: Arc<dyn KitComponentRepository>,
 }
 
 impl ProductService {
     pub fn new(
         product_repo: Arc<dyn ProductRepository>,
         family_repo: Arc<dyn ProductFamilyRepository>,
+        stock_repo: Arc<dyn StockMovementRepository>,
+        component_repo: Arc<dyn KitComponentRepository>,
     ) -> Self {
         Self {
             product_repo,
             family_repo,
+            stock_repo,
+            component_repo,
         }
     }
 }
@@ -76,9 +93,196 @@ impl CreateProductUseCase for ProductService {
             saleable: command.saleable,
             saleable_without_stock: command.saleable_without_stock,
             signature: product_signature.to_owned(),
+            tracking_mode: command.tracking_mode,
         };
         let product_id = self.product_repo.save(&product_created_event).await?;
 
         Ok(product_id)
     }
 }
+
+#[async_trait::async_trait]
+impl FindAllProductsUseCase for ProductService {
+    async fn find_all_products(
+        &self,
+        query: &PaginationQuery,
+        criteria: &ProductQuery,
+    ) -> Result<Page<ProductPageSelector>, FindManyFailure> {
+        if !criteria.include_descendants || criteria.family_ids.is_empty() {
+            return Ok(self.product_repo.find_all(query, criteria).await?);
+        }
+
+        let descendants =
+            find_family_descendants(self.family_repo.as_ref(), &criteria.family_ids).await?;
+
+        let mut family_criteria = criteria.clone();
+        family_criteria
+            .family_ids
+            .extend(descendants.iter().map(|family| family.product_family_id));
+
+        Ok(self.product_repo.find_all(query, &family_criteria).await?)
+    }
+}
+
+#[async_trait::async_trait]
+impl FindProductDetailsUseCase for ProductService {
+    async fn find_product_details(
+        &self,
+        product_id: i32,
+    ) -> Result<ProductDetailsSelector, FindOneFailure> {
+        let product_selector = self.product_repo.find_details_by_id(product_id).await?;
+
+        if product_selector.is_none() {
+            tracing::info!(product_id = &product_id, "product does not exist");
+
+            Err(FindOneFailure::NotFound(
+                "product does not exist".to_string(),
+            ))?;
+        }
+
+        Ok(product_selector.unwrap())
+    }
+}
+
+#[async_trait::async_trait]
+impl UpdateProductUseCase for ProductService {
+    async fn update_product(
+        &self,
+        command: &UpdateProductCommand,
+    ) -> Result<(), UpdateDomainFailure> {
+        tracing::info!("updating product {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let product = self.product_repo.find_by_id(command.product_id).await?;
+        if product.is_none() {
+            tracing::info!(product_id = &command.product_id, "product does not exist");
+
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "product_id".into(),
+                "product does not exist".into(),
+            )))?
+        }
+
+        let product_family = self.family_repo.find_by_id(command.family_id).await?;
+        if product_family.is_none() {
+            tracing::info!(
+                product_family_id = &command.family_id,
+                "product family does not exist"
+            );
+
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "product_family_id".into(),
+                "product family does not exist".into(),
+            )))?
+        }
+
+        let product = product.unwrap();
+        if product.discontinued && (command.saleable || command.saleable_without_stock) {
+            tracing::info!(
+                product_id = &command.product_id,
+                "discontinued product cannot be sold"
+            );
+
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "saleable".into(),
+                "discontinued product cannot be sold".into(),
+            )))?
+        }
+
+        if command
+            .tracking_mode
+            .is_some_and(|tracking_mode| tracking_mode != product.tracking_mode)
+        {
+            let on_hand = self.stock_repo.find_on_hand(command.product_id).await?;
+            if on_hand != 0 {
+                tracing::info!(
+                    product_id = &command.product_id,
+                    on_hand = &on_hand,
+                    "tracking mode changed on stocked product"
+                );
+
+                Err(UpdateDomainFailure::Conflict(
+                    "tracking mode cannot change while the product has stock".to_string(),
+                ))?;
+            }
+
+            let kit_components = self
+                .component_repo
+                .find_by_components(&[command.product_id])
+                .await?;
+            if !kit_components.is_empty() {
+                tracing::info!(
+                    product_id = &command.product_id,
+                    "tracking mode changed on kit component"
+                );
+
+                Err(UpdateDomainFailure::Conflict(
+                    "tracking mode cannot change while the product is a kit component".to_string(),
+                ))?;
+            }
+        }
+
+        let product_signature = ProductSignature::new(command.name.as_str()).get();
+
+        let another_similar_product = self
+            .product_repo
+            .find_other_by_signature(command.product_id, product_signature.as_str())
+            .await?;
+
+        if let Some(prd) = another_similar_product {
+            tracing::info!(
+                product_signature = &prd.signature,
+                "product signature already exist"
+            );
+
+            Err(UpdateDomainFailure::Conflict(
+                "product signature already exist".to_string(),
+            ))?;
+        }
+
+        let product_updated_event = UpdateProduct {
+            product_id: command.product_id,
+            name: command.name.to_owned(),
+            summary: command.summary.to_owned(),
+            family_id: command.family_id,
+            purchasable: command.purchasable,
+            saleable: command.saleable,
+            saleable_without_stock: command.saleable_without_stock,
+            signature: product_signature,
+            tracking_mode: command.tracking_mode.unwrap_or(product.tracking_mode),
+        };
+        self.product_repo.update(&product_updated_event).await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl DiscontinueProductUseCase for ProductService {
+    async fn discontinue_product(&self, product_id: i32) -> Result<(), UpdateDomainFailure> {
+        tracing::info!(product_id = &product_id, "discontinuing product");
+
+        let product = self.product_repo.find_by_id(product_id).await?;
+        if product.is_none() {
+            tracing::info!(product_id = &product_id, "product does not exist");
+
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "product_id".into(),
+                "product does not exist".into(),
+            )))?
+        }
+
+        if product.unwrap().discontinued {
+            tracing::info!(product_id = &product_id, "product already discontinued");
+
+            Err(UpdateDomainFailure::Conflict(
+                "product already discontinued".to_string(),
+            ))?;
+        }
+
+        self.product_repo.discontinue(product_id).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,697 @@
+use crate::domain::changes::purchase::{
+    AddPurchaseOrder, AddPurchaseOrderLine, ChangePurchaseOrderStatus, ReceivePurchaseOrder,
+    ReceivePurchaseOrderLine, UpdateLastPurchasePrice,
+};
+use crate::domain::changes::stock::AddStockMovement;
+use crate::domain::commands::purchase::{
+    AddPurchaseOrderLineCommand, CreatePurchaseOrderCommand, ReceivePurchaseOrderCommand,
+};
+use crate::domain::ports::api::purchase::{
+    AddPurchaseOrderLineUseCase, ApprovePurchaseOrderUseCase, ClosePurchaseOrderUseCase,
+    CreatePurchaseOrderUseCase, FindAllPurchaseOrdersUseCase, FindPurchaseOrderUseCase,
+    ReceivePurchaseOrderUseCase, SendPurchaseOrderUseCase,
+};
+use crate::domain::ports::spi::location::StorageLocationRepository;
+use crate::domain::ports::spi::lot::StockLotRepository;
+use crate::domain::ports::spi::product::ProductRepository;
+use crate::domain::ports::spi::purchase::PurchaseOrderRepository;
+use crate::domain::ports::spi::stock::StockMovementRepository;
+use crate::domain::ports::spi::supplier::SupplierRepository;
+use crate::domain::ports::spi::unit::UnitOfMeasureRepository;
+use crate::domain::queries::purchase::PurchaseOrderQuery;
+use crate::domain::selectors::purchase::{
+    PurchaseOrderDetailsSelector, PurchaseOrderLineSelector, PurchaseOrderSelector,
+};
+use crate::domain::services::stock::check_tracked_unit;
+use crate::domain::services::unit::{normalise_quantity, normalise_unit_cost};
+use crate::domain::valuables::money::Money;
+use crate::domain::valuables::purchase::{LineReceipt, PurchaseOrderStatus};
+use crate::domain::valuables::stock::StockMovementKind;
+use ids_std_domain::api::failure::{
+    CreateDomainFailure, FindManyFailure, FindOneFailure, InvalidField, UpdateDomainFailure,
+};
+use ids_std_domain::pagination::{Page, PaginationQuery};
+use ids_std_domain::validation;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct PurchaseOrderService {
+    purchase_repo: Arc<dyn PurchaseOrderRepository>,
+    supplier_repo: Arc<dyn SupplierRepository>,
+    product_repo: Arc<dyn ProductRepository>,
+    location_repo: Arc<dyn StorageLocationRepository>,
+    unit_repo: Arc<dyn UnitOfMeasureRepository>,
+    stock_repo: Arc<dyn StockMovementRepository>,
+    lot_repo: Arc<dyn StockLotRepository>,
+}
+
+impl PurchaseOrderService {
+    pub fn new(
+        purchase_repo: Arc<dyn PurchaseOrderRepository>,
+        supplier_repo: Arc<dyn SupplierRepository>,
+        product_repo: Arc<dyn ProductRepository>,
+        location_repo: Arc<dyn StorageLocationRepository>,
+        unit_repo: Arc<dyn UnitOfMeasureRepository>,
+        stock_repo: Arc<dyn StockMovementRepository>,
+        lot_repo: Arc<dyn StockLotRepository>,
+    ) -> Self {
+        Self {
+            purchase_repo,
+            supplier_repo,
+            product_repo,
+            location_repo,
+            unit_repo,
+            stock_repo,
+            lot_repo,
+        }
+    }
+
+    async fn find_order(
+        &self,
+        purchase_order_id: i32,
+    ) -> Result<PurchaseOrderSelector, UpdateDomainFailure> {
+        let purchase_order = self.purchase_repo.find_by_id(purchase_order_id).await?;
+        if purchase_order.is_none() {
+            tracing::info!(
+                purchase_order_id = &purchase_order_id,
+                "purchase order does not exist"
+            );
+
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "purchase_order_id".into(),
+                "purchase order does not exist".into(),
+            )))?
+        }
+
+        Ok(purchase_order.unwrap())
+    }
+
+    async fn find_order_in_status(
+        &self,
+        purchase_order_id: i32,
+        status: PurchaseOrderStatus,
+    ) -> Result<PurchaseOrderSelector, UpdateDomainFailure> {
+        let purchase_order = self.find_order(purchase_order_id).await?;
+
+        if purchase_order.status != status {
+            tracing::info!(
+                purchase_order_id = &purchase_order_id,
+                status = purchase_order.status.as_str(),
+                "purchase order is not {}",
+                status.as_str()
+            );
+
+            Err(UpdateDomainFailure::Conflict(format!(
+                "purchase order is not {}",
+                status.as_str()
+            )))?;
+        }
+
+        Ok(purchase_order)
+    }
+
+    async fn find_receivable_order(
+        &self,
+        purchase_order_id: i32,
+    ) -> Result<PurchaseOrderSelector, UpdateDomainFailure> {
+        let purchase_order = self.find_order(purchase_order_id).await?;
+
+        if !purchase_order.status.is_receivable() {
+            tracing::info!(
+                purchase_order_id = &purchase_order_id,
+                status = purchase_order.status.as_str(),
+                "purchase order is not open for receiving"
+            );
+
+            Err(UpdateDomainFailure::Conflict(
+                "purchase order is not open for receiving".to_string(),
+            ))?;
+        }
+
+        Ok(purchase_order)
+    }
+}
+
+#[async_trait::async_trait]
+impl CreatePurchaseOrderUseCase for PurchaseOrderService {
+    async fn create_purchase_order(
+        &self,
+        command: &CreatePurchaseOrderCommand,
+    ) -> Result<i32, CreateDomainFailure> {
+        tracing::info!("creating purchase order {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let supplier = self.supplier_repo.find_by_id(command.supplier_id).await?;
+        if supplier.is_none() {
+            tracing::info!(
+                supplier_id = &command.supplier_id,
+                "supplier does not exist"
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "supplier_id".into(),
+                "supplier does not exist".into(),
+            )))?
+        }
+
+        let purchase_order_created_event = AddPurchaseOrder {
+            supplier_id: command.supplier_id,
+            currency: supplier.unwrap().currency,
+        };
+        let purchase_order_id = self
+            .purchase_repo
+            .save(&purchase_order_created_event)
+            .await?;
+
+        Ok(purchase_order_id)
+    }
+}
+
+#[async_trait::async_trait]
+impl AddPurchaseOrderLineUseCase for PurchaseOrderService {
+    async fn add_purchase_order_line(
+        &self,
+        command: &AddPurchaseOrderLineCommand,
+    ) -> Result<i32, CreateDomainFailure> {
+        tracing::info!("adding purchase order line {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let purchase_order = self
+            .purchase_repo
+            .find_by_id(command.purchase_order_id)
+            .await?;
+        if purchase_order.is_none() {
+            tracing::info!(
+                purchase_order_id = &command.purchase_order_id,
+                "purchase order does not exist"
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "purchase_order_id".into(),
+                "purchase order does not exist".into(),
+            )))?
+        }
+        let purchase_order = purchase_order.unwrap();
+
+        if purchase_order.status != PurchaseOrderStatus::Draft {
+            tracing::info!(
+                purchase_order_id = &command.purchase_order_id,
+                status = purchase_order.status.as_str(),
+                "purchase order is not draft"
+            );
+
+            Err(CreateDomainFailure::Conflict(
+                "purchase order is not draft".to_string(),
+            ))?;
+        }
+
+        let product = self.product_repo.find_by_id(command.product_id).await?;
+        if product.is_none() {
+            tracing::info!(product_id = &command.product_id, "product does not exist");
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "product_id".into(),
+                "product does not exist".into(),
+            )))?
+        }
+        let product = product.unwrap();
+
+        if !product.purchasable {
+            tracing::info!(
+                product_id = &command.product_id,
+                "product is not purchasable"
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "product_id".into(),
+                "product is not purchasable".into(),
+            )))?
+        }
+
+        let ordered_quantity = normalise_quantity(
+            self.unit_repo.as_ref(),
+            &product,
+            command.unit_id,
+            command.quantity,
+            "quantity",
+        )
+        .await?
+        .map_err(CreateDomainFailure::InvalidField)?;
+
+        let unit_price = match &command.unit_price {
+            Some(unit_price) => match Money::parse(unit_price.as_str()) {
+                Some(amount) if !amount.is_negative() => Some(amount),
+                _ => Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                    "unit_price".into(),
+                    "unit price must be a positive decimal with up to four decimal places".into(),
+                )))?,
+            },
+            None => self
+                .supplier_repo
+                .find_product(purchase_order.supplier_id, command.product_id)
+                .await?
+                .and_then(|supplier_product| supplier_product.last_price),
+        };
+
+        if unit_price.is_none() {
+            tracing::info!(
+                product_id = &command.product_id,
+                "purchase order line without unit price"
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "unit_price".into(),
+                "unit price is required when the supplier has no last purchase price".into(),
+            )))?
+        }
+
+        let ordered_line = self
+            .purchase_repo
+            .find_lines(command.purchase_order_id)
+            .await?
+            .into_iter()
+            .find(|line| line.product_id == command.product_id);
+
+        if let Some(line) = ordered_line {
+            tracing::info!(
+                purchase_order_line_id = &line.purchase_order_line_id,
+                "purchase order line already exist"
+            );
+
+            Err(CreateDomainFailure::Conflict(
+                "purchase order line already exist".to_string(),
+            ))?;
+        }
+
+        let line_added_event = AddPurchaseOrderLine {
+            purchase_order_id: command.purchase_order_id,
+            product_id: command.product_id,
+            ordered_quantity,
+            unit_id: command.unit_id,
+            unit_price: unit_price.unwrap(),
+        };
+        let purchase_order_line_id = self.purchase_repo.add_line(&line_added_event).await?;
+
+        Ok(purchase_order_line_id)
+    }
+}
+
+#[async_trait::async_trait]
+impl ApprovePurchaseOrderUseCase for PurchaseOrderService {
+    async fn approve_purchase_order(
+        &self,
+        purchase_order_id: i32,
+    ) -> Result<(), UpdateDomainFailure> {
+        tracing::info!(
+            purchase_order_id = &purchase_order_id,
+            "approving purchase order"
+        );
+
+        self.find_order_in_status(purchase_order_id, PurchaseOrderStatus::Draft)
+            .await?;
+
+        let lines = self.purchase_repo.find_lines(purchase_order_id).await?;
+        if lines.is_empty() {
+            tracing::info!(
+                purchase_order_id = &purchase_order_id,
+                "purchase order has no lines"
+            );
+
+            Err(UpdateDomainFailure::Conflict(
+                "purchase order has no lines".to_string(),
+            ))?;
+        }
+
+        let order_approved_event = ChangePurchaseOrderStatus {
+            purchase_order_id,
+            current_status: PurchaseOrderStatus::Draft,
+            status: PurchaseOrderStatus::Approved,
+        };
+        if !self
+            .purchase_repo
+            .change_status(&order_approved_event)
+            .await?
+        {
+            tracing::info!(
+                purchase_order_id = &purchase_order_id,
+                "purchase order changed while approving"
+            );
+
+            Err(UpdateDomainFailure::Conflict(
+                "purchase order is not draft".to_string(),
+            ))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl SendPurchaseOrderUseCase for PurchaseOrderService {
+    async fn send_purchase_order(&self, purchase_order_id: i32) -> Result<(), UpdateDomainFailure> {
+        tracing::info!(
+            purchase_order_id = &purchase_order_id,
+            "sending purchase order"
+        );
+
+        self.find_order_in_status(purchase_order_id, PurchaseOrderStatus::Approved)
+            .await?;
+
+        let order_sent_event = ChangePurchaseOrderStatus {
+            purchase_order_id,
+            current_status: PurchaseOrderStatus::Approved,
+            status: PurchaseOrderStatus::Sent,
+        };
+        if !self.purchase_repo.change_status(&order_sent_event).await? {
+            tracing::info!(
+                purchase_order_id = &purchase_order_id,
+                "purchase order changed while sending"
+            );
+
+            Err(UpdateDomainFailure::Conflict(
+                "purchase order is not approved".to_string(),
+            ))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ReceivePurchaseOrderUseCase for PurchaseOrderService {
+    async fn receive_purchase_order(
+        &self,
+        command: &ReceivePurchaseOrderCommand,
+    ) -> Result<(), UpdateDomainFailure> {
+        tracing::info!("receiving purchase order {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let purchase_order = self
+            .find_receivable_order(command.purchase_order_id)
+            .await?;
+
+        if let Some(storage_location_id) = command.storage_location_id {
+            let location = self.location_repo.find_by_id(storage_location_id).await?;
+
+            if location.is_none() {
+                tracing::info!(
+                    storage_location_id = &storage_location_id,
+                    "storage location does not exist"
+                );
+
+                Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                    "storage_location_id".into(),
+                    "storage location does not exist".into(),
+                )))?
+            }
+        }
+
+        let lines: HashMap<i32, PurchaseOrderLineSelector> = self
+            .purchase_repo
+            .find_lines(command.purchase_order_id)
+            .await?
+            .into_iter()
+            .map(|line| (line.purchase_order_line_id, line))
+            .collect();
+        let mut line_receipts: HashMap<i32, LineReceipt> = lines
+            .values()
+            .map(|line| {
+                (
+                    line.purchase_order_line_id,
+                    LineReceipt::new(line.ordered_quantity, line.received_quantity),
+                )
+            })
+            .collect();
+
+        let mut receipts = Vec::new();
+        let mut received_serials = HashSet::new();
+        let mut received_lots = HashSet::new();
+        for received_line in &command.lines {
+            let Some(line) = lines.get(&received_line.purchase_order_line_id) else {
+                tracing::info!(
+                    purchase_order_line_id = &received_line.purchase_order_line_id,
+                    "purchase order line does not exist"
+                );
+
+                return Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                    "purchase_order_line_id".into(),
+                    "purchase order line does not exist".into(),
+                )));
+            };
+
+            if received_line.quantity < 1 {
+                tracing::info!(
+                    purchase_order_line_id = &line.purchase_order_line_id,
+                    "received quantity is not positive"
+                );
+
+                Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                    "quantity".into(),
+                    "received quantity must be positive".into(),
+                )))?
+            }
+
+            let product = self.product_repo.find_by_id(line.product_id).await?;
+            if product.is_none() {
+                tracing::info!(product_id = &line.product_id, "product does not exist");
+
+                Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                    "product_id".into(),
+                    "product does not exist".into(),
+                )))?
+            }
+            let product = product.unwrap();
+
+            let quantity = normalise_quantity(
+                self.unit_repo.as_ref(),
+                &product,
+                line.unit_id,
+                received_line.quantity,
+                "quantity",
+            )
+            .await?
+            .map_err(UpdateDomainFailure::InvalidField)?;
+
+            let unit_costs = normalise_unit_cost(
+                self.unit_repo.as_ref(),
+                &product,
+                line.unit_id,
+                line.unit_price,
+                quantity,
+                "unit_price",
+            )
+            .await?
+            .map_err(UpdateDomainFailure::InvalidField)?;
+
+            let tracked_unit = product.tracking_mode.track(
+                received_line.lot_number.as_deref(),
+                received_line.serial_number.as_deref(),
+                quantity,
+            );
+            if let Err(violation) = tracked_unit {
+                tracing::info!(
+                    product_id = &line.product_id,
+                    tracking_mode = product.tracking_mode.as_str(),
+                    "{}",
+                    violation.message()
+                );
+
+                Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                    violation.field().into(),
+                    violation.message().into(),
+                )))?
+            }
+            let tracked_unit = tracked_unit.unwrap();
+
+            if let Some(serial_number) = tracked_unit.serial_number() {
+                if !received_serials.insert((line.product_id, serial_number)) {
+                    tracing::info!(
+                        product_id = &line.product_id,
+                        "serial number received twice"
+                    );
+
+                    Err(UpdateDomainFailure::Conflict(
+                        "serial number already in stock".to_string(),
+                    ))?;
+                }
+            }
+
+            let stock_lot = check_tracked_unit(
+                self.stock_repo.as_ref(),
+                self.lot_repo.as_ref(),
+                line.product_id,
+                &tracked_unit,
+                quantity,
+                received_line.expires_on,
+            )
+            .await?
+            .map_err(UpdateDomainFailure::from)?;
+
+            let line_receipt = line_receipts[&line.purchase_order_line_id].receive(quantity);
+            if line_receipt.over_received_quantity() > 0 {
+                tracing::info!(
+                    purchase_order_line_id = &line.purchase_order_line_id,
+                    over_received_quantity = &line_receipt.over_received_quantity(),
+                    "purchase order line over-received"
+                );
+
+                if !command.allow_over_receipt {
+                    Err(UpdateDomainFailure::Conflict(
+                        "received quantity exceeds the outstanding quantity".to_string(),
+                    ))?;
+                }
+            }
+            line_receipts.insert(line.purchase_order_line_id, line_receipt);
+
+            for (costed_quantity, unit_cost) in unit_costs {
+                receipts.push(AddStockMovement {
+                    product_id: line.product_id,
+                    kind: StockMovementKind::Receipt,
+                    quantity: costed_quantity,
+                    storage_location_id: command.storage_location_id,
+                    lot_number: tracked_unit.lot_number(),
+                    serial_number: tracked_unit.serial_number(),
+                    unit_cost: Some(unit_cost),
+                    lot_expires_on: received_line.expires_on.filter(|_| {
+                        stock_lot.is_none()
+                            && received_lots.insert((line.product_id, tracked_unit.lot_number()))
+                    }),
+                    reason: Some(format!("purchase order {}", command.purchase_order_id)),
+                });
+            }
+        }
+
+        let received_lines = command
+            .lines
+            .iter()
+            .map(|received_line| received_line.purchase_order_line_id)
+            .collect::<BTreeSet<i32>>();
+
+        let order_received_event = ReceivePurchaseOrder {
+            purchase_order_id: command.purchase_order_id,
+            allow_over_receipt: command.allow_over_receipt,
+            lines: received_lines
+                .iter()
+                .map(|purchase_order_line_id| ReceivePurchaseOrderLine {
+                    purchase_order_line_id: *purchase_order_line_id,
+                    quantity: line_receipts[purchase_order_line_id].received_quantity()
+                        - lines[purchase_order_line_id].received_quantity,
+                })
+                .collect(),
+            receipts,
+            last_prices: received_lines
+                .iter()
+                .map(|purchase_order_line_id| UpdateLastPurchasePrice {
+                    supplier_id: purchase_order.supplier_id,
+                    product_id: lines[purchase_order_line_id].product_id,
+                    last_price: lines[purchase_order_line_id].unit_price,
+                })
+                .collect(),
+        };
+        if !self.purchase_repo.receive(&order_received_event).await? {
+            tracing::info!(
+                purchase_order_id = &command.purchase_order_id,
+                "purchase order changed while receiving"
+            );
+
+            self.find_receivable_order(command.purchase_order_id)
+                .await?;
+
+            Err(UpdateDomainFailure::Conflict(
+                "received quantity exceeds the outstanding quantity".to_string(),
+            ))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ClosePurchaseOrderUseCase for PurchaseOrderService {
+    async fn close_purchase_order(
+        &self,
+        purchase_order_id: i32,
+    ) -> Result<(), UpdateDomainFailure> {
+        tracing::info!(
+            purchase_order_id = &purchase_order_id,
+            "closing purchase order"
+        );
+
+        let purchase_order = self.find_receivable_order(purchase_order_id).await?;
+
+        let order_closed_event = ChangePurchaseOrderStatus {
+            purchase_order_id,
+            current_status: purchase_order.status,
+            status: PurchaseOrderStatus::Closed,
+        };
+        if !self
+            .purchase_repo
+            .change_status(&order_closed_event)
+            .await?
+        {
+            tracing::info!(
+                purchase_order_id = &purchase_order_id,
+                "purchase order changed while closing"
+            );
+
+            self.find_receivable_order(purchase_order_id).await?;
+
+            Err(UpdateDomainFailure::Conflict(
+                "purchase order was received while closing".to_string(),
+            ))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl FindAllPurchaseOrdersUseCase for PurchaseOrderService {
+    async fn find_all_purchase_orders(
+        &self,
+        query: &PaginationQuery,
+        criteria: &PurchaseOrderQuery,
+    ) -> Result<Page<PurchaseOrderSelector>, FindManyFailure> {
+        Ok(self.purchase_repo.find_all(query, criteria).await?)
+    }
+}
+
+#[async_trait::async_trait]
+impl FindPurchaseOrderUseCase for PurchaseOrderService {
+    async fn find_purchase_order(
+        &self,
+        purchase_order_id: i32,
+    ) -> Result<PurchaseOrderDetailsSelector, FindOneFailure> {
+        let purchase_order = self.purchase_repo.find_by_id(purchase_order_id).await?;
+
+        if purchase_order.is_none() {
+            tracing::info!(
+                purchase_order_id = &purchase_order_id,
+                "purchase order does not exist"
+            );
+
+            Err(FindOneFailure::NotFound(
+                "purchase order does not exist".to_string(),
+            ))?;
+        }
+        let purchase_order = purchase_order.unwrap();
+
+        let lines = self.purchase_repo.find_lines(purchase_order_id).await?;
+
+        Ok(PurchaseOrderDetailsSelector {
+            purchase_order_id: purchase_order.purchase_order_id,
+            supplier_id: purchase_order.supplier_id,
+            status: purchase_order.status,
+            currency: purchase_order.currency,
+            created_at: purchase_order.created_at,
+            approved_at: purchase_order.approved_at,
+            sent_at: purchase_order.sent_at,
+            closed_at: purchase_order.closed_at,
+            lines,
+        })
+    }
+}
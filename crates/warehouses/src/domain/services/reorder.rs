@@ -0,0 +1,161 @@
+use crate::domain::changes::reorder::SetReorderPoint;
+use crate::domain::commands::reorder::SetReorderPointCommand;
+use crate::domain::ports::api::reorder::{
+    FindLowStockProductsUseCase, FindReorderPointUseCase, SetReorderPointUseCase,
+};
+use crate::domain::ports::spi::product::ProductRepository;
+use crate::domain::ports::spi::reorder::ReorderPointRepository;
+use crate::domain::ports::spi::stock::StockMovementRepository;
+use crate::domain::ports::spi::unit::UnitOfMeasureRepository;
+use crate::domain::selectors::reorder::{LowStockSelector, ReorderPointSelector};
+use crate::domain::services::unit::normalise_quantity;
+use crate::domain::valuables::reorder::ReorderLevel;
+use ids_std_domain::api::failure::{
+    FindManyFailure, FindOneFailure, InvalidField, UpdateDomainFailure,
+};
+use ids_std_domain::validation;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct ReorderPointService {
+    reorder_repo: Arc<dyn ReorderPointRepository>,
+    product_repo: Arc<dyn ProductRepository>,
+    stock_repo: Arc<dyn StockMovementRepository>,
+    unit_repo: Arc<dyn UnitOfMeasureRepository>,
+}
+
+impl ReorderPointService {
+    pub fn new(
+        reorder_repo: Arc<dyn ReorderPointRepository>,
+        product_repo: Arc<dyn ProductRepository>,
+        stock_repo: Arc<dyn StockMovementRepository>,
+        unit_repo: Arc<dyn UnitOfMeasureRepository>,
+    ) -> Self {
+        Self {
+            reorder_repo,
+            product_repo,
+            stock_repo,
+            unit_repo,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl SetReorderPointUseCase for ReorderPointService {
+    async fn set_reorder_point(
+        &self,
+        command: &SetReorderPointCommand,
+    ) -> Result<(), UpdateDomainFailure> {
+        tracing::info!("setting reorder point {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let product = self.product_repo.find_by_id(command.product_id).await?;
+        if product.is_none() {
+            tracing::info!(product_id = &command.product_id, "product does not exist");
+
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "product_id".into(),
+                "product does not exist".into(),
+            )))?
+        }
+        let product = product.unwrap();
+
+        let min_quantity = normalise_quantity(
+            self.unit_repo.as_ref(),
+            &product,
+            command.unit_id,
+            command.min_quantity,
+            "min_quantity",
+        )
+        .await?
+        .map_err(UpdateDomainFailure::InvalidField)?;
+
+        let max_quantity = match command.max_quantity {
+            Some(max_quantity) => Some(
+                normalise_quantity(
+                    self.unit_repo.as_ref(),
+                    &product,
+                    command.unit_id,
+                    max_quantity,
+                    "max_quantity",
+                )
+                .await?
+                .map_err(UpdateDomainFailure::InvalidField)?,
+            ),
+            None => None,
+        };
+
+        if max_quantity.is_some_and(|max_quantity| max_quantity < min_quantity) {
+            tracing::info!(
+                product_id = &command.product_id,
+                "max quantity lower than min quantity"
+            );
+
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "max_quantity".into(),
+                "max quantity must not be lower than the min quantity".into(),
+            )))?
+        }
+
+        let reorder_point_set_event = SetReorderPoint {
+            product_id: command.product_id,
+            min_quantity,
+            max_quantity,
+        };
+        self.reorder_repo.save(&reorder_point_set_event).await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl FindReorderPointUseCase for ReorderPointService {
+    async fn find_reorder_point(
+        &self,
+        product_id: i32,
+    ) -> Result<ReorderPointSelector, FindOneFailure> {
+        let reorder_point = self.reorder_repo.find_by_product(product_id).await?;
+
+        if reorder_point.is_none() {
+            tracing::info!(product_id = &product_id, "reorder point does not exist");
+
+            Err(FindOneFailure::NotFound(
+                "reorder point does not exist".to_string(),
+            ))?;
+        }
+
+        Ok(reorder_point.unwrap())
+    }
+}
+
+#[async_trait::async_trait]
+impl FindLowStockProductsUseCase for ReorderPointService {
+    async fn find_low_stock_products(&self) -> Result<Vec<LowStockSelector>, FindManyFailure> {
+        tracing::info!("finding low stock products");
+
+        let reorder_points = self.reorder_repo.find_purchasable().await?;
+
+        let mut low_stock = Vec::new();
+        for reorder_point in reorder_points {
+            let on_hand = self
+                .stock_repo
+                .find_on_hand(reorder_point.product_id)
+                .await?;
+            let level = ReorderLevel::new(reorder_point.min_quantity, reorder_point.max_quantity);
+
+            if let Some(suggested_quantity) = level.suggest(on_hand) {
+                low_stock.push(LowStockSelector {
+                    product_id: reorder_point.product_id,
+                    product_name: reorder_point.product_name,
+                    on_hand,
+                    min_quantity: reorder_point.min_quantity,
+                    max_quantity: reorder_point.max_quantity,
+                    suggested_quantity,
+                });
+            }
+        }
+
+        Ok(low_stock)
+    }
+}
@@ -0,0 +1,683 @@
+use crate::domain::changes::sales::{
+    AddSalesOrder, AddSalesOrderLine, ChangeSalesOrderStatus, CommittedStock, FulfilSalesOrder,
+    ReserveSalesOrderLine,
+};
+use crate::domain::commands::sales::{
+    AddSalesOrderLineCommand, CreateSalesOrderCommand, FulfilSalesOrderCommand,
+};
+use crate::domain::ports::api::sales::{
+    AddSalesOrderLineUseCase, CancelSalesOrderUseCase, ConfirmSalesOrderUseCase,
+    CreateSalesOrderUseCase, FindAllSalesOrdersUseCase, FindAvailableToPromiseUseCase,
+    FindSalesOrderUseCase, FulfilSalesOrderUseCase,
+};
+use crate::domain::ports::spi::kit::KitComponentRepository;
+use crate::domain::ports::spi::location::StorageLocationRepository;
+use crate::domain::ports::spi::product::ProductRepository;
+use crate::domain::ports::spi::sales::SalesOrderRepository;
+use crate::domain::ports::spi::stock::StockMovementRepository;
+use crate::domain::ports::spi::unit::UnitOfMeasureRepository;
+use crate::domain::queries::sales::SalesOrderQuery;
+use crate::domain::selectors::sales::{
+    AvailableToPromiseSelector, SalesOrderDetailsSelector, SalesOrderSelector,
+};
+use crate::domain::services::kit::{
+    check_kit_structure, find_kit_structure, find_on_hand_of_products, find_shared_kit_structure,
+    kit_issue_movement,
+};
+use crate::domain::services::unit::normalise_quantity;
+use crate::domain::valuables::kit::KitStructure;
+use crate::domain::valuables::sales::{AvailableToPromise, SalesOrderStatus};
+use crate::domain::valuables::tracking::TrackingMode;
+use ids_std_domain::api::failure::{
+    CreateDomainFailure, FindManyFailure, FindOneFailure, InvalidField, UpdateDomainFailure,
+};
+use ids_std_domain::pagination::{Page, PaginationQuery};
+use ids_std_domain::spi::failure::SelectRepoFailure;
+use ids_std_domain::validation;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct SalesOrderService {
+    sales_repo: Arc<dyn SalesOrderRepository>,
+    product_repo: Arc<dyn ProductRepository>,
+    stock_repo: Arc<dyn StockMovementRepository>,
+    component_repo: Arc<dyn KitComponentRepository>,
+    location_repo: Arc<dyn StorageLocationRepository>,
+    unit_repo: Arc<dyn UnitOfMeasureRepository>,
+}
+
+impl SalesOrderService {
+    pub fn new(
+        sales_repo: Arc<dyn SalesOrderRepository>,
+        product_repo: Arc<dyn ProductRepository>,
+        stock_repo: Arc<dyn StockMovementRepository>,
+        component_repo: Arc<dyn KitComponentRepository>,
+        location_repo: Arc<dyn StorageLocationRepository>,
+        unit_repo: Arc<dyn UnitOfMeasureRepository>,
+    ) -> Self {
+        Self {
+            sales_repo,
+            product_repo,
+            stock_repo,
+            component_repo,
+            location_repo,
+            unit_repo,
+        }
+    }
+
+    async fn find_order(
+        &self,
+        sales_order_id: i32,
+    ) -> Result<SalesOrderSelector, UpdateDomainFailure> {
+        let sales_order = self.sales_repo.find_by_id(sales_order_id).await?;
+        if sales_order.is_none() {
+            tracing::info!(
+                sales_order_id = &sales_order_id,
+                "sales order does not exist"
+            );
+
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "sales_order_id".into(),
+                "sales order does not exist".into(),
+            )))?
+        }
+
+        Ok(sales_order.unwrap())
+    }
+
+    async fn find_committed_stock(
+        &self,
+        product_ids: &[i32],
+    ) -> Result<BTreeMap<i32, CommittedStock>, SelectRepoFailure> {
+        let on_hand = find_on_hand_of_products(self.stock_repo.as_ref(), product_ids, None).await?;
+        let commitments = self.sales_repo.find_commitments(product_ids).await?;
+
+        Ok(product_ids
+            .iter()
+            .map(|product_id| {
+                let commitment = commitments
+                    .iter()
+                    .find(|commitment| commitment.product_id == *product_id);
+                let committed_stock = CommittedStock {
+                    product_id: *product_id,
+                    on_hand: on_hand[product_id],
+                    reserved: commitment.map_or(0, |commitment| commitment.reserved),
+                    backordered: commitment.map_or(0, |commitment| commitment.backordered),
+                };
+
+                (*product_id, committed_stock)
+            })
+            .collect())
+    }
+}
+
+fn stock_product_ids(structure: &KitStructure, product_id: i32) -> Vec<i32> {
+    let mut product_ids = structure.product_ids();
+    if !product_ids.contains(&product_id) {
+        product_ids.push(product_id);
+    }
+
+    product_ids
+}
+
+fn available_to_promise(
+    structure: &KitStructure,
+    product_id: i32,
+    committed_stock: &BTreeMap<i32, CommittedStock>,
+    pending: &[(i32, i32)],
+) -> AvailableToPromise {
+    let on_hand: HashMap<i32, i32> = committed_stock
+        .values()
+        .map(|stock| (stock.product_id, stock.on_hand))
+        .collect();
+
+    // A kit reservation holds its components, so every reservation sharing
+    // the stock is exploded before measuring what is left to promise.
+    let mut uncommitted = on_hand.clone();
+    let reservations = committed_stock
+        .values()
+        .map(|stock| (stock.product_id, stock.reserved))
+        .chain(pending.iter().copied());
+    for (reserved_product_id, reserved) in reservations {
+        for issue in structure.explode(reserved_product_id, reserved, &uncommitted) {
+            *uncommitted.entry(issue.product_id).or_default() -= issue.quantity;
+        }
+    }
+
+    let backordered = committed_stock
+        .get(&product_id)
+        .map_or(0, |stock| stock.backordered);
+    let available = structure.available(product_id, &on_hand);
+
+    AvailableToPromise::new(
+        available,
+        available - structure.available(product_id, &uncommitted),
+        backordered,
+    )
+}
+
+#[async_trait::async_trait]
+impl CreateSalesOrderUseCase for SalesOrderService {
+    async fn create_sales_order(
+        &self,
+        command: &CreateSalesOrderCommand,
+    ) -> Result<i32, CreateDomainFailure> {
+        tracing::info!("creating sales order {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        if !self.sales_repo.exists_customer(command.customer_id).await? {
+            tracing::info!(
+                customer_id = &command.customer_id,
+                "customer does not exist"
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "customer_id".into(),
+                "customer does not exist".into(),
+            )))?
+        }
+
+        let sales_order_created_event = AddSalesOrder {
+            customer_id: command.customer_id,
+        };
+        let sales_order_id = self.sales_repo.save(&sales_order_created_event).await?;
+
+        Ok(sales_order_id)
+    }
+}
+
+#[async_trait::async_trait]
+impl AddSalesOrderLineUseCase for SalesOrderService {
+    async fn add_sales_order_line(
+        &self,
+        command: &AddSalesOrderLineCommand,
+    ) -> Result<i32, CreateDomainFailure> {
+        tracing::info!("adding sales order line {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let sales_order = self.sales_repo.find_by_id(command.sales_order_id).await?;
+        if sales_order.is_none() {
+            tracing::info!(
+                sales_order_id = &command.sales_order_id,
+                "sales order does not exist"
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "sales_order_id".into(),
+                "sales order does not exist".into(),
+            )))?
+        }
+        let sales_order = sales_order.unwrap();
+
+        if sales_order.status != SalesOrderStatus::Draft {
+            tracing::info!(
+                sales_order_id = &command.sales_order_id,
+                status = sales_order.status.as_str(),
+                "sales order is not draft"
+            );
+
+            Err(CreateDomainFailure::Conflict(
+                "sales order is not draft".to_string(),
+            ))?;
+        }
+
+        let product = self.product_repo.find_by_id(command.product_id).await?;
+        if product.is_none() {
+            tracing::info!(product_id = &command.product_id, "product does not exist");
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "product_id".into(),
+                "product does not exist".into(),
+            )))?
+        }
+        let product = product.unwrap();
+
+        if !product.saleable {
+            tracing::info!(product_id = &command.product_id, "product is not saleable");
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "product_id".into(),
+                "product is not saleable".into(),
+            )))?
+        }
+
+        if product.tracking_mode != TrackingMode::None {
+            tracing::info!(
+                product_id = &command.product_id,
+                tracking_mode = product.tracking_mode.as_str(),
+                "tracked product cannot be ordered"
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "product_id".into(),
+                "product tracked by lot or serial cannot be ordered".into(),
+            )))?
+        }
+
+        let ordered_quantity = normalise_quantity(
+            self.unit_repo.as_ref(),
+            &product,
+            command.unit_id,
+            command.quantity,
+            "quantity",
+        )
+        .await?
+        .map_err(CreateDomainFailure::InvalidField)?;
+
+        let ordered_line = self
+            .sales_repo
+            .find_lines(command.sales_order_id)
+            .await?
+            .into_iter()
+            .find(|line| line.product_id == command.product_id);
+
+        if let Some(line) = ordered_line {
+            tracing::info!(
+                sales_order_line_id = &line.sales_order_line_id,
+                "sales order line already exist"
+            );
+
+            Err(CreateDomainFailure::Conflict(
+                "sales order line already exist".to_string(),
+            ))?;
+        }
+
+        let line_added_event = AddSalesOrderLine {
+            sales_order_id: command.sales_order_id,
+            product_id: command.product_id,
+            ordered_quantity,
+        };
+        let sales_order_line_id = self.sales_repo.add_line(&line_added_event).await?;
+
+        Ok(sales_order_line_id)
+    }
+}
+
+#[async_trait::async_trait]
+impl ConfirmSalesOrderUseCase for SalesOrderService {
+    async fn confirm_sales_order(&self, sales_order_id: i32) -> Result<(), UpdateDomainFailure> {
+        tracing::info!(sales_order_id = &sales_order_id, "confirming sales order");
+
+        let sales_order = self.find_order(sales_order_id).await?;
+
+        if sales_order.status != SalesOrderStatus::Draft {
+            tracing::info!(
+                sales_order_id = &sales_order_id,
+                status = sales_order.status.as_str(),
+                "sales order is not draft"
+            );
+
+            Err(UpdateDomainFailure::Conflict(
+                "sales order is not draft".to_string(),
+            ))?;
+        }
+
+        let lines = self.sales_repo.find_lines(sales_order_id).await?;
+        if lines.is_empty() {
+            tracing::info!(sales_order_id = &sales_order_id, "sales order has no lines");
+
+            Err(UpdateDomainFailure::Conflict(
+                "sales order has no lines".to_string(),
+            ))?;
+        }
+
+        let mut ordered_products = Vec::new();
+        let mut product_ids = BTreeSet::new();
+        for line in &lines {
+            let product = self.product_repo.find_by_id(line.product_id).await?;
+            let Some(product) = product.filter(|product| product.saleable) else {
+                tracing::info!(product_id = &line.product_id, "product is not saleable");
+
+                return Err(UpdateDomainFailure::Conflict(
+                    "product is not saleable".to_string(),
+                ));
+            };
+
+            if product.tracking_mode != TrackingMode::None {
+                tracing::info!(
+                    product_id = &line.product_id,
+                    tracking_mode = product.tracking_mode.as_str(),
+                    "tracked product cannot be ordered"
+                );
+
+                Err(UpdateDomainFailure::Conflict(
+                    "product tracked by lot or serial cannot be ordered".to_string(),
+                ))?;
+            }
+
+            let structure =
+                find_shared_kit_structure(self.component_repo.as_ref(), line.product_id).await?;
+            product_ids.extend(stock_product_ids(&structure, line.product_id));
+            ordered_products.push((line, product, structure));
+        }
+
+        let product_ids: Vec<i32> = product_ids.into_iter().collect();
+        let committed_stock = self.find_committed_stock(&product_ids).await?;
+
+        let mut reservations = Vec::new();
+        let mut pending = Vec::new();
+        for (line, product, structure) in &ordered_products {
+            let available =
+                available_to_promise(structure, line.product_id, &committed_stock, &pending);
+            let reservation = available.reserve(line.ordered_quantity);
+
+            if reservation.is_backorder() && !product.saleable_without_stock {
+                tracing::info!(
+                    product_id = &line.product_id,
+                    available_to_promise = &available.quantity(),
+                    "insufficient stock"
+                );
+
+                Err(UpdateDomainFailure::Conflict(
+                    "insufficient stock".to_string(),
+                ))?;
+            }
+
+            pending.push((line.product_id, reservation.reserved_quantity()));
+            reservations.push(ReserveSalesOrderLine {
+                sales_order_line_id: line.sales_order_line_id,
+                reserved_quantity: reservation.reserved_quantity(),
+                backordered_quantity: reservation.backordered_quantity(),
+            });
+        }
+
+        let order_confirmed_event = ChangeSalesOrderStatus {
+            sales_order_id,
+            current_status: SalesOrderStatus::Draft,
+            status: SalesOrderStatus::Confirmed,
+            lines: reservations,
+            committed_stock: committed_stock.into_values().collect(),
+        };
+        if !self
+            .sales_repo
+            .change_status(&order_confirmed_event)
+            .await?
+        {
+            tracing::info!(
+                sales_order_id = &sales_order_id,
+                "sales order or its stock changed while confirming"
+            );
+
+            let sales_order = self.find_order(sales_order_id).await?;
+            if sales_order.status != SalesOrderStatus::Draft {
+                Err(UpdateDomainFailure::Conflict(
+                    "sales order is not draft".to_string(),
+                ))?;
+            }
+
+            Err(UpdateDomainFailure::Conflict(
+                "insufficient stock".to_string(),
+            ))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl CancelSalesOrderUseCase for SalesOrderService {
+    async fn cancel_sales_order(&self, sales_order_id: i32) -> Result<(), UpdateDomainFailure> {
+        tracing::info!(sales_order_id = &sales_order_id, "cancelling sales order");
+
+        let sales_order = self.find_order(sales_order_id).await?;
+
+        if !sales_order.status.is_cancellable() {
+            tracing::info!(
+                sales_order_id = &sales_order_id,
+                status = sales_order.status.as_str(),
+                "sales order is not cancellable"
+            );
+
+            Err(UpdateDomainFailure::Conflict(
+                "sales order is not cancellable".to_string(),
+            ))?;
+        }
+
+        let lines = self.sales_repo.find_lines(sales_order_id).await?;
+
+        let order_cancelled_event = ChangeSalesOrderStatus {
+            sales_order_id,
+            current_status: sales_order.status,
+            status: SalesOrderStatus::Cancelled,
+            lines: lines
+                .iter()
+                .map(|line| ReserveSalesOrderLine {
+                    sales_order_line_id: line.sales_order_line_id,
+                    reserved_quantity: 0,
+                    backordered_quantity: 0,
+                })
+                .collect(),
+            committed_stock: Vec::new(),
+        };
+        if !self
+            .sales_repo
+            .change_status(&order_cancelled_event)
+            .await?
+        {
+            tracing::info!(
+                sales_order_id = &sales_order_id,
+                "sales order changed while cancelling"
+            );
+
+            Err(UpdateDomainFailure::Conflict(
+                "sales order is not cancellable".to_string(),
+            ))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl FulfilSalesOrderUseCase for SalesOrderService {
+    async fn fulfil_sales_order(
+        &self,
+        command: &FulfilSalesOrderCommand,
+    ) -> Result<(), UpdateDomainFailure> {
+        tracing::info!("fulfilling sales order {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let sales_order = self.find_order(command.sales_order_id).await?;
+
+        if sales_order.status != SalesOrderStatus::Confirmed {
+            tracing::info!(
+                sales_order_id = &command.sales_order_id,
+                status = sales_order.status.as_str(),
+                "sales order is not confirmed"
+            );
+
+            Err(UpdateDomainFailure::Conflict(
+                "sales order is not confirmed".to_string(),
+            ))?;
+        }
+
+        if let Some(storage_location_id) = command.storage_location_id {
+            let location = self.location_repo.find_by_id(storage_location_id).await?;
+
+            if location.is_none() {
+                tracing::info!(
+                    storage_location_id = &storage_location_id,
+                    "storage location does not exist"
+                );
+
+                Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                    "storage_location_id".into(),
+                    "storage location does not exist".into(),
+                )))?
+            }
+        }
+
+        let lines = self.sales_repo.find_lines(command.sales_order_id).await?;
+        let reason = format!("sales order {}", command.sales_order_id);
+
+        let mut issues = Vec::new();
+        let mut in_stock_product_ids = Vec::new();
+        for line in &lines {
+            let product = self.product_repo.find_by_id(line.product_id).await?;
+            let Some(product) = product else {
+                tracing::info!(product_id = &line.product_id, "product does not exist");
+
+                return Err(UpdateDomainFailure::Conflict(
+                    "product does not exist".to_string(),
+                ));
+            };
+
+            if product.tracking_mode != TrackingMode::None {
+                tracing::info!(
+                    product_id = &line.product_id,
+                    tracking_mode = product.tracking_mode.as_str(),
+                    "tracked product cannot be fulfilled"
+                );
+
+                Err(UpdateDomainFailure::Conflict(
+                    "tracked product must be issued by lot or serial".to_string(),
+                ))?;
+            }
+
+            let structure =
+                find_kit_structure(self.component_repo.as_ref(), line.product_id).await?;
+            check_kit_structure(self.product_repo.as_ref(), &structure, line.product_id)
+                .await?
+                .map_err(UpdateDomainFailure::Conflict)?;
+            let on_hand = find_on_hand_of_products(
+                self.stock_repo.as_ref(),
+                &structure.product_ids(),
+                command.storage_location_id,
+            )
+            .await?;
+
+            issues.extend(
+                structure
+                    .explode(line.product_id, line.ordered_quantity, &on_hand)
+                    .iter()
+                    .map(|issue| {
+                        kit_issue_movement(
+                            issue,
+                            command.storage_location_id,
+                            Some(reason.to_owned()),
+                        )
+                    }),
+            );
+
+            if !product.saleable_without_stock {
+                in_stock_product_ids.push(line.product_id);
+                in_stock_product_ids.extend(structure.product_ids());
+            }
+        }
+
+        let order_fulfilled_event = FulfilSalesOrder {
+            sales_order_id: command.sales_order_id,
+            lines: lines
+                .iter()
+                .map(|line| ReserveSalesOrderLine {
+                    sales_order_line_id: line.sales_order_line_id,
+                    reserved_quantity: 0,
+                    backordered_quantity: 0,
+                })
+                .collect(),
+            issues,
+            in_stock_product_ids,
+        };
+        if !self.sales_repo.fulfil(&order_fulfilled_event).await? {
+            tracing::info!(
+                sales_order_id = &command.sales_order_id,
+                "sales order or its stock changed while fulfilling"
+            );
+
+            let sales_order = self.find_order(command.sales_order_id).await?;
+            if sales_order.status != SalesOrderStatus::Confirmed {
+                Err(UpdateDomainFailure::Conflict(
+                    "sales order is not confirmed".to_string(),
+                ))?;
+            }
+
+            Err(UpdateDomainFailure::Conflict(
+                "insufficient stock".to_string(),
+            ))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl FindAllSalesOrdersUseCase for SalesOrderService {
+    async fn find_all_sales_orders(
+        &self,
+        query: &PaginationQuery,
+        criteria: &SalesOrderQuery,
+    ) -> Result<Page<SalesOrderSelector>, FindManyFailure> {
+        Ok(self.sales_repo.find_all(query, criteria).await?)
+    }
+}
+
+#[async_trait::async_trait]
+impl FindSalesOrderUseCase for SalesOrderService {
+    async fn find_sales_order(
+        &self,
+        sales_order_id: i32,
+    ) -> Result<SalesOrderDetailsSelector, FindOneFailure> {
+        let sales_order = self.sales_repo.find_by_id(sales_order_id).await?;
+
+        if sales_order.is_none() {
+            tracing::info!(
+                sales_order_id = &sales_order_id,
+                "sales order does not exist"
+            );
+
+            Err(FindOneFailure::NotFound(
+                "sales order does not exist".to_string(),
+            ))?;
+        }
+        let sales_order = sales_order.unwrap();
+
+        let lines = self.sales_repo.find_lines(sales_order_id).await?;
+
+        Ok(SalesOrderDetailsSelector {
+            sales_order_id: sales_order.sales_order_id,
+            customer_id: sales_order.customer_id,
+            status: sales_order.status,
+            created_at: sales_order.created_at,
+            confirmed_at: sales_order.confirmed_at,
+            cancelled_at: sales_order.cancelled_at,
+            fulfilled_at: sales_order.fulfilled_at,
+            lines,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl FindAvailableToPromiseUseCase for SalesOrderService {
+    async fn find_available_to_promise(
+        &self,
+        product_id: i32,
+    ) -> Result<AvailableToPromiseSelector, FindOneFailure> {
+        let product = self.product_repo.find_by_id(product_id).await?;
+
+        if product.is_none() {
+            tracing::info!(product_id = &product_id, "product does not exist");
+
+            Err(FindOneFailure::NotFound(
+                "product does not exist".to_string(),
+            ))?;
+        }
+
+        let structure = find_shared_kit_structure(self.component_repo.as_ref(), product_id).await?;
+        let committed_stock = self
+            .find_committed_stock(&stock_product_ids(&structure, product_id))
+            .await?;
+        let available = available_to_promise(&structure, product_id, &committed_stock, &[]);
+
+        Ok(AvailableToPromiseSelector {
+            product_id,
+            on_hand: available.on_hand(),
+            reserved: available.reserved(),
+            backordered: available.backordered(),
+            available_to_promise: available.quantity(),
+        })
+    }
+}
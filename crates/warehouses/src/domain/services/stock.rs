@@ -0,0 +1,547 @@
+use crate::domain::changes::stock::AddStockMovement;
+use crate::domain::commands::stock::RecordStockMovementCommand;
+use crate::domain::ports::api::stock::{
+    FindProductLocationsUseCase, FindProductOnHandUseCase, FindStockMovementsUseCase,
+    RecordStockMovementUseCase, TraceStockMovementsUseCase,
+};
+use crate::domain::ports::spi::kit::KitComponentRepository;
+use crate::domain::ports::spi::location::StorageLocationRepository;
+use crate::domain::ports::spi::lot::StockLotRepository;
+use crate::domain::ports::spi::product::ProductRepository;
+use crate::domain::ports::spi::stock::StockMovementRepository;
+use crate::domain::ports::spi::transfer::StockTransferRepository;
+use crate::domain::ports::spi::unit::UnitOfMeasureRepository;
+use crate::domain::queries::stock::StockQuery;
+use crate::domain::selectors::lot::StockLotSelector;
+use crate::domain::selectors::stock::{
+    ProductLocationSelector, ProductOnHandSelector, StockMovementPageSelector,
+};
+use crate::domain::services::kit::{
+    check_kit_structure, find_kit_structure, find_on_hand_of_products, kit_issue_movement,
+};
+use crate::domain::services::unit::normalise_quantity;
+use crate::domain::services::valuation::find_unit_cost;
+use crate::domain::valuables::lot::LotExpiry;
+use crate::domain::valuables::money::Money;
+use crate::domain::valuables::stock::{StockMovementKind, StockQuantity};
+use crate::domain::valuables::tracking::TrackedUnit;
+use chrono::NaiveDate;
+use ids_std_domain::api::failure::{
+    CreateDomainFailure, FindManyFailure, FindOneFailure, InvalidField, UpdateDomainFailure,
+};
+use ids_std_domain::pagination::{Page, PaginationQuery};
+use ids_std_domain::spi::failure::SelectRepoFailure;
+use ids_std_domain::validation;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct StockService {
+    stock_repo: Arc<dyn StockMovementRepository>,
+    product_repo: Arc<dyn ProductRepository>,
+    location_repo: Arc<dyn StorageLocationRepository>,
+    transfer_repo: Arc<dyn StockTransferRepository>,
+    lot_repo: Arc<dyn StockLotRepository>,
+    unit_repo: Arc<dyn UnitOfMeasureRepository>,
+    component_repo: Arc<dyn KitComponentRepository>,
+}
+
+impl StockService {
+    pub fn new(
+        stock_repo: Arc<dyn StockMovementRepository>,
+        product_repo: Arc<dyn ProductRepository>,
+        location_repo: Arc<dyn StorageLocationRepository>,
+        transfer_repo: Arc<dyn StockTransferRepository>,
+        lot_repo: Arc<dyn StockLotRepository>,
+        unit_repo: Arc<dyn UnitOfMeasureRepository>,
+        component_repo: Arc<dyn KitComponentRepository>,
+    ) -> Self {
+        Self {
+            stock_repo,
+            product_repo,
+            location_repo,
+            transfer_repo,
+            lot_repo,
+            unit_repo,
+            component_repo,
+        }
+    }
+
+    async fn save_in_stock(
+        &self,
+        product_id: i32,
+        events: &[AddStockMovement],
+    ) -> Result<i32, CreateDomainFailure> {
+        let stock_movement_ids = self.stock_repo.save_all_in_stock(events).await?;
+        let Some(stock_movement_ids) = stock_movement_ids else {
+            tracing::info!(
+                product_id = &product_id,
+                "stock moved out while recording the movement"
+            );
+
+            return Err(CreateDomainFailure::Conflict(
+                "insufficient stock".to_string(),
+            ));
+        };
+
+        Ok(stock_movement_ids[0])
+    }
+}
+
+pub(crate) enum TrackedUnitFailure {
+    InvalidField(InvalidField),
+    Conflict(String),
+}
+
+impl From<TrackedUnitFailure> for CreateDomainFailure {
+    fn from(value: TrackedUnitFailure) -> Self {
+        match value {
+            TrackedUnitFailure::InvalidField(field) => Self::InvalidField(field),
+            TrackedUnitFailure::Conflict(message) => Self::Conflict(message),
+        }
+    }
+}
+
+impl From<TrackedUnitFailure> for UpdateDomainFailure {
+    fn from(value: TrackedUnitFailure) -> Self {
+        match value {
+            TrackedUnitFailure::InvalidField(field) => Self::InvalidField(field),
+            TrackedUnitFailure::Conflict(message) => Self::Conflict(message),
+        }
+    }
+}
+
+pub(crate) async fn check_tracked_unit(
+    stock_repo: &dyn StockMovementRepository,
+    lot_repo: &dyn StockLotRepository,
+    product_id: i32,
+    tracked_unit: &TrackedUnit,
+    quantity: i32,
+    expires_on: Option<NaiveDate>,
+) -> Result<Result<Option<StockLotSelector>, TrackedUnitFailure>, SelectRepoFailure> {
+    if *tracked_unit != TrackedUnit::Untracked {
+        let unit_on_hand = stock_repo
+            .find_on_hand_of_unit(product_id, tracked_unit)
+            .await?;
+
+        if unit_on_hand + quantity < 0 {
+            tracing::info!(
+                product_id = &product_id,
+                on_hand = &unit_on_hand,
+                "insufficient stock of the tracked unit"
+            );
+
+            return Ok(Err(TrackedUnitFailure::Conflict(
+                "insufficient stock of the tracked unit".to_string(),
+            )));
+        }
+
+        if matches!(tracked_unit, TrackedUnit::Serial(_)) && quantity > 0 && unit_on_hand > 0 {
+            tracing::info!(product_id = &product_id, "serial number already in stock");
+
+            return Ok(Err(TrackedUnitFailure::Conflict(
+                "serial number already in stock".to_string(),
+            )));
+        }
+    }
+
+    let stock_lot = match tracked_unit {
+        TrackedUnit::Lot(lot_number) => lot_repo.find_by_lot(product_id, lot_number).await?,
+        _ => None,
+    };
+
+    if let Some(expires_on) = expires_on {
+        if !matches!(tracked_unit, TrackedUnit::Lot(_)) || quantity < 0 {
+            tracing::info!(
+                product_id = &product_id,
+                "expiry date outside of a lot receipt"
+            );
+
+            return Ok(Err(TrackedUnitFailure::InvalidField(InvalidField::new(
+                "expires_on".into(),
+                "expiry date is only recorded on lot receipts".into(),
+            ))));
+        }
+
+        if stock_lot
+            .as_ref()
+            .is_some_and(|lot| lot.expires_on != expires_on)
+        {
+            tracing::info!(
+                product_id = &product_id,
+                "lot already recorded with another expiry date"
+            );
+
+            return Ok(Err(TrackedUnitFailure::Conflict(
+                "lot already recorded with another expiry date".to_string(),
+            )));
+        }
+    }
+
+    Ok(Ok(stock_lot))
+}
+
+#[async_trait::async_trait]
+impl RecordStockMovementUseCase for StockService {
+    async fn record_stock_movement(
+        &self,
+        command: &RecordStockMovementCommand,
+    ) -> Result<i32, CreateDomainFailure> {
+        tracing::info!("recording stock movement {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        if command.kind.is_transfer() {
+            tracing::info!(
+                kind = command.kind.as_str(),
+                "transfer movement recorded directly"
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "kind".into(),
+                "transfer movements are booked through stock transfers".into(),
+            )))?
+        }
+
+        let product = self.product_repo.find_by_id(command.product_id).await?;
+        if product.is_none() {
+            tracing::info!(product_id = &command.product_id, "product does not exist");
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "product_id".into(),
+                "product does not exist".into(),
+            )))?
+        }
+        let product = product.unwrap();
+
+        if let Some(storage_location_id) = command.storage_location_id {
+            let location = self.location_repo.find_by_id(storage_location_id).await?;
+
+            if location.is_none() {
+                tracing::info!(
+                    storage_location_id = &storage_location_id,
+                    "storage location does not exist"
+                );
+
+                Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                    "storage_location_id".into(),
+                    "storage location does not exist".into(),
+                )))?
+            }
+        }
+
+        let quantity = normalise_quantity(
+            self.unit_repo.as_ref(),
+            &product,
+            command.unit_id,
+            command.quantity,
+            "quantity",
+        )
+        .await?
+        .map_err(CreateDomainFailure::InvalidField)?;
+
+        let quantity = StockQuantity::new(command.kind, quantity);
+        if quantity.is_none() {
+            tracing::info!(quantity = &command.quantity, "invalid stock quantity");
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "quantity".into(),
+                "quantity is not valid for the movement kind".into(),
+            )))?
+        }
+        let quantity = quantity.unwrap().get();
+
+        let tracked_unit = product.tracking_mode.track(
+            command.lot_number.as_deref(),
+            command.serial_number.as_deref(),
+            quantity,
+        );
+        if let Err(violation) = tracked_unit {
+            tracing::info!(
+                product_id = &command.product_id,
+                tracking_mode = product.tracking_mode.as_str(),
+                "{}",
+                violation.message()
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                violation.field().into(),
+                violation.message().into(),
+            )))?
+        }
+        let tracked_unit = tracked_unit.unwrap();
+
+        let stock_lot = check_tracked_unit(
+            self.stock_repo.as_ref(),
+            self.lot_repo.as_ref(),
+            command.product_id,
+            &tracked_unit,
+            quantity,
+            command.expires_on,
+        )
+        .await?
+        .map_err(CreateDomainFailure::from)?;
+
+        let today = chrono::Utc::now().date_naive();
+        let expired_lot = stock_lot
+            .as_ref()
+            .is_some_and(|lot| LotExpiry::new(lot.expires_on).is_expired_on(today));
+
+        if quantity < 0 && expired_lot && !command.override_expiry {
+            tracing::info!(
+                product_id = &command.product_id,
+                "expired lot issued without an expiry override"
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "override_expiry".into(),
+                "expired lot can only be issued with an expiry override".into(),
+            )))?
+        }
+
+        if command.override_expiry && command.reason.is_none() {
+            tracing::info!(
+                product_id = &command.product_id,
+                "expiry override without a reason"
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "reason".into(),
+                "expiry override requires a reason".into(),
+            )))?
+        }
+
+        let structure =
+            find_kit_structure(self.component_repo.as_ref(), command.product_id).await?;
+        let kit_issued =
+            command.kind == StockMovementKind::Issue && structure.is_kit(command.product_id);
+
+        if quantity < 0 && !product.saleable_without_stock && !kit_issued {
+            let on_hand = match command.storage_location_id {
+                Some(storage_location_id) => {
+                    self.stock_repo
+                        .find_on_hand_at(command.product_id, storage_location_id)
+                        .await?
+                }
+                None => self.stock_repo.find_on_hand(command.product_id).await?,
+            };
+
+            if on_hand + quantity < 0 {
+                tracing::info!(
+                    product_id = &command.product_id,
+                    on_hand = &on_hand,
+                    "insufficient stock"
+                );
+
+                Err(CreateDomainFailure::Conflict(
+                    "insufficient stock".to_string(),
+                ))?;
+            }
+        }
+
+        let unit_cost = match &command.unit_cost {
+            Some(_) if quantity < 0 => {
+                tracing::info!(
+                    product_id = &command.product_id,
+                    "unit cost on an outbound quantity"
+                );
+
+                Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                    "unit_cost".into(),
+                    "unit cost is only recorded on inbound quantities".into(),
+                )))?
+            }
+            Some(unit_cost) => match Money::parse(unit_cost.as_str()) {
+                Some(amount) if !amount.is_negative() => Some(amount),
+                _ => Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                    "unit_cost".into(),
+                    "unit cost must be a positive decimal with up to four decimal places".into(),
+                )))?,
+            },
+            None if quantity > 0 => Some(find_unit_cost(self.stock_repo.as_ref(), &product).await?),
+            None => None,
+        };
+
+        if kit_issued {
+            check_kit_structure(self.product_repo.as_ref(), &structure, command.product_id)
+                .await?
+                .map_err(CreateDomainFailure::Conflict)?;
+
+            let on_hand = find_on_hand_of_products(
+                self.stock_repo.as_ref(),
+                &structure.product_ids(),
+                command.storage_location_id,
+            )
+            .await?;
+            let available = structure.available(command.product_id, &on_hand);
+
+            if !product.saleable_without_stock && available + quantity < 0 {
+                tracing::info!(
+                    product_id = &command.product_id,
+                    available = &available,
+                    "insufficient stock"
+                );
+
+                Err(CreateDomainFailure::Conflict(
+                    "insufficient stock".to_string(),
+                ))?;
+            }
+
+            let component_reason = command
+                .reason
+                .to_owned()
+                .or_else(|| Some(format!("kit {}", command.product_id)));
+            let kit_issued_events: Vec<AddStockMovement> = structure
+                .explode(command.product_id, -quantity, &on_hand)
+                .iter()
+                .map(|issue| {
+                    let reason = if issue.product_id == command.product_id {
+                        command.reason.to_owned()
+                    } else {
+                        component_reason.to_owned()
+                    };
+                    kit_issue_movement(issue, command.storage_location_id, reason)
+                })
+                .collect();
+            if product.saleable_without_stock {
+                let stock_movement_ids = self.stock_repo.save_all(&kit_issued_events).await?;
+
+                return Ok(stock_movement_ids[0]);
+            }
+
+            return self
+                .save_in_stock(command.product_id, &kit_issued_events)
+                .await;
+        }
+
+        let stock_moved_event = AddStockMovement {
+            product_id: command.product_id,
+            kind: command.kind,
+            quantity,
+            storage_location_id: command.storage_location_id,
+            lot_number: tracked_unit.lot_number(),
+            serial_number: tracked_unit.serial_number(),
+            unit_cost,
+            lot_expires_on: command.expires_on.filter(|_| stock_lot.is_none()),
+            reason: command.reason.to_owned(),
+        };
+        if quantity < 0 && !product.saleable_without_stock {
+            return self
+                .save_in_stock(command.product_id, std::slice::from_ref(&stock_moved_event))
+                .await;
+        }
+        let stock_movement_id = self.stock_repo.save(&stock_moved_event).await?;
+
+        Ok(stock_movement_id)
+    }
+}
+
+#[async_trait::async_trait]
+impl FindProductOnHandUseCase for StockService {
+    async fn find_product_on_hand(
+        &self,
+        product_id: i32,
+        criteria: &StockQuery,
+    ) -> Result<ProductOnHandSelector, FindOneFailure> {
+        let product = self.product_repo.find_by_id(product_id).await?;
+
+        if product.is_none() {
+            tracing::info!(product_id = &product_id, "product does not exist");
+
+            Err(FindOneFailure::NotFound(
+                "product does not exist".to_string(),
+            ))?;
+        }
+
+        let on_hand = match criteria.warehouse_id {
+            Some(warehouse_id) => {
+                self.stock_repo
+                    .find_on_hand_in_warehouse(product_id, warehouse_id)
+                    .await?
+            }
+            None => self.stock_repo.find_on_hand(product_id).await?,
+        };
+        let in_transit = self
+            .transfer_repo
+            .find_in_transit(product_id, criteria.warehouse_id)
+            .await?;
+
+        Ok(ProductOnHandSelector {
+            product_id,
+            warehouse_id: criteria.warehouse_id,
+            on_hand,
+            in_transit,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl FindStockMovementsUseCase for StockService {
+    async fn find_stock_movements(
+        &self,
+        query: &PaginationQuery,
+        product_id: i32,
+    ) -> Result<Page<StockMovementPageSelector>, FindManyFailure> {
+        Ok(self.stock_repo.find_by_product(query, product_id).await?)
+    }
+}
+
+#[async_trait::async_trait]
+impl TraceStockMovementsUseCase for StockService {
+    async fn trace_stock_movements(
+        &self,
+        query: &PaginationQuery,
+        tracking_code: &str,
+    ) -> Result<Page<StockMovementPageSelector>, FindManyFailure> {
+        Ok(self
+            .stock_repo
+            .find_by_tracking_code(query, tracking_code)
+            .await?)
+    }
+}
+
+#[async_trait::async_trait]
+impl FindProductLocationsUseCase for StockService {
+    async fn find_product_locations(
+        &self,
+        product_id: i32,
+    ) -> Result<Vec<ProductLocationSelector>, FindOneFailure> {
+        let product = self.product_repo.find_by_id(product_id).await?;
+
+        if product.is_none() {
+            tracing::info!(product_id = &product_id, "product does not exist");
+
+            Err(FindOneFailure::NotFound(
+                "product does not exist".to_string(),
+            ))?;
+        }
+
+        let stocked_locations: HashMap<i32, i32> = self
+            .stock_repo
+            .find_on_hand_by_location(product_id)
+            .await?
+            .into_iter()
+            .filter(|location| location.on_hand != 0)
+            .map(|location| (location.storage_location_id, location.on_hand))
+            .collect();
+
+        let storage_location_ids: Vec<i32> = stocked_locations.keys().copied().collect();
+        let locations = self
+            .location_repo
+            .find_by_ids(&storage_location_ids)
+            .await?;
+
+        Ok(locations
+            .into_iter()
+            .map(|location| ProductLocationSelector {
+                on_hand: stocked_locations[&location.storage_location_id],
+                storage_location_id: location.storage_location_id,
+                warehouse_id: location.warehouse_id,
+                warehouse_code: location.warehouse_code,
+                aisle: location.aisle,
+                shelf: location.shelf,
+                bin: location.bin,
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,326 @@
+use crate::domain::changes::supplier::{AddSupplier, AddSupplierProduct, UpdateSupplierProduct};
+use crate::domain::commands::supplier::{
+    AddSupplierProductCommand, CreateSupplierCommand, UpdateSupplierProductCommand,
+};
+use crate::domain::ports::api::supplier::{
+    AddSupplierProductUseCase, CreateSupplierUseCase, FindAllSuppliersUseCase,
+    FindPreferredSupplierUseCase, FindSupplierProductsUseCase, UpdateSupplierProductUseCase,
+};
+use crate::domain::ports::spi::product::ProductRepository;
+use crate::domain::ports::spi::supplier::SupplierRepository;
+use crate::domain::selectors::supplier::{
+    SupplierOfferSelector, SupplierProductSelector, SupplierSelector,
+};
+use crate::domain::valuables::money::{CurrencyCode, Money};
+use crate::domain::valuables::supplier::{SupplierCode, SupplierRank};
+use ids_std_domain::api::failure::{
+    CreateDomainFailure, FindManyFailure, FindOneFailure, InvalidField, UpdateDomainFailure,
+};
+use ids_std_domain::validation;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct SupplierService {
+    supplier_repo: Arc<dyn SupplierRepository>,
+    product_repo: Arc<dyn ProductRepository>,
+}
+
+impl SupplierService {
+    pub fn new(
+        supplier_repo: Arc<dyn SupplierRepository>,
+        product_repo: Arc<dyn ProductRepository>,
+    ) -> Self {
+        Self {
+            supplier_repo,
+            product_repo,
+        }
+    }
+}
+
+fn parse_last_price(last_price: &Option<String>) -> Result<Option<Money>, InvalidField> {
+    match last_price {
+        Some(last_price) => match Money::parse(last_price.as_str()) {
+            Some(amount) if !amount.is_negative() => Ok(Some(amount)),
+            _ => Err(InvalidField::new(
+                "last_price".into(),
+                "last price must be a positive decimal with up to four decimal places".into(),
+            )),
+        },
+        None => Ok(None),
+    }
+}
+
+#[async_trait::async_trait]
+impl CreateSupplierUseCase for SupplierService {
+    async fn create_supplier(
+        &self,
+        command: &CreateSupplierCommand,
+    ) -> Result<i32, CreateDomainFailure> {
+        tracing::info!("creating supplier {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let currency = CurrencyCode::parse(command.currency.as_str());
+        if currency.is_none() {
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "currency".into(),
+                "currency must be a three letter ISO 4217 code".into(),
+            )))?
+        }
+
+        if let Some(contact_person_id) = command.contact_person_id {
+            if !self.supplier_repo.exists_contact(contact_person_id).await? {
+                tracing::info!(
+                    contact_person_id = &contact_person_id,
+                    "contact person does not exist"
+                );
+
+                Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                    "contact_person_id".into(),
+                    "contact person does not exist".into(),
+                )))?
+            }
+        }
+
+        let another_supplier = self
+            .supplier_repo
+            .find_by_name(command.name.as_str())
+            .await?;
+
+        if let Some(sup) = another_supplier {
+            tracing::info!(supplier_id = &sup.supplier_id, "supplier already exist");
+
+            Err(CreateDomainFailure::Conflict(
+                "supplier already exist".to_string(),
+            ))?;
+        }
+
+        let supplier_created_event = AddSupplier {
+            name: command.name.to_owned(),
+            contact_person_id: command.contact_person_id,
+            currency: currency.unwrap().get(),
+        };
+        let supplier_id = self.supplier_repo.save(&supplier_created_event).await?;
+
+        Ok(supplier_id)
+    }
+}
+
+#[async_trait::async_trait]
+impl FindAllSuppliersUseCase for SupplierService {
+    async fn find_all_suppliers(&self) -> Result<Vec<SupplierSelector>, FindManyFailure> {
+        Ok(self.supplier_repo.find_all().await?)
+    }
+}
+
+#[async_trait::async_trait]
+impl AddSupplierProductUseCase for SupplierService {
+    async fn add_supplier_product(
+        &self,
+        command: &AddSupplierProductCommand,
+    ) -> Result<i32, CreateDomainFailure> {
+        tracing::info!("adding supplier product {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let supplier = self.supplier_repo.find_by_id(command.supplier_id).await?;
+        if supplier.is_none() {
+            tracing::info!(
+                supplier_id = &command.supplier_id,
+                "supplier does not exist"
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "supplier_id".into(),
+                "supplier does not exist".into(),
+            )))?
+        }
+
+        let product = self.product_repo.find_by_id(command.product_id).await?;
+        if product.is_none() {
+            tracing::info!(product_id = &command.product_id, "product does not exist");
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "product_id".into(),
+                "product does not exist".into(),
+            )))?
+        }
+
+        if !product.unwrap().purchasable {
+            tracing::info!(
+                product_id = &command.product_id,
+                "product is not purchasable"
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "product_id".into(),
+                "product is not purchasable".into(),
+            )))?
+        }
+
+        let last_price =
+            parse_last_price(&command.last_price).map_err(CreateDomainFailure::InvalidField)?;
+        let supplier_code = SupplierCode::new(command.supplier_code.as_str()).get();
+
+        let supplier_product = self
+            .supplier_repo
+            .find_product(command.supplier_id, command.product_id)
+            .await?;
+
+        if let Some(prd) = supplier_product {
+            tracing::info!(
+                supplier_product_id = &prd.supplier_product_id,
+                "supplier product already exist"
+            );
+
+            Err(CreateDomainFailure::Conflict(
+                "supplier product already exist".to_string(),
+            ))?;
+        }
+
+        let another_supplier_product = self
+            .supplier_repo
+            .find_product_by_code(command.supplier_id, supplier_code.as_str())
+            .await?;
+
+        if let Some(prd) = another_supplier_product {
+            tracing::info!(
+                supplier_product_id = &prd.supplier_product_id,
+                "supplier product code already exist"
+            );
+
+            Err(CreateDomainFailure::Conflict(
+                "supplier product code already exist".to_string(),
+            ))?;
+        }
+
+        let supplier_product_added_event = AddSupplierProduct {
+            supplier_id: command.supplier_id,
+            product_id: command.product_id,
+            supplier_code,
+            pack_size: command.pack_size,
+            lead_time_days: command.lead_time_days,
+            last_price,
+            preferred: command.preferred,
+        };
+        let supplier_product_id = self
+            .supplier_repo
+            .save_product(&supplier_product_added_event)
+            .await?;
+
+        Ok(supplier_product_id)
+    }
+}
+
+#[async_trait::async_trait]
+impl UpdateSupplierProductUseCase for SupplierService {
+    async fn update_supplier_product(
+        &self,
+        command: &UpdateSupplierProductCommand,
+    ) -> Result<(), UpdateDomainFailure> {
+        tracing::info!("updating supplier product {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let supplier_product = self
+            .supplier_repo
+            .find_product(command.supplier_id, command.product_id)
+            .await?;
+
+        if supplier_product.is_none() {
+            tracing::info!(
+                supplier_id = &command.supplier_id,
+                product_id = &command.product_id,
+                "supplier product does not exist"
+            );
+
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "product_id".into(),
+                "supplier product does not exist".into(),
+            )))?
+        }
+        let supplier_product = supplier_product.unwrap();
+
+        let last_price =
+            parse_last_price(&command.last_price).map_err(UpdateDomainFailure::InvalidField)?;
+        let supplier_code = SupplierCode::new(command.supplier_code.as_str()).get();
+
+        let another_supplier_product = self
+            .supplier_repo
+            .find_product_by_code(command.supplier_id, supplier_code.as_str())
+            .await?;
+
+        if let Some(prd) = another_supplier_product {
+            if prd.supplier_product_id != supplier_product.supplier_product_id {
+                tracing::info!(
+                    supplier_product_id = &prd.supplier_product_id,
+                    "supplier product code already exist"
+                );
+
+                Err(UpdateDomainFailure::Conflict(
+                    "supplier product code already exist".to_string(),
+                ))?;
+            }
+        }
+
+        let supplier_product_updated_event = UpdateSupplierProduct {
+            supplier_product_id: supplier_product.supplier_product_id,
+            product_id: command.product_id,
+            supplier_code,
+            pack_size: command.pack_size,
+            lead_time_days: command.lead_time_days,
+            last_price,
+            preferred: command.preferred,
+        };
+        self.supplier_repo
+            .update_product(&supplier_product_updated_event)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl FindSupplierProductsUseCase for SupplierService {
+    async fn find_supplier_products(
+        &self,
+        supplier_id: i32,
+    ) -> Result<Vec<SupplierProductSelector>, FindManyFailure> {
+        Ok(self.supplier_repo.find_products(supplier_id).await?)
+    }
+}
+
+#[async_trait::async_trait]
+impl FindPreferredSupplierUseCase for SupplierService {
+    async fn find_preferred_supplier(
+        &self,
+        product_id: i32,
+    ) -> Result<SupplierOfferSelector, FindOneFailure> {
+        tracing::info!(product_id = &product_id, "finding preferred supplier");
+
+        let offers = self.supplier_repo.find_offers(product_id).await?;
+
+        // Prices in different currencies are not comparable, so they only break
+        // ties when every offer is quoted in the same currency.
+        let same_currency = offers
+            .windows(2)
+            .all(|pair| pair[0].currency == pair[1].currency);
+        let preferred_offer = offers.into_iter().min_by_key(|offer| {
+            SupplierRank::new(
+                offer.preferred,
+                offer.lead_time_days,
+                offer.last_price.filter(|_| same_currency),
+            )
+        });
+
+        if preferred_offer.is_none() {
+            tracing::info!(product_id = &product_id, "product has no supplier");
+
+            Err(FindOneFailure::NotFound(
+                "product has no supplier".to_string(),
+            ))?;
+        }
+
+        Ok(preferred_offer.unwrap())
+    }
+}
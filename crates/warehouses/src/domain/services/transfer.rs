@@ -0,0 +1,453 @@
+use crate::domain::changes::transfer::{
+    AddStockTransfer, CancelStockTransfer, ReceiveStockTransfer,
+};
+use crate::domain::commands::transfer::{ReceiveStockTransferCommand, TransferStockCommand};
+use crate::domain::ports::api::transfer::{
+    CancelStockTransferUseCase, FindAllStockTransfersUseCase, FindStockTransferUseCase,
+    ReceiveStockTransferUseCase, TransferStockUseCase,
+};
+use crate::domain::ports::spi::location::StorageLocationRepository;
+use crate::domain::ports::spi::product::ProductRepository;
+use crate::domain::ports::spi::stock::StockMovementRepository;
+use crate::domain::ports::spi::transfer::StockTransferRepository;
+use crate::domain::ports::spi::unit::UnitOfMeasureRepository;
+use crate::domain::ports::spi::warehouse::WarehouseRepository;
+use crate::domain::queries::transfer::StockTransferQuery;
+use crate::domain::selectors::transfer::StockTransferSelector;
+use crate::domain::services::unit::normalise_quantity;
+use crate::domain::services::valuation::{find_issue_cost, find_unit_cost};
+use crate::domain::valuables::money::Money;
+use crate::domain::valuables::stock::{StockMovementKind, StockQuantity};
+use crate::domain::valuables::tracking::TrackedUnit;
+use ids_std_domain::api::failure::{
+    CreateDomainFailure, FindManyFailure, FindOneFailure, InvalidField, UpdateDomainFailure,
+};
+use ids_std_domain::pagination::{Page, PaginationQuery};
+use ids_std_domain::validation;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct StockTransferService {
+    transfer_repo: Arc<dyn StockTransferRepository>,
+    stock_repo: Arc<dyn StockMovementRepository>,
+    product_repo: Arc<dyn ProductRepository>,
+    location_repo: Arc<dyn StorageLocationRepository>,
+    warehouse_repo: Arc<dyn WarehouseRepository>,
+    unit_repo: Arc<dyn UnitOfMeasureRepository>,
+}
+
+impl StockTransferService {
+    pub fn new(
+        transfer_repo: Arc<dyn StockTransferRepository>,
+        stock_repo: Arc<dyn StockMovementRepository>,
+        product_repo: Arc<dyn ProductRepository>,
+        location_repo: Arc<dyn StorageLocationRepository>,
+        warehouse_repo: Arc<dyn WarehouseRepository>,
+        unit_repo: Arc<dyn UnitOfMeasureRepository>,
+    ) -> Self {
+        Self {
+            transfer_repo,
+            stock_repo,
+            product_repo,
+            location_repo,
+            warehouse_repo,
+            unit_repo,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl TransferStockUseCase for StockTransferService {
+    async fn transfer_stock(
+        &self,
+        command: &TransferStockCommand,
+    ) -> Result<i32, CreateDomainFailure> {
+        tracing::info!("transferring stock {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let product = self.product_repo.find_by_id(command.product_id).await?;
+        if product.is_none() {
+            tracing::info!(product_id = &command.product_id, "product does not exist");
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "product_id".into(),
+                "product does not exist".into(),
+            )))?
+        }
+        let product = product.unwrap();
+
+        let source_location = self
+            .location_repo
+            .find_by_id(command.source_location_id)
+            .await?;
+        if source_location.is_none() {
+            tracing::info!(
+                source_location_id = &command.source_location_id,
+                "storage location does not exist"
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "source_location_id".into(),
+                "storage location does not exist".into(),
+            )))?
+        }
+        let source_location = source_location.unwrap();
+
+        let destination_warehouse = self
+            .warehouse_repo
+            .find_by_id(command.destination_warehouse_id)
+            .await?;
+        if destination_warehouse.is_none() {
+            tracing::info!(
+                destination_warehouse_id = &command.destination_warehouse_id,
+                "warehouse does not exist"
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "destination_warehouse_id".into(),
+                "warehouse does not exist".into(),
+            )))?
+        }
+
+        if source_location.warehouse_id == command.destination_warehouse_id {
+            tracing::info!(
+                destination_warehouse_id = &command.destination_warehouse_id,
+                "stock transfer within the same warehouse"
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "destination_warehouse_id".into(),
+                "destination warehouse must differ from the source warehouse".into(),
+            )))?
+        }
+
+        let quantity = normalise_quantity(
+            self.unit_repo.as_ref(),
+            &product,
+            command.unit_id,
+            command.quantity,
+            "quantity",
+        )
+        .await?
+        .map_err(CreateDomainFailure::InvalidField)?;
+
+        if StockQuantity::new(StockMovementKind::TransferOut, quantity).is_none() {
+            tracing::info!(quantity = &command.quantity, "invalid transfer quantity");
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "quantity".into(),
+                "quantity is not valid for the movement kind".into(),
+            )))?
+        }
+
+        let tracked_unit = product.tracking_mode.track(
+            command.lot_number.as_deref(),
+            command.serial_number.as_deref(),
+            quantity,
+        );
+        if let Err(violation) = tracked_unit {
+            tracing::info!(
+                product_id = &command.product_id,
+                tracking_mode = product.tracking_mode.as_str(),
+                "{}",
+                violation.message()
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                violation.field().into(),
+                violation.message().into(),
+            )))?
+        }
+        let tracked_unit = tracked_unit.unwrap();
+
+        if tracked_unit != TrackedUnit::Untracked {
+            let unit_on_hand = self
+                .stock_repo
+                .find_on_hand_of_unit_at(
+                    command.product_id,
+                    &tracked_unit,
+                    command.source_location_id,
+                )
+                .await?;
+
+            if unit_on_hand < quantity {
+                tracing::info!(
+                    product_id = &command.product_id,
+                    on_hand = &unit_on_hand,
+                    "insufficient stock of the tracked unit"
+                );
+
+                Err(CreateDomainFailure::Conflict(
+                    "insufficient stock of the tracked unit".to_string(),
+                ))?;
+            }
+        }
+
+        let on_hand = self
+            .stock_repo
+            .find_on_hand_at(command.product_id, command.source_location_id)
+            .await?;
+
+        if on_hand < quantity {
+            tracing::info!(
+                product_id = &command.product_id,
+                on_hand = &on_hand,
+                "insufficient stock"
+            );
+
+            Err(CreateDomainFailure::Conflict(
+                "insufficient stock".to_string(),
+            ))?;
+        }
+
+        let issued_cost = find_issue_cost(self.stock_repo.as_ref(), &product, quantity).await?;
+
+        let stock_transferred_event = AddStockTransfer {
+            product_id: command.product_id,
+            source_location_id: command.source_location_id,
+            source_warehouse_id: source_location.warehouse_id,
+            destination_warehouse_id: command.destination_warehouse_id,
+            quantity,
+            lot_number: tracked_unit.lot_number(),
+            serial_number: tracked_unit.serial_number(),
+            unit_cost: Money::from_minor(issued_cost.minor() / i64::from(quantity)),
+        };
+        let stock_transfer_id = self.transfer_repo.save(&stock_transferred_event).await?;
+        let Some(stock_transfer_id) = stock_transfer_id else {
+            tracing::info!(
+                product_id = &command.product_id,
+                "stock moved out while transferring"
+            );
+
+            return Err(CreateDomainFailure::Conflict(
+                "insufficient stock".to_string(),
+            ));
+        };
+
+        Ok(stock_transfer_id)
+    }
+}
+
+#[async_trait::async_trait]
+impl ReceiveStockTransferUseCase for StockTransferService {
+    async fn receive_stock_transfer(
+        &self,
+        command: &ReceiveStockTransferCommand,
+    ) -> Result<(), UpdateDomainFailure> {
+        tracing::info!("receiving stock transfer {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let stock_transfer = self
+            .transfer_repo
+            .find_by_id(command.stock_transfer_id)
+            .await?;
+        if stock_transfer.is_none() {
+            tracing::info!(
+                stock_transfer_id = &command.stock_transfer_id,
+                "stock transfer does not exist"
+            );
+
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "stock_transfer_id".into(),
+                "stock transfer does not exist".into(),
+            )))?
+        }
+        let stock_transfer = stock_transfer.unwrap();
+
+        if !stock_transfer.status.is_open() {
+            tracing::info!(
+                stock_transfer_id = &command.stock_transfer_id,
+                status = stock_transfer.status.as_str(),
+                "stock transfer is not in transit"
+            );
+
+            Err(UpdateDomainFailure::Conflict(
+                "stock transfer is not in transit".to_string(),
+            ))?;
+        }
+
+        let location = self
+            .location_repo
+            .find_by_id(command.storage_location_id)
+            .await?;
+        let in_destination = location
+            .map(|location| location.warehouse_id == stock_transfer.destination_warehouse_id)
+            .unwrap_or(false);
+
+        if !in_destination {
+            tracing::info!(
+                storage_location_id = &command.storage_location_id,
+                "storage location is not in the destination warehouse"
+            );
+
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "storage_location_id".into(),
+                "storage location is not in the destination warehouse".into(),
+            )))?
+        }
+
+        let product = self
+            .product_repo
+            .find_by_id(stock_transfer.product_id)
+            .await?
+            .unwrap();
+        let quantity = normalise_quantity(
+            self.unit_repo.as_ref(),
+            &product,
+            command.unit_id,
+            command.quantity,
+            "quantity",
+        )
+        .await?
+        .map_err(UpdateDomainFailure::InvalidField)?;
+
+        let in_transit = stock_transfer.quantity - stock_transfer.received_quantity;
+        if StockQuantity::new(StockMovementKind::TransferIn, quantity).is_none()
+            || quantity > in_transit
+        {
+            tracing::info!(
+                quantity = &command.quantity,
+                in_transit = &in_transit,
+                "invalid received quantity"
+            );
+
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "quantity".into(),
+                "quantity must be positive and not exceed the quantity in transit".into(),
+            )))?
+        }
+
+        let unit_cost = match stock_transfer.unit_cost {
+            Some(unit_cost) => unit_cost,
+            None => find_unit_cost(self.stock_repo.as_ref(), &product).await?,
+        };
+
+        let stock_received_event = ReceiveStockTransfer {
+            stock_transfer_id: command.stock_transfer_id,
+            product_id: stock_transfer.product_id,
+            storage_location_id: command.storage_location_id,
+            quantity,
+            unit_cost,
+        };
+        let received = self.transfer_repo.receive(&stock_received_event).await?;
+        if !received {
+            tracing::info!(
+                stock_transfer_id = &command.stock_transfer_id,
+                "stock transfer changed while receiving"
+            );
+
+            Err(UpdateDomainFailure::Conflict(
+                "quantity exceeds the quantity in transit".to_string(),
+            ))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl CancelStockTransferUseCase for StockTransferService {
+    async fn cancel_stock_transfer(
+        &self,
+        stock_transfer_id: i32,
+    ) -> Result<(), UpdateDomainFailure> {
+        tracing::info!(
+            stock_transfer_id = &stock_transfer_id,
+            "cancelling stock transfer"
+        );
+
+        let stock_transfer = self.transfer_repo.find_by_id(stock_transfer_id).await?;
+        if stock_transfer.is_none() {
+            tracing::info!(
+                stock_transfer_id = &stock_transfer_id,
+                "stock transfer does not exist"
+            );
+
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "stock_transfer_id".into(),
+                "stock transfer does not exist".into(),
+            )))?
+        }
+        let stock_transfer = stock_transfer.unwrap();
+
+        if !stock_transfer.status.is_open() {
+            tracing::info!(
+                stock_transfer_id = &stock_transfer_id,
+                status = stock_transfer.status.as_str(),
+                "stock transfer is not in transit"
+            );
+
+            Err(UpdateDomainFailure::Conflict(
+                "stock transfer is not in transit".to_string(),
+            ))?;
+        }
+
+        let unit_cost = match stock_transfer.unit_cost {
+            Some(unit_cost) => unit_cost,
+            None => {
+                let product = self
+                    .product_repo
+                    .find_by_id(stock_transfer.product_id)
+                    .await?
+                    .unwrap();
+                find_unit_cost(self.stock_repo.as_ref(), &product).await?
+            }
+        };
+
+        let stock_cancelled_event = CancelStockTransfer {
+            stock_transfer_id,
+            product_id: stock_transfer.product_id,
+            source_location_id: stock_transfer.source_location_id,
+            unit_cost,
+        };
+        let cancelled = self.transfer_repo.cancel(&stock_cancelled_event).await?;
+        if !cancelled {
+            tracing::info!(
+                stock_transfer_id = &stock_transfer_id,
+                "stock transfer closed while cancelling"
+            );
+
+            Err(UpdateDomainFailure::Conflict(
+                "stock transfer is not in transit".to_string(),
+            ))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl FindAllStockTransfersUseCase for StockTransferService {
+    async fn find_all_stock_transfers(
+        &self,
+        query: &PaginationQuery,
+        criteria: &StockTransferQuery,
+    ) -> Result<Page<StockTransferSelector>, FindManyFailure> {
+        Ok(self.transfer_repo.find_all(query, criteria).await?)
+    }
+}
+
+#[async_trait::async_trait]
+impl FindStockTransferUseCase for StockTransferService {
+    async fn find_stock_transfer(
+        &self,
+        stock_transfer_id: i32,
+    ) -> Result<StockTransferSelector, FindOneFailure> {
+        let stock_transfer = self.transfer_repo.find_by_id(stock_transfer_id).await?;
+
+        if stock_transfer.is_none() {
+            tracing::info!(
+                stock_transfer_id = &stock_transfer_id,
+                "stock transfer does not exist"
+            );
+
+            Err(FindOneFailure::NotFound(
+                "stock transfer does not exist".to_string(),
+            ))?;
+        }
+
+        Ok(stock_transfer.unwrap())
+    }
+}
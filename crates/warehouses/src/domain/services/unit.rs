@@ -0,0 +1,414 @@
+use crate::domain::changes::product::UpdateProductUnits;
+use crate::domain::changes::unit::{AddUnitConversion, AddUnitOfMeasure};
+use crate::domain::commands::unit::{
+    AddUnitConversionCommand, CreateUnitOfMeasureCommand, SetProductUnitsCommand,
+};
+use crate::domain::ports::api::unit::{
+    AddUnitConversionUseCase, CreateUnitOfMeasureUseCase, FindAllUnitsOfMeasureUseCase,
+    FindProductUnitsUseCase, SetProductUnitsUseCase,
+};
+use crate::domain::ports::spi::product::ProductRepository;
+use crate::domain::ports::spi::stock::StockMovementRepository;
+use crate::domain::ports::spi::unit::UnitOfMeasureRepository;
+use crate::domain::selectors::product::ProductSelector;
+use crate::domain::selectors::unit::{
+    ProductUnitsSelector, UnitConversionSelector, UnitOfMeasureDetailsSelector,
+};
+use crate::domain::valuables::money::Money;
+use crate::domain::valuables::unit::{UnitCode, UnitRatio};
+use ids_std_domain::api::failure::{
+    CreateDomainFailure, FindManyFailure, FindOneFailure, InvalidField, UpdateDomainFailure,
+};
+use ids_std_domain::spi::failure::SelectRepoFailure;
+use ids_std_domain::validation;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct UnitOfMeasureService {
+    unit_repo: Arc<dyn UnitOfMeasureRepository>,
+    product_repo: Arc<dyn ProductRepository>,
+    stock_repo: Arc<dyn StockMovementRepository>,
+}
+
+impl UnitOfMeasureService {
+    pub fn new(
+        unit_repo: Arc<dyn UnitOfMeasureRepository>,
+        product_repo: Arc<dyn ProductRepository>,
+        stock_repo: Arc<dyn StockMovementRepository>,
+    ) -> Self {
+        Self {
+            unit_repo,
+            product_repo,
+            stock_repo,
+        }
+    }
+}
+
+pub(crate) async fn find_unit_ratio(
+    unit_repo: &dyn UnitOfMeasureRepository,
+    unit_id: i32,
+    base_unit_id: i32,
+) -> Result<Option<UnitRatio>, SelectRepoFailure> {
+    if unit_id == base_unit_id {
+        return Ok(Some(UnitRatio::Identity));
+    }
+
+    if let Some(conversion) = unit_repo.find_conversion(unit_id, base_unit_id).await? {
+        return Ok(Some(UnitRatio::Multiply(conversion.factor)));
+    }
+
+    let ratio = unit_repo
+        .find_conversion(base_unit_id, unit_id)
+        .await?
+        .map(|conversion| UnitRatio::Divide(conversion.factor));
+
+    Ok(ratio)
+}
+
+async fn find_product_unit_ratio(
+    unit_repo: &dyn UnitOfMeasureRepository,
+    product: &ProductSelector,
+    unit_id: i32,
+) -> Result<Result<UnitRatio, InvalidField>, SelectRepoFailure> {
+    let ratio = match product.base_unit_id {
+        Some(base_unit_id) => find_unit_ratio(unit_repo, unit_id, base_unit_id).await?,
+        None => None,
+    };
+    let Some(ratio) = ratio else {
+        tracing::info!(
+            product_id = &product.product_id,
+            unit_id = &unit_id,
+            "unit cannot be converted to the base unit"
+        );
+
+        return Ok(Err(InvalidField::new(
+            "unit_id".into(),
+            "unit cannot be converted to the base unit of the product".into(),
+        )));
+    };
+
+    Ok(Ok(ratio))
+}
+
+pub(crate) async fn normalise_quantity(
+    unit_repo: &dyn UnitOfMeasureRepository,
+    product: &ProductSelector,
+    unit_id: Option<i32>,
+    quantity: i32,
+    quantity_field: &str,
+) -> Result<Result<i32, InvalidField>, SelectRepoFailure> {
+    let Some(unit_id) = unit_id else {
+        return Ok(Ok(quantity));
+    };
+
+    let ratio = match find_product_unit_ratio(unit_repo, product, unit_id).await? {
+        Ok(ratio) => ratio,
+        Err(field) => return Ok(Err(field)),
+    };
+
+    match ratio.to_base(quantity) {
+        Some(base_quantity) => Ok(Ok(base_quantity)),
+        None => {
+            tracing::info!(
+                product_id = &product.product_id,
+                quantity = &quantity,
+                "quantity is not a whole number of base units"
+            );
+
+            Ok(Err(InvalidField::new(
+                quantity_field.into(),
+                "quantity cannot be expressed in whole base units".into(),
+            )))
+        }
+    }
+}
+
+pub(crate) async fn normalise_unit_cost(
+    unit_repo: &dyn UnitOfMeasureRepository,
+    product: &ProductSelector,
+    unit_id: Option<i32>,
+    unit_price: Money,
+    base_quantity: i32,
+    price_field: &str,
+) -> Result<Result<Vec<(i32, Money)>, InvalidField>, SelectRepoFailure> {
+    let Some(unit_id) = unit_id else {
+        return Ok(Ok(vec![(base_quantity, unit_price)]));
+    };
+
+    let ratio = match find_product_unit_ratio(unit_repo, product, unit_id).await? {
+        Ok(ratio) => ratio,
+        Err(field) => return Ok(Err(field)),
+    };
+
+    match ratio.cost_to_base(unit_price, base_quantity) {
+        Some(base_costs) => Ok(Ok(base_costs)),
+        None => {
+            tracing::info!(
+                product_id = &product.product_id,
+                "unit price overflows in base units"
+            );
+
+            Ok(Err(InvalidField::new(
+                price_field.into(),
+                "unit price cannot be expressed in base units".into(),
+            )))
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl CreateUnitOfMeasureUseCase for UnitOfMeasureService {
+    async fn create_unit_of_measure(
+        &self,
+        command: &CreateUnitOfMeasureCommand,
+    ) -> Result<i32, CreateDomainFailure> {
+        tracing::info!("creating unit of measure {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let unit_code = UnitCode::new(command.code.as_str()).get();
+
+        let another_unit = self.unit_repo.find_by_code(unit_code.as_str()).await?;
+        if let Some(unit) = another_unit {
+            tracing::info!(code = &unit.code, "unit of measure code already exist");
+
+            Err(CreateDomainFailure::Conflict(
+                "unit of measure code already exist".to_string(),
+            ))?;
+        }
+
+        let unit_created_event = AddUnitOfMeasure {
+            code: unit_code,
+            name: command.name.to_owned(),
+        };
+        let unit_of_measure_id = self.unit_repo.save(&unit_created_event).await?;
+
+        Ok(unit_of_measure_id)
+    }
+}
+
+#[async_trait::async_trait]
+impl AddUnitConversionUseCase for UnitOfMeasureService {
+    async fn add_unit_conversion(
+        &self,
+        command: &AddUnitConversionCommand,
+    ) -> Result<i32, CreateDomainFailure> {
+        tracing::info!("adding unit conversion {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let from_unit = self.unit_repo.find_by_id(command.from_unit_id).await?;
+        if from_unit.is_none() {
+            tracing::info!(
+                unit_of_measure_id = &command.from_unit_id,
+                "unit of measure does not exist"
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "from_unit_id".into(),
+                "unit of measure does not exist".into(),
+            )))?
+        }
+
+        let to_unit = self.unit_repo.find_by_id(command.to_unit_id).await?;
+        if to_unit.is_none() {
+            tracing::info!(
+                unit_of_measure_id = &command.to_unit_id,
+                "unit of measure does not exist"
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "to_unit_id".into(),
+                "unit of measure does not exist".into(),
+            )))?
+        }
+
+        if command.from_unit_id == command.to_unit_id {
+            tracing::info!(
+                unit_of_measure_id = &command.from_unit_id,
+                "unit converted into itself"
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "to_unit_id".into(),
+                "unit cannot be converted into itself".into(),
+            )))?
+        }
+
+        let existing_ratio = find_unit_ratio(
+            self.unit_repo.as_ref(),
+            command.from_unit_id,
+            command.to_unit_id,
+        )
+        .await?;
+        if existing_ratio.is_some() {
+            tracing::info!(
+                from_unit_id = &command.from_unit_id,
+                to_unit_id = &command.to_unit_id,
+                "unit conversion already exist"
+            );
+
+            Err(CreateDomainFailure::Conflict(
+                "unit conversion already exist".to_string(),
+            ))?;
+        }
+
+        let conversion_added_event = AddUnitConversion {
+            from_unit_id: command.from_unit_id,
+            to_unit_id: command.to_unit_id,
+            factor: command.factor,
+        };
+        let unit_conversion_id = self
+            .unit_repo
+            .save_conversion(&conversion_added_event)
+            .await?;
+
+        Ok(unit_conversion_id)
+    }
+}
+
+#[async_trait::async_trait]
+impl FindAllUnitsOfMeasureUseCase for UnitOfMeasureService {
+    async fn find_all_units_of_measure(
+        &self,
+    ) -> Result<Vec<UnitOfMeasureDetailsSelector>, FindManyFailure> {
+        let units = self.unit_repo.find_all().await?;
+        let conversions = self.unit_repo.find_conversions().await?;
+
+        let mut conversions_by_unit: HashMap<i32, Vec<UnitConversionSelector>> = HashMap::new();
+        for conversion in conversions {
+            conversions_by_unit
+                .entry(conversion.from_unit_id)
+                .or_default()
+                .push(conversion);
+        }
+
+        Ok(units
+            .into_iter()
+            .map(|unit| UnitOfMeasureDetailsSelector {
+                unit_of_measure_id: unit.unit_of_measure_id,
+                code: unit.code,
+                name: unit.name,
+                conversions: conversions_by_unit
+                    .remove(&unit.unit_of_measure_id)
+                    .unwrap_or_default(),
+            })
+            .collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl SetProductUnitsUseCase for UnitOfMeasureService {
+    async fn set_product_units(
+        &self,
+        command: &SetProductUnitsCommand,
+    ) -> Result<(), UpdateDomainFailure> {
+        tracing::info!("setting product units {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let product = self.product_repo.find_by_id(command.product_id).await?;
+        if product.is_none() {
+            tracing::info!(product_id = &command.product_id, "product does not exist");
+
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "product_id".into(),
+                "product does not exist".into(),
+            )))?
+        }
+        let product = product.unwrap();
+
+        let base_unit = self.unit_repo.find_by_id(command.base_unit_id).await?;
+        if base_unit.is_none() {
+            tracing::info!(
+                unit_of_measure_id = &command.base_unit_id,
+                "unit of measure does not exist"
+            );
+
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "base_unit_id".into(),
+                "unit of measure does not exist".into(),
+            )))?
+        }
+
+        let units = [
+            ("purchase_unit_id", command.purchase_unit_id),
+            ("sale_unit_id", command.sale_unit_id),
+        ];
+        for (field, unit_id) in units {
+            let Some(unit_id) = unit_id else {
+                continue;
+            };
+
+            let ratio =
+                find_unit_ratio(self.unit_repo.as_ref(), unit_id, command.base_unit_id).await?;
+            if ratio.is_none() {
+                tracing::info!(
+                    unit_of_measure_id = &unit_id,
+                    "unit cannot be converted to the base unit"
+                );
+
+                Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                    field.into(),
+                    "unit cannot be converted to the base unit of the product".into(),
+                )))?
+            }
+        }
+
+        let base_unit_changed = product
+            .base_unit_id
+            .is_some_and(|base_unit_id| base_unit_id != command.base_unit_id);
+        if base_unit_changed {
+            let on_hand = self.stock_repo.find_on_hand(command.product_id).await?;
+
+            if on_hand != 0 {
+                tracing::info!(
+                    product_id = &command.product_id,
+                    on_hand = &on_hand,
+                    "base unit changed with stock on hand"
+                );
+
+                Err(UpdateDomainFailure::Conflict(
+                    "base unit cannot change while the product has stock on hand".to_string(),
+                ))?;
+            }
+        }
+
+        let units_updated_event = UpdateProductUnits {
+            product_id: command.product_id,
+            base_unit_id: command.base_unit_id,
+            purchase_unit_id: command.purchase_unit_id,
+            sale_unit_id: command.sale_unit_id,
+        };
+        self.product_repo.update_units(&units_updated_event).await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl FindProductUnitsUseCase for UnitOfMeasureService {
+    async fn find_product_units(
+        &self,
+        product_id: i32,
+    ) -> Result<ProductUnitsSelector, FindOneFailure> {
+        let product = self.product_repo.find_by_id(product_id).await?;
+
+        if product.is_none() {
+            tracing::info!(product_id = &product_id, "product does not exist");
+
+            Err(FindOneFailure::NotFound(
+                "product does not exist".to_string(),
+            ))?;
+        }
+        let product = product.unwrap();
+
+        Ok(ProductUnitsSelector {
+            product_id: product.product_id,
+            base_unit_id: product.base_unit_id,
+            purchase_unit_id: product.purchase_unit_id,
+            sale_unit_id: product.sale_unit_id,
+        })
+    }
+}
@@ -0,0 +1,249 @@
+use crate::domain::changes::product::UpdateProductCostingMethod;
+use crate::domain::commands::valuation::SetCostingMethodCommand;
+use crate::domain::ports::api::valuation::{
+    FindProductValuationUseCase, FindStockValuationUseCase, SetCostingMethodUseCase,
+};
+use crate::domain::ports::spi::product::ProductRepository;
+use crate::domain::ports::spi::stock::StockMovementRepository;
+use crate::domain::queries::valuation::StockValuationQuery;
+use crate::domain::selectors::product::ProductSelector;
+use crate::domain::selectors::stock::CostedMovementSelector;
+use crate::domain::selectors::valuation::{
+    FamilyValuationSelector, ProductValuationSelector, StockValuationSelector,
+};
+use crate::domain::valuables::money::Money;
+use crate::domain::valuables::stock::StockMovementKind;
+use crate::domain::valuables::valuation::CostLedger;
+use chrono::{NaiveDate, NaiveDateTime};
+use ids_std_domain::api::failure::{
+    FindManyFailure, FindOneFailure, InvalidField, UpdateDomainFailure,
+};
+use ids_std_domain::spi::failure::SelectRepoFailure;
+use ids_std_domain::validation;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct ValuationService {
+    product_repo: Arc<dyn ProductRepository>,
+    stock_repo: Arc<dyn StockMovementRepository>,
+}
+
+impl ValuationService {
+    pub fn new(
+        product_repo: Arc<dyn ProductRepository>,
+        stock_repo: Arc<dyn StockMovementRepository>,
+    ) -> Self {
+        Self {
+            product_repo,
+            stock_repo,
+        }
+    }
+}
+
+fn value_product<'a>(
+    product: &ProductSelector,
+    movements: impl Iterator<Item = &'a CostedMovementSelector>,
+) -> ProductValuationSelector {
+    let mut ledger = CostLedger::new(product.costing_method);
+    let mut issued_quantity = 0;
+    let mut issued_cost = 0;
+
+    for movement in movements {
+        let cost = ledger.post(movement.quantity, movement.unit_cost);
+        if movement.kind == StockMovementKind::Issue {
+            issued_quantity -= movement.quantity;
+            issued_cost += cost.minor();
+        }
+    }
+
+    ProductValuationSelector {
+        product_id: product.product_id,
+        family_id: product.family_id,
+        costing_method: product.costing_method,
+        quantity: ledger.quantity(),
+        unit_cost: ledger.unit_cost(),
+        value: ledger.value(),
+        issued_quantity,
+        issued_cost: Money::from_minor(issued_cost),
+    }
+}
+
+fn as_of_limits(criteria: &StockValuationQuery) -> (NaiveDate, NaiveDateTime) {
+    let as_of = criteria
+        .as_of
+        .unwrap_or_else(|| chrono::Utc::now().date_naive());
+    let moved_before = (as_of + chrono::Duration::days(1))
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+
+    (as_of, moved_before)
+}
+
+pub(crate) async fn find_unit_cost(
+    stock_repo: &dyn StockMovementRepository,
+    product: &ProductSelector,
+) -> Result<Money, SelectRepoFailure> {
+    let movements = stock_repo
+        .find_costed_movements(product.product_id, None)
+        .await?;
+
+    Ok(value_product(product, movements.iter()).unit_cost)
+}
+
+pub(crate) async fn find_issue_cost(
+    stock_repo: &dyn StockMovementRepository,
+    product: &ProductSelector,
+    quantity: i32,
+) -> Result<Money, SelectRepoFailure> {
+    let movements = stock_repo
+        .find_costed_movements(product.product_id, None)
+        .await?;
+
+    let mut ledger = CostLedger::new(product.costing_method);
+    for movement in &movements {
+        ledger.post(movement.quantity, movement.unit_cost);
+    }
+
+    Ok(ledger.issue(quantity))
+}
+
+#[async_trait::async_trait]
+impl SetCostingMethodUseCase for ValuationService {
+    async fn set_costing_method(
+        &self,
+        command: &SetCostingMethodCommand,
+    ) -> Result<(), UpdateDomainFailure> {
+        tracing::info!("setting costing method {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let product = self.product_repo.find_by_id(command.product_id).await?;
+        let Some(product) = product else {
+            tracing::info!(product_id = &command.product_id, "product does not exist");
+
+            return Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "product_id".into(),
+                "product does not exist".into(),
+            )));
+        };
+
+        // Valuations are replayed from the movements under the current method,
+        // changing it afterwards would rewrite past reports and issued costs.
+        if product.costing_method != command.costing_method {
+            let movements = self
+                .stock_repo
+                .find_costed_movements(command.product_id, None)
+                .await?;
+            if !movements.is_empty() {
+                tracing::info!(
+                    product_id = &command.product_id,
+                    "costing method changed on product with stock movements"
+                );
+
+                Err(UpdateDomainFailure::Conflict(
+                    "costing method cannot change once the product has stock movements".to_string(),
+                ))?;
+            }
+        }
+
+        let costing_method_updated_event = UpdateProductCostingMethod {
+            product_id: command.product_id,
+            costing_method: command.costing_method,
+        };
+        self.product_repo
+            .update_costing_method(&costing_method_updated_event)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl FindStockValuationUseCase for ValuationService {
+    async fn find_stock_valuation(
+        &self,
+        criteria: &StockValuationQuery,
+    ) -> Result<StockValuationSelector, FindManyFailure> {
+        tracing::info!("finding stock valuation {:?}", criteria);
+
+        let (as_of, moved_before) = as_of_limits(criteria);
+        let product_ids = self.stock_repo.find_stocked_products(moved_before).await?;
+        let movements = self
+            .stock_repo
+            .find_costed_movements_of_products(&product_ids, moved_before)
+            .await?;
+        let products = self.product_repo.find_by_ids(product_ids).await?;
+
+        let mut movements_by_product: BTreeMap<i32, Vec<CostedMovementSelector>> = BTreeMap::new();
+        for movement in movements {
+            movements_by_product
+                .entry(movement.product_id)
+                .or_default()
+                .push(movement);
+        }
+
+        let mut value = 0;
+        let mut families: BTreeMap<i32, FamilyValuationSelector> = BTreeMap::new();
+        let mut valued_products = Vec::new();
+        for product in products {
+            let movements = movements_by_product
+                .get(&product.product_id)
+                .map(Vec::as_slice)
+                .unwrap_or(&[]);
+            let valuation = value_product(&product, movements.iter());
+
+            let family = families
+                .entry(product.family_id)
+                .or_insert(FamilyValuationSelector {
+                    family_id: product.family_id,
+                    quantity: 0,
+                    value: Money::default(),
+                });
+            family.quantity += valuation.quantity;
+            family.value = Money::from_minor(family.value.minor() + valuation.value.minor());
+            value += valuation.value.minor();
+
+            valued_products.push(valuation);
+        }
+
+        Ok(StockValuationSelector {
+            as_of,
+            value: Money::from_minor(value),
+            families: families.into_values().collect(),
+            products: valued_products,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl FindProductValuationUseCase for ValuationService {
+    async fn find_product_valuation(
+        &self,
+        product_id: i32,
+        criteria: &StockValuationQuery,
+    ) -> Result<ProductValuationSelector, FindOneFailure> {
+        tracing::info!(
+            product_id = &product_id,
+            "finding product valuation {:?}",
+            criteria
+        );
+
+        let product = self.product_repo.find_by_id(product_id).await?;
+        let Some(product) = product else {
+            tracing::info!(product_id = &product_id, "product does not exist");
+
+            return Err(FindOneFailure::NotFound(
+                "product does not exist".to_string(),
+            ));
+        };
+
+        let (_, moved_before) = as_of_limits(criteria);
+        let movements = self
+            .stock_repo
+            .find_costed_movements(product_id, Some(moved_before))
+            .await?;
+
+        Ok(value_product(&product, movements.iter()))
+    }
+}
@@ -0,0 +1,268 @@
+use crate::domain::changes::variant::AddProductVariant;
+use crate::domain::commands::variant::{CreateProductVariantCommand, GenerateVariantMatrixCommand};
+use crate::domain::ports::api::variant::{
+    CreateProductVariantUseCase, FindProductVariantsUseCase, GenerateVariantMatrixUseCase,
+};
+use crate::domain::ports::spi::attribute::ProductAttributeRepository;
+use crate::domain::ports::spi::product::ProductRepository;
+use crate::domain::ports::spi::variant::ProductVariantRepository;
+use crate::domain::selectors::attribute::AttributeValueSelector;
+use crate::domain::selectors::variant::ProductVariantDetailsSelector;
+use crate::domain::valuables::product::{ProductSignature, ProductSku};
+use crate::domain::valuables::variant::VariantMatrix;
+use ids_std_domain::api::failure::{CreateDomainFailure, FindManyFailure, InvalidField};
+use ids_std_domain::spi::failure::SelectRepoFailure;
+use ids_std_domain::validation;
+use std::collections::{BTreeMap, HashSet};
+use std::sync::Arc;
+
+const MAX_MATRIX_SIZE: usize = 250;
+
+#[derive(Clone)]
+pub struct ProductVariantService {
+    variant_repo: Arc<dyn ProductVariantRepository>,
+    attribute_repo: Arc<dyn ProductAttributeRepository>,
+    product_repo: Arc<dyn ProductRepository>,
+}
+
+impl ProductVariantService {
+    pub fn new(
+        variant_repo: Arc<dyn ProductVariantRepository>,
+        attribute_repo: Arc<dyn ProductAttributeRepository>,
+        product_repo: Arc<dyn ProductRepository>,
+    ) -> Self {
+        Self {
+            variant_repo,
+            attribute_repo,
+            product_repo,
+        }
+    }
+}
+
+async fn find_attribute_values(
+    attribute_repo: &dyn ProductAttributeRepository,
+    attribute_value_ids: &[i32],
+) -> Result<Result<Vec<AttributeValueSelector>, InvalidField>, SelectRepoFailure> {
+    let value_ids = attribute_value_ids
+        .iter()
+        .copied()
+        .collect::<HashSet<i32>>();
+
+    let values = attribute_repo
+        .find_values(Some(value_ids.iter().copied().collect()))
+        .await?;
+
+    if values.len() != value_ids.len() {
+        tracing::info!("attribute value does not exist");
+
+        return Ok(Err(InvalidField::new(
+            "attribute_value_ids".into(),
+            "attribute value does not exist".into(),
+        )));
+    }
+
+    Ok(Ok(values))
+}
+
+fn compose_variant(
+    product_id: i32,
+    product_name: &str,
+    mut values: Vec<&AttributeValueSelector>,
+    sku: Option<String>,
+) -> AddProductVariant {
+    values.sort_by_key(|value| value.attribute_id);
+
+    let attributes = values
+        .iter()
+        .map(|value| (value.attribute_name.as_str(), value.value.as_str()))
+        .collect::<Vec<(&str, &str)>>();
+
+    let variant_name = std::iter::once(product_name)
+        .chain(values.iter().map(|value| value.value.as_str()))
+        .collect::<Vec<&str>>()
+        .join(" / ");
+
+    AddProductVariant {
+        product_id,
+        name: variant_name,
+        sku,
+        signature: ProductSignature::with_attributes(product_name, &attributes).get(),
+        attribute_value_ids: values
+            .iter()
+            .map(|value| value.product_attribute_value_id)
+            .collect(),
+    }
+}
+
+#[async_trait::async_trait]
+impl CreateProductVariantUseCase for ProductVariantService {
+    async fn create_product_variant(
+        &self,
+        command: &CreateProductVariantCommand,
+    ) -> Result<i32, CreateDomainFailure> {
+        tracing::info!("creating product variant {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let product = self
+            .product_repo
+            .find_details_by_id(command.product_id)
+            .await?;
+        if product.is_none() {
+            tracing::info!(product_id = &command.product_id, "product does not exist");
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "product_id".into(),
+                "product does not exist".into(),
+            )))?
+        }
+        let product = product.unwrap();
+
+        let values =
+            find_attribute_values(self.attribute_repo.as_ref(), &command.attribute_value_ids)
+                .await?
+                .map_err(CreateDomainFailure::InvalidField)?;
+
+        let attribute_ids = values
+            .iter()
+            .map(|value| value.attribute_id)
+            .collect::<HashSet<i32>>();
+        if attribute_ids.len() != values.len() {
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "attribute_value_ids".into(),
+                "variant cannot have two values of the same attribute".into(),
+            )))?
+        }
+
+        let variant_sku = command.sku.as_deref().map(|sku| ProductSku::new(sku).get());
+        if let Some(sku) = &variant_sku {
+            let variant_with_sku = self.variant_repo.find_by_sku(sku.as_str()).await?;
+            let product_with_sku = self.product_repo.find_by_sku(sku.as_str()).await?;
+
+            if variant_with_sku.is_some() || product_with_sku.is_some() {
+                tracing::info!(product_sku = &sku, "product sku already exist");
+
+                Err(CreateDomainFailure::Conflict(
+                    "product sku already exist".to_string(),
+                ))?;
+            }
+        }
+
+        let variant_created_event = compose_variant(
+            product.product_id,
+            product.name.as_str(),
+            values.iter().collect(),
+            variant_sku,
+        );
+
+        let another_variant = self
+            .variant_repo
+            .find_by_signature(variant_created_event.signature.as_str())
+            .await?;
+
+        if let Some(vrn) = another_variant {
+            tracing::info!(
+                product_variant_id = &vrn.product_variant_id,
+                "product variant already exist"
+            );
+
+            Err(CreateDomainFailure::Conflict(
+                "product variant already exist".to_string(),
+            ))?;
+        }
+
+        let variant_ids = self.variant_repo.save_all(&[variant_created_event]).await?;
+
+        Ok(variant_ids[0])
+    }
+}
+
+#[async_trait::async_trait]
+impl GenerateVariantMatrixUseCase for ProductVariantService {
+    async fn generate_variant_matrix(
+        &self,
+        command: &GenerateVariantMatrixCommand,
+    ) -> Result<Vec<i32>, CreateDomainFailure> {
+        tracing::info!("generating variant matrix {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let product = self
+            .product_repo
+            .find_details_by_id(command.product_id)
+            .await?;
+        if product.is_none() {
+            tracing::info!(product_id = &command.product_id, "product does not exist");
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "product_id".into(),
+                "product does not exist".into(),
+            )))?
+        }
+        let product = product.unwrap();
+
+        let values =
+            find_attribute_values(self.attribute_repo.as_ref(), &command.attribute_value_ids)
+                .await?
+                .map_err(CreateDomainFailure::InvalidField)?;
+
+        let mut axes: BTreeMap<i32, Vec<i32>> = BTreeMap::new();
+        for value in &values {
+            axes.entry(value.attribute_id)
+                .or_default()
+                .push(value.product_attribute_value_id);
+        }
+
+        let matrix = VariantMatrix::new(axes.into_values().collect());
+        if matrix.size() > MAX_MATRIX_SIZE {
+            tracing::info!(
+                product_id = &command.product_id,
+                matrix_size = &matrix.size(),
+                "variant matrix is too large"
+            );
+
+            Err(CreateDomainFailure::InvalidField(InvalidField::new(
+                "attribute_value_ids".into(),
+                "variant matrix is too large".into(),
+            )))?
+        }
+
+        let mut variants_created_events = Vec::new();
+        for combination in matrix.combinations() {
+            let combination_values = values
+                .iter()
+                .filter(|value| combination.contains(&value.product_attribute_value_id))
+                .collect();
+            let variant_created_event = compose_variant(
+                product.product_id,
+                product.name.as_str(),
+                combination_values,
+                None,
+            );
+
+            let existing_variant = self
+                .variant_repo
+                .find_by_signature(variant_created_event.signature.as_str())
+                .await?;
+            if existing_variant.is_none() {
+                variants_created_events.push(variant_created_event);
+            }
+        }
+
+        let variant_ids = self.variant_repo.save_all(&variants_created_events).await?;
+
+        Ok(variant_ids)
+    }
+}
+
+#[async_trait::async_trait]
+impl FindProductVariantsUseCase for ProductVariantService {
+    async fn find_product_variants(
+        &self,
+        product_id: i32,
+    ) -> Result<Vec<ProductVariantDetailsSelector>, FindManyFailure> {
+        tracing::info!(product_id = &product_id, "finding product variants");
+
+        Ok(self.variant_repo.find_by_product(product_id).await?)
+    }
+}
@@ -0,0 +1,141 @@
+use crate::domain::changes::warehouse::{AddWarehouse, UpdateWarehouse};
+use crate::domain::commands::warehouse::{CreateWarehouseCommand, UpdateWarehouseCommand};
+use crate::domain::ports::api::warehouse::{
+    CreateWarehouseUseCase, FindAllWarehousesUseCase, FindWarehouseUseCase, UpdateWarehouseUseCase,
+};
+use crate::domain::ports::spi::warehouse::WarehouseRepository;
+use crate::domain::queries::warehouse::WarehouseQuery;
+use crate::domain::selectors::warehouse::{WarehousePageSelector, WarehouseSelector};
+use crate::domain::valuables::warehouse::StorageCode;
+use ids_std_domain::api::failure::{
+    CreateDomainFailure, FindManyFailure, FindOneFailure, InvalidField, UpdateDomainFailure,
+};
+use ids_std_domain::pagination::{Page, PaginationQuery};
+use ids_std_domain::validation;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct WarehouseService {
+    warehouse_repo: Arc<dyn WarehouseRepository>,
+}
+
+impl WarehouseService {
+    pub fn new(warehouse_repo: Arc<dyn WarehouseRepository>) -> Self {
+        Self { warehouse_repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl CreateWarehouseUseCase for WarehouseService {
+    async fn create_warehouse(
+        &self,
+        command: &CreateWarehouseCommand,
+    ) -> Result<i32, CreateDomainFailure> {
+        tracing::info!("creating warehouse {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let warehouse_code = StorageCode::new(command.code.as_str()).get();
+
+        let another_warehouse = self
+            .warehouse_repo
+            .find_by_code(warehouse_code.as_str())
+            .await?;
+
+        if let Some(warehouse) = another_warehouse {
+            tracing::info!(code = &warehouse.code, "warehouse code already exist");
+
+            Err(CreateDomainFailure::Conflict(
+                "warehouse code already exist".to_string(),
+            ))?;
+        }
+
+        let warehouse_created_event = AddWarehouse {
+            code: warehouse_code,
+            name: command.name.to_owned(),
+            address: command.address.to_owned(),
+        };
+        let warehouse_id = self.warehouse_repo.save(&warehouse_created_event).await?;
+
+        Ok(warehouse_id)
+    }
+}
+
+#[async_trait::async_trait]
+impl UpdateWarehouseUseCase for WarehouseService {
+    async fn update_warehouse(
+        &self,
+        command: &UpdateWarehouseCommand,
+    ) -> Result<(), UpdateDomainFailure> {
+        tracing::info!("updating warehouse {:?}", command);
+
+        validation::Validator::try_validate(command)?;
+
+        let warehouse = self.warehouse_repo.find_by_id(command.warehouse_id).await?;
+
+        if warehouse.is_none() {
+            tracing::info!(
+                warehouse_id = &command.warehouse_id,
+                "warehouse does not exist"
+            );
+
+            Err(UpdateDomainFailure::InvalidField(InvalidField::new(
+                "warehouse_id".into(),
+                "warehouse does not exist".into(),
+            )))?
+        }
+
+        let warehouse_code = StorageCode::new(command.code.as_str()).get();
+
+        let another_warehouse = self
+            .warehouse_repo
+            .find_other_by_code(command.warehouse_id, warehouse_code.as_str())
+            .await?;
+
+        if let Some(warehouse) = another_warehouse {
+            tracing::info!(code = &warehouse.code, "warehouse code already exist");
+
+            Err(UpdateDomainFailure::Conflict(
+                "warehouse code already exist".to_string(),
+            ))?;
+        }
+
+        let warehouse_updated_event = UpdateWarehouse {
+            warehouse_id: command.warehouse_id,
+            code: warehouse_code,
+            name: command.name.to_owned(),
+            address: command.address.to_owned(),
+        };
+        self.warehouse_repo.update(&warehouse_updated_event).await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl FindAllWarehousesUseCase for WarehouseService {
+    async fn find_all_warehouses(
+        &self,
+        query: &PaginationQuery,
+        criteria: &WarehouseQuery,
+    ) -> Result<Page<WarehousePageSelector>, FindManyFailure> {
+        Ok(self.warehouse_repo.find_all(query, criteria).await?)
+    }
+}
+
+#[async_trait::async_trait]
+impl FindWarehouseUseCase for WarehouseService {
+    async fn find_warehouse(&self, warehouse_id: i32) -> Result<WarehouseSelector, FindOneFailure> {
+        let warehouse = self.warehouse_repo.find_by_id(warehouse_id).await?;
+
+        if warehouse.is_none() {
+            tracing::info!(warehouse_id = &warehouse_id, "warehouse does not exist");
+
+            Err(FindOneFailure::NotFound(
+                "warehouse does not exist".to_string(),
+            ))?;
+        }
+
+        Ok(warehouse.unwrap())
+    }
+}
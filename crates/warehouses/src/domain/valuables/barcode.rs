@@ -0,0 +1,148 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BarcodeSymbology {
+    Ean13,
+    UpcA,
+    Gtin14,
+}
+
+impl BarcodeSymbology {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ean13 => "ean13",
+            Self::UpcA => "upca",
+            Self::Gtin14 => "gtin14",
+        }
+    }
+
+    pub fn parse(symbology: &str) -> Option<Self> {
+        match symbology {
+            "ean13" => Some(Self::Ean13),
+            "upca" => Some(Self::UpcA),
+            "gtin14" => Some(Self::Gtin14),
+            _ => None,
+        }
+    }
+
+    fn from_len(len: usize) -> Option<Self> {
+        match len {
+            12 => Some(Self::UpcA),
+            13 => Some(Self::Ean13),
+            14 => Some(Self::Gtin14),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Barcode {
+    code: String,
+    symbology: BarcodeSymbology,
+}
+
+impl Barcode {
+    pub fn parse(code: &str) -> Result<Self, BarcodeViolation> {
+        let code = code.trim();
+
+        if !code.chars().all(|digit| digit.is_ascii_digit()) {
+            return Err(BarcodeViolation::NotNumeric);
+        }
+
+        let symbology =
+            BarcodeSymbology::from_len(code.len()).ok_or(BarcodeViolation::UnsupportedLength)?;
+
+        let (payload, check_digit) = code.split_at(code.len() - 1);
+        if Self::check_digit(payload).to_string() != check_digit {
+            return Err(BarcodeViolation::CheckDigit);
+        }
+
+        Ok(Self {
+            code: code.to_owned(),
+            symbology,
+        })
+    }
+
+    pub fn code(&self) -> String {
+        self.code.to_owned()
+    }
+
+    pub fn symbology(&self) -> BarcodeSymbology {
+        self.symbology
+    }
+
+    pub fn gtin(&self) -> String {
+        format!("{:0>14}", self.code)
+    }
+
+    fn check_digit(payload: &str) -> u32 {
+        let sum: u32 = payload
+            .chars()
+            .rev()
+            .filter_map(|digit| digit.to_digit(10))
+            .enumerate()
+            .map(|(position, digit)| if position % 2 == 0 { digit * 3 } else { digit })
+            .sum();
+
+        (10 - sum % 10) % 10
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BarcodeViolation {
+    NotNumeric,
+    UnsupportedLength,
+    CheckDigit,
+}
+
+impl BarcodeViolation {
+    pub fn message(&self) -> &'static str {
+        match self {
+            Self::NotNumeric => "barcode must contain only digits",
+            Self::UnsupportedLength => "barcode must be an EAN-13, UPC-A or GTIN-14 code",
+            Self::CheckDigit => "barcode check digit is not valid",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Barcode, BarcodeSymbology, BarcodeViolation};
+
+    #[test]
+    fn it_detect_symbology_by_length() {
+        let ean = Barcode::parse("4006381333931").unwrap();
+        let upc = Barcode::parse("036000291452").unwrap();
+        let gtin = Barcode::parse("10012345600019").unwrap();
+
+        assert_eq!(BarcodeSymbology::Ean13, ean.symbology());
+        assert_eq!(BarcodeSymbology::UpcA, upc.symbology());
+        assert_eq!(BarcodeSymbology::Gtin14, gtin.symbology());
+    }
+
+    #[test]
+    fn it_reject_wrong_check_digit() {
+        assert_eq!(
+            Err(BarcodeViolation::CheckDigit),
+            Barcode::parse("4006381333932")
+        );
+    }
+
+    #[test]
+    fn it_reject_unsupported_codes() {
+        assert_eq!(
+            Err(BarcodeViolation::NotNumeric),
+            Barcode::parse("40063813339A1")
+        );
+        assert_eq!(
+            Err(BarcodeViolation::UnsupportedLength),
+            Barcode::parse("96385074")
+        );
+    }
+
+    #[test]
+    fn it_pad_codes_into_gtin() {
+        let upc = Barcode::parse(" 036000291452 ").unwrap();
+
+        assert_eq!("036000291452", upc.code());
+        assert_eq!("00036000291452", upc.gtin());
+    }
+}
@@ -0,0 +1,81 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InventoryCountStatus {
+    Open,
+    Counting,
+    Reviewed,
+    Posted,
+}
+
+impl InventoryCountStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Open => "open",
+            Self::Counting => "counting",
+            Self::Reviewed => "reviewed",
+            Self::Posted => "posted",
+        }
+    }
+
+    pub fn parse(status: &str) -> Option<Self> {
+        match status {
+            "open" => Some(Self::Open),
+            "counting" => Some(Self::Counting),
+            "reviewed" => Some(Self::Reviewed),
+            "posted" => Some(Self::Posted),
+            _ => None,
+        }
+    }
+
+    pub fn next(&self) -> Option<Self> {
+        match self {
+            Self::Open => Some(Self::Counting),
+            Self::Counting => Some(Self::Reviewed),
+            Self::Reviewed => Some(Self::Posted),
+            Self::Posted => None,
+        }
+    }
+}
+
+pub struct CountVariance {
+    book_quantity: i32,
+    counted_quantity: Option<i32>,
+}
+
+impl CountVariance {
+    pub fn new(book_quantity: i32, counted_quantity: Option<i32>) -> Self {
+        Self {
+            book_quantity,
+            counted_quantity,
+        }
+    }
+
+    pub fn get(&self) -> Option<i32> {
+        self.counted_quantity
+            .map(|counted_quantity| counted_quantity - self.book_quantity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CountVariance, InventoryCountStatus};
+
+    #[test]
+    fn it_advance_count_until_posted() {
+        assert_eq!(
+            Some(InventoryCountStatus::Counting),
+            InventoryCountStatus::Open.next()
+        );
+        assert_eq!(
+            Some(InventoryCountStatus::Posted),
+            InventoryCountStatus::Reviewed.next()
+        );
+        assert_eq!(None, InventoryCountStatus::Posted.next());
+    }
+
+    #[test]
+    fn it_compute_variance_of_counted_line() {
+        assert_eq!(Some(-3), CountVariance::new(10, Some(7)).get());
+        assert_eq!(Some(2), CountVariance::new(0, Some(2)).get());
+        assert_eq!(None, CountVariance::new(10, None).get());
+    }
+}
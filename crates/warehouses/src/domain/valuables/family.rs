@@ -0,0 +1,62 @@
+use ids_std_utils::signer;
+
+pub struct ProductFamilySignature {
+    signature: String,
+}
+
+impl ProductFamilySignature {
+    pub fn new(name: &str) -> Self {
+        Self {
+            signature: Self::sign(name),
+        }
+    }
+
+    pub fn get(self) -> String {
+        self.signature
+    }
+
+    fn sign(name: &str) -> String {
+        let fingerprint = ProductFamilyName::new(name).get().to_lowercase();
+
+        signer::sign(fingerprint)
+    }
+}
+
+pub struct ProductFamilyName {
+    name: String,
+}
+
+impl ProductFamilyName {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.split_whitespace().collect::<Vec<&str>>().join(" "),
+        }
+    }
+
+    pub fn get(self) -> String {
+        self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ProductFamilyName, ProductFamilySignature};
+
+    #[test]
+    fn it_normalise_product_family_name_spaces() {
+        let expected = "Home Appliances";
+
+        let result = ProductFamilyName::new("  Home   Appliances ").get();
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn it_sign_product_family_ignoring_case_and_spaces() {
+        let expected = ProductFamilySignature::new("home appliances").get();
+
+        let result = ProductFamilySignature::new(" Home  APPLIANCES ").get();
+
+        assert_eq!(expected, result);
+    }
+}
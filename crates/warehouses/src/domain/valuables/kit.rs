@@ -0,0 +1,286 @@
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KitIssue {
+    pub product_id: i32,
+    pub quantity: i32,
+}
+
+pub struct KitStructure {
+    components: HashMap<i32, Vec<(i32, i32)>>,
+}
+
+impl KitStructure {
+    pub fn new(components: HashMap<i32, Vec<(i32, i32)>>) -> Self {
+        Self { components }
+    }
+
+    pub fn is_kit(&self, product_id: i32) -> bool {
+        !self.components(product_id).is_empty()
+    }
+
+    pub fn components(&self, product_id: i32) -> &[(i32, i32)] {
+        self.components
+            .get(&product_id)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    pub fn contains(&self, product_id: i32) -> bool {
+        self.product_ids().contains(&product_id)
+    }
+
+    pub fn product_ids(&self) -> Vec<i32> {
+        let mut product_ids: HashSet<i32> = self.components.keys().copied().collect();
+        for components in self.components.values() {
+            product_ids.extend(components.iter().map(|(component_id, _)| *component_id));
+        }
+
+        let mut product_ids: Vec<i32> = product_ids.into_iter().collect();
+        product_ids.sort_unstable();
+        product_ids
+    }
+
+    pub fn is_cyclic(&self) -> bool {
+        let mut visited = HashSet::new();
+
+        self.components
+            .keys()
+            .any(|product_id| self.reaches_itself(*product_id, &mut Vec::new(), &mut visited))
+    }
+
+    pub fn buildable(&self, product_id: i32, on_hand: &HashMap<i32, i32>) -> i32 {
+        if !self.is_kit(product_id) {
+            return 0;
+        }
+
+        self.largest_fitting(product_id, on_hand, |quantity| {
+            self.assemble(product_id, quantity, on_hand)
+        })
+    }
+
+    pub fn available(&self, product_id: i32, on_hand: &HashMap<i32, i32>) -> i32 {
+        self.largest_fitting(product_id, on_hand, |quantity| {
+            self.explode(product_id, quantity, on_hand)
+        })
+    }
+
+    pub fn explode(
+        &self,
+        product_id: i32,
+        quantity: i32,
+        on_hand: &HashMap<i32, i32>,
+    ) -> Vec<KitIssue> {
+        let mut on_hand = on_hand.clone();
+        let mut issues = Vec::new();
+        self.explode_into(product_id, quantity, &mut on_hand, &mut issues);
+
+        issues
+    }
+
+    pub fn assemble(
+        &self,
+        product_id: i32,
+        quantity: i32,
+        on_hand: &HashMap<i32, i32>,
+    ) -> Vec<KitIssue> {
+        let mut on_hand = on_hand.clone();
+        let mut issues = Vec::new();
+        for (component_id, component_quantity) in self.components(product_id) {
+            self.explode_into(
+                *component_id,
+                component_quantity * quantity,
+                &mut on_hand,
+                &mut issues,
+            );
+        }
+
+        issues
+    }
+
+    fn largest_fitting(
+        &self,
+        product_id: i32,
+        on_hand: &HashMap<i32, i32>,
+        issue: impl Fn(i32) -> Vec<KitIssue>,
+    ) -> i32 {
+        // Sibling components may share stock, so quantities are checked by
+        // consuming the whole structure rather than per component.
+        let fits = |quantity: i32| {
+            let mut remaining = on_hand.clone();
+            issue(quantity).iter().all(|kit_issue| {
+                let stock = remaining.entry(kit_issue.product_id).or_insert(0);
+                *stock -= kit_issue.quantity;
+
+                self.is_kit(kit_issue.product_id) || *stock >= 0
+            })
+        };
+
+        let mut low = 0;
+        let mut high = self
+            .product_ids()
+            .into_iter()
+            .chain([product_id])
+            .map(|stocked_id| on_hand.get(&stocked_id).copied().unwrap_or(0).max(0))
+            .sum::<i32>();
+        while low < high {
+            let middle = low + (high - low + 1) / 2;
+            if fits(middle) {
+                low = middle;
+            } else {
+                high = middle - 1;
+            }
+        }
+
+        low
+    }
+
+    fn reaches_itself(
+        &self,
+        product_id: i32,
+        path: &mut Vec<i32>,
+        visited: &mut HashSet<i32>,
+    ) -> bool {
+        if path.contains(&product_id) {
+            return true;
+        }
+        if !visited.insert(product_id) {
+            return false;
+        }
+
+        path.push(product_id);
+        let cyclic = self
+            .components(product_id)
+            .iter()
+            .any(|(component_id, _)| self.reaches_itself(*component_id, path, visited));
+        path.pop();
+
+        cyclic
+    }
+
+    fn explode_into(
+        &self,
+        product_id: i32,
+        quantity: i32,
+        on_hand: &mut HashMap<i32, i32>,
+        issues: &mut Vec<KitIssue>,
+    ) {
+        let stock = on_hand.entry(product_id).or_insert(0);
+        let from_stock = if self.is_kit(product_id) {
+            quantity.min((*stock).max(0))
+        } else {
+            quantity
+        };
+        *stock -= from_stock;
+
+        if from_stock > 0 {
+            issues.push(KitIssue {
+                product_id,
+                quantity: from_stock,
+            });
+        }
+
+        let shortfall = quantity - from_stock;
+        if shortfall > 0 {
+            for (component_id, component_quantity) in self.components(product_id) {
+                self.explode_into(
+                    *component_id,
+                    component_quantity * shortfall,
+                    on_hand,
+                    issues,
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KitIssue, KitStructure};
+    use std::collections::HashMap;
+
+    fn phone_bundle() -> KitStructure {
+        KitStructure::new(HashMap::from([(1, vec![(2, 1), (3, 2)])]))
+    }
+
+    #[test]
+    fn it_limit_availability_by_scarcest_component() {
+        let on_hand = HashMap::from([(1, 1), (2, 10), (3, 7)]);
+
+        assert_eq!(3, phone_bundle().buildable(1, &on_hand));
+        assert_eq!(4, phone_bundle().available(1, &on_hand));
+    }
+
+    #[test]
+    fn it_count_shared_sub_components_once() {
+        let structure = KitStructure::new(HashMap::from([
+            (1, vec![(2, 1), (3, 1)]),
+            (2, vec![(4, 1)]),
+            (3, vec![(4, 1)]),
+        ]));
+        let on_hand = HashMap::from([(4, 1)]);
+
+        assert_eq!(0, structure.buildable(1, &on_hand));
+        assert_eq!(0, structure.available(1, &on_hand));
+        assert_eq!(1, structure.available(2, &on_hand));
+    }
+
+    #[test]
+    fn it_issue_assembled_kits_before_exploding() {
+        let on_hand = HashMap::from([(1, 1), (2, 10), (3, 7)]);
+
+        assert_eq!(
+            vec![
+                KitIssue {
+                    product_id: 1,
+                    quantity: 1
+                },
+                KitIssue {
+                    product_id: 2,
+                    quantity: 2
+                },
+                KitIssue {
+                    product_id: 3,
+                    quantity: 4
+                },
+            ],
+            phone_bundle().explode(1, 3, &on_hand)
+        );
+    }
+
+    #[test]
+    fn it_explode_nested_kits_when_assembling() {
+        let structure = KitStructure::new(HashMap::from([
+            (1, vec![(2, 1), (4, 1)]),
+            (4, vec![(3, 2)]),
+        ]));
+
+        assert_eq!(
+            vec![
+                KitIssue {
+                    product_id: 2,
+                    quantity: 2
+                },
+                KitIssue {
+                    product_id: 3,
+                    quantity: 4
+                },
+            ],
+            structure.assemble(1, 2, &HashMap::new())
+        );
+        assert!(structure.contains(3));
+        assert!(!structure.contains(5));
+    }
+
+    #[test]
+    fn it_detect_kit_containing_itself() {
+        let structure = KitStructure::new(HashMap::from([
+            (1, vec![(2, 1), (3, 2)]),
+            (3, vec![(4, 1)]),
+            (4, vec![(1, 1)]),
+        ]));
+
+        assert!(structure.is_cyclic());
+        assert!(!phone_bundle().is_cyclic());
+    }
+}
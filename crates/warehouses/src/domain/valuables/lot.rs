@@ -0,0 +1,140 @@
+use chrono::NaiveDate;
+
+pub struct LotExpiry {
+    expires_on: NaiveDate,
+}
+
+impl LotExpiry {
+    pub fn new(expires_on: NaiveDate) -> Self {
+        Self { expires_on }
+    }
+
+    pub fn is_expired_on(&self, today: NaiveDate) -> bool {
+        self.expires_on < today
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LotStock {
+    pub lot_number: String,
+    pub expires_on: Option<NaiveDate>,
+    pub on_hand: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LotPick {
+    pub lot_number: String,
+    pub expires_on: Option<NaiveDate>,
+    pub quantity: i32,
+}
+
+pub struct FefoPlan {
+    picks: Vec<LotPick>,
+    shortage: i32,
+}
+
+impl FefoPlan {
+    pub fn new(lots: &[LotStock], quantity: i32, today: NaiveDate, include_expired: bool) -> Self {
+        let mut candidates: Vec<&LotStock> = lots
+            .iter()
+            .filter(|lot| lot.on_hand > 0)
+            .filter(|lot| {
+                include_expired
+                    || !lot
+                        .expires_on
+                        .is_some_and(|expires_on| LotExpiry::new(expires_on).is_expired_on(today))
+            })
+            .collect();
+        candidates.sort_by(|a, b| match (a.expires_on, b.expires_on) {
+            (Some(a_expiry), Some(b_expiry)) => a_expiry.cmp(&b_expiry),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => a.lot_number.cmp(&b.lot_number),
+        });
+
+        let mut remaining = quantity.max(0);
+        let mut picks = Vec::new();
+        for lot in candidates {
+            if remaining == 0 {
+                break;
+            }
+
+            let picked = remaining.min(lot.on_hand);
+            picks.push(LotPick {
+                lot_number: lot.lot_number.to_owned(),
+                expires_on: lot.expires_on,
+                quantity: picked,
+            });
+            remaining -= picked;
+        }
+
+        Self {
+            picks,
+            shortage: remaining,
+        }
+    }
+
+    pub fn picks(&self) -> &[LotPick] {
+        &self.picks
+    }
+
+    pub fn shortage(&self) -> i32 {
+        self.shortage
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FefoPlan, LotExpiry, LotStock};
+    use chrono::NaiveDate;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 12, day).unwrap()
+    }
+
+    fn lot(lot_number: &str, expires_on: Option<NaiveDate>, on_hand: i32) -> LotStock {
+        LotStock {
+            lot_number: lot_number.to_string(),
+            expires_on,
+            on_hand,
+        }
+    }
+
+    #[test]
+    fn it_consider_lot_expired_after_expiry_date() {
+        assert!(!LotExpiry::new(date(10)).is_expired_on(date(10)));
+        assert!(LotExpiry::new(date(10)).is_expired_on(date(11)));
+    }
+
+    #[test]
+    fn it_pick_first_expired_lots_first() {
+        let lots = vec![
+            lot("L-3", None, 10),
+            lot("L-2", Some(date(20)), 4),
+            lot("L-1", Some(date(15)), 3),
+        ];
+
+        let plan = FefoPlan::new(&lots, 9, date(10), false);
+
+        let picked: Vec<(&str, i32)> = plan
+            .picks()
+            .iter()
+            .map(|pick| (pick.lot_number.as_str(), pick.quantity))
+            .collect();
+        assert_eq!(vec![("L-1", 3), ("L-2", 4), ("L-3", 2)], picked);
+        assert_eq!(0, plan.shortage());
+    }
+
+    #[test]
+    fn it_skip_expired_lots_unless_included() {
+        let lots = vec![lot("L-1", Some(date(5)), 3), lot("L-2", Some(date(20)), 4)];
+
+        let plan = FefoPlan::new(&lots, 6, date(10), false);
+        assert_eq!(1, plan.picks().len());
+        assert_eq!(2, plan.shortage());
+
+        let plan = FefoPlan::new(&lots, 6, date(10), true);
+        assert_eq!("L-1", plan.picks()[0].lot_number);
+        assert_eq!(0, plan.shortage());
+    }
+}
@@ -1 +1,19 @@
+pub mod barcode;
+pub mod count;
+pub mod family;
+pub mod kit;
+pub mod lot;
+pub mod money;
+pub mod price;
 pub mod product;
+pub mod purchase;
+pub mod reorder;
+pub mod sales;
+pub mod stock;
+pub mod supplier;
+pub mod tracking;
+pub mod transfer;
+pub mod unit;
+pub mod valuation;
+pub mod variant;
+pub mod warehouse;
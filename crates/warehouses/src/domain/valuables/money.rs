@@ -0,0 +1,136 @@
+const SCALE: u32 = 4;
+const UNIT: i64 = 10_i64.pow(SCALE);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Money {
+    minor: i64,
+}
+
+impl Money {
+    pub fn from_minor(minor: i64) -> Self {
+        Self { minor }
+    }
+
+    pub fn parse(amount: &str) -> Option<Self> {
+        let amount = amount.trim();
+        let (negative, digits) = match amount.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, amount),
+        };
+
+        let (units, fraction) = match digits.split_once('.') {
+            Some((units, fraction)) => (units, fraction),
+            None => (digits, ""),
+        };
+
+        let is_numeric = |part: &str| part.chars().all(|digit| digit.is_ascii_digit());
+        if units.is_empty() || !is_numeric(units) || !is_numeric(fraction) {
+            return None;
+        }
+        if fraction.len() > SCALE as usize || (digits.contains('.') && fraction.is_empty()) {
+            return None;
+        }
+
+        let units = units.parse::<i64>().ok()?;
+        let fraction = format!("{:0<width$}", fraction, width = SCALE as usize)
+            .parse::<i64>()
+            .ok()?;
+        let minor = units.checked_mul(UNIT)?.checked_add(fraction)?;
+
+        Some(Self {
+            minor: if negative { -minor } else { minor },
+        })
+    }
+
+    pub fn minor(&self) -> i64 {
+        self.minor
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.minor < 0
+    }
+
+    pub fn checked_add(&self, other: Money) -> Option<Money> {
+        self.minor.checked_add(other.minor).map(Self::from_minor)
+    }
+
+    pub fn checked_mul(&self, quantity: i32) -> Option<Money> {
+        self.minor
+            .checked_mul(i64::from(quantity))
+            .map(Self::from_minor)
+    }
+
+    pub fn format(&self) -> String {
+        let sign = if self.is_negative() { "-" } else { "" };
+        let minor = self.minor.unsigned_abs();
+        let unit = UNIT.unsigned_abs();
+
+        let fraction = format!("{:0>width$}", minor % unit, width = SCALE as usize);
+        let fraction = fraction.trim_end_matches('0');
+
+        format!("{}{}.{:0<2}", sign, minor / unit, fraction)
+    }
+}
+
+pub struct CurrencyCode {
+    code: String,
+}
+
+impl CurrencyCode {
+    pub fn parse(code: &str) -> Option<Self> {
+        let code = code.trim().to_uppercase();
+
+        if code.len() != 3 || !code.chars().all(|letter| letter.is_ascii_alphabetic()) {
+            return None;
+        }
+
+        Some(Self { code })
+    }
+
+    pub fn get(self) -> String {
+        self.code
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CurrencyCode, Money};
+
+    #[test]
+    fn it_parse_decimal_amounts() {
+        assert_eq!(Some(Money::from_minor(125_000)), Money::parse("12.5"));
+        assert_eq!(Some(Money::from_minor(120_000)), Money::parse("12"));
+        assert_eq!(Some(Money::from_minor(1)), Money::parse("0.0001"));
+        assert_eq!(Some(Money::from_minor(-9_990)), Money::parse("-0.999"));
+    }
+
+    #[test]
+    fn it_reject_malformed_amounts() {
+        assert_eq!(None, Money::parse("12.34567"));
+        assert_eq!(None, Money::parse("12."));
+        assert_eq!(None, Money::parse(".5"));
+        assert_eq!(None, Money::parse("1e3"));
+        assert_eq!(None, Money::parse("99999999999999999"));
+    }
+
+    #[test]
+    fn it_format_with_at_least_two_decimals() {
+        assert_eq!("12.50", Money::from_minor(125_000).format());
+        assert_eq!("0.0001", Money::from_minor(1).format());
+        assert_eq!("-3.75", Money::from_minor(-37_500).format());
+        assert_eq!("7.00", Money::from_minor(70_000).format());
+    }
+
+    #[test]
+    fn it_multiply_by_quantity() {
+        let price = Money::parse("19.99").unwrap();
+
+        assert_eq!(Some(Money::parse("59.97").unwrap()), price.checked_mul(3));
+    }
+
+    #[test]
+    fn it_normalise_currency_code() {
+        assert_eq!("EUR", CurrencyCode::parse(" eur ").unwrap().get());
+        assert!(CurrencyCode::parse("EURO").is_none());
+    }
+}
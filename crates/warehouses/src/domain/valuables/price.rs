@@ -0,0 +1,98 @@
+use chrono::NaiveDate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PriceListKind {
+    Retail,
+    Wholesale,
+}
+
+impl PriceListKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Retail => "retail",
+            Self::Wholesale => "wholesale",
+        }
+    }
+
+    pub fn parse(kind: &str) -> Option<Self> {
+        match kind {
+            "retail" => Some(Self::Retail),
+            "wholesale" => Some(Self::Wholesale),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PriceValidity {
+    valid_from: NaiveDate,
+    valid_to: Option<NaiveDate>,
+}
+
+impl PriceValidity {
+    pub fn new(valid_from: NaiveDate, valid_to: Option<NaiveDate>) -> Option<Self> {
+        if valid_to.is_some_and(|valid_to| valid_to < valid_from) {
+            return None;
+        }
+
+        Some(Self {
+            valid_from,
+            valid_to,
+        })
+    }
+
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.valid_from <= date && self.valid_to.map_or(true, |valid_to| date <= valid_to)
+    }
+
+    pub fn overlaps(&self, other: &PriceValidity) -> bool {
+        let starts_before_other_ends = other
+            .valid_to
+            .map_or(true, |valid_to| self.valid_from <= valid_to);
+        let ends_after_other_starts = self
+            .valid_to
+            .map_or(true, |valid_to| other.valid_from <= valid_to);
+
+        starts_before_other_ends && ends_after_other_starts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PriceValidity;
+    use chrono::NaiveDate;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 12, day).unwrap()
+    }
+
+    #[test]
+    fn it_reject_validity_ending_before_start() {
+        assert_eq!(None, PriceValidity::new(date(10), Some(date(9))));
+    }
+
+    #[test]
+    fn it_contain_dates_within_bounds() {
+        let validity = PriceValidity::new(date(10), Some(date(20))).unwrap();
+
+        assert!(validity.contains(date(10)));
+        assert!(validity.contains(date(20)));
+        assert!(!validity.contains(date(21)));
+        assert!(PriceValidity::new(date(10), None)
+            .unwrap()
+            .contains(date(31)));
+    }
+
+    #[test]
+    fn it_detect_overlapping_validities() {
+        let december = PriceValidity::new(date(1), Some(date(31))).unwrap();
+        let open_ended = PriceValidity::new(date(31), None).unwrap();
+        let early = PriceValidity::new(date(1), Some(date(9))).unwrap();
+        let late = PriceValidity::new(date(10), Some(date(20))).unwrap();
+
+        assert!(december.overlaps(&open_ended));
+        assert!(open_ended.overlaps(&december));
+        assert!(!early.overlaps(&late));
+        assert!(!late.overlaps(&early));
+    }
+}
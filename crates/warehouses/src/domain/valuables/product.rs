@@ -11,6 +11,20 @@ impl ProductSignature {
         }
     }
 
+    pub fn with_attributes(name: &str, attributes: &[(&str, &str)]) -> Self {
+        let mut attributes = attributes
+            .iter()
+            .map(|(attribute, value)| {
+                format!("{}={}", attribute.trim(), value.trim()).to_lowercase()
+            })
+            .collect::<Vec<String>>();
+        attributes.sort();
+
+        Self {
+            signature: Self::sign(format!("{}|{}", name, attributes.join("|")).as_str()),
+        }
+    }
+
     pub fn get(self) -> String {
         self.signature
     }
@@ -21,3 +35,48 @@ impl ProductSignature {
         signer::sign(fingerprint)
     }
 }
+
+pub struct ProductSku {
+    sku: String,
+}
+
+impl ProductSku {
+    pub fn new(sku: &str) -> Self {
+        Self {
+            sku: sku.trim().to_uppercase(),
+        }
+    }
+
+    pub fn get(self) -> String {
+        self.sku
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ProductSignature, ProductSku};
+
+    #[test]
+    fn it_sign_variants_by_attribute_values() {
+        let red = ProductSignature::with_attributes("T-shirt", &[("Colour", "Red"), ("Size", "M")]);
+        let blue =
+            ProductSignature::with_attributes("T-shirt", &[("Colour", "Blue"), ("Size", "M")]);
+
+        assert_ne!(red.get(), blue.get());
+    }
+
+    #[test]
+    fn it_sign_variants_regardless_of_attribute_order() {
+        let first =
+            ProductSignature::with_attributes("T-shirt", &[("Colour", "Red"), ("Size", "M")]);
+        let second =
+            ProductSignature::with_attributes("T-shirt", &[("size", "m"), ("colour", "red")]);
+
+        assert_eq!(first.get(), second.get());
+    }
+
+    #[test]
+    fn it_normalise_sku() {
+        assert_eq!("TS-RED-M", ProductSku::new(" ts-red-m ").get());
+    }
+}
@@ -0,0 +1,121 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PurchaseOrderStatus {
+    Draft,
+    Approved,
+    Sent,
+    PartiallyReceived,
+    Closed,
+}
+
+impl PurchaseOrderStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Draft => "draft",
+            Self::Approved => "approved",
+            Self::Sent => "sent",
+            Self::PartiallyReceived => "partially_received",
+            Self::Closed => "closed",
+        }
+    }
+
+    pub fn parse(status: &str) -> Option<Self> {
+        match status {
+            "draft" => Some(Self::Draft),
+            "approved" => Some(Self::Approved),
+            "sent" => Some(Self::Sent),
+            "partially_received" => Some(Self::PartiallyReceived),
+            "closed" => Some(Self::Closed),
+            _ => None,
+        }
+    }
+
+    pub fn is_receivable(&self) -> bool {
+        matches!(self, Self::Sent | Self::PartiallyReceived)
+    }
+
+    pub fn after_receipt(receipts: &[LineReceipt]) -> Self {
+        if receipts.iter().all(|receipt| receipt.is_complete()) {
+            Self::Closed
+        } else {
+            Self::PartiallyReceived
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineReceipt {
+    ordered_quantity: i32,
+    received_quantity: i32,
+}
+
+impl LineReceipt {
+    pub fn new(ordered_quantity: i32, received_quantity: i32) -> Self {
+        Self {
+            ordered_quantity,
+            received_quantity,
+        }
+    }
+
+    pub fn receive(&self, quantity: i32) -> Self {
+        Self::new(self.ordered_quantity, self.received_quantity + quantity)
+    }
+
+    pub fn received_quantity(&self) -> i32 {
+        self.received_quantity
+    }
+
+    pub fn outstanding_quantity(&self) -> i32 {
+        (self.ordered_quantity - self.received_quantity).max(0)
+    }
+
+    pub fn over_received_quantity(&self) -> i32 {
+        (self.received_quantity - self.ordered_quantity).max(0)
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.received_quantity >= self.ordered_quantity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LineReceipt, PurchaseOrderStatus};
+
+    #[test]
+    fn it_track_outstanding_and_over_received_quantities() {
+        let receipt = LineReceipt::new(10, 0).receive(4);
+
+        assert_eq!(6, receipt.outstanding_quantity());
+        assert_eq!(0, receipt.over_received_quantity());
+
+        let receipt = receipt.receive(8);
+
+        assert_eq!(0, receipt.outstanding_quantity());
+        assert_eq!(2, receipt.over_received_quantity());
+        assert!(receipt.is_complete());
+    }
+
+    #[test]
+    fn it_close_order_only_when_every_line_is_received() {
+        let partial = [LineReceipt::new(10, 10), LineReceipt::new(5, 3)];
+        let complete = [LineReceipt::new(10, 12), LineReceipt::new(5, 5)];
+
+        assert_eq!(
+            PurchaseOrderStatus::PartiallyReceived,
+            PurchaseOrderStatus::after_receipt(&partial)
+        );
+        assert_eq!(
+            PurchaseOrderStatus::Closed,
+            PurchaseOrderStatus::after_receipt(&complete)
+        );
+    }
+
+    #[test]
+    fn it_receive_only_sent_or_partially_received_orders() {
+        assert!(!PurchaseOrderStatus::Draft.is_receivable());
+        assert!(!PurchaseOrderStatus::Approved.is_receivable());
+        assert!(PurchaseOrderStatus::Sent.is_receivable());
+        assert!(PurchaseOrderStatus::PartiallyReceived.is_receivable());
+        assert!(!PurchaseOrderStatus::Closed.is_receivable());
+    }
+}
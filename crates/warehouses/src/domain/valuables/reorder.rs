@@ -0,0 +1,49 @@
+pub struct ReorderLevel {
+    min_quantity: i32,
+    max_quantity: Option<i32>,
+}
+
+impl ReorderLevel {
+    pub fn new(min_quantity: i32, max_quantity: Option<i32>) -> Self {
+        Self {
+            min_quantity,
+            max_quantity,
+        }
+    }
+
+    pub fn is_below(&self, on_hand: i32) -> bool {
+        on_hand < self.min_quantity
+    }
+
+    pub fn suggest(&self, on_hand: i32) -> Option<i32> {
+        if !self.is_below(on_hand) {
+            return None;
+        }
+
+        let target = self.max_quantity.unwrap_or(self.min_quantity);
+
+        Some(target - on_hand)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReorderLevel;
+
+    #[test]
+    fn it_not_suggest_stock_at_reorder_point() {
+        assert_eq!(None, ReorderLevel::new(5, Some(20)).suggest(5));
+        assert_eq!(None, ReorderLevel::new(5, None).suggest(12));
+    }
+
+    #[test]
+    fn it_suggest_refill_up_to_max_quantity() {
+        assert_eq!(Some(17), ReorderLevel::new(5, Some(20)).suggest(3));
+        assert_eq!(Some(22), ReorderLevel::new(5, Some(20)).suggest(-2));
+    }
+
+    #[test]
+    fn it_suggest_refill_up_to_reorder_point_without_max() {
+        assert_eq!(Some(4), ReorderLevel::new(5, None).suggest(1));
+    }
+}
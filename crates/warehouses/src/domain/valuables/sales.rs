@@ -0,0 +1,126 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SalesOrderStatus {
+    Draft,
+    Confirmed,
+    Cancelled,
+    Fulfilled,
+}
+
+impl SalesOrderStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Draft => "draft",
+            Self::Confirmed => "confirmed",
+            Self::Cancelled => "cancelled",
+            Self::Fulfilled => "fulfilled",
+        }
+    }
+
+    pub fn parse(status: &str) -> Option<Self> {
+        match status {
+            "draft" => Some(Self::Draft),
+            "confirmed" => Some(Self::Confirmed),
+            "cancelled" => Some(Self::Cancelled),
+            "fulfilled" => Some(Self::Fulfilled),
+            _ => None,
+        }
+    }
+
+    pub fn is_cancellable(&self) -> bool {
+        matches!(self, Self::Draft | Self::Confirmed)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AvailableToPromise {
+    on_hand: i32,
+    reserved: i32,
+    backordered: i32,
+}
+
+impl AvailableToPromise {
+    pub fn new(on_hand: i32, reserved: i32, backordered: i32) -> Self {
+        Self {
+            on_hand,
+            reserved,
+            backordered,
+        }
+    }
+
+    pub fn on_hand(&self) -> i32 {
+        self.on_hand
+    }
+
+    pub fn reserved(&self) -> i32 {
+        self.reserved
+    }
+
+    pub fn backordered(&self) -> i32 {
+        self.backordered
+    }
+
+    pub fn quantity(&self) -> i32 {
+        (self.on_hand - self.reserved - self.backordered).max(0)
+    }
+
+    pub fn reserve(&self, requested: i32) -> Reservation {
+        let reserved = requested.min(self.quantity());
+
+        Reservation {
+            reserved,
+            backordered: requested - reserved,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reservation {
+    reserved: i32,
+    backordered: i32,
+}
+
+impl Reservation {
+    pub fn reserved_quantity(&self) -> i32 {
+        self.reserved
+    }
+
+    pub fn backordered_quantity(&self) -> i32 {
+        self.backordered
+    }
+
+    pub fn is_backorder(&self) -> bool {
+        self.backordered > 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AvailableToPromise, SalesOrderStatus};
+
+    #[test]
+    fn it_promise_on_hand_not_yet_committed() {
+        assert_eq!(6, AvailableToPromise::new(10, 3, 1).quantity());
+        assert_eq!(0, AvailableToPromise::new(10, 8, 4).quantity());
+        assert_eq!(0, AvailableToPromise::new(-2, 0, 0).quantity());
+    }
+
+    #[test]
+    fn it_reserve_available_quantity_and_backorder_the_rest() {
+        let reservation = AvailableToPromise::new(10, 4, 0).reserve(5);
+        assert_eq!(5, reservation.reserved_quantity());
+        assert!(!reservation.is_backorder());
+
+        let reservation = AvailableToPromise::new(10, 9, 0).reserve(3);
+        assert_eq!(1, reservation.reserved_quantity());
+        assert_eq!(2, reservation.backordered_quantity());
+        assert!(reservation.is_backorder());
+    }
+
+    #[test]
+    fn it_cancel_only_draft_or_confirmed_orders() {
+        assert!(SalesOrderStatus::Draft.is_cancellable());
+        assert!(SalesOrderStatus::Confirmed.is_cancellable());
+        assert!(!SalesOrderStatus::Cancelled.is_cancellable());
+        assert!(!SalesOrderStatus::Fulfilled.is_cancellable());
+    }
+}
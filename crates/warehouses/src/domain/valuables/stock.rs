@@ -0,0 +1,86 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StockMovementKind {
+    Receipt,
+    Issue,
+    Adjustment,
+    TransferOut,
+    TransferIn,
+}
+
+impl StockMovementKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Receipt => "receipt",
+            Self::Issue => "issue",
+            Self::Adjustment => "adjustment",
+            Self::TransferOut => "transfer_out",
+            Self::TransferIn => "transfer_in",
+        }
+    }
+
+    pub fn is_transfer(&self) -> bool {
+        matches!(self, Self::TransferOut | Self::TransferIn)
+    }
+
+    pub fn parse(kind: &str) -> Option<Self> {
+        match kind {
+            "receipt" => Some(Self::Receipt),
+            "issue" => Some(Self::Issue),
+            "adjustment" => Some(Self::Adjustment),
+            "transfer_out" => Some(Self::TransferOut),
+            "transfer_in" => Some(Self::TransferIn),
+            _ => None,
+        }
+    }
+}
+
+pub struct StockQuantity {
+    quantity: i32,
+}
+
+impl StockQuantity {
+    pub fn new(kind: StockMovementKind, quantity: i32) -> Option<Self> {
+        let signed_quantity = match kind {
+            StockMovementKind::Receipt if quantity > 0 => quantity,
+            StockMovementKind::Issue if quantity > 0 => -quantity,
+            StockMovementKind::Adjustment if quantity != 0 => quantity,
+            StockMovementKind::TransferOut if quantity > 0 => -quantity,
+            StockMovementKind::TransferIn if quantity > 0 => quantity,
+            _ => return None,
+        };
+
+        Some(Self {
+            quantity: signed_quantity,
+        })
+    }
+
+    pub fn get(self) -> i32 {
+        self.quantity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StockMovementKind, StockQuantity};
+
+    #[test]
+    fn it_sign_issued_quantity_as_outgoing() {
+        let result = StockQuantity::new(StockMovementKind::Issue, 5).map(StockQuantity::get);
+
+        assert_eq!(Some(-5), result);
+    }
+
+    #[test]
+    fn it_not_accept_non_positive_receipt() {
+        let result = StockQuantity::new(StockMovementKind::Receipt, 0).map(StockQuantity::get);
+
+        assert_eq!(None, result);
+    }
+
+    #[test]
+    fn it_keep_adjustment_direction() {
+        let result = StockQuantity::new(StockMovementKind::Adjustment, -3).map(StockQuantity::get);
+
+        assert_eq!(Some(-3), result);
+    }
+}
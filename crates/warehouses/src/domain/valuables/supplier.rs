@@ -0,0 +1,121 @@
+use crate::domain::valuables::money::Money;
+use std::cmp::Ordering;
+
+pub struct SupplierCode {
+    code: String,
+}
+
+impl SupplierCode {
+    pub fn new(code: &str) -> Self {
+        Self {
+            code: code.trim().to_uppercase(),
+        }
+    }
+
+    pub fn get(self) -> String {
+        self.code
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PackSize {
+    size: i32,
+}
+
+impl PackSize {
+    pub fn new(size: i32) -> Option<Self> {
+        if size < 1 {
+            return None;
+        }
+
+        Some(Self { size })
+    }
+
+    pub fn round_up(&self, quantity: i32) -> i32 {
+        if quantity <= 0 {
+            return 0;
+        }
+
+        (quantity + self.size - 1) / self.size * self.size
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SupplierRank {
+    preferred: bool,
+    lead_time_days: i32,
+    last_price: Option<Money>,
+}
+
+impl SupplierRank {
+    pub fn new(preferred: bool, lead_time_days: i32, last_price: Option<Money>) -> Self {
+        Self {
+            preferred,
+            lead_time_days,
+            last_price,
+        }
+    }
+}
+
+impl PartialOrd for SupplierRank {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SupplierRank {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .preferred
+            .cmp(&self.preferred)
+            .then(self.lead_time_days.cmp(&other.lead_time_days))
+            .then(match (self.last_price, other.last_price) {
+                (Some(price), Some(other_price)) => price.cmp(&other_price),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PackSize, SupplierCode, SupplierRank};
+    use crate::domain::valuables::money::Money;
+
+    #[test]
+    fn it_normalise_supplier_code() {
+        assert_eq!("AB-100", SupplierCode::new(" ab-100 ").get());
+    }
+
+    #[test]
+    fn it_round_quantity_up_to_full_packs() {
+        let pack = PackSize::new(12).unwrap();
+
+        assert_eq!(0, pack.round_up(0));
+        assert_eq!(12, pack.round_up(1));
+        assert_eq!(12, pack.round_up(12));
+        assert_eq!(24, pack.round_up(13));
+        assert_eq!(None, PackSize::new(0));
+    }
+
+    #[test]
+    fn it_rank_preferred_supplier_first() {
+        let preferred = SupplierRank::new(true, 10, Some(Money::from_minor(20_000)));
+        let faster = SupplierRank::new(false, 2, Some(Money::from_minor(10_000)));
+
+        assert!(preferred < faster);
+    }
+
+    #[test]
+    fn it_rank_by_lead_time_then_known_lower_price() {
+        let slow = SupplierRank::new(false, 7, Some(Money::from_minor(10_000)));
+        let fast = SupplierRank::new(false, 3, Some(Money::from_minor(20_000)));
+        let fast_cheap = SupplierRank::new(false, 3, Some(Money::from_minor(15_000)));
+        let fast_unknown = SupplierRank::new(false, 3, None);
+
+        assert!(fast < slow);
+        assert!(fast_cheap < fast);
+        assert!(fast < fast_unknown);
+    }
+}
@@ -0,0 +1,132 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrackingMode {
+    None,
+    Lot,
+    Serial,
+}
+
+impl TrackingMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Lot => "lot",
+            Self::Serial => "serial",
+        }
+    }
+
+    pub fn parse(mode: &str) -> Option<Self> {
+        match mode {
+            "none" => Some(Self::None),
+            "lot" => Some(Self::Lot),
+            "serial" => Some(Self::Serial),
+            _ => None,
+        }
+    }
+
+    pub fn track(
+        &self,
+        lot_number: Option<&str>,
+        serial_number: Option<&str>,
+        quantity: i32,
+    ) -> Result<TrackedUnit, TrackingViolation> {
+        match self {
+            Self::None if lot_number.is_some() => Err(TrackingViolation::UnexpectedLot),
+            Self::None if serial_number.is_some() => Err(TrackingViolation::UnexpectedSerial),
+            Self::None => Ok(TrackedUnit::Untracked),
+            Self::Lot if serial_number.is_some() => Err(TrackingViolation::UnexpectedSerial),
+            Self::Lot => lot_number
+                .map(|lot| TrackedUnit::Lot(lot.to_owned()))
+                .ok_or(TrackingViolation::MissingLot),
+            Self::Serial if lot_number.is_some() => Err(TrackingViolation::UnexpectedLot),
+            Self::Serial if quantity.abs() != 1 => Err(TrackingViolation::SerialQuantity),
+            Self::Serial => serial_number
+                .map(|serial| TrackedUnit::Serial(serial.to_owned()))
+                .ok_or(TrackingViolation::MissingSerial),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrackedUnit {
+    Untracked,
+    Lot(String),
+    Serial(String),
+}
+
+impl TrackedUnit {
+    pub fn lot_number(&self) -> Option<String> {
+        match self {
+            Self::Lot(lot) => Some(lot.to_owned()),
+            _ => None,
+        }
+    }
+
+    pub fn serial_number(&self) -> Option<String> {
+        match self {
+            Self::Serial(serial) => Some(serial.to_owned()),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrackingViolation {
+    UnexpectedLot,
+    UnexpectedSerial,
+    MissingLot,
+    MissingSerial,
+    SerialQuantity,
+}
+
+impl TrackingViolation {
+    pub fn field(&self) -> &'static str {
+        match self {
+            Self::UnexpectedLot | Self::MissingLot => "lot_number",
+            Self::UnexpectedSerial | Self::MissingSerial => "serial_number",
+            Self::SerialQuantity => "quantity",
+        }
+    }
+
+    pub fn message(&self) -> &'static str {
+        match self {
+            Self::UnexpectedLot => "product is not tracked by lot",
+            Self::UnexpectedSerial => "product is not tracked by serial number",
+            Self::MissingLot => "lot number is required for lot tracked products",
+            Self::MissingSerial => "serial number is required for serial tracked products",
+            Self::SerialQuantity => "serial tracked movements must move a single unit",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TrackedUnit, TrackingMode, TrackingViolation};
+
+    #[test]
+    fn it_require_lot_for_lot_tracked_products() {
+        let result = TrackingMode::Lot.track(None, None, 5);
+
+        assert_eq!(Err(TrackingViolation::MissingLot), result);
+    }
+
+    #[test]
+    fn it_reject_serial_movement_of_many_units() {
+        let result = TrackingMode::Serial.track(None, Some("SN-1"), -2);
+
+        assert_eq!(Err(TrackingViolation::SerialQuantity), result);
+    }
+
+    #[test]
+    fn it_reject_tracking_codes_on_untracked_products() {
+        let result = TrackingMode::None.track(Some("L-1"), None, 5);
+
+        assert_eq!(Err(TrackingViolation::UnexpectedLot), result);
+    }
+
+    #[test]
+    fn it_track_serial_unit() {
+        let result = TrackingMode::Serial.track(None, Some("SN-1"), -1);
+
+        assert_eq!(Ok(TrackedUnit::Serial("SN-1".to_string())), result);
+    }
+}
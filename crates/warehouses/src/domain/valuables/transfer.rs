@@ -0,0 +1,61 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StockTransferStatus {
+    InTransit,
+    PartiallyReceived,
+    Received,
+    Cancelled,
+}
+
+impl StockTransferStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::InTransit => "in_transit",
+            Self::PartiallyReceived => "partially_received",
+            Self::Received => "received",
+            Self::Cancelled => "cancelled",
+        }
+    }
+
+    pub fn parse(status: &str) -> Option<Self> {
+        match status {
+            "in_transit" => Some(Self::InTransit),
+            "partially_received" => Some(Self::PartiallyReceived),
+            "received" => Some(Self::Received),
+            "cancelled" => Some(Self::Cancelled),
+            _ => None,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        matches!(self, Self::InTransit | Self::PartiallyReceived)
+    }
+
+    pub fn after_receipt(quantity: i32, received_quantity: i32) -> Self {
+        if received_quantity >= quantity {
+            Self::Received
+        } else {
+            Self::PartiallyReceived
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StockTransferStatus;
+
+    #[test]
+    fn it_keep_transfer_open_while_partially_received() {
+        let result = StockTransferStatus::after_receipt(10, 4);
+
+        assert_eq!(StockTransferStatus::PartiallyReceived, result);
+        assert!(result.is_open());
+    }
+
+    #[test]
+    fn it_close_transfer_once_fully_received() {
+        let result = StockTransferStatus::after_receipt(10, 10);
+
+        assert_eq!(StockTransferStatus::Received, result);
+        assert!(!result.is_open());
+    }
+}
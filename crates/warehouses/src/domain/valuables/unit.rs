@@ -0,0 +1,119 @@
+use crate::domain::valuables::money::Money;
+
+pub struct UnitCode {
+    code: String,
+}
+
+impl UnitCode {
+    pub fn new(code: &str) -> Self {
+        Self {
+            code: code.trim().to_lowercase(),
+        }
+    }
+
+    pub fn get(self) -> String {
+        self.code
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnitRatio {
+    Identity,
+    Multiply(i32),
+    Divide(i32),
+}
+
+impl UnitRatio {
+    pub fn to_base(self, quantity: i32) -> Option<i32> {
+        match self {
+            Self::Identity => Some(quantity),
+            Self::Multiply(factor) => quantity.checked_mul(factor),
+            Self::Divide(factor) if quantity % factor == 0 => Some(quantity / factor),
+            Self::Divide(_) => None,
+        }
+    }
+
+    pub fn cost_to_base(self, price: Money, base_quantity: i32) -> Option<Vec<(i32, Money)>> {
+        let factor = match self {
+            Self::Identity => return Some(vec![(base_quantity, price)]),
+            Self::Divide(factor) => {
+                return price
+                    .checked_mul(factor)
+                    .map(|base_price| vec![(base_quantity, base_price)])
+            }
+            Self::Multiply(factor) => factor,
+        };
+
+        // The price of a pack rarely divides evenly, so the remainder is spread
+        // over some base units at one minor unit more to keep the exact cost.
+        let base_price = price.minor().div_euclid(i64::from(factor));
+        let remainder = price.minor().rem_euclid(i64::from(factor));
+        let dearer_quantity = i32::try_from(i64::from(base_quantity / factor) * remainder).ok()?;
+
+        let mut costs = vec![(
+            base_quantity - dearer_quantity,
+            Money::from_minor(base_price),
+        )];
+        if dearer_quantity > 0 {
+            costs.push((dearer_quantity, Money::from_minor(base_price + 1)));
+        }
+
+        Some(costs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{UnitCode, UnitRatio};
+    use crate::domain::valuables::money::Money;
+
+    #[test]
+    fn it_normalise_unit_code() {
+        assert_eq!("kg", UnitCode::new(" KG ").get());
+    }
+
+    #[test]
+    fn it_multiply_quantity_into_base_unit() {
+        assert_eq!(Some(7), UnitRatio::Identity.to_base(7));
+        assert_eq!(Some(36), UnitRatio::Multiply(12).to_base(3));
+        assert_eq!(Some(-24), UnitRatio::Multiply(12).to_base(-2));
+        assert_eq!(None, UnitRatio::Multiply(1000).to_base(i32::MAX));
+    }
+
+    #[test]
+    fn it_price_one_base_unit() {
+        let box_price = Money::parse("24").unwrap();
+
+        assert_eq!(
+            Some(vec![(24, Money::parse("2").unwrap())]),
+            UnitRatio::Multiply(12).cost_to_base(box_price, 24)
+        );
+        assert_eq!(
+            Some(vec![(3, Money::parse("24000").unwrap())]),
+            UnitRatio::Divide(1000).cost_to_base(box_price, 3)
+        );
+    }
+
+    #[test]
+    fn it_keep_the_whole_pack_cost_in_base_units() {
+        let box_price = Money::parse("10").unwrap();
+
+        assert_eq!(
+            Some(vec![
+                (2, Money::parse("3.3333").unwrap()),
+                (1, Money::parse("3.3334").unwrap()),
+            ]),
+            UnitRatio::Multiply(3).cost_to_base(box_price, 3)
+        );
+        assert_eq!(
+            Some(vec![(5, box_price)]),
+            UnitRatio::Identity.cost_to_base(box_price, 5)
+        );
+    }
+
+    #[test]
+    fn it_divide_quantity_only_into_whole_base_units() {
+        assert_eq!(Some(2), UnitRatio::Divide(1000).to_base(2000));
+        assert_eq!(None, UnitRatio::Divide(1000).to_base(1500));
+    }
+}
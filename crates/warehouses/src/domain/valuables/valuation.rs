@@ -0,0 +1,214 @@
+use crate::domain::valuables::money::Money;
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CostingMethod {
+    MovingAverage,
+    Fifo,
+}
+
+impl CostingMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::MovingAverage => "moving_average",
+            Self::Fifo => "fifo",
+        }
+    }
+
+    pub fn parse(method: &str) -> Option<Self> {
+        match method {
+            "moving_average" => Some(Self::MovingAverage),
+            "fifo" => Some(Self::Fifo),
+            _ => None,
+        }
+    }
+}
+
+struct CostLayer {
+    quantity: i32,
+    unit_cost: i64,
+}
+
+pub struct CostLedger {
+    method: CostingMethod,
+    quantity: i32,
+    value: i64,
+    layers: VecDeque<CostLayer>,
+    last_unit_cost: i64,
+}
+
+impl CostLedger {
+    pub fn new(method: CostingMethod) -> Self {
+        Self {
+            method,
+            quantity: 0,
+            value: 0,
+            layers: VecDeque::new(),
+            last_unit_cost: 0,
+        }
+    }
+
+    pub fn post(&mut self, quantity: i32, unit_cost: Option<Money>) -> Money {
+        if quantity > 0 {
+            let unit_cost = unit_cost.unwrap_or_else(|| self.unit_cost());
+            self.receive(quantity, unit_cost);
+            Money::default()
+        } else {
+            self.issue(-quantity)
+        }
+    }
+
+    pub fn receive(&mut self, quantity: i32, unit_cost: Money) {
+        let unit_cost = unit_cost.minor();
+        self.last_unit_cost = unit_cost;
+
+        match self.method {
+            CostingMethod::MovingAverage if self.quantity <= 0 => {
+                self.quantity += quantity;
+                self.value = i64::from(self.quantity) * unit_cost;
+            }
+            CostingMethod::MovingAverage => {
+                self.quantity += quantity;
+                self.value += i64::from(quantity) * unit_cost;
+            }
+            CostingMethod::Fifo => {
+                let deficit = (-self.quantity).clamp(0, quantity);
+                self.quantity += quantity;
+                if quantity > deficit {
+                    self.layers.push_back(CostLayer {
+                        quantity: quantity - deficit,
+                        unit_cost,
+                    });
+                }
+                self.value = self.layered_value();
+            }
+        }
+    }
+
+    pub fn issue(&mut self, quantity: i32) -> Money {
+        let cost = match self.method {
+            CostingMethod::MovingAverage if self.quantity >= quantity => {
+                let cost = i128::from(self.value) * i128::from(quantity)
+                    / i128::from(self.quantity.max(1));
+                self.value -= cost as i64;
+                cost as i64
+            }
+            CostingMethod::MovingAverage => {
+                let covered_value = if self.quantity > 0 { self.value } else { 0 };
+                let shortfall = quantity - self.quantity.max(0);
+                covered_value + i64::from(shortfall) * self.last_unit_cost
+            }
+            CostingMethod::Fifo => {
+                let mut remaining = quantity;
+                let mut cost = 0;
+                while remaining > 0 {
+                    let Some(layer) = self.layers.front_mut() else {
+                        break;
+                    };
+                    let taken = remaining.min(layer.quantity);
+                    cost += i64::from(taken) * layer.unit_cost;
+                    remaining -= taken;
+                    layer.quantity -= taken;
+                    if layer.quantity == 0 {
+                        self.layers.pop_front();
+                    }
+                }
+                cost + i64::from(remaining) * self.last_unit_cost
+            }
+        };
+
+        self.quantity -= quantity;
+        if self.quantity <= 0 {
+            self.value = i64::from(self.quantity) * self.last_unit_cost;
+        } else if self.method == CostingMethod::Fifo {
+            self.value = self.layered_value();
+        }
+
+        Money::from_minor(cost)
+    }
+
+    pub fn quantity(&self) -> i32 {
+        self.quantity
+    }
+
+    pub fn value(&self) -> Money {
+        Money::from_minor(self.value)
+    }
+
+    pub fn unit_cost(&self) -> Money {
+        if self.quantity > 0 {
+            Money::from_minor(self.value / i64::from(self.quantity))
+        } else {
+            Money::from_minor(self.last_unit_cost)
+        }
+    }
+
+    fn layered_value(&self) -> i64 {
+        let layered = self
+            .layers
+            .iter()
+            .map(|layer| i64::from(layer.quantity) * layer.unit_cost)
+            .sum::<i64>();
+
+        if self.quantity < 0 {
+            i64::from(self.quantity) * self.last_unit_cost
+        } else {
+            layered
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CostLedger, CostingMethod};
+    use crate::domain::valuables::money::Money;
+
+    fn ledger_of(method: CostingMethod) -> CostLedger {
+        let mut ledger = CostLedger::new(method);
+        ledger.receive(10, Money::parse("2").unwrap());
+        ledger.receive(10, Money::parse("4").unwrap());
+        ledger
+    }
+
+    #[test]
+    fn it_average_unit_cost_over_receipts() {
+        let mut ledger = ledger_of(CostingMethod::MovingAverage);
+
+        assert_eq!(Money::parse("3").unwrap(), ledger.unit_cost());
+        assert_eq!(Money::parse("15").unwrap(), ledger.issue(5));
+        assert_eq!(Money::parse("45").unwrap(), ledger.value());
+        assert_eq!(15, ledger.quantity());
+    }
+
+    #[test]
+    fn it_consume_oldest_layers_first() {
+        let mut ledger = ledger_of(CostingMethod::Fifo);
+
+        assert_eq!(Money::parse("40").unwrap(), ledger.issue(15));
+        assert_eq!(Money::parse("20").unwrap(), ledger.value());
+        assert_eq!(Money::parse("4").unwrap(), ledger.unit_cost());
+    }
+
+    #[test]
+    fn it_cost_shortfall_at_last_unit_cost() {
+        let mut ledger = ledger_of(CostingMethod::Fifo);
+
+        assert_eq!(Money::parse("68").unwrap(), ledger.issue(22));
+        assert_eq!(Money::parse("-8").unwrap(), ledger.value());
+
+        ledger.receive(5, Money::parse("5").unwrap());
+
+        assert_eq!(3, ledger.quantity());
+        assert_eq!(Money::parse("15").unwrap(), ledger.value());
+    }
+
+    #[test]
+    fn it_receive_without_cost_at_current_unit_cost() {
+        let mut ledger = ledger_of(CostingMethod::MovingAverage);
+
+        ledger.post(5, None);
+
+        assert_eq!(Money::parse("3").unwrap(), ledger.unit_cost());
+        assert_eq!(Money::parse("75").unwrap(), ledger.value());
+    }
+}
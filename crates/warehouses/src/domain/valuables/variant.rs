@@ -0,0 +1,71 @@
+pub struct VariantMatrix {
+    axes: Vec<Vec<i32>>,
+}
+
+impl VariantMatrix {
+    pub fn new(axes: Vec<Vec<i32>>) -> Self {
+        Self { axes }
+    }
+
+    pub fn size(&self) -> usize {
+        if self.axes.is_empty() {
+            return 0;
+        }
+
+        self.axes
+            .iter()
+            .fold(1usize, |size, axis| size.saturating_mul(axis.len()))
+    }
+
+    pub fn combinations(&self) -> Vec<Vec<i32>> {
+        if self.axes.is_empty() {
+            return Vec::new();
+        }
+
+        self.axes
+            .iter()
+            .fold(vec![Vec::new()], |combinations, axis| {
+                combinations
+                    .iter()
+                    .flat_map(|combination| {
+                        axis.iter().map(move |value_id| {
+                            let mut next = combination.clone();
+                            next.push(*value_id);
+                            next
+                        })
+                    })
+                    .collect()
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VariantMatrix;
+
+    #[test]
+    fn it_combine_every_value_of_each_attribute() {
+        let matrix = VariantMatrix::new(vec![vec![1, 2], vec![3, 4, 5]]);
+
+        assert_eq!(6, matrix.size());
+        assert_eq!(
+            vec![
+                vec![1, 3],
+                vec![1, 4],
+                vec![1, 5],
+                vec![2, 3],
+                vec![2, 4],
+                vec![2, 5]
+            ],
+            matrix.combinations()
+        );
+    }
+
+    #[test]
+    fn it_generate_nothing_without_attributes() {
+        let matrix = VariantMatrix::new(Vec::new());
+
+        assert_eq!(0, matrix.size());
+        assert!(matrix.combinations().is_empty());
+    }
+}
@@ -0,0 +1,33 @@
+pub struct StorageCode {
+    code: String,
+}
+
+impl StorageCode {
+    pub fn new(code: &str) -> Self {
+        Self {
+            code: code
+                .split_whitespace()
+                .collect::<Vec<&str>>()
+                .join("-")
+                .to_uppercase(),
+        }
+    }
+
+    pub fn get(self) -> String {
+        self.code
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StorageCode;
+
+    #[test]
+    fn it_normalise_storage_code_case_and_spaces() {
+        let expected = "MAIN-01";
+
+        let result = StorageCode::new("  main 01 ").get();
+
+        assert_eq!(expected, result);
+    }
+}
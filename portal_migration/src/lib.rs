@@ -6,6 +6,44 @@ mod m20240207_211848_create_person_document_type_table;
 mod m20240207_211901_create_person_table;
 mod m20241025_093336_create_product_family_table;
 mod m20241025_094129_create_product_table;
+mod m20241202_093512_add_deleted_at_to_person_table;
+mod m20241203_101245_add_discontinued_at_to_product_table;
+mod m20241204_083017_add_parent_family_id_to_product_family_table;
+mod m20241205_091522_create_stock_movement_table;
+mod m20241206_080124_create_warehouse_table;
+mod m20241206_080517_create_storage_location_table;
+mod m20241206_081043_add_storage_location_id_to_stock_movement_table;
+mod m20241209_094418_create_stock_transfer_table;
+mod m20241209_094852_add_stock_transfer_id_to_stock_movement_table;
+mod m20241210_083214_add_tracking_mode_to_product_table;
+mod m20241210_083529_add_tracking_codes_to_stock_movement_table;
+mod m20241210_083746_add_tracking_codes_to_stock_transfer_table;
+mod m20241211_074512_create_stock_lot_table;
+mod m20241212_083015_create_inventory_count_table;
+mod m20241212_083422_create_inventory_count_line_table;
+mod m20241213_091408_create_reorder_point_table;
+mod m20241216_081522_create_unit_of_measure_table;
+mod m20241216_081907_create_unit_conversion_table;
+mod m20241216_082344_add_units_to_product_table;
+mod m20241217_090214_add_sku_to_product_table;
+mod m20241217_090731_create_product_barcode_table;
+mod m20241218_081204_create_product_attribute_table;
+mod m20241218_081532_create_product_attribute_value_table;
+mod m20241218_082017_create_product_variant_table;
+mod m20241218_082409_create_product_variant_value_table;
+mod m20241219_083107_create_price_list_table;
+mod m20241219_083542_create_product_price_table;
+mod m20241220_080912_create_supplier_table;
+mod m20241220_081305_create_supplier_product_table;
+mod m20241223_082140_create_purchase_order_table;
+mod m20241223_082615_create_purchase_order_line_table;
+mod m20241227_090312_create_sales_order_table;
+mod m20241227_090548_create_sales_order_line_table;
+mod m20241230_081117_add_unit_cost_to_stock_movement_table;
+mod m20241230_081452_add_costing_method_to_product_table;
+mod m20241231_093204_create_product_component_table;
+mod m20250102_084310_add_unit_cost_to_stock_transfer_table;
+mod m20250106_081530_create_user_authority_table;
 
 pub struct Migrator;
 
@@ -19,6 +57,44 @@ impl MigratorTrait for Migrator {
             Box::new(m20241025_093336_create_product_family_table::Migration),
             Box::new(m20241025_094129_create_product_table::Migration),
             Box::new(m20200118_120326_create_user_table::Migration),
+            Box::new(m20241202_093512_add_deleted_at_to_person_table::Migration),
+            Box::new(m20241203_101245_add_discontinued_at_to_product_table::Migration),
+            Box::new(m20241204_083017_add_parent_family_id_to_product_family_table::Migration),
+            Box::new(m20241205_091522_create_stock_movement_table::Migration),
+            Box::new(m20241206_080124_create_warehouse_table::Migration),
+            Box::new(m20241206_080517_create_storage_location_table::Migration),
+            Box::new(m20241206_081043_add_storage_location_id_to_stock_movement_table::Migration),
+            Box::new(m20241209_094418_create_stock_transfer_table::Migration),
+            Box::new(m20241209_094852_add_stock_transfer_id_to_stock_movement_table::Migration),
+            Box::new(m20241210_083214_add_tracking_mode_to_product_table::Migration),
+            Box::new(m20241210_083529_add_tracking_codes_to_stock_movement_table::Migration),
+            Box::new(m20241210_083746_add_tracking_codes_to_stock_transfer_table::Migration),
+            Box::new(m20241211_074512_create_stock_lot_table::Migration),
+            Box::new(m20241212_083015_create_inventory_count_table::Migration),
+            Box::new(m20241212_083422_create_inventory_count_line_table::Migration),
+            Box::new(m20241213_091408_create_reorder_point_table::Migration),
+            Box::new(m20241216_081522_create_unit_of_measure_table::Migration),
+            Box::new(m20241216_081907_create_unit_conversion_table::Migration),
+            Box::new(m20241216_082344_add_units_to_product_table::Migration),
+            Box::new(m20241217_090214_add_sku_to_product_table::Migration),
+            Box::new(m20241217_090731_create_product_barcode_table::Migration),
+            Box::new(m20241218_081204_create_product_attribute_table::Migration),
+            Box::new(m20241218_081532_create_product_attribute_value_table::Migration),
+            Box::new(m20241218_082017_create_product_variant_table::Migration),
+            Box::new(m20241218_082409_create_product_variant_value_table::Migration),
+            Box::new(m20241219_083107_create_price_list_table::Migration),
+            Box::new(m20241219_083542_create_product_price_table::Migration),
+            Box::new(m20241220_080912_create_supplier_table::Migration),
+            Box::new(m20241220_081305_create_supplier_product_table::Migration),
+            Box::new(m20241223_082140_create_purchase_order_table::Migration),
+            Box::new(m20241223_082615_create_purchase_order_line_table::Migration),
+            Box::new(m20241227_090312_create_sales_order_table::Migration),
+            Box::new(m20241227_090548_create_sales_order_line_table::Migration),
+            Box::new(m20241230_081117_add_unit_cost_to_stock_movement_table::Migration),
+            Box::new(m20241230_081452_add_costing_method_to_product_table::Migration),
+            Box::new(m20241231_093204_create_product_component_table::Migration),
+            Box::new(m20250102_084310_add_unit_cost_to_stock_transfer_table::Migration),
+            Box::new(m20250106_081530_create_user_authority_table::Migration),
         ]
     }
 }
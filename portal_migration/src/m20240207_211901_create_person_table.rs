@@ -59,7 +59,7 @@ impl MigrationTrait for Migration {
 }
 
 #[derive(DeriveIden)]
-enum Person {
+pub enum Person {
     Table,
     PersonId,
     FirstName,
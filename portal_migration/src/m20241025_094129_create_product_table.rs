@@ -48,7 +48,7 @@ impl MigrationTrait for Migration {
 }
 
 #[derive(DeriveIden)]
-enum Product {
+pub enum Product {
     Table,
     ProductId,
     FamilyId,
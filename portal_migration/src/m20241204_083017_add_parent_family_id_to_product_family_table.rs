@@ -0,0 +1,69 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DatabaseBackend;
+
+const PARENT_FAMILY_FK: &str = "fk_product_family_parent_family_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ProductFamily::Table)
+                    .add_column(
+                        ColumnDef::new(ProductFamily::ParentFamilyId)
+                            .integer()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // SQLite cannot add a constraint to an existing table.
+        if manager.get_database_backend() != DatabaseBackend::Sqlite {
+            manager
+                .create_foreign_key(
+                    ForeignKey::create()
+                        .name(PARENT_FAMILY_FK)
+                        .from(ProductFamily::Table, ProductFamily::ParentFamilyId)
+                        .to(ProductFamily::Table, ProductFamily::ProductFamilyId)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() != DatabaseBackend::Sqlite {
+            manager
+                .drop_foreign_key(
+                    ForeignKey::drop()
+                        .name(PARENT_FAMILY_FK)
+                        .table(ProductFamily::Table)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(ProductFamily::Table)
+                    .drop_column(ProductFamily::ParentFamilyId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ProductFamily {
+    Table,
+    ProductFamilyId,
+    ParentFamilyId,
+}
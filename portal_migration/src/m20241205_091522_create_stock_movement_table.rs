@@ -0,0 +1,65 @@
+use crate::m20241025_094129_create_product_table::Product;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(StockMovement::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(StockMovement::StockMovementId)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(StockMovement::ProductId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StockMovement::Kind)
+                            .string_len(20)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(StockMovement::Quantity).integer().not_null())
+                    .col(ColumnDef::new(StockMovement::Reason).string().null())
+                    .col(
+                        ColumnDef::new(StockMovement::MovedAt)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StockMovement::Table, StockMovement::ProductId)
+                            .to(Product::Table, Product::ProductId),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(StockMovement::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum StockMovement {
+    Table,
+    StockMovementId,
+    ProductId,
+    Kind,
+    Quantity,
+    Reason,
+    MovedAt,
+}
@@ -0,0 +1,48 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Warehouse::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Warehouse::WarehouseId)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(Warehouse::Code)
+                            .string_len(20)
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(Warehouse::Name).string_len(100).not_null())
+                    .col(ColumnDef::new(Warehouse::Address).string().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Warehouse::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Warehouse {
+    Table,
+    WarehouseId,
+    Code,
+    Name,
+    Address,
+}
@@ -0,0 +1,75 @@
+use crate::m20241206_080124_create_warehouse_table::Warehouse;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(StorageLocation::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(StorageLocation::StorageLocationId)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(StorageLocation::WarehouseId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StorageLocation::Aisle)
+                            .string_len(20)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StorageLocation::Shelf)
+                            .string_len(20)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StorageLocation::Bin)
+                            .string_len(20)
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StorageLocation::Table, StorageLocation::WarehouseId)
+                            .to(Warehouse::Table, Warehouse::WarehouseId),
+                    )
+                    .index(
+                        Index::create()
+                            .unique()
+                            .col(StorageLocation::WarehouseId)
+                            .col(StorageLocation::Aisle)
+                            .col(StorageLocation::Shelf)
+                            .col(StorageLocation::Bin),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(StorageLocation::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum StorageLocation {
+    Table,
+    StorageLocationId,
+    WarehouseId,
+    Aisle,
+    Shelf,
+    Bin,
+}
@@ -0,0 +1,69 @@
+use crate::m20241206_080517_create_storage_location_table::StorageLocation;
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DatabaseBackend;
+
+const STORAGE_LOCATION_FK: &str = "fk_stock_movement_storage_location_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(StockMovement::Table)
+                    .add_column(
+                        ColumnDef::new(StockMovement::StorageLocationId)
+                            .integer()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // SQLite cannot add a constraint to an existing table.
+        if manager.get_database_backend() != DatabaseBackend::Sqlite {
+            manager
+                .create_foreign_key(
+                    ForeignKey::create()
+                        .name(STORAGE_LOCATION_FK)
+                        .from(StockMovement::Table, StockMovement::StorageLocationId)
+                        .to(StorageLocation::Table, StorageLocation::StorageLocationId)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() != DatabaseBackend::Sqlite {
+            manager
+                .drop_foreign_key(
+                    ForeignKey::drop()
+                        .name(STORAGE_LOCATION_FK)
+                        .table(StockMovement::Table)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(StockMovement::Table)
+                    .drop_column(StockMovement::StorageLocationId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum StockMovement {
+    Table,
+    StorageLocationId,
+}
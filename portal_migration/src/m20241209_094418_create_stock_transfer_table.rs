@@ -0,0 +1,107 @@
+use crate::m20241025_094129_create_product_table::Product;
+use crate::m20241206_080124_create_warehouse_table::Warehouse;
+use crate::m20241206_080517_create_storage_location_table::StorageLocation;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(StockTransfer::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(StockTransfer::StockTransferId)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(StockTransfer::ProductId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StockTransfer::SourceLocationId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StockTransfer::SourceWarehouseId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StockTransfer::DestinationWarehouseId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(StockTransfer::Quantity).integer().not_null())
+                    .col(
+                        ColumnDef::new(StockTransfer::ReceivedQuantity)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(StockTransfer::Status)
+                            .string_len(20)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StockTransfer::TransferredAt)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(StockTransfer::ClosedAt).date_time().null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StockTransfer::Table, StockTransfer::ProductId)
+                            .to(Product::Table, Product::ProductId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StockTransfer::Table, StockTransfer::SourceLocationId)
+                            .to(StorageLocation::Table, StorageLocation::StorageLocationId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StockTransfer::Table, StockTransfer::SourceWarehouseId)
+                            .to(Warehouse::Table, Warehouse::WarehouseId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StockTransfer::Table, StockTransfer::DestinationWarehouseId)
+                            .to(Warehouse::Table, Warehouse::WarehouseId),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(StockTransfer::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum StockTransfer {
+    Table,
+    StockTransferId,
+    ProductId,
+    SourceLocationId,
+    SourceWarehouseId,
+    DestinationWarehouseId,
+    Quantity,
+    ReceivedQuantity,
+    Status,
+    TransferredAt,
+    ClosedAt,
+}
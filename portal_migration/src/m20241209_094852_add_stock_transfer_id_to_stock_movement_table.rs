@@ -0,0 +1,69 @@
+use crate::m20241209_094418_create_stock_transfer_table::StockTransfer;
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DatabaseBackend;
+
+const STOCK_TRANSFER_FK: &str = "fk_stock_movement_stock_transfer_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(StockMovement::Table)
+                    .add_column(
+                        ColumnDef::new(StockMovement::StockTransferId)
+                            .integer()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // SQLite cannot add a constraint to an existing table.
+        if manager.get_database_backend() != DatabaseBackend::Sqlite {
+            manager
+                .create_foreign_key(
+                    ForeignKey::create()
+                        .name(STOCK_TRANSFER_FK)
+                        .from(StockMovement::Table, StockMovement::StockTransferId)
+                        .to(StockTransfer::Table, StockTransfer::StockTransferId)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() != DatabaseBackend::Sqlite {
+            manager
+                .drop_foreign_key(
+                    ForeignKey::drop()
+                        .name(STOCK_TRANSFER_FK)
+                        .table(StockMovement::Table)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(StockMovement::Table)
+                    .drop_column(StockMovement::StockTransferId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum StockMovement {
+    Table,
+    StockTransferId,
+}
@@ -0,0 +1,62 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(StockMovement::Table)
+                    .add_column(
+                        ColumnDef::new(StockMovement::LotNumber)
+                            .string_len(50)
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(StockMovement::Table)
+                    .add_column(
+                        ColumnDef::new(StockMovement::SerialNumber)
+                            .string_len(50)
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(StockMovement::Table)
+                    .drop_column(StockMovement::SerialNumber)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(StockMovement::Table)
+                    .drop_column(StockMovement::LotNumber)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum StockMovement {
+    Table,
+    LotNumber,
+    SerialNumber,
+}
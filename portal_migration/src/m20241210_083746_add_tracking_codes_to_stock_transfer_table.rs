@@ -0,0 +1,62 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(StockTransfer::Table)
+                    .add_column(
+                        ColumnDef::new(StockTransfer::LotNumber)
+                            .string_len(50)
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(StockTransfer::Table)
+                    .add_column(
+                        ColumnDef::new(StockTransfer::SerialNumber)
+                            .string_len(50)
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(StockTransfer::Table)
+                    .drop_column(StockTransfer::SerialNumber)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(StockTransfer::Table)
+                    .drop_column(StockTransfer::LotNumber)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum StockTransfer {
+    Table,
+    LotNumber,
+    SerialNumber,
+}
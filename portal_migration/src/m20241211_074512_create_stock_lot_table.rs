@@ -0,0 +1,59 @@
+use crate::m20241025_094129_create_product_table::Product;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(StockLot::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(StockLot::StockLotId)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(StockLot::ProductId).integer().not_null())
+                    .col(
+                        ColumnDef::new(StockLot::LotNumber)
+                            .string_len(50)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(StockLot::ExpiresOn).date().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(StockLot::Table, StockLot::ProductId)
+                            .to(Product::Table, Product::ProductId),
+                    )
+                    .index(
+                        Index::create()
+                            .unique()
+                            .col(StockLot::ProductId)
+                            .col(StockLot::LotNumber),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(StockLot::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum StockLot {
+    Table,
+    StockLotId,
+    ProductId,
+    LotNumber,
+    ExpiresOn,
+}
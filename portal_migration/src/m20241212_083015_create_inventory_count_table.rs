@@ -0,0 +1,67 @@
+use crate::m20241025_093336_create_product_family_table::ProductFamily;
+use crate::m20241206_080124_create_warehouse_table::Warehouse;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(InventoryCount::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(InventoryCount::InventoryCountId)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(InventoryCount::WarehouseId).integer().null())
+                    .col(ColumnDef::new(InventoryCount::FamilyId).integer().null())
+                    .col(
+                        ColumnDef::new(InventoryCount::Status)
+                            .string_len(20)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(InventoryCount::OpenedAt)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(InventoryCount::PostedAt).date_time().null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(InventoryCount::Table, InventoryCount::WarehouseId)
+                            .to(Warehouse::Table, Warehouse::WarehouseId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(InventoryCount::Table, InventoryCount::FamilyId)
+                            .to(ProductFamily::Table, ProductFamily::ProductFamilyId),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(InventoryCount::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum InventoryCount {
+    Table,
+    InventoryCountId,
+    WarehouseId,
+    FamilyId,
+    Status,
+    OpenedAt,
+    PostedAt,
+}
@@ -0,0 +1,91 @@
+use crate::m20241025_094129_create_product_table::Product;
+use crate::m20241206_080517_create_storage_location_table::StorageLocation;
+use crate::m20241212_083015_create_inventory_count_table::InventoryCount;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(InventoryCountLine::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(InventoryCountLine::InventoryCountLineId)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(InventoryCountLine::InventoryCountId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(InventoryCountLine::ProductId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(InventoryCountLine::StorageLocationId)
+                            .integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(InventoryCountLine::BookQuantity)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(InventoryCountLine::CountedQuantity)
+                            .integer()
+                            .null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(
+                                InventoryCountLine::Table,
+                                InventoryCountLine::InventoryCountId,
+                            )
+                            .to(InventoryCount::Table, InventoryCount::InventoryCountId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(InventoryCountLine::Table, InventoryCountLine::ProductId)
+                            .to(Product::Table, Product::ProductId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(
+                                InventoryCountLine::Table,
+                                InventoryCountLine::StorageLocationId,
+                            )
+                            .to(StorageLocation::Table, StorageLocation::StorageLocationId),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(InventoryCountLine::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum InventoryCountLine {
+    Table,
+    InventoryCountLineId,
+    InventoryCountId,
+    ProductId,
+    StorageLocationId,
+    BookQuantity,
+    CountedQuantity,
+}
@@ -0,0 +1,58 @@
+use crate::m20241025_094129_create_product_table::Product;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ReorderPoint::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ReorderPoint::ReorderPointId)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ReorderPoint::ProductId)
+                            .integer()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ReorderPoint::MinQuantity)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ReorderPoint::MaxQuantity).integer().null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(ReorderPoint::Table, ReorderPoint::ProductId)
+                            .to(Product::Table, Product::ProductId),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ReorderPoint::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ReorderPoint {
+    Table,
+    ReorderPointId,
+    ProductId,
+    MinQuantity,
+    MaxQuantity,
+}
@@ -0,0 +1,68 @@
+use crate::m20241216_081522_create_unit_of_measure_table::UnitOfMeasure;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(UnitConversion::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(UnitConversion::UnitConversionId)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(UnitConversion::FromUnitId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(UnitConversion::ToUnitId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(UnitConversion::Factor).integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(UnitConversion::Table, UnitConversion::FromUnitId)
+                            .to(UnitOfMeasure::Table, UnitOfMeasure::UnitOfMeasureId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(UnitConversion::Table, UnitConversion::ToUnitId)
+                            .to(UnitOfMeasure::Table, UnitOfMeasure::UnitOfMeasureId),
+                    )
+                    .index(
+                        Index::create()
+                            .unique()
+                            .col(UnitConversion::FromUnitId)
+                            .col(UnitConversion::ToUnitId),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(UnitConversion::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum UnitConversion {
+    Table,
+    UnitConversionId,
+    FromUnitId,
+    ToUnitId,
+    Factor,
+}
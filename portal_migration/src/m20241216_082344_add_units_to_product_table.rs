@@ -0,0 +1,111 @@
+use crate::m20241216_081522_create_unit_of_measure_table::UnitOfMeasure;
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::DatabaseBackend;
+
+const UNIT_FKS: [(&str, Product); 3] = [
+    ("fk_product_base_unit_id", Product::BaseUnitId),
+    ("fk_product_purchase_unit_id", Product::PurchaseUnitId),
+    ("fk_product_sale_unit_id", Product::SaleUnitId),
+];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Product::Table)
+                    .add_column(ColumnDef::new(Product::BaseUnitId).integer().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Product::Table)
+                    .add_column(ColumnDef::new(Product::PurchaseUnitId).integer().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Product::Table)
+                    .add_column(ColumnDef::new(Product::SaleUnitId).integer().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // SQLite cannot add a constraint to an existing table.
+        if manager.get_database_backend() != DatabaseBackend::Sqlite {
+            for (name, column) in UNIT_FKS {
+                manager
+                    .create_foreign_key(
+                        ForeignKey::create()
+                            .name(name)
+                            .from(Product::Table, column)
+                            .to(UnitOfMeasure::Table, UnitOfMeasure::UnitOfMeasureId)
+                            .to_owned(),
+                    )
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() != DatabaseBackend::Sqlite {
+            for (name, _) in UNIT_FKS {
+                manager
+                    .drop_foreign_key(
+                        ForeignKey::drop()
+                            .name(name)
+                            .table(Product::Table)
+                            .to_owned(),
+                    )
+                    .await?;
+            }
+        }
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Product::Table)
+                    .drop_column(Product::SaleUnitId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Product::Table)
+                    .drop_column(Product::PurchaseUnitId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Product::Table)
+                    .drop_column(Product::BaseUnitId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Product {
+    Table,
+    BaseUnitId,
+    PurchaseUnitId,
+    SaleUnitId,
+}
@@ -0,0 +1,68 @@
+use crate::m20241025_094129_create_product_table::Product;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ProductBarcode::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ProductBarcode::ProductBarcodeId)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ProductBarcode::ProductId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ProductBarcode::Code)
+                            .string_len(14)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ProductBarcode::Gtin)
+                            .string_len(14)
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ProductBarcode::Symbology)
+                            .string_len(10)
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(ProductBarcode::Table, ProductBarcode::ProductId)
+                            .to(Product::Table, Product::ProductId),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ProductBarcode::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ProductBarcode {
+    Table,
+    ProductBarcodeId,
+    ProductId,
+    Code,
+    Gtin,
+    Symbology,
+}
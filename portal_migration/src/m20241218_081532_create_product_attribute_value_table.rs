@@ -0,0 +1,67 @@
+use crate::m20241218_081204_create_product_attribute_table::ProductAttribute;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ProductAttributeValue::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ProductAttributeValue::ProductAttributeValueId)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ProductAttributeValue::AttributeId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ProductAttributeValue::Value)
+                            .string_len(50)
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(
+                                ProductAttributeValue::Table,
+                                ProductAttributeValue::AttributeId,
+                            )
+                            .to(
+                                ProductAttribute::Table,
+                                ProductAttribute::ProductAttributeId,
+                            ),
+                    )
+                    .index(
+                        Index::create()
+                            .unique()
+                            .col(ProductAttributeValue::AttributeId)
+                            .col(ProductAttributeValue::Value),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ProductAttributeValue::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum ProductAttributeValue {
+    Table,
+    ProductAttributeValueId,
+    AttributeId,
+    Value,
+}
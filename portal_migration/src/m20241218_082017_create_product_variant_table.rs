@@ -0,0 +1,69 @@
+use crate::m20241025_094129_create_product_table::Product;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ProductVariant::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ProductVariant::ProductVariantId)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ProductVariant::ProductId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ProductVariant::Name)
+                            .string_len(255)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ProductVariant::Sku)
+                            .string_len(50)
+                            .null()
+                            .unique_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ProductVariant::Signature)
+                            .string_len(50)
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(ProductVariant::Table, ProductVariant::ProductId)
+                            .to(Product::Table, Product::ProductId),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ProductVariant::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum ProductVariant {
+    Table,
+    ProductVariantId,
+    ProductId,
+    Name,
+    Sku,
+    Signature,
+}
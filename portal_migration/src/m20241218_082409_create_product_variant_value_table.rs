@@ -0,0 +1,73 @@
+use crate::m20241218_081532_create_product_attribute_value_table::ProductAttributeValue;
+use crate::m20241218_082017_create_product_variant_table::ProductVariant;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ProductVariantValue::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ProductVariantValue::ProductVariantValueId)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ProductVariantValue::VariantId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ProductVariantValue::AttributeValueId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(ProductVariantValue::Table, ProductVariantValue::VariantId)
+                            .to(ProductVariant::Table, ProductVariant::ProductVariantId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(
+                                ProductVariantValue::Table,
+                                ProductVariantValue::AttributeValueId,
+                            )
+                            .to(
+                                ProductAttributeValue::Table,
+                                ProductAttributeValue::ProductAttributeValueId,
+                            ),
+                    )
+                    .index(
+                        Index::create()
+                            .unique()
+                            .col(ProductVariantValue::VariantId)
+                            .col(ProductVariantValue::AttributeValueId),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ProductVariantValue::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ProductVariantValue {
+    Table,
+    ProductVariantValueId,
+    VariantId,
+    AttributeValueId,
+}
@@ -0,0 +1,52 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PriceList::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(PriceList::PriceListId)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(PriceList::Name)
+                            .string_len(50)
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(PriceList::Kind).string_len(10).not_null())
+                    .col(ColumnDef::new(PriceList::Currency).string_len(3).not_null())
+                    .col(ColumnDef::new(PriceList::ValidFrom).date().not_null())
+                    .col(ColumnDef::new(PriceList::ValidTo).date().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PriceList::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum PriceList {
+    Table,
+    PriceListId,
+    Name,
+    Kind,
+    Currency,
+    ValidFrom,
+    ValidTo,
+}
@@ -0,0 +1,67 @@
+use crate::m20241025_094129_create_product_table::Product;
+use crate::m20241219_083107_create_price_list_table::PriceList;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ProductPrice::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ProductPrice::ProductPriceId)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ProductPrice::PriceListId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ProductPrice::ProductId).integer().not_null())
+                    .col(
+                        ColumnDef::new(ProductPrice::Amount)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ProductPrice::ValidFrom).date().not_null())
+                    .col(ColumnDef::new(ProductPrice::ValidTo).date().null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(ProductPrice::Table, ProductPrice::PriceListId)
+                            .to(PriceList::Table, PriceList::PriceListId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(ProductPrice::Table, ProductPrice::ProductId)
+                            .to(Product::Table, Product::ProductId),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ProductPrice::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ProductPrice {
+    Table,
+    ProductPriceId,
+    PriceListId,
+    ProductId,
+    Amount,
+    ValidFrom,
+    ValidTo,
+}
@@ -0,0 +1,54 @@
+use crate::m20240207_211901_create_person_table::Person;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Supplier::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Supplier::SupplierId)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(Supplier::Name)
+                            .string_len(100)
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(Supplier::ContactPersonId).integer().null())
+                    .col(ColumnDef::new(Supplier::Currency).string_len(3).not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Supplier::Table, Supplier::ContactPersonId)
+                            .to(Person::Table, Person::PersonId),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Supplier::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Supplier {
+    Table,
+    SupplierId,
+    Name,
+    ContactPersonId,
+    Currency,
+}
@@ -0,0 +1,103 @@
+use crate::m20241025_094129_create_product_table::Product;
+use crate::m20241220_080912_create_supplier_table::Supplier;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SupplierProduct::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SupplierProduct::SupplierProductId)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SupplierProduct::SupplierId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SupplierProduct::ProductId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SupplierProduct::SupplierCode)
+                            .string_len(50)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SupplierProduct::PackSize)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SupplierProduct::LeadTimeDays)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SupplierProduct::LastPrice)
+                            .big_integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SupplierProduct::Preferred)
+                            .boolean()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(SupplierProduct::Table, SupplierProduct::SupplierId)
+                            .to(Supplier::Table, Supplier::SupplierId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(SupplierProduct::Table, SupplierProduct::ProductId)
+                            .to(Product::Table, Product::ProductId),
+                    )
+                    .index(
+                        Index::create()
+                            .unique()
+                            .col(SupplierProduct::SupplierId)
+                            .col(SupplierProduct::ProductId),
+                    )
+                    .index(
+                        Index::create()
+                            .unique()
+                            .col(SupplierProduct::SupplierId)
+                            .col(SupplierProduct::SupplierCode),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SupplierProduct::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SupplierProduct {
+    Table,
+    SupplierProductId,
+    SupplierId,
+    ProductId,
+    SupplierCode,
+    PackSize,
+    LeadTimeDays,
+    LastPrice,
+    Preferred,
+}
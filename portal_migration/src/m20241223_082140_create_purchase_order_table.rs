@@ -0,0 +1,73 @@
+use crate::m20241220_080912_create_supplier_table::Supplier;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PurchaseOrder::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(PurchaseOrder::PurchaseOrderId)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(PurchaseOrder::SupplierId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PurchaseOrder::Status)
+                            .string_len(20)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PurchaseOrder::Currency)
+                            .string_len(3)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PurchaseOrder::CreatedAt)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(PurchaseOrder::ApprovedAt).date_time().null())
+                    .col(ColumnDef::new(PurchaseOrder::SentAt).date_time().null())
+                    .col(ColumnDef::new(PurchaseOrder::ClosedAt).date_time().null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PurchaseOrder::Table, PurchaseOrder::SupplierId)
+                            .to(Supplier::Table, Supplier::SupplierId),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PurchaseOrder::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum PurchaseOrder {
+    Table,
+    PurchaseOrderId,
+    SupplierId,
+    Status,
+    Currency,
+    CreatedAt,
+    ApprovedAt,
+    SentAt,
+    ClosedAt,
+}
@@ -0,0 +1,93 @@
+use crate::m20241025_094129_create_product_table::Product;
+use crate::m20241216_081522_create_unit_of_measure_table::UnitOfMeasure;
+use crate::m20241223_082140_create_purchase_order_table::PurchaseOrder;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PurchaseOrderLine::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(PurchaseOrderLine::PurchaseOrderLineId)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(PurchaseOrderLine::PurchaseOrderId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PurchaseOrderLine::ProductId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PurchaseOrderLine::OrderedQuantity)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PurchaseOrderLine::ReceivedQuantity)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(PurchaseOrderLine::UnitId).integer().null())
+                    .col(
+                        ColumnDef::new(PurchaseOrderLine::UnitPrice)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PurchaseOrderLine::Table, PurchaseOrderLine::PurchaseOrderId)
+                            .to(PurchaseOrder::Table, PurchaseOrder::PurchaseOrderId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PurchaseOrderLine::Table, PurchaseOrderLine::ProductId)
+                            .to(Product::Table, Product::ProductId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PurchaseOrderLine::Table, PurchaseOrderLine::UnitId)
+                            .to(UnitOfMeasure::Table, UnitOfMeasure::UnitOfMeasureId),
+                    )
+                    .index(
+                        Index::create()
+                            .unique()
+                            .col(PurchaseOrderLine::PurchaseOrderId)
+                            .col(PurchaseOrderLine::ProductId),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PurchaseOrderLine::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PurchaseOrderLine {
+    Table,
+    PurchaseOrderLineId,
+    PurchaseOrderId,
+    ProductId,
+    OrderedQuantity,
+    ReceivedQuantity,
+    UnitId,
+    UnitPrice,
+}
@@ -0,0 +1,55 @@
+use crate::m20240207_211901_create_person_table::Person;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SalesOrder::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SalesOrder::SalesOrderId)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SalesOrder::CustomerId).integer().not_null())
+                    .col(ColumnDef::new(SalesOrder::Status).string_len(20).not_null())
+                    .col(ColumnDef::new(SalesOrder::CreatedAt).date_time().not_null())
+                    .col(ColumnDef::new(SalesOrder::ConfirmedAt).date_time().null())
+                    .col(ColumnDef::new(SalesOrder::CancelledAt).date_time().null())
+                    .col(ColumnDef::new(SalesOrder::FulfilledAt).date_time().null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(SalesOrder::Table, SalesOrder::CustomerId)
+                            .to(Person::Table, Person::PersonId),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SalesOrder::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SalesOrder {
+    Table,
+    SalesOrderId,
+    CustomerId,
+    Status,
+    CreatedAt,
+    ConfirmedAt,
+    CancelledAt,
+    FulfilledAt,
+}
@@ -0,0 +1,85 @@
+use crate::m20241025_094129_create_product_table::Product;
+use crate::m20241227_090312_create_sales_order_table::SalesOrder;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SalesOrderLine::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SalesOrderLine::SalesOrderLineId)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SalesOrderLine::SalesOrderId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SalesOrderLine::ProductId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SalesOrderLine::OrderedQuantity)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SalesOrderLine::ReservedQuantity)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SalesOrderLine::BackorderedQuantity)
+                            .integer()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(SalesOrderLine::Table, SalesOrderLine::SalesOrderId)
+                            .to(SalesOrder::Table, SalesOrder::SalesOrderId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(SalesOrderLine::Table, SalesOrderLine::ProductId)
+                            .to(Product::Table, Product::ProductId),
+                    )
+                    .index(
+                        Index::create()
+                            .unique()
+                            .col(SalesOrderLine::SalesOrderId)
+                            .col(SalesOrderLine::ProductId),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SalesOrderLine::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SalesOrderLine {
+    Table,
+    SalesOrderLineId,
+    SalesOrderId,
+    ProductId,
+    OrderedQuantity,
+    ReservedQuantity,
+    BackorderedQuantity,
+}
@@ -0,0 +1,75 @@
+use crate::m20241025_094129_create_product_table::Product;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ProductComponent::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ProductComponent::ProductComponentId)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ProductComponent::KitProductId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ProductComponent::ComponentProductId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ProductComponent::Quantity)
+                            .integer()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(ProductComponent::Table, ProductComponent::KitProductId)
+                            .to(Product::Table, Product::ProductId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(
+                                ProductComponent::Table,
+                                ProductComponent::ComponentProductId,
+                            )
+                            .to(Product::Table, Product::ProductId),
+                    )
+                    .index(
+                        Index::create()
+                            .unique()
+                            .col(ProductComponent::KitProductId)
+                            .col(ProductComponent::ComponentProductId),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ProductComponent::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ProductComponent {
+    Table,
+    ProductComponentId,
+    KitProductId,
+    ComponentProductId,
+    Quantity,
+}
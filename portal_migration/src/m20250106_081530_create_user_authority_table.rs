@@ -0,0 +1,57 @@
+use crate::m20200118_120326_create_user_table::User;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserAuthority::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(UserAuthority::UserAuthorityId)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(UserAuthority::UserId).integer().not_null())
+                    .col(
+                        ColumnDef::new(UserAuthority::Authority)
+                            .string_len(50)
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(UserAuthority::Table, UserAuthority::UserId)
+                            .to(User::Table, User::Id),
+                    )
+                    .index(
+                        Index::create()
+                            .unique()
+                            .col(UserAuthority::UserId)
+                            .col(UserAuthority::Authority),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(UserAuthority::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum UserAuthority {
+    Table,
+    UserAuthorityId,
+    UserId,
+    Authority,
+}
@@ -2,9 +2,34 @@
 
 pub mod prelude;
 
+pub mod inventory_count;
+pub mod inventory_count_line;
 pub mod person;
 pub mod person_document_type;
 pub mod person_gender;
+pub mod price_list;
 pub mod product;
+pub mod product_attribute;
+pub mod product_attribute_value;
+pub mod product_barcode;
+pub mod product_component;
 pub mod product_family;
+pub mod product_price;
+pub mod product_variant;
+pub mod product_variant_value;
+pub mod purchase_order;
+pub mod purchase_order_line;
+pub mod reorder_point;
+pub mod sales_order;
+pub mod sales_order_line;
+pub mod stock_lot;
+pub mod stock_movement;
+pub mod stock_transfer;
+pub mod storage_location;
+pub mod supplier;
+pub mod supplier_product;
+pub mod unit_conversion;
+pub mod unit_of_measure;
 pub mod user;
+pub mod user_authority;
+pub mod warehouse;
@@ -13,6 +13,7 @@ pub struct Model {
     pub document_type_id: i32,
     pub gender_id: i32,
     pub signature: String,
+    pub deleted_at: Option<DateTime>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -33,6 +34,10 @@ pub enum Relation {
         on_delete = "NoAction"
     )]
     PersonGender,
+    #[sea_orm(has_many = "super::sales_order::Entity")]
+    SalesOrder,
+    #[sea_orm(has_many = "super::supplier::Entity")]
+    Supplier,
 }
 
 impl Related<super::person_document_type::Entity> for Entity {
@@ -47,4 +52,16 @@ impl Related<super::person_gender::Entity> for Entity {
     }
 }
 
+impl Related<super::sales_order::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SalesOrder.def()
+    }
+}
+
+impl Related<super::supplier::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Supplier.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
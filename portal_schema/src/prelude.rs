@@ -1,8 +1,33 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.3
 
+pub use super::inventory_count::Entity as InventoryCount;
+pub use super::inventory_count_line::Entity as InventoryCountLine;
 pub use super::person::Entity as Person;
 pub use super::person_document_type::Entity as PersonDocumentType;
 pub use super::person_gender::Entity as PersonGender;
+pub use super::price_list::Entity as PriceList;
 pub use super::product::Entity as Product;
+pub use super::product_attribute::Entity as ProductAttribute;
+pub use super::product_attribute_value::Entity as ProductAttributeValue;
+pub use super::product_barcode::Entity as ProductBarcode;
+pub use super::product_component::Entity as ProductComponent;
 pub use super::product_family::Entity as ProductFamily;
+pub use super::product_price::Entity as ProductPrice;
+pub use super::product_variant::Entity as ProductVariant;
+pub use super::product_variant_value::Entity as ProductVariantValue;
+pub use super::purchase_order::Entity as PurchaseOrder;
+pub use super::purchase_order_line::Entity as PurchaseOrderLine;
+pub use super::reorder_point::Entity as ReorderPoint;
+pub use super::sales_order::Entity as SalesOrder;
+pub use super::sales_order_line::Entity as SalesOrderLine;
+pub use super::stock_lot::Entity as StockLot;
+pub use super::stock_movement::Entity as StockMovement;
+pub use super::stock_transfer::Entity as StockTransfer;
+pub use super::storage_location::Entity as StorageLocation;
+pub use super::supplier::Entity as Supplier;
+pub use super::supplier_product::Entity as SupplierProduct;
+pub use super::unit_conversion::Entity as UnitConversion;
+pub use super::unit_of_measure::Entity as UnitOfMeasure;
 pub use super::user::Entity as User;
+pub use super::user_authority::Entity as UserAuthority;
+pub use super::warehouse::Entity as Warehouse;
@@ -14,10 +14,22 @@ pub struct Model {
     pub saleable: i8,
     pub saleable_without_stock: i8,
     pub signature: String,
+    pub discontinued_at: Option<DateTime>,
+    pub tracking_mode: String,
+    pub base_unit_id: Option<i32>,
+    pub purchase_unit_id: Option<i32>,
+    pub sale_unit_id: Option<i32>,
+    #[sea_orm(unique)]
+    pub sku: Option<String>,
+    pub costing_method: String,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::inventory_count_line::Entity")]
+    InventoryCountLine,
+    #[sea_orm(has_many = "super::product_barcode::Entity")]
+    ProductBarcode,
     #[sea_orm(
         belongs_to = "super::product_family::Entity",
         from = "Column::FamilyId",
@@ -26,6 +38,36 @@ pub enum Relation {
         on_delete = "NoAction"
     )]
     ProductFamily,
+    #[sea_orm(has_many = "super::product_price::Entity")]
+    ProductPrice,
+    #[sea_orm(has_many = "super::product_variant::Entity")]
+    ProductVariant,
+    #[sea_orm(has_many = "super::purchase_order_line::Entity")]
+    PurchaseOrderLine,
+    #[sea_orm(has_one = "super::reorder_point::Entity")]
+    ReorderPoint,
+    #[sea_orm(has_many = "super::sales_order_line::Entity")]
+    SalesOrderLine,
+    #[sea_orm(has_many = "super::stock_lot::Entity")]
+    StockLot,
+    #[sea_orm(has_many = "super::stock_movement::Entity")]
+    StockMovement,
+    #[sea_orm(has_many = "super::stock_transfer::Entity")]
+    StockTransfer,
+    #[sea_orm(has_many = "super::supplier_product::Entity")]
+    SupplierProduct,
+}
+
+impl Related<super::inventory_count_line::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::InventoryCountLine.def()
+    }
+}
+
+impl Related<super::product_barcode::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ProductBarcode.def()
+    }
 }
 
 impl Related<super::product_family::Entity> for Entity {
@@ -34,4 +76,58 @@ impl Related<super::product_family::Entity> for Entity {
     }
 }
 
+impl Related<super::product_price::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ProductPrice.def()
+    }
+}
+
+impl Related<super::product_variant::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ProductVariant.def()
+    }
+}
+
+impl Related<super::purchase_order_line::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PurchaseOrderLine.def()
+    }
+}
+
+impl Related<super::reorder_point::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ReorderPoint.def()
+    }
+}
+
+impl Related<super::sales_order_line::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SalesOrderLine.def()
+    }
+}
+
+impl Related<super::stock_lot::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::StockLot.def()
+    }
+}
+
+impl Related<super::stock_movement::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::StockMovement.def()
+    }
+}
+
+impl Related<super::stock_transfer::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::StockTransfer.def()
+    }
+}
+
+impl Related<super::supplier_product::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SupplierProduct.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
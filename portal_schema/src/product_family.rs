@@ -10,12 +10,29 @@ pub struct Model {
     pub name: String,
     pub summary: Option<String>,
     pub signature: String,
+    pub parent_family_id: Option<i32>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::inventory_count::Entity")]
+    InventoryCount,
     #[sea_orm(has_many = "super::product::Entity")]
     Product,
+    #[sea_orm(
+        belongs_to = "Entity",
+        from = "Column::ParentFamilyId",
+        to = "Column::ProductFamilyId",
+        on_update = "NoAction",
+        on_delete = "NoAction"
+    )]
+    SelfRef,
+}
+
+impl Related<super::inventory_count::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::InventoryCount.def()
+    }
 }
 
 impl Related<super::product::Entity> for Entity {
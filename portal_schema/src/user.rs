@@ -14,6 +14,15 @@ pub struct Model {
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
-pub enum Relation {}
+pub enum Relation {
+    #[sea_orm(has_many = "super::user_authority::Entity")]
+    UserAuthority,
+}
+
+impl Related<super::user_authority::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserAuthority.def()
+    }
+}
 
 impl ActiveModelBehavior for ActiveModel {}
@@ -3,7 +3,26 @@ use lumx_axum::axum::Router;
 pub fn routes() -> Router {
     Router::new()
         .merge(people::adapters::rest::routers::person::router())
+        .merge(people::adapters::rest::routers::person_document_type::router())
         .merge(people::adapters::rest::routers::person_gender::router())
         .merge(warehouses::adapters::rest::routers::product::router())
+        .merge(warehouses::adapters::rest::routers::family::router())
+        .merge(warehouses::adapters::rest::routers::stock::router())
+        .merge(warehouses::adapters::rest::routers::warehouse::router())
+        .merge(warehouses::adapters::rest::routers::location::router())
+        .merge(warehouses::adapters::rest::routers::transfer::router())
+        .merge(warehouses::adapters::rest::routers::lot::router())
+        .merge(warehouses::adapters::rest::routers::count::router())
+        .merge(warehouses::adapters::rest::routers::reorder::router())
+        .merge(warehouses::adapters::rest::routers::unit::router())
+        .merge(warehouses::adapters::rest::routers::barcode::router())
+        .merge(warehouses::adapters::rest::routers::attribute::router())
+        .merge(warehouses::adapters::rest::routers::variant::router())
+        .merge(warehouses::adapters::rest::routers::price::router())
+        .merge(warehouses::adapters::rest::routers::supplier::router())
+        .merge(warehouses::adapters::rest::routers::purchase::router())
+        .merge(warehouses::adapters::rest::routers::sales::router())
+        .merge(warehouses::adapters::rest::routers::valuation::router())
+        .merge(warehouses::adapters::rest::routers::kit::router())
         .merge(passport::adapters::rest::routers::auth::router())
 }
@@ -1,4 +1,6 @@
 use lumx_axum::axum;
+use lumx_axum::axum::body::Body;
+use lumx_axum::axum::http::{Method, Request};
 use lumx_axum::plugin::WebPlugin;
 use lumx_axum::router::ProgramRoutable;
 use lumx_core::plugable::plugin::Plugin;
@@ -43,3 +45,11 @@ pub async fn configure() -> std::sync::Arc<Program> {
 
     program
 }
+
+pub fn delete_request(uri: &str) -> Request<Body> {
+    Request::builder()
+        .method(Method::DELETE)
+        .uri(uri)
+        .body(Body::empty())
+        .unwrap()
+}
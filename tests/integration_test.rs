@@ -2,3 +2,6 @@ mod common;
 mod passport;
 mod people;
 mod products;
+mod purchasing;
+mod selling;
+mod storage;
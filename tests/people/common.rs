@@ -1,9 +1,11 @@
+use lumx_sea_orm::sea_orm::sqlx::types::chrono::Utc;
 use lumx_sea_orm::sea_orm::{ActiveModelTrait, ActiveValue, DbErr};
 use lumx_sea_orm::sea_orm::{DatabaseConnection, EntityTrait};
-use portal_schema::{person, person_document_type, person_gender};
+use portal_schema::{person, person_document_type, person_gender, user, user_authority};
 
 pub const PEOPLE_URL: &str = "/api/v1/people";
 pub const PERSON_GENDERS_URL: &str = "/api/v1/person-genders";
+pub const PERSON_DOCUMENT_TYPES_URL: &str = "/api/v1/person-document-types";
 
 pub async fn insert_person_gender_female(
     conn: &DatabaseConnection,
@@ -35,7 +37,7 @@ pub async fn insert_document_dni(
     let document_type_model = person_document_type::ActiveModel {
         name: ActiveValue::Set("DNI".to_string()),
         summary: ActiveValue::Set(Some("DNI".to_string())),
-        signature: ActiveValue::Set("".to_string()),
+        signature: ActiveValue::Set("d56f5e97524d5d1ad77ec197ae11dad0".to_string()),
         ..Default::default()
     };
     document_type_model.save(conn).await
@@ -85,6 +87,33 @@ pub async fn insert_person_sample_2(conn: &DatabaseConnection) {
     person_model.save(conn).await.unwrap();
 }
 
+pub async fn archive_person(conn: &DatabaseConnection, person_id: i32) {
+    let person_model = person::ActiveModel {
+        person_id: ActiveValue::Unchanged(person_id),
+        deleted_at: ActiveValue::Set(Some(Utc::now().naive_utc())),
+        ..Default::default()
+    };
+    person_model.update(conn).await.unwrap();
+}
+
+pub async fn insert_admin_sample(conn: &DatabaseConnection) {
+    let user_model = user::ActiveModel {
+        username: ActiveValue::Set("admin".to_owned()),
+        password: ActiveValue::Set("admin".to_owned()),
+        created_at: ActiveValue::Set(Utc::now().naive_utc()),
+        creator_id: ActiveValue::Set(1),
+        ..Default::default()
+    };
+    let user_model = user_model.insert(conn).await.unwrap();
+
+    let authority_model = user_authority::ActiveModel {
+        user_id: ActiveValue::Set(user_model.id),
+        authority: ActiveValue::Set("admin".to_owned()),
+        ..Default::default()
+    };
+    authority_model.save(conn).await.unwrap();
+}
+
 pub async fn insert_people_sample_to_paginate(conn: &DatabaseConnection) {
     insert_person_gender_female(conn).await.unwrap();
     insert_person_gender_male(conn).await.unwrap();
@@ -1,3 +1,4 @@
-mod common;
+pub mod common;
 mod person;
+mod person_document_type;
 mod person_gender;
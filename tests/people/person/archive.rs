@@ -0,0 +1,72 @@
+use crate::{
+    common::{self},
+    people::common::{archive_person, insert_person_sample_1, PEOPLE_URL},
+};
+use ids_std_rest_testing::{extractors::IntoValueExt, factory::RequestFactory};
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{DatabaseConnection, EntityTrait};
+use portal_schema::person;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use tower::ServiceExt;
+
+fn url_to_archive_person(person_id: i32) -> String {
+    format!("{PEOPLE_URL}/{person_id}/archive")
+}
+
+#[tokio::test]
+async fn it_not_accept_invalid_person_id() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let req = RequestFactory::post(url_to_archive_person(10).as_str(), Body::empty());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "person_id",
+                "error": "person does not exist"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body)
+}
+
+#[tokio::test]
+async fn it_not_archive_person_twice() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+    archive_person(conn.as_ref(), 1).await;
+
+    let req = RequestFactory::post(url_to_archive_person(1).as_str(), Body::empty());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_archive_person_keeping_the_record() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+
+    let req = RequestFactory::post(url_to_archive_person(1).as_str(), Body::empty());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let archived_person = person::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(archived_person.deleted_at.is_some());
+}
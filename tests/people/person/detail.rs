@@ -1,6 +1,6 @@
 use crate::{
     common::{self},
-    people::common::{insert_person_sample_1, PEOPLE_URL},
+    people::common::{archive_person, insert_person_sample_1, PEOPLE_URL},
 };
 use ids_std_rest_testing::{extractors::IntoValueExt, factory::RequestFactory};
 use lumx_axum::axum::http::StatusCode;
@@ -33,6 +33,21 @@ async fn it_not_accept_invalid_person_id() {
     assert_eq!(res.into_value().await, expected_body)
 }
 
+#[tokio::test]
+async fn it_not_retrieve_archived_person() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+    archive_person(conn.as_ref(), 1).await;
+
+    let req = RequestFactory::get(url_to_retrieve_person(1).as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+}
+
 #[tokio::test]
 async fn it_retrieve_person_details() {
     let program = common::configure().await;
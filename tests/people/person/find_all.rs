@@ -9,7 +9,10 @@ use tower::ServiceExt;
 
 use crate::{
     common::{self},
-    people::common::{insert_people_sample_to_paginate, insert_person_sample_1, PEOPLE_URL},
+    people::common::{
+        archive_person, insert_admin_sample, insert_people_sample_to_paginate,
+        insert_person_sample_1, insert_person_sample_2, PEOPLE_URL,
+    },
 };
 
 #[tokio::test]
@@ -55,7 +58,8 @@ async fn it_retrieve_paginate_people() {
                 "documentTypeId": 1,
                 "documentTypeName": "P.IVA",
                 "genderId": 1,
-                "genderName": "Female"
+                "genderName": "Female",
+                "archived": false
             }
         ],
         "total": 1,
@@ -89,7 +93,8 @@ async fn it_retrieve_paginate_second_page_people() {
                 "documentTypeId": 1,
                 "documentTypeName": "P.IVA",
                 "genderId": 1,
-                "genderName": "Female"
+                "genderName": "Female",
+                "archived": false
             },
             {
                 "personId": 2,
@@ -100,7 +105,8 @@ async fn it_retrieve_paginate_second_page_people() {
                 "documentTypeId": 1,
                 "documentTypeName": "P.IVA",
                 "genderId": 2,
-                "genderName": "Male"
+                "genderName": "Male",
+                "archived": false
             },
             {
                 "personId": 1,
@@ -111,7 +117,8 @@ async fn it_retrieve_paginate_second_page_people() {
                 "documentTypeId": 1,
                 "documentTypeName": "P.IVA",
                 "genderId": 1,
-                "genderName": "Female"
+                "genderName": "Female",
+                "archived": false
             }
         ],
         "total": 13,
@@ -120,3 +127,207 @@ async fn it_retrieve_paginate_second_page_people() {
     });
     assert_eq!(res.into_value().await, expected_body)
 }
+
+#[tokio::test]
+async fn it_exclude_archived_people_by_default() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+    insert_person_sample_2(conn.as_ref()).await;
+    archive_person(conn.as_ref(), 2).await;
+
+    let req = RequestFactory::get(format!("{PEOPLE_URL}?page=1&page_size=10").as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "data": [
+            {
+                "personId": 1,
+                "firstName": "Idesoft",
+                "lastName": "Systems",
+                "fullName": "Idesoft Systems",
+                "documentNumber": "ID3SOFT",
+                "documentTypeId": 1,
+                "documentTypeName": "P.IVA",
+                "genderId": 1,
+                "genderName": "Female",
+                "archived": false
+            }
+        ],
+        "total": 1,
+        "page": 1,
+        "pageSize": 10
+    });
+    assert_eq!(res.into_value().await, expected_body)
+}
+
+#[tokio::test]
+async fn it_include_archived_people_on_demand() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+    insert_person_sample_2(conn.as_ref()).await;
+    archive_person(conn.as_ref(), 2).await;
+    insert_admin_sample(conn.as_ref()).await;
+
+    let req = RequestFactory::get(
+        format!("{PEOPLE_URL}?page=1&page_size=10&includeDeleted=true").as_str(),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "data": [
+            {
+                "personId": 2,
+                "firstName": "Person",
+                "lastName": "One",
+                "fullName": "Person One",
+                "documentNumber": "0001",
+                "documentTypeId": 2,
+                "documentTypeName": "DNI",
+                "genderId": 2,
+                "genderName": "Male",
+                "archived": true
+            },
+            {
+                "personId": 1,
+                "firstName": "Idesoft",
+                "lastName": "Systems",
+                "fullName": "Idesoft Systems",
+                "documentNumber": "ID3SOFT",
+                "documentTypeId": 1,
+                "documentTypeName": "P.IVA",
+                "genderId": 1,
+                "genderName": "Female",
+                "archived": false
+            }
+        ],
+        "total": 2,
+        "page": 1,
+        "pageSize": 10
+    });
+    assert_eq!(res.into_value().await, expected_body)
+}
+
+#[tokio::test]
+async fn it_forbid_archived_people_to_non_admins() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+    insert_person_sample_2(conn.as_ref()).await;
+    archive_person(conn.as_ref(), 2).await;
+
+    let req = RequestFactory::get(
+        format!("{PEOPLE_URL}?page=1&page_size=10&includeDeleted=true").as_str(),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn it_filter_people_by_gender() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_people_sample_to_paginate(conn.as_ref()).await;
+
+    let req = RequestFactory::get(format!("{PEOPLE_URL}?page=1&page_size=10&genderId=2").as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "data": [
+            {
+                "personId": 2,
+                "firstName": "Person",
+                "lastName": "Two",
+                "fullName": "Person Two",
+                "documentNumber": "0002",
+                "documentTypeId": 1,
+                "documentTypeName": "P.IVA",
+                "genderId": 2,
+                "genderName": "Male",
+                "archived": false
+            }
+        ],
+        "total": 1,
+        "page": 1,
+        "pageSize": 10
+    });
+    assert_eq!(res.into_value().await, expected_body)
+}
+
+#[tokio::test]
+async fn it_sort_people_by_requested_fields() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+    insert_person_sample_2(conn.as_ref()).await;
+
+    let req = RequestFactory::get(
+        format!("{PEOPLE_URL}?page=1&page_size=10&sort=-lastName,personId").as_str(),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "data": [
+            {
+                "personId": 1,
+                "firstName": "Idesoft",
+                "lastName": "Systems",
+                "fullName": "Idesoft Systems",
+                "documentNumber": "ID3SOFT",
+                "documentTypeId": 1,
+                "documentTypeName": "P.IVA",
+                "genderId": 1,
+                "genderName": "Female",
+                "archived": false
+            },
+            {
+                "personId": 2,
+                "firstName": "Person",
+                "lastName": "One",
+                "fullName": "Person One",
+                "documentNumber": "0001",
+                "documentTypeId": 2,
+                "documentTypeName": "DNI",
+                "genderId": 2,
+                "genderName": "Male",
+                "archived": false
+            }
+        ],
+        "total": 2,
+        "page": 1,
+        "pageSize": 10
+    });
+    assert_eq!(res.into_value().await, expected_body)
+}
+
+#[tokio::test]
+async fn it_not_accept_unknown_sort_field() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let req =
+        RequestFactory::get(format!("{PEOPLE_URL}?page=1&page_size=10&sort=signature").as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+}
@@ -1,4 +1,6 @@
+mod archive;
 mod create;
 mod detail;
 mod find_all;
+mod restore;
 mod update;
@@ -0,0 +1,75 @@
+use crate::{
+    common::{self},
+    people::common::{archive_person, insert_person_sample_1, PEOPLE_URL},
+};
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{ActiveModelTrait, ActiveValue, DatabaseConnection, EntityTrait};
+use portal_schema::person;
+use pretty_assertions::assert_eq;
+use tower::ServiceExt;
+
+fn url_to_restore_person(person_id: i32) -> String {
+    format!("{PEOPLE_URL}/{person_id}/restore")
+}
+
+#[tokio::test]
+async fn it_not_restore_active_person() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+
+    let req = RequestFactory::post(url_to_restore_person(1).as_str(), Body::empty());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_not_restore_person_with_duplicate_document() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+    archive_person(conn.as_ref(), 1).await;
+
+    let same_document_model = person::ActiveModel {
+        first_name: ActiveValue::Set("Another".to_string()),
+        last_name: ActiveValue::Set("Company".to_string()),
+        document_number: ActiveValue::Set("ID3SOFT".to_string()),
+        document_type_id: ActiveValue::Set(1),
+        gender_id: ActiveValue::Set(1),
+        signature: ActiveValue::Set("".to_string()),
+        ..Default::default()
+    };
+    same_document_model.save(conn.as_ref()).await.unwrap();
+
+    let req = RequestFactory::post(url_to_restore_person(1).as_str(), Body::empty());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_restore_archived_person() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+    archive_person(conn.as_ref(), 1).await;
+
+    let req = RequestFactory::post(url_to_restore_person(1).as_str(), Body::empty());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let restored_person = person::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(restored_person.deleted_at, None);
+}
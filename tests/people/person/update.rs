@@ -268,7 +268,8 @@ async fn it_accept_and_update_valid_person() {
         "document_number": "0001",
         "document_type_id": another_doc_id,
         "gender_id": another_gender_id,
-        "signature": "548696ad63cc5a7b6c86e4c1b39c89b6"
+        "signature": "548696ad63cc5a7b6c86e4c1b39c89b6",
+        "deleted_at": null
     });
 
     assert_eq!(expected_person, updated_person)
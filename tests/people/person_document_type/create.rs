@@ -0,0 +1,108 @@
+use crate::{
+    common::{self},
+    people::common::{insert_document_dni, PERSON_DOCUMENT_TYPES_URL},
+};
+use ids_std_rest_testing::{extractors::IntoValueExt, factory::RequestFactory};
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{DatabaseConnection, EntityTrait};
+use portal_schema::person_document_type;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn it_not_accept_empty_person_document_type_request() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let req = RequestFactory::post(PERSON_DOCUMENT_TYPES_URL, Body::empty());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn it_validate_required_person_document_type_fields() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let document_type_info = json!({
+        "name": "",
+    });
+    let req = RequestFactory::post(
+        PERSON_DOCUMENT_TYPES_URL,
+        Body::from(serde_json::to_string(&document_type_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "error": "length",
+                "field": "name"
+            },
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_accept_duplicate_person_document_type() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_document_dni(conn.as_ref()).await.unwrap();
+
+    let document_type_info = json!({
+        "name": " dni "
+    });
+    let req = RequestFactory::post(
+        PERSON_DOCUMENT_TYPES_URL,
+        Body::from(serde_json::to_string(&document_type_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_accept_and_save_valid_person_document_type() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    let document_type_info = json!({
+        "name": " P.IVA ",
+        "summary": "Partita IVA"
+    });
+    let req = RequestFactory::post(
+        PERSON_DOCUMENT_TYPES_URL,
+        Body::from(serde_json::to_string(&document_type_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "id": 1
+    });
+    assert_eq!(res.into_value().await, expected_body);
+
+    let document_type_saved = person_document_type::Entity::find_by_id(1)
+        .into_json()
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    let expected_document_type = json!({
+        "person_document_type_id": 1,
+        "name": "P.IVA",
+        "summary": "Partita IVA",
+        "signature": "6e2f49224dbe16b8d8d215e86f35d24b"
+    });
+    assert_eq!(document_type_saved, expected_document_type);
+}
@@ -0,0 +1,54 @@
+use crate::{
+    common::{self},
+    people::common::{insert_document_piva, PERSON_DOCUMENT_TYPES_URL},
+};
+use ids_std_rest_testing::{extractors::IntoValueExt, factory::RequestFactory};
+use lumx_axum::axum::http::StatusCode;
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::DatabaseConnection;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use tower::ServiceExt;
+
+fn url_to_retrieve_person_document_type(person_document_type_id: i32) -> String {
+    format!("{PERSON_DOCUMENT_TYPES_URL}/{person_document_type_id}")
+}
+
+#[tokio::test]
+async fn it_not_accept_invalid_person_document_type_id() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let req = RequestFactory::get(url_to_retrieve_person_document_type(10).as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    let expected_body = json!({
+        "errors": [],
+        "message": "document type does not exist"
+    });
+    assert_eq!(res.into_value().await, expected_body)
+}
+
+#[tokio::test]
+async fn it_retrieve_person_document_type_details() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_document_piva(conn.as_ref()).await.unwrap();
+
+    let req = RequestFactory::get(url_to_retrieve_person_document_type(1).as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "personDocumentTypeId": 1,
+        "name": "P.IVA",
+        "summary": "Partiva IVA"
+    });
+    assert_eq!(res.into_value().await, expected_body)
+}
@@ -0,0 +1,116 @@
+use ids_std_rest_testing::{extractors::IntoValueExt, factory::RequestFactory};
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{DatabaseConnection, EntityTrait};
+use portal_schema::person_document_type;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use tower::ServiceExt;
+
+use crate::{
+    common::{self},
+    people::common::{insert_document_dni, insert_document_piva, PERSON_DOCUMENT_TYPES_URL},
+};
+
+fn url_to_update_person_document_type(person_document_type_id: i32) -> String {
+    format!("{PERSON_DOCUMENT_TYPES_URL}/{person_document_type_id}")
+}
+
+#[tokio::test]
+async fn it_not_accept_empty_person_document_type_request() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let req = RequestFactory::put(
+        url_to_update_person_document_type(1).as_str(),
+        Body::empty(),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST)
+}
+
+#[tokio::test]
+async fn it_not_accept_invalid_person_document_type_id() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let document_type_info = json!({
+        "name": "DNI"
+    });
+    let req = RequestFactory::put(
+        url_to_update_person_document_type(10).as_str(),
+        Body::from(serde_json::to_string(&document_type_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "person_document_type_id",
+                "error": "document type does not exist"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body)
+}
+
+#[tokio::test]
+async fn it_not_accept_duplicate_document_type_signature() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_document_piva(conn.as_ref()).await.unwrap();
+    insert_document_dni(conn.as_ref()).await.unwrap();
+
+    let document_type_info = json!({
+        "name": "DNI"
+    });
+    let req = RequestFactory::put(
+        url_to_update_person_document_type(1).as_str(),
+        Body::from(serde_json::to_string(&document_type_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_accept_and_update_valid_person_document_type() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_document_dni(conn.as_ref()).await.unwrap();
+
+    let person_document_type_id_to_update: i32 = 1;
+    let document_type_info = json!({
+        "name": "RUC",
+        "summary": "Registro Unico de Contribuyentes"
+    });
+    let req = RequestFactory::put(
+        url_to_update_person_document_type(person_document_type_id_to_update).as_str(),
+        Body::from(serde_json::to_string(&document_type_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let updated_document_type =
+        person_document_type::Entity::find_by_id(person_document_type_id_to_update)
+            .into_json()
+            .one(conn.as_ref())
+            .await
+            .unwrap()
+            .unwrap();
+
+    let expected_document_type = json!({
+        "person_document_type_id": person_document_type_id_to_update,
+        "name": "RUC",
+        "summary": "Registro Unico de Contribuyentes",
+        "signature": "21d0e26d0b99b0d483771d5426e8c5aa"
+    });
+    assert_eq!(expected_document_type, updated_document_type);
+}
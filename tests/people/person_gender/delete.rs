@@ -0,0 +1,70 @@
+use crate::{
+    common::{self},
+    people::common::{insert_person_gender_male, insert_person_sample_1, PERSON_GENDERS_URL},
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use lumx_axum::axum::http::StatusCode;
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{DatabaseConnection, EntityTrait};
+use portal_schema::person_gender;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use tower::ServiceExt;
+
+fn url_to_delete_person_gender(person_gender_id: i32) -> String {
+    format!("{PERSON_GENDERS_URL}/{person_gender_id}")
+}
+
+#[tokio::test]
+async fn it_not_accept_invalid_person_gender_id() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let req = common::delete_request(url_to_delete_person_gender(10).as_str());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "person_gender_id",
+                "error": "gender does not exist"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body)
+}
+
+#[tokio::test]
+async fn it_not_delete_gender_assigned_to_people() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+
+    let req = common::delete_request(url_to_delete_person_gender(1).as_str());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_delete_unassigned_person_gender() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_gender_male(conn.as_ref()).await.unwrap();
+
+    let req = common::delete_request(url_to_delete_person_gender(1).as_str());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let deleted_gender = person_gender::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap();
+    assert_eq!(deleted_gender, None);
+}
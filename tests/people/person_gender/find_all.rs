@@ -0,0 +1,96 @@
+use ids_std_rest_testing::{extractors::IntoValueExt, factory::RequestFactory};
+use lumx_axum::axum::http::StatusCode;
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::DatabaseConnection;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use tower::ServiceExt;
+
+use crate::{
+    common::{self},
+    people::common::{insert_person_gender_female, insert_person_gender_male, PERSON_GENDERS_URL},
+};
+
+#[tokio::test]
+async fn it_retrieve_empty_paginate_person_genders() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let req = RequestFactory::get(format!("{PERSON_GENDERS_URL}?page=1&page_size=10").as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "data": [],
+        "total": 0,
+        "page": 0,
+        "pageSize": 10,
+    });
+    assert_eq!(res.into_value().await, expected_body)
+}
+
+#[tokio::test]
+async fn it_retrieve_paginate_person_genders() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_gender_female(conn.as_ref()).await.unwrap();
+    insert_person_gender_male(conn.as_ref()).await.unwrap();
+
+    let req = RequestFactory::get(format!("{PERSON_GENDERS_URL}?page=1&page_size=10").as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "data": [
+            {
+                "personGenderId": 2,
+                "name": "Male",
+                "summary": "Male"
+            },
+            {
+                "personGenderId": 1,
+                "name": "Female",
+                "summary": "Female"
+            }
+        ],
+        "total": 2,
+        "page": 1,
+        "pageSize": 10
+    });
+    assert_eq!(res.into_value().await, expected_body)
+}
+
+#[tokio::test]
+async fn it_retrieve_person_genders_by_name() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_gender_female(conn.as_ref()).await.unwrap();
+    insert_person_gender_male(conn.as_ref()).await.unwrap();
+
+    let req =
+        RequestFactory::get(format!("{PERSON_GENDERS_URL}?page=1&page_size=10&name=Fem").as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "data": [
+            {
+                "personGenderId": 1,
+                "name": "Female",
+                "summary": "Female"
+            }
+        ],
+        "total": 1,
+        "page": 1,
+        "pageSize": 10
+    });
+    assert_eq!(res.into_value().await, expected_body)
+}
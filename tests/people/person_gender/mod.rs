@@ -1,2 +1,5 @@
 mod create;
+mod delete;
+mod detail;
+mod find_all;
 mod update;
@@ -1,9 +1,16 @@
 use lumx_sea_orm::sea_orm;
+use lumx_sea_orm::sea_orm::sqlx::types::chrono::{NaiveDate, Utc};
 use lumx_sea_orm::sea_orm::{ActiveModelTrait, ActiveValue, DatabaseConnection};
-use portal_schema::{product, product_family};
+use portal_schema::{
+    price_list, product, product_attribute, product_attribute_value, product_barcode,
+    product_component, product_family, product_price, product_variant, product_variant_value,
+    reorder_point, stock_lot, stock_movement, unit_conversion, unit_of_measure,
+};
+use warehouses::domain::valuables::family::ProductFamilySignature;
 use warehouses::domain::valuables::product::ProductSignature;
 
 pub const PRODUCTS_URL: &str = "/api/v1/products";
+pub const PRODUCT_FAMILIES_URL: &str = "/api/v1/product-families";
 
 pub struct ProductFactory;
 
@@ -29,6 +36,177 @@ impl ProductFactory {
 
         product_model.save(conn).await
     }
+
+    pub async fn macbook_pro(
+        conn: &DatabaseConnection,
+    ) -> Result<product::ActiveModel, sea_orm::DbErr> {
+        let family = ProductFamilyFactory::computers(conn).await?;
+
+        let product_name = "MacBook Pro";
+        let product_signature = ProductSignature::new(product_name).get();
+
+        let product_model = product::ActiveModel {
+            family_id: ActiveValue::Set(family.product_family_id.unwrap()),
+            name: ActiveValue::Set(product_name.to_string()),
+            summary: ActiveValue::Set(None),
+            purchasable: ActiveValue::Set(true.into()),
+            saleable: ActiveValue::Set(false.into()),
+            saleable_without_stock: ActiveValue::Set(false.into()),
+            signature: ActiveValue::Set(product_signature.to_owned()),
+            ..Default::default()
+        };
+
+        product_model.save(conn).await
+    }
+
+    pub async fn in_family(
+        conn: &DatabaseConnection,
+        product_name: &str,
+        family_id: i32,
+    ) -> Result<product::ActiveModel, sea_orm::DbErr> {
+        let product_model = product::ActiveModel {
+            family_id: ActiveValue::Set(family_id),
+            name: ActiveValue::Set(product_name.to_string()),
+            summary: ActiveValue::Set(None),
+            purchasable: ActiveValue::Set(true.into()),
+            saleable: ActiveValue::Set(true.into()),
+            saleable_without_stock: ActiveValue::Set(false.into()),
+            signature: ActiveValue::Set(ProductSignature::new(product_name).get()),
+            ..Default::default()
+        };
+
+        product_model.save(conn).await
+    }
+
+    pub async fn discontinue(
+        conn: &DatabaseConnection,
+        product_id: i32,
+    ) -> Result<product::Model, sea_orm::DbErr> {
+        let product_model = product::ActiveModel {
+            product_id: ActiveValue::Unchanged(product_id),
+            saleable: ActiveValue::Set(false.into()),
+            saleable_without_stock: ActiveValue::Set(false.into()),
+            discontinued_at: ActiveValue::Set(Some(Utc::now().naive_utc())),
+            ..Default::default()
+        };
+
+        product_model.update(conn).await
+    }
+
+    pub async fn stop_purchasing(
+        conn: &DatabaseConnection,
+        product_id: i32,
+    ) -> Result<product::Model, sea_orm::DbErr> {
+        let product_model = product::ActiveModel {
+            product_id: ActiveValue::Unchanged(product_id),
+            purchasable: ActiveValue::Set(false.into()),
+            ..Default::default()
+        };
+
+        product_model.update(conn).await
+    }
+
+    pub async fn stop_selling(
+        conn: &DatabaseConnection,
+        product_id: i32,
+    ) -> Result<product::Model, sea_orm::DbErr> {
+        let product_model = product::ActiveModel {
+            product_id: ActiveValue::Unchanged(product_id),
+            saleable: ActiveValue::Set(false.into()),
+            ..Default::default()
+        };
+
+        product_model.update(conn).await
+    }
+
+    pub async fn sell_without_stock(
+        conn: &DatabaseConnection,
+        product_id: i32,
+    ) -> Result<product::Model, sea_orm::DbErr> {
+        let product_model = product::ActiveModel {
+            product_id: ActiveValue::Unchanged(product_id),
+            saleable_without_stock: ActiveValue::Set(true.into()),
+            ..Default::default()
+        };
+
+        product_model.update(conn).await
+    }
+
+    pub async fn track(
+        conn: &DatabaseConnection,
+        product_id: i32,
+        tracking_mode: &str,
+    ) -> Result<product::Model, sea_orm::DbErr> {
+        let product_model = product::ActiveModel {
+            product_id: ActiveValue::Unchanged(product_id),
+            tracking_mode: ActiveValue::Set(tracking_mode.to_string()),
+            ..Default::default()
+        };
+
+        product_model.update(conn).await
+    }
+
+    pub async fn cost_with(
+        conn: &DatabaseConnection,
+        product_id: i32,
+        costing_method: &str,
+    ) -> Result<product::Model, sea_orm::DbErr> {
+        let product_model = product::ActiveModel {
+            product_id: ActiveValue::Unchanged(product_id),
+            costing_method: ActiveValue::Set(costing_method.to_string()),
+            ..Default::default()
+        };
+
+        product_model.update(conn).await
+    }
+
+    pub async fn with_sku(
+        conn: &DatabaseConnection,
+        product_id: i32,
+        sku: &str,
+    ) -> Result<product::Model, sea_orm::DbErr> {
+        let product_model = product::ActiveModel {
+            product_id: ActiveValue::Unchanged(product_id),
+            sku: ActiveValue::Set(Some(sku.to_string())),
+            ..Default::default()
+        };
+
+        product_model.update(conn).await
+    }
+
+    pub async fn measure_in(
+        conn: &DatabaseConnection,
+        product_id: i32,
+        base_unit_id: i32,
+    ) -> Result<product::Model, sea_orm::DbErr> {
+        let product_model = product::ActiveModel {
+            product_id: ActiveValue::Unchanged(product_id),
+            base_unit_id: ActiveValue::Set(Some(base_unit_id)),
+            ..Default::default()
+        };
+
+        product_model.update(conn).await
+    }
+}
+
+pub struct ProductBarcodeFactory;
+
+impl ProductBarcodeFactory {
+    pub async fn ean13(
+        conn: &DatabaseConnection,
+        product_id: i32,
+        code: &str,
+    ) -> Result<product_barcode::ActiveModel, sea_orm::DbErr> {
+        let barcode_model = product_barcode::ActiveModel {
+            product_id: ActiveValue::Set(product_id),
+            code: ActiveValue::Set(code.to_string()),
+            gtin: ActiveValue::Set(format!("{:0>14}", code)),
+            symbology: ActiveValue::Set("ean13".to_string()),
+            ..Default::default()
+        };
+
+        barcode_model.save(conn).await
+    }
 }
 
 pub struct ProductFamilyFactory;
@@ -40,10 +218,390 @@ impl ProductFamilyFactory {
         let family_model = product_family::ActiveModel {
             name: ActiveValue::Set("Electronics".to_owned()),
             summary: ActiveValue::Set(Some("All Electronics".to_string())),
-            signature: ActiveValue::Set("".to_owned()),
+            signature: ActiveValue::Set(ProductFamilySignature::new("Electronics").get()),
+            ..Default::default()
+        };
+
+        family_model.save(conn).await
+    }
+
+    pub async fn computers(
+        conn: &DatabaseConnection,
+    ) -> Result<product_family::ActiveModel, sea_orm::DbErr> {
+        let family_model = product_family::ActiveModel {
+            name: ActiveValue::Set("Computers".to_owned()),
+            summary: ActiveValue::Set(None),
+            signature: ActiveValue::Set(ProductFamilySignature::new("Computers").get()),
+            ..Default::default()
+        };
+
+        family_model.save(conn).await
+    }
+
+    pub async fn child_of(
+        conn: &DatabaseConnection,
+        name: &str,
+        parent_family_id: i32,
+    ) -> Result<product_family::ActiveModel, sea_orm::DbErr> {
+        let family_model = product_family::ActiveModel {
+            name: ActiveValue::Set(name.to_owned()),
+            summary: ActiveValue::Set(None),
+            signature: ActiveValue::Set(ProductFamilySignature::new(name).get()),
+            parent_family_id: ActiveValue::Set(Some(parent_family_id)),
             ..Default::default()
         };
 
         family_model.save(conn).await
     }
 }
+
+pub struct StockFactory;
+
+impl StockFactory {
+    pub async fn receipt(
+        conn: &DatabaseConnection,
+        product_id: i32,
+        quantity: i32,
+    ) -> Result<stock_movement::ActiveModel, sea_orm::DbErr> {
+        let movement_model = stock_movement::ActiveModel {
+            product_id: ActiveValue::Set(product_id),
+            kind: ActiveValue::Set("receipt".to_string()),
+            quantity: ActiveValue::Set(quantity),
+            reason: ActiveValue::Set(None),
+            moved_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        movement_model.save(conn).await
+    }
+
+    pub async fn receipt_at(
+        conn: &DatabaseConnection,
+        product_id: i32,
+        storage_location_id: i32,
+        quantity: i32,
+    ) -> Result<stock_movement::ActiveModel, sea_orm::DbErr> {
+        let movement_model = stock_movement::ActiveModel {
+            product_id: ActiveValue::Set(product_id),
+            kind: ActiveValue::Set("receipt".to_string()),
+            quantity: ActiveValue::Set(quantity),
+            storage_location_id: ActiveValue::Set(Some(storage_location_id)),
+            reason: ActiveValue::Set(None),
+            moved_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        movement_model.save(conn).await
+    }
+
+    pub async fn costed_receipt(
+        conn: &DatabaseConnection,
+        product_id: i32,
+        quantity: i32,
+        unit_cost: i64,
+    ) -> Result<stock_movement::ActiveModel, sea_orm::DbErr> {
+        let movement_model = stock_movement::ActiveModel {
+            product_id: ActiveValue::Set(product_id),
+            kind: ActiveValue::Set("receipt".to_string()),
+            quantity: ActiveValue::Set(quantity),
+            unit_cost: ActiveValue::Set(Some(unit_cost)),
+            reason: ActiveValue::Set(None),
+            moved_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        movement_model.save(conn).await
+    }
+
+    pub async fn costed_receipt_at(
+        conn: &DatabaseConnection,
+        product_id: i32,
+        storage_location_id: i32,
+        quantity: i32,
+        unit_cost: i64,
+    ) -> Result<stock_movement::ActiveModel, sea_orm::DbErr> {
+        let movement_model = stock_movement::ActiveModel {
+            product_id: ActiveValue::Set(product_id),
+            kind: ActiveValue::Set("receipt".to_string()),
+            quantity: ActiveValue::Set(quantity),
+            storage_location_id: ActiveValue::Set(Some(storage_location_id)),
+            unit_cost: ActiveValue::Set(Some(unit_cost)),
+            reason: ActiveValue::Set(None),
+            moved_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        movement_model.save(conn).await
+    }
+
+    pub async fn issue(
+        conn: &DatabaseConnection,
+        product_id: i32,
+        quantity: i32,
+    ) -> Result<stock_movement::ActiveModel, sea_orm::DbErr> {
+        let movement_model = stock_movement::ActiveModel {
+            product_id: ActiveValue::Set(product_id),
+            kind: ActiveValue::Set("issue".to_string()),
+            quantity: ActiveValue::Set(-quantity),
+            reason: ActiveValue::Set(None),
+            moved_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        movement_model.save(conn).await
+    }
+
+    pub async fn tracked_receipt(
+        conn: &DatabaseConnection,
+        product_id: i32,
+        lot_number: Option<&str>,
+        serial_number: Option<&str>,
+        quantity: i32,
+    ) -> Result<stock_movement::ActiveModel, sea_orm::DbErr> {
+        let movement_model = stock_movement::ActiveModel {
+            product_id: ActiveValue::Set(product_id),
+            kind: ActiveValue::Set("receipt".to_string()),
+            quantity: ActiveValue::Set(quantity),
+            lot_number: ActiveValue::Set(lot_number.map(str::to_string)),
+            serial_number: ActiveValue::Set(serial_number.map(str::to_string)),
+            reason: ActiveValue::Set(None),
+            moved_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        movement_model.save(conn).await
+    }
+
+    pub async fn tracked_receipt_at(
+        conn: &DatabaseConnection,
+        product_id: i32,
+        storage_location_id: i32,
+        lot_number: &str,
+        quantity: i32,
+    ) -> Result<stock_movement::ActiveModel, sea_orm::DbErr> {
+        let movement_model = stock_movement::ActiveModel {
+            product_id: ActiveValue::Set(product_id),
+            kind: ActiveValue::Set("receipt".to_string()),
+            quantity: ActiveValue::Set(quantity),
+            storage_location_id: ActiveValue::Set(Some(storage_location_id)),
+            lot_number: ActiveValue::Set(Some(lot_number.to_string())),
+            reason: ActiveValue::Set(None),
+            moved_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        movement_model.save(conn).await
+    }
+}
+
+pub struct StockLotFactory;
+
+impl StockLotFactory {
+    pub async fn expiring_on(
+        conn: &DatabaseConnection,
+        product_id: i32,
+        lot_number: &str,
+        expires_on: NaiveDate,
+    ) -> Result<stock_lot::ActiveModel, sea_orm::DbErr> {
+        let stock_lot_model = stock_lot::ActiveModel {
+            product_id: ActiveValue::Set(product_id),
+            lot_number: ActiveValue::Set(lot_number.to_string()),
+            expires_on: ActiveValue::Set(expires_on),
+            ..Default::default()
+        };
+
+        stock_lot_model.save(conn).await
+    }
+}
+
+pub struct ReorderPointFactory;
+
+impl ReorderPointFactory {
+    pub async fn between(
+        conn: &DatabaseConnection,
+        product_id: i32,
+        min_quantity: i32,
+        max_quantity: Option<i32>,
+    ) -> Result<reorder_point::ActiveModel, sea_orm::DbErr> {
+        let reorder_point_model = reorder_point::ActiveModel {
+            product_id: ActiveValue::Set(product_id),
+            min_quantity: ActiveValue::Set(min_quantity),
+            max_quantity: ActiveValue::Set(max_quantity),
+            ..Default::default()
+        };
+
+        reorder_point_model.save(conn).await
+    }
+}
+
+pub struct UnitOfMeasureFactory;
+
+impl UnitOfMeasureFactory {
+    pub async fn code(
+        conn: &DatabaseConnection,
+        code: &str,
+        name: &str,
+    ) -> Result<unit_of_measure::ActiveModel, sea_orm::DbErr> {
+        let unit_model = unit_of_measure::ActiveModel {
+            code: ActiveValue::Set(code.to_string()),
+            name: ActiveValue::Set(name.to_string()),
+            ..Default::default()
+        };
+
+        unit_model.save(conn).await
+    }
+
+    pub async fn conversion(
+        conn: &DatabaseConnection,
+        from_unit_id: i32,
+        to_unit_id: i32,
+        factor: i32,
+    ) -> Result<unit_conversion::ActiveModel, sea_orm::DbErr> {
+        let conversion_model = unit_conversion::ActiveModel {
+            from_unit_id: ActiveValue::Set(from_unit_id),
+            to_unit_id: ActiveValue::Set(to_unit_id),
+            factor: ActiveValue::Set(factor),
+            ..Default::default()
+        };
+
+        conversion_model.save(conn).await
+    }
+}
+
+pub struct ProductAttributeFactory;
+
+impl ProductAttributeFactory {
+    pub async fn with_values(
+        conn: &DatabaseConnection,
+        name: &str,
+        values: &[&str],
+    ) -> Result<product_attribute::ActiveModel, sea_orm::DbErr> {
+        let attribute_model = product_attribute::ActiveModel {
+            name: ActiveValue::Set(name.to_string()),
+            ..Default::default()
+        };
+        let attribute_model = attribute_model.save(conn).await?;
+
+        for value in values {
+            let value_model = product_attribute_value::ActiveModel {
+                attribute_id: ActiveValue::Set(
+                    attribute_model.product_attribute_id.clone().unwrap(),
+                ),
+                value: ActiveValue::Set(value.to_string()),
+                ..Default::default()
+            };
+            value_model.save(conn).await?;
+        }
+
+        Ok(attribute_model)
+    }
+}
+
+pub struct ProductVariantFactory;
+
+impl ProductVariantFactory {
+    pub async fn of(
+        conn: &DatabaseConnection,
+        product_id: i32,
+        product_name: &str,
+        attribute_values: &[(i32, &str, &str)],
+    ) -> Result<product_variant::ActiveModel, sea_orm::DbErr> {
+        let attributes = attribute_values
+            .iter()
+            .map(|(_, attribute, value)| (*attribute, *value))
+            .collect::<Vec<(&str, &str)>>();
+        let variant_name = std::iter::once(product_name)
+            .chain(attribute_values.iter().map(|(_, _, value)| *value))
+            .collect::<Vec<&str>>()
+            .join(" / ");
+
+        let variant_model = product_variant::ActiveModel {
+            product_id: ActiveValue::Set(product_id),
+            name: ActiveValue::Set(variant_name),
+            sku: ActiveValue::Set(None),
+            signature: ActiveValue::Set(
+                ProductSignature::with_attributes(product_name, &attributes).get(),
+            ),
+            ..Default::default()
+        };
+        let variant_model = variant_model.save(conn).await?;
+
+        for (attribute_value_id, _, _) in attribute_values {
+            let variant_value_model = product_variant_value::ActiveModel {
+                variant_id: ActiveValue::Set(variant_model.product_variant_id.clone().unwrap()),
+                attribute_value_id: ActiveValue::Set(*attribute_value_id),
+                ..Default::default()
+            };
+            variant_value_model.save(conn).await?;
+        }
+
+        Ok(variant_model)
+    }
+}
+
+pub struct PriceListFactory;
+
+impl PriceListFactory {
+    pub async fn retail(
+        conn: &DatabaseConnection,
+        name: &str,
+        currency: &str,
+        valid_from: NaiveDate,
+        valid_to: Option<NaiveDate>,
+    ) -> Result<price_list::ActiveModel, sea_orm::DbErr> {
+        let price_list_model = price_list::ActiveModel {
+            name: ActiveValue::Set(name.to_string()),
+            kind: ActiveValue::Set("retail".to_string()),
+            currency: ActiveValue::Set(currency.to_string()),
+            valid_from: ActiveValue::Set(valid_from),
+            valid_to: ActiveValue::Set(valid_to),
+            ..Default::default()
+        };
+
+        price_list_model.save(conn).await
+    }
+}
+
+pub struct ProductPriceFactory;
+
+impl ProductPriceFactory {
+    pub async fn between(
+        conn: &DatabaseConnection,
+        price_list_id: i32,
+        product_id: i32,
+        amount: i64,
+        valid_from: NaiveDate,
+        valid_to: Option<NaiveDate>,
+    ) -> Result<product_price::ActiveModel, sea_orm::DbErr> {
+        let price_model = product_price::ActiveModel {
+            price_list_id: ActiveValue::Set(price_list_id),
+            product_id: ActiveValue::Set(product_id),
+            amount: ActiveValue::Set(amount),
+            valid_from: ActiveValue::Set(valid_from),
+            valid_to: ActiveValue::Set(valid_to),
+            ..Default::default()
+        };
+
+        price_model.save(conn).await
+    }
+}
+
+pub struct ProductComponentFactory;
+
+impl ProductComponentFactory {
+    pub async fn of(
+        conn: &DatabaseConnection,
+        kit_product_id: i32,
+        component_product_id: i32,
+        quantity: i32,
+    ) -> Result<product_component::ActiveModel, sea_orm::DbErr> {
+        let component_model = product_component::ActiveModel {
+            kit_product_id: ActiveValue::Set(kit_product_id),
+            component_product_id: ActiveValue::Set(component_product_id),
+            quantity: ActiveValue::Set(quantity),
+            ..Default::default()
+        };
+
+        component_model.save(conn).await
+    }
+}
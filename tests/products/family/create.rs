@@ -0,0 +1,112 @@
+use crate::products::common::ProductFamilyFactory;
+use crate::{
+    common::{self},
+    products::common::PRODUCT_FAMILIES_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{DatabaseConnection, EntityTrait};
+use portal_schema::product_family;
+use serde_json::json;
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn it_not_accept_empty_product_family_request() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let req = RequestFactory::post(PRODUCT_FAMILIES_URL, Body::empty());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn it_validate_required_product_family_fields() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let family_info = json!({
+        "name": ""
+    });
+    let req = RequestFactory::post(
+        PRODUCT_FAMILIES_URL,
+        Body::from(serde_json::to_string(&family_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "error": "length",
+                "field": "name"
+            },
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_accept_duplicate_product_family() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFamilyFactory::electronics(conn.as_ref())
+        .await
+        .unwrap();
+
+    let family_info = json!({
+        "name": " ELECTRONICS "
+    });
+    let req = RequestFactory::post(
+        PRODUCT_FAMILIES_URL,
+        Body::from(serde_json::to_string(&family_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_accepts_and_save_valid_product_family() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    let family_info = json!({
+        "name": "  Home   Appliances ",
+        "summary": "Kitchen and laundry"
+    });
+    let req = RequestFactory::post(
+        PRODUCT_FAMILIES_URL,
+        Body::from(serde_json::to_string(&family_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "id": 1
+    });
+    assert_eq!(res.into_value().await, expected_body);
+
+    let family_saved = product_family::Entity::find_by_id(1)
+        .into_json()
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    let expected_family = json!({
+        "product_family_id": 1,
+        "name": "Home Appliances",
+        "summary": "Kitchen and laundry",
+        "signature": "7dc6bf24b40e17e8a14528cc848562f0",
+        "parent_family_id": null
+    });
+    assert_eq!(family_saved, expected_family);
+}
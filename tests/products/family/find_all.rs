@@ -0,0 +1,89 @@
+use crate::products::common::ProductFamilyFactory;
+use crate::{
+    common::{self},
+    products::common::PRODUCT_FAMILIES_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::http::StatusCode;
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::DatabaseConnection;
+use serde_json::json;
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn it_retrieve_paginate_product_families() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFamilyFactory::electronics(conn.as_ref())
+        .await
+        .unwrap();
+    ProductFamilyFactory::computers(conn.as_ref())
+        .await
+        .unwrap();
+
+    let req = RequestFactory::get(format!("{PRODUCT_FAMILIES_URL}?page=1&page_size=10").as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "data": [
+            {
+                "productFamilyId": 2,
+                "name": "Computers",
+                "summary": null,
+                "parentFamilyId": null
+            },
+            {
+                "productFamilyId": 1,
+                "name": "Electronics",
+                "summary": "All Electronics",
+                "parentFamilyId": null
+            }
+        ],
+        "total": 2,
+        "page": 1,
+        "pageSize": 10
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_filter_product_families_by_name() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFamilyFactory::electronics(conn.as_ref())
+        .await
+        .unwrap();
+    ProductFamilyFactory::computers(conn.as_ref())
+        .await
+        .unwrap();
+
+    let req = RequestFactory::get(
+        format!("{PRODUCT_FAMILIES_URL}?page=1&page_size=10&name=Elec").as_str(),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "data": [
+            {
+                "productFamilyId": 1,
+                "name": "Electronics",
+                "summary": "All Electronics",
+                "parentFamilyId": null
+            }
+        ],
+        "total": 1,
+        "page": 1,
+        "pageSize": 10
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
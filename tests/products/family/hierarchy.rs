@@ -0,0 +1,168 @@
+use crate::products::common::ProductFamilyFactory;
+use crate::{
+    common::{self},
+    products::common::PRODUCT_FAMILIES_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::DatabaseConnection;
+use serde_json::json;
+use tower::ServiceExt;
+
+async fn insert_phone_families(conn: &DatabaseConnection) {
+    ProductFamilyFactory::electronics(conn).await.unwrap();
+    ProductFamilyFactory::child_of(conn, "Phones", 1)
+        .await
+        .unwrap();
+    ProductFamilyFactory::child_of(conn, "Smartphones", 2)
+        .await
+        .unwrap();
+    ProductFamilyFactory::child_of(conn, "Televisions", 1)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn it_not_accept_unknown_parent_family() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let family_info = json!({
+        "name": "Phones",
+        "parentFamilyId": 10
+    });
+    let req = RequestFactory::post(
+        PRODUCT_FAMILIES_URL,
+        Body::from(serde_json::to_string(&family_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "parent_family_id",
+                "error": "product family does not exist"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_accept_family_nested_under_its_descendant() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_phone_families(conn.as_ref()).await;
+
+    let family_info = json!({
+        "name": "Electronics",
+        "parentFamilyId": 3
+    });
+    let req = RequestFactory::put(
+        format!("{PRODUCT_FAMILIES_URL}/1").as_str(),
+        Body::from(serde_json::to_string(&family_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "parent_family_id",
+                "error": "product family cannot be nested under itself"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_retrieve_product_family_subtree() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_phone_families(conn.as_ref()).await;
+
+    let req = RequestFactory::get(format!("{PRODUCT_FAMILIES_URL}/1/subtree").as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "productFamilyId": 1,
+        "name": "Electronics",
+        "summary": "All Electronics",
+        "children": [
+            {
+                "productFamilyId": 2,
+                "name": "Phones",
+                "summary": null,
+                "children": [
+                    {
+                        "productFamilyId": 3,
+                        "name": "Smartphones",
+                        "summary": null,
+                        "children": []
+                    }
+                ]
+            },
+            {
+                "productFamilyId": 4,
+                "name": "Televisions",
+                "summary": null,
+                "children": []
+            }
+        ]
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_retrieve_product_family_breadcrumb() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_phone_families(conn.as_ref()).await;
+
+    let req = RequestFactory::get(format!("{PRODUCT_FAMILIES_URL}/3/breadcrumb").as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!([
+        {
+            "productFamilyId": 1,
+            "name": "Electronics"
+        },
+        {
+            "productFamilyId": 2,
+            "name": "Phones"
+        },
+        {
+            "productFamilyId": 3,
+            "name": "Smartphones"
+        }
+    ]);
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_retrieve_breadcrumb_of_unknown_family() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let req = RequestFactory::get(format!("{PRODUCT_FAMILIES_URL}/10/breadcrumb").as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+}
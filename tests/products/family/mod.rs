@@ -0,0 +1,5 @@
+pub mod create;
+pub mod detail;
+pub mod find_all;
+pub mod hierarchy;
+pub mod update;
@@ -0,0 +1,107 @@
+use crate::products::common::ProductFamilyFactory;
+use crate::{
+    common::{self},
+    products::common::PRODUCT_FAMILIES_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{DatabaseConnection, EntityTrait};
+use portal_schema::product_family;
+use serde_json::json;
+use tower::ServiceExt;
+
+fn url_to_update_product_family(product_family_id: i32) -> String {
+    format!("{PRODUCT_FAMILIES_URL}/{product_family_id}")
+}
+
+#[tokio::test]
+async fn it_not_accept_invalid_product_family_id() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let family_info = json!({
+        "name": "Phones"
+    });
+    let req = RequestFactory::put(
+        url_to_update_product_family(10).as_str(),
+        Body::from(serde_json::to_string(&family_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "product_family_id",
+                "error": "product family does not exist"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_accept_duplicate_product_family() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFamilyFactory::electronics(conn.as_ref())
+        .await
+        .unwrap();
+    ProductFamilyFactory::computers(conn.as_ref())
+        .await
+        .unwrap();
+
+    let family_info = json!({
+        "name": "electronics"
+    });
+    let req = RequestFactory::put(
+        url_to_update_product_family(2).as_str(),
+        Body::from(serde_json::to_string(&family_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_accepts_and_update_valid_product_family() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFamilyFactory::electronics(conn.as_ref())
+        .await
+        .unwrap();
+
+    let family_info = json!({
+        "name": "Home  Appliances",
+        "summary": "Kitchen and laundry"
+    });
+    let req = RequestFactory::put(
+        url_to_update_product_family(1).as_str(),
+        Body::from(serde_json::to_string(&family_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let family_saved = product_family::Entity::find_by_id(1)
+        .into_json()
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    let expected_family = json!({
+        "product_family_id": 1,
+        "name": "Home Appliances",
+        "summary": "Kitchen and laundry",
+        "signature": "7dc6bf24b40e17e8a14528cc848562f0",
+        "parent_family_id": null
+    });
+    assert_eq!(family_saved, expected_family);
+}
@@ -1,2 +1,4 @@
 pub mod common;
+pub mod family;
 pub mod product;
+pub mod stock;
@@ -0,0 +1,202 @@
+use crate::products::common::{ProductBarcodeFactory, ProductFactory};
+use crate::{
+    common::{self},
+    products::common::PRODUCTS_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{DatabaseConnection, EntityTrait};
+use portal_schema::{product, product_barcode};
+use serde_json::json;
+use tower::ServiceExt;
+
+fn url_to_product_sku(product_id: i32) -> String {
+    format!("{PRODUCTS_URL}/{product_id}/sku")
+}
+
+fn url_to_product_barcodes(product_id: i32) -> String {
+    format!("{PRODUCTS_URL}/{product_id}/barcodes")
+}
+
+fn url_to_product_by_barcode(code: &str) -> String {
+    format!("{PRODUCTS_URL}/by-barcode/{code}")
+}
+
+#[tokio::test]
+async fn it_set_normalised_sku_of_product() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+
+    let sku_info = json!({
+        "sku": " ipx-64 "
+    });
+    let req = RequestFactory::put(
+        url_to_product_sku(1).as_str(),
+        Body::from(serde_json::to_string(&sku_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let product_saved = product::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(product_saved.sku, Some("IPX-64".to_string()));
+}
+
+#[tokio::test]
+async fn it_not_accept_sku_of_another_product() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::macbook_pro(conn.as_ref()).await.unwrap();
+    ProductFactory::with_sku(conn.as_ref(), 1, "IPX-64")
+        .await
+        .unwrap();
+
+    let sku_info = json!({
+        "sku": "ipx-64"
+    });
+    let req = RequestFactory::put(
+        url_to_product_sku(2).as_str(),
+        Body::from(serde_json::to_string(&sku_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_not_accept_barcode_with_wrong_check_digit() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+
+    let barcode_info = json!({
+        "code": "4006381333932"
+    });
+    let req = RequestFactory::post(
+        url_to_product_barcodes(1).as_str(),
+        Body::from(serde_json::to_string(&barcode_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "code",
+                "error": "barcode check digit is not valid"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_accepts_and_add_valid_barcode() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+
+    let barcode_info = json!({
+        "code": "036000291452"
+    });
+    let req = RequestFactory::post(
+        url_to_product_barcodes(1).as_str(),
+        Body::from(serde_json::to_string(&barcode_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.into_value().await, json!({ "id": 1 }));
+
+    let barcode_saved = product_barcode::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(barcode_saved.code, "036000291452");
+    assert_eq!(barcode_saved.gtin, "00036000291452");
+    assert_eq!(barcode_saved.symbology, "upca");
+}
+
+#[tokio::test]
+async fn it_not_accept_barcode_already_used_in_catalog() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::macbook_pro(conn.as_ref()).await.unwrap();
+    ProductBarcodeFactory::ean13(conn.as_ref(), 1, "0036000291452")
+        .await
+        .unwrap();
+
+    let barcode_info = json!({
+        "code": "036000291452"
+    });
+    let req = RequestFactory::post(
+        url_to_product_barcodes(2).as_str(),
+        Body::from(serde_json::to_string(&barcode_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_find_product_by_scanned_barcode() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::with_sku(conn.as_ref(), 1, "IPX-64")
+        .await
+        .unwrap();
+    ProductBarcodeFactory::ean13(conn.as_ref(), 1, "4006381333931")
+        .await
+        .unwrap();
+
+    let req = RequestFactory::get(url_to_product_by_barcode("4006381333931").as_str());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "productId": 1,
+        "productName": "iPhone X",
+        "sku": "IPX-64",
+        "code": "4006381333931",
+        "symbology": "ean13",
+        "discontinued": false
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_find_product_by_unknown_barcode() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+
+    let req = RequestFactory::get(url_to_product_by_barcode("4006381333931").as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+}
@@ -154,7 +154,47 @@ async fn it_accepts_and_save_valid_product() {
         "purchasable": true,
         "saleable": true,
         "saleable_without_stock": false,
-        "signature": "882a0465d260983ada874710ef46aaef"
+        "signature": "882a0465d260983ada874710ef46aaef",
+        "discontinued_at": null,
+        "tracking_mode": "none",
+        "base_unit_id": null,
+        "purchase_unit_id": null,
+        "sale_unit_id": null,
+        "sku": null,
+        "costing_method": "moving_average"
     });
     assert_eq!(product_saved, expected_product);
 }
+
+#[tokio::test]
+async fn it_save_product_tracking_mode() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFamilyFactory::electronics(conn.as_ref())
+        .await
+        .unwrap();
+
+    let product_info = json!({
+        "name": "iPhone X",
+        "familyId": 1,
+        "purchasable": true,
+        "saleable": true,
+        "saleableWithoutStock": false,
+        "trackingMode": "serial"
+    });
+    let req = RequestFactory::post(
+        PRODUCTS_URL,
+        Body::from(serde_json::to_string(&product_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let product_saved = product::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(product_saved.tracking_mode, "serial");
+}
@@ -0,0 +1,56 @@
+use crate::products::common::ProductFactory;
+use crate::{
+    common::{self},
+    products::common::PRODUCTS_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::http::StatusCode;
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::DatabaseConnection;
+use serde_json::json;
+use tower::ServiceExt;
+
+fn url_to_retrieve_product(product_id: i32) -> String {
+    format!("{PRODUCTS_URL}/{product_id}")
+}
+
+#[tokio::test]
+async fn it_not_accept_invalid_product_id() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let req = RequestFactory::get(url_to_retrieve_product(10).as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn it_retrieve_product_details() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+
+    let req = RequestFactory::get(url_to_retrieve_product(1).as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "productId": 1,
+        "familyId": 1,
+        "familyName": "Electronics",
+        "name": "iPhone X",
+        "summary": "iPhone 10",
+        "purchasable": true,
+        "saleable": true,
+        "saleableWithoutStock": false,
+        "discontinued": false,
+        "trackingMode": "none"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
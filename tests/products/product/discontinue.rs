@@ -0,0 +1,76 @@
+use crate::products::common::ProductFactory;
+use crate::{
+    common::{self},
+    products::common::PRODUCTS_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{DatabaseConnection, EntityTrait};
+use portal_schema::product;
+use serde_json::json;
+use tower::ServiceExt;
+
+fn url_to_discontinue_product(product_id: i32) -> String {
+    format!("{PRODUCTS_URL}/{product_id}/discontinue")
+}
+
+#[tokio::test]
+async fn it_not_accept_invalid_product_id() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let req = RequestFactory::post(url_to_discontinue_product(10).as_str(), Body::empty());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "product_id",
+                "error": "product does not exist"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_discontinue_product_twice() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::discontinue(conn.as_ref(), 1).await.unwrap();
+
+    let req = RequestFactory::post(url_to_discontinue_product(1).as_str(), Body::empty());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_discontinue_product_keeping_the_record() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+
+    let req = RequestFactory::post(url_to_discontinue_product(1).as_str(), Body::empty());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let product_saved = product::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(product_saved.discontinued_at.is_some());
+    assert_eq!(product_saved.saleable, 0);
+    assert_eq!(product_saved.saleable_without_stock, 0);
+}
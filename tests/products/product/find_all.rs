@@ -0,0 +1,169 @@
+use crate::products::common::{ProductFactory, ProductFamilyFactory};
+use crate::{
+    common::{self},
+    products::common::PRODUCTS_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::http::StatusCode;
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::DatabaseConnection;
+use serde_json::json;
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn it_retrieve_empty_paginate_products() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let req = RequestFactory::get(format!("{PRODUCTS_URL}?page=1&page_size=10").as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "data": [],
+        "total": 0,
+        "page": 0,
+        "pageSize": 10,
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_retrieve_paginate_products() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::macbook_pro(conn.as_ref()).await.unwrap();
+
+    let req = RequestFactory::get(format!("{PRODUCTS_URL}?page=1&page_size=10").as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "data": [
+            {
+                "productId": 2,
+                "familyId": 2,
+                "familyName": "Computers",
+                "name": "MacBook Pro",
+                "summary": null,
+                "purchasable": true,
+                "saleable": false,
+                "saleableWithoutStock": false,
+                "discontinued": false,
+                "trackingMode": "none"
+            },
+            {
+                "productId": 1,
+                "familyId": 1,
+                "familyName": "Electronics",
+                "name": "iPhone X",
+                "summary": "iPhone 10",
+                "purchasable": true,
+                "saleable": true,
+                "saleableWithoutStock": false,
+                "discontinued": false,
+                "trackingMode": "none"
+            }
+        ],
+        "total": 2,
+        "page": 1,
+        "pageSize": 10
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_filter_products_by_family_and_flags() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::macbook_pro(conn.as_ref()).await.unwrap();
+
+    let req = RequestFactory::get(
+        format!("{PRODUCTS_URL}?page=1&page_size=10&familyId=1&saleable=true&name=iPhone").as_str(),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "data": [
+            {
+                "productId": 1,
+                "familyId": 1,
+                "familyName": "Electronics",
+                "name": "iPhone X",
+                "summary": "iPhone 10",
+                "purchasable": true,
+                "saleable": true,
+                "saleableWithoutStock": false,
+                "discontinued": false,
+                "trackingMode": "none"
+            }
+        ],
+        "total": 1,
+        "page": 1,
+        "pageSize": 10
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_filter_products_not_for_sale() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::macbook_pro(conn.as_ref()).await.unwrap();
+
+    let req =
+        RequestFactory::get(format!("{PRODUCTS_URL}?page=1&page_size=10&saleable=false").as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body = res.into_value().await;
+    assert_eq!(body["total"], json!(1));
+    assert_eq!(body["data"][0]["name"], json!("MacBook Pro"));
+}
+
+#[tokio::test]
+async fn it_filter_products_including_family_descendants() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::macbook_pro(conn.as_ref()).await.unwrap();
+    ProductFamilyFactory::child_of(conn.as_ref(), "Laptops", 2)
+        .await
+        .unwrap();
+    ProductFamilyFactory::child_of(conn.as_ref(), "Gaming Laptops", 3)
+        .await
+        .unwrap();
+    ProductFactory::in_family(conn.as_ref(), "ROG Strix", 4)
+        .await
+        .unwrap();
+
+    let req = RequestFactory::get(
+        format!("{PRODUCTS_URL}?page=1&page_size=10&familyId=2&includeDescendants=true").as_str(),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body = res.into_value().await;
+    assert_eq!(body["total"], json!(2));
+    assert_eq!(body["data"][0]["name"], json!("ROG Strix"));
+    assert_eq!(body["data"][0]["familyName"], json!("Gaming Laptops"));
+    assert_eq!(body["data"][1]["name"], json!("MacBook Pro"));
+}
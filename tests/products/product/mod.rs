@@ -1 +1,9 @@
+pub mod barcodes;
 pub mod create;
+pub mod detail;
+pub mod discontinue;
+pub mod find_all;
+pub mod prices;
+pub mod units;
+pub mod update;
+pub mod variants;
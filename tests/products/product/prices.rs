@@ -0,0 +1,258 @@
+use crate::products::common::{PriceListFactory, ProductFactory, ProductPriceFactory};
+use crate::{
+    common::{self},
+    products::common::PRODUCTS_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::sqlx::types::chrono::NaiveDate;
+use lumx_sea_orm::sea_orm::{DatabaseConnection, EntityTrait};
+use portal_schema::{price_list, product_price};
+use serde_json::json;
+use tower::ServiceExt;
+
+const PRICE_LISTS_URL: &str = "/api/v1/price-lists";
+
+fn url_to_product_prices(price_list_id: i32) -> String {
+    format!("{PRICE_LISTS_URL}/{price_list_id}/prices")
+}
+
+fn url_to_resolve_product_price(product_id: i32, price_list_id: i32, date: &str) -> String {
+    format!("{PRODUCTS_URL}/{product_id}/price?priceListId={price_list_id}&date={date}")
+}
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[tokio::test]
+async fn it_accepts_and_create_valid_price_list() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    let price_list_info = json!({
+        "name": "Retail 2025",
+        "kind": "retail",
+        "currency": "eur",
+        "validFrom": "2025-01-01",
+        "validTo": "2025-12-31"
+    });
+    let req = RequestFactory::post(
+        PRICE_LISTS_URL,
+        Body::from(serde_json::to_string(&price_list_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.into_value().await, json!({ "id": 1 }));
+
+    let price_list_saved = price_list::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(price_list_saved.currency, "EUR");
+    assert_eq!(price_list_saved.kind, "retail");
+}
+
+#[tokio::test]
+async fn it_not_accept_price_list_with_invalid_currency() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let price_list_info = json!({
+        "name": "Retail 2025",
+        "kind": "retail",
+        "currency": "EURO",
+        "validFrom": "2025-01-01"
+    });
+    let req = RequestFactory::post(
+        PRICE_LISTS_URL,
+        Body::from(serde_json::to_string(&price_list_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "currency",
+                "error": "currency must be a three letter ISO 4217 code"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_accepts_and_store_product_price_in_minor_units() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    PriceListFactory::retail(conn.as_ref(), "Retail", "EUR", date(2025, 1, 1), None)
+        .await
+        .unwrap();
+
+    let price_info = json!({
+        "productId": 1,
+        "amount": "999.99",
+        "validFrom": "2025-01-01"
+    });
+    let req = RequestFactory::post(
+        url_to_product_prices(1).as_str(),
+        Body::from(serde_json::to_string(&price_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.into_value().await, json!({ "id": 1 }));
+
+    let price_saved = product_price::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(price_saved.amount, 9_999_900);
+}
+
+#[tokio::test]
+async fn it_not_accept_negative_product_price() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    PriceListFactory::retail(conn.as_ref(), "Retail", "EUR", date(2025, 1, 1), None)
+        .await
+        .unwrap();
+
+    let price_info = json!({
+        "productId": 1,
+        "amount": "-10.00",
+        "validFrom": "2025-01-01"
+    });
+    let req = RequestFactory::post(
+        url_to_product_prices(1).as_str(),
+        Body::from(serde_json::to_string(&price_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "amount",
+                "error": "amount must be a positive decimal with up to four decimal places"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_accept_overlapping_product_price() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    PriceListFactory::retail(conn.as_ref(), "Retail", "EUR", date(2025, 1, 1), None)
+        .await
+        .unwrap();
+    ProductPriceFactory::between(
+        conn.as_ref(),
+        1,
+        1,
+        125_000,
+        date(2025, 1, 1),
+        Some(date(2025, 6, 30)),
+    )
+    .await
+    .unwrap();
+
+    let price_info = json!({
+        "productId": 1,
+        "amount": "11.00",
+        "validFrom": "2025-06-01"
+    });
+    let req = RequestFactory::post(
+        url_to_product_prices(1).as_str(),
+        Body::from(serde_json::to_string(&price_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_resolve_product_price_valid_on_date() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    PriceListFactory::retail(conn.as_ref(), "Retail", "EUR", date(2025, 1, 1), None)
+        .await
+        .unwrap();
+    ProductPriceFactory::between(
+        conn.as_ref(),
+        1,
+        1,
+        100_000,
+        date(2025, 1, 1),
+        Some(date(2025, 6, 30)),
+    )
+    .await
+    .unwrap();
+    ProductPriceFactory::between(conn.as_ref(), 1, 1, 125_000, date(2025, 7, 1), None)
+        .await
+        .unwrap();
+
+    let req = RequestFactory::get(url_to_resolve_product_price(1, 1, "2025-08-15").as_str());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "productId": 1,
+        "priceListId": 1,
+        "priceListName": "Retail",
+        "kind": "retail",
+        "currency": "EUR",
+        "amount": "12.50",
+        "validFrom": "2025-07-01",
+        "validTo": null
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_resolve_price_outside_price_list_validity() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    PriceListFactory::retail(
+        conn.as_ref(),
+        "Retail",
+        "EUR",
+        date(2025, 1, 1),
+        Some(date(2025, 12, 31)),
+    )
+    .await
+    .unwrap();
+    ProductPriceFactory::between(conn.as_ref(), 1, 1, 100_000, date(2025, 1, 1), None)
+        .await
+        .unwrap();
+
+    let req = RequestFactory::get(url_to_resolve_product_price(1, 1, "2026-01-15").as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+}
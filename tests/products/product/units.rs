@@ -0,0 +1,310 @@
+use crate::products::common::{ProductFactory, UnitOfMeasureFactory};
+use crate::{
+    common::{self},
+    products::common::PRODUCTS_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{DatabaseConnection, EntityTrait};
+use portal_schema::{product, stock_movement, unit_of_measure};
+use serde_json::json;
+use tower::ServiceExt;
+
+const UNITS_OF_MEASURE_URL: &str = "/api/v1/units-of-measure";
+
+fn url_to_unit_conversions(unit_of_measure_id: i32) -> String {
+    format!("{UNITS_OF_MEASURE_URL}/{unit_of_measure_id}/conversions")
+}
+
+fn url_to_product_units(product_id: i32) -> String {
+    format!("{PRODUCTS_URL}/{product_id}/units")
+}
+
+fn url_to_record_stock_movement(product_id: i32) -> String {
+    format!("{PRODUCTS_URL}/{product_id}/stock-movements")
+}
+
+#[tokio::test]
+async fn it_accepts_and_create_valid_unit_of_measure() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    let unit_info = json!({
+        "code": " BOX ",
+        "name": "Box"
+    });
+    let req = RequestFactory::post(
+        UNITS_OF_MEASURE_URL,
+        Body::from(serde_json::to_string(&unit_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.into_value().await, json!({ "id": 1 }));
+
+    let unit_saved = unit_of_measure::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(unit_saved.code, "box");
+}
+
+#[tokio::test]
+async fn it_not_accept_duplicated_unit_code() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    UnitOfMeasureFactory::code(conn.as_ref(), "box", "Box")
+        .await
+        .unwrap();
+
+    let unit_info = json!({
+        "code": "Box",
+        "name": "Carton box"
+    });
+    let req = RequestFactory::post(
+        UNITS_OF_MEASURE_URL,
+        Body::from(serde_json::to_string(&unit_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_not_accept_conversion_into_same_unit() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    UnitOfMeasureFactory::code(conn.as_ref(), "box", "Box")
+        .await
+        .unwrap();
+
+    let conversion_info = json!({
+        "toUnitId": 1,
+        "factor": 12
+    });
+    let req = RequestFactory::post(
+        url_to_unit_conversions(1).as_str(),
+        Body::from(serde_json::to_string(&conversion_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "to_unit_id",
+                "error": "unit cannot be converted into itself"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_find_units_of_measure_with_conversions() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    UnitOfMeasureFactory::code(conn.as_ref(), "ea", "Each")
+        .await
+        .unwrap();
+    UnitOfMeasureFactory::code(conn.as_ref(), "box", "Box")
+        .await
+        .unwrap();
+    UnitOfMeasureFactory::conversion(conn.as_ref(), 2, 1, 12)
+        .await
+        .unwrap();
+
+    let req = RequestFactory::get(UNITS_OF_MEASURE_URL);
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!([
+        {
+            "unitOfMeasureId": 2,
+            "code": "box",
+            "name": "Box",
+            "conversions": [
+                {
+                    "unitConversionId": 1,
+                    "toUnitId": 1,
+                    "factor": 12
+                }
+            ]
+        },
+        {
+            "unitOfMeasureId": 1,
+            "code": "ea",
+            "name": "Each",
+            "conversions": []
+        }
+    ]);
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_accept_purchase_unit_without_conversion() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    UnitOfMeasureFactory::code(conn.as_ref(), "ea", "Each")
+        .await
+        .unwrap();
+    UnitOfMeasureFactory::code(conn.as_ref(), "box", "Box")
+        .await
+        .unwrap();
+
+    let units_info = json!({
+        "baseUnitId": 1,
+        "purchaseUnitId": 2
+    });
+    let req = RequestFactory::put(
+        url_to_product_units(1).as_str(),
+        Body::from(serde_json::to_string(&units_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "purchase_unit_id",
+                "error": "unit cannot be converted to the base unit of the product"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_set_units_of_product() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    UnitOfMeasureFactory::code(conn.as_ref(), "ea", "Each")
+        .await
+        .unwrap();
+    UnitOfMeasureFactory::code(conn.as_ref(), "box", "Box")
+        .await
+        .unwrap();
+    UnitOfMeasureFactory::conversion(conn.as_ref(), 2, 1, 12)
+        .await
+        .unwrap();
+
+    let units_info = json!({
+        "baseUnitId": 1,
+        "purchaseUnitId": 2,
+        "saleUnitId": 1
+    });
+    let req = RequestFactory::put(
+        url_to_product_units(1).as_str(),
+        Body::from(serde_json::to_string(&units_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let product_saved = product::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(product_saved.base_unit_id, Some(1));
+    assert_eq!(product_saved.purchase_unit_id, Some(2));
+    assert_eq!(product_saved.sale_unit_id, Some(1));
+}
+
+#[tokio::test]
+async fn it_record_stock_movement_in_base_units() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    UnitOfMeasureFactory::code(conn.as_ref(), "ea", "Each")
+        .await
+        .unwrap();
+    UnitOfMeasureFactory::code(conn.as_ref(), "box", "Box")
+        .await
+        .unwrap();
+    UnitOfMeasureFactory::conversion(conn.as_ref(), 2, 1, 12)
+        .await
+        .unwrap();
+    ProductFactory::measure_in(conn.as_ref(), 1, 1)
+        .await
+        .unwrap();
+
+    let movement_info = json!({
+        "kind": "receipt",
+        "quantity": 2,
+        "unitId": 2
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(1).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let movement_saved = stock_movement::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(movement_saved.quantity, 24);
+}
+
+#[tokio::test]
+async fn it_not_accept_stock_movement_in_unconvertible_unit() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    UnitOfMeasureFactory::code(conn.as_ref(), "ea", "Each")
+        .await
+        .unwrap();
+    UnitOfMeasureFactory::code(conn.as_ref(), "box", "Box")
+        .await
+        .unwrap();
+    ProductFactory::measure_in(conn.as_ref(), 1, 1)
+        .await
+        .unwrap();
+
+    let movement_info = json!({
+        "kind": "receipt",
+        "quantity": 2,
+        "unitId": 2
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(1).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "unit_id",
+                "error": "unit cannot be converted to the base unit of the product"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
@@ -0,0 +1,245 @@
+use crate::products::common::{
+    ProductComponentFactory, ProductFactory, ProductFamilyFactory, StockFactory,
+};
+use crate::{
+    common::{self},
+    products::common::PRODUCTS_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{DatabaseConnection, EntityTrait};
+use portal_schema::product;
+use serde_json::json;
+use tower::ServiceExt;
+
+fn url_to_update_product(product_id: i32) -> String {
+    format!("{PRODUCTS_URL}/{product_id}")
+}
+
+#[tokio::test]
+async fn it_not_accept_invalid_product_id() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFamilyFactory::electronics(conn.as_ref())
+        .await
+        .unwrap();
+
+    let product_info = json!({
+        "name": "iPhone 11",
+        "familyId": 1,
+        "purchasable": true,
+        "saleable": true,
+        "saleableWithoutStock": false
+    });
+    let req = RequestFactory::put(
+        url_to_update_product(10).as_str(),
+        Body::from(serde_json::to_string(&product_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "product_id",
+                "error": "product does not exist"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_accept_invalid_family_type() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+
+    let product_info = json!({
+        "name": "iPhone X",
+        "familyId": 10,
+        "purchasable": true,
+        "saleable": true,
+        "saleableWithoutStock": false
+    });
+    let req = RequestFactory::put(
+        url_to_update_product(1).as_str(),
+        Body::from(serde_json::to_string(&product_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "product_family_id",
+                "error": "product family does not exist"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_accept_duplicate_product() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::macbook_pro(conn.as_ref()).await.unwrap();
+
+    let product_info = json!({
+        "name": "IPHONE X",
+        "familyId": 2,
+        "purchasable": true,
+        "saleable": false,
+        "saleableWithoutStock": false
+    });
+    let req = RequestFactory::put(
+        url_to_update_product(2).as_str(),
+        Body::from(serde_json::to_string(&product_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_accepts_and_update_valid_product() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+
+    let product_info = json!({
+        "name": "iPhone XS",
+        "summary": "iPhone 10 S",
+        "familyId": 1,
+        "purchasable": false,
+        "saleable": true,
+        "saleableWithoutStock": true
+    });
+    let req = RequestFactory::put(
+        url_to_update_product(1).as_str(),
+        Body::from(serde_json::to_string(&product_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let product_saved = product::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(product_saved.name, "iPhone XS");
+    assert_eq!(product_saved.summary, Some("iPhone 10 S".to_string()));
+    assert_eq!(product_saved.purchasable, 0);
+    assert_eq!(product_saved.saleable_without_stock, 1);
+    assert_eq!(product_saved.signature, "25d150cd46c7b25c83de0054f9173488");
+}
+
+#[tokio::test]
+async fn it_not_put_discontinued_product_back_on_sale() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::discontinue(conn.as_ref(), 1).await.unwrap();
+
+    let product_info = json!({
+        "name": "iPhone X",
+        "familyId": 1,
+        "purchasable": true,
+        "saleable": true,
+        "saleableWithoutStock": false
+    });
+    let req = RequestFactory::put(
+        url_to_update_product(1).as_str(),
+        Body::from(serde_json::to_string(&product_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "saleable",
+                "error": "discontinued product cannot be sold"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_change_tracking_mode_of_stocked_product() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    StockFactory::receipt(conn.as_ref(), 1, 5).await.unwrap();
+
+    let product_info = json!({
+        "name": "iPhone X",
+        "familyId": 1,
+        "purchasable": true,
+        "saleable": true,
+        "saleableWithoutStock": false,
+        "trackingMode": "serial"
+    });
+    let req = RequestFactory::put(
+        url_to_update_product(1).as_str(),
+        Body::from(serde_json::to_string(&product_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+
+    let product_saved = product::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(product_saved.tracking_mode, "none");
+}
+
+#[tokio::test]
+async fn it_not_change_tracking_mode_of_kit_component() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::macbook_pro(conn.as_ref()).await.unwrap();
+    ProductComponentFactory::of(conn.as_ref(), 2, 1, 1)
+        .await
+        .unwrap();
+
+    let product_info = json!({
+        "name": "iPhone X",
+        "familyId": 1,
+        "purchasable": true,
+        "saleable": true,
+        "saleableWithoutStock": false,
+        "trackingMode": "lot"
+    });
+    let req = RequestFactory::put(
+        url_to_update_product(1).as_str(),
+        Body::from(serde_json::to_string(&product_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
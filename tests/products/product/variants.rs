@@ -0,0 +1,267 @@
+use crate::products::common::{
+    ProductAttributeFactory, ProductFactory, ProductFamilyFactory, ProductVariantFactory,
+};
+use crate::{
+    common::{self},
+    products::common::PRODUCTS_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{DatabaseConnection, EntityTrait, PaginatorTrait};
+use portal_schema::{product_attribute_value, product_variant};
+use serde_json::json;
+use tower::ServiceExt;
+
+const PRODUCT_ATTRIBUTES_URL: &str = "/api/v1/product-attributes";
+
+fn url_to_product_variants(product_id: i32) -> String {
+    format!("{PRODUCTS_URL}/{product_id}/variants")
+}
+
+fn url_to_variant_matrix(product_id: i32) -> String {
+    format!("{PRODUCTS_URL}/{product_id}/variants/matrix")
+}
+
+async fn t_shirt_with_attributes(conn: &DatabaseConnection) {
+    let family = ProductFamilyFactory::electronics(conn).await.unwrap();
+    ProductFactory::in_family(conn, "T-shirt", family.product_family_id.unwrap())
+        .await
+        .unwrap();
+    ProductAttributeFactory::with_values(conn, "Colour", &["Red", "Blue"])
+        .await
+        .unwrap();
+    ProductAttributeFactory::with_values(conn, "Size", &["S", "M"])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn it_accepts_and_create_attribute_with_values() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    let attribute_info = json!({
+        "name": "Colour",
+        "values": ["Red", " Blue "]
+    });
+    let req = RequestFactory::post(
+        PRODUCT_ATTRIBUTES_URL,
+        Body::from(serde_json::to_string(&attribute_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.into_value().await, json!({ "id": 1 }));
+
+    let values_saved = product_attribute_value::Entity::find()
+        .all(conn.as_ref())
+        .await
+        .unwrap();
+    assert_eq!(
+        values_saved
+            .iter()
+            .map(|value| value.value.as_str())
+            .collect::<Vec<&str>>(),
+        vec!["Red", "Blue"]
+    );
+}
+
+#[tokio::test]
+async fn it_not_accept_repeated_attribute_values() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let attribute_info = json!({
+        "name": "Colour",
+        "values": ["Red", " red "]
+    });
+    let req = RequestFactory::post(
+        PRODUCT_ATTRIBUTES_URL,
+        Body::from(serde_json::to_string(&attribute_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "values",
+                "error": "attribute values must be unique"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_accept_variant_with_two_values_of_same_attribute() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    t_shirt_with_attributes(conn.as_ref()).await;
+
+    let variant_info = json!({
+        "attributeValueIds": [1, 2]
+    });
+    let req = RequestFactory::post(
+        url_to_product_variants(1).as_str(),
+        Body::from(serde_json::to_string(&variant_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "attribute_value_ids",
+                "error": "variant cannot have two values of the same attribute"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_create_variant_named_by_attribute_values() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    t_shirt_with_attributes(conn.as_ref()).await;
+
+    let variant_info = json!({
+        "attributeValueIds": [4, 1],
+        "sku": "ts-red-m"
+    });
+    let req = RequestFactory::post(
+        url_to_product_variants(1).as_str(),
+        Body::from(serde_json::to_string(&variant_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.into_value().await, json!({ "id": 1 }));
+
+    let variant_saved = product_variant::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(variant_saved.name, "T-shirt / Red / M");
+    assert_eq!(variant_saved.sku, Some("TS-RED-M".to_string()));
+}
+
+#[tokio::test]
+async fn it_not_accept_variant_already_defined() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    t_shirt_with_attributes(conn.as_ref()).await;
+    ProductVariantFactory::of(
+        conn.as_ref(),
+        1,
+        "T-shirt",
+        &[(1, "Colour", "Red"), (4, "Size", "M")],
+    )
+    .await
+    .unwrap();
+
+    let variant_info = json!({
+        "attributeValueIds": [1, 4]
+    });
+    let req = RequestFactory::post(
+        url_to_product_variants(1).as_str(),
+        Body::from(serde_json::to_string(&variant_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_generate_missing_variants_of_matrix() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    t_shirt_with_attributes(conn.as_ref()).await;
+    ProductVariantFactory::of(
+        conn.as_ref(),
+        1,
+        "T-shirt",
+        &[(1, "Colour", "Red"), (4, "Size", "M")],
+    )
+    .await
+    .unwrap();
+
+    let matrix_info = json!({
+        "attributeValueIds": [1, 2, 3, 4]
+    });
+    let req = RequestFactory::post(
+        url_to_variant_matrix(1).as_str(),
+        Body::from(serde_json::to_string(&matrix_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(
+        res.into_value().await,
+        json!([{ "id": 2 }, { "id": 3 }, { "id": 4 }])
+    );
+
+    let variants_count = product_variant::Entity::find()
+        .count(conn.as_ref())
+        .await
+        .unwrap();
+    assert_eq!(variants_count, 4);
+}
+
+#[tokio::test]
+async fn it_find_variants_of_product() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    t_shirt_with_attributes(conn.as_ref()).await;
+    ProductVariantFactory::of(
+        conn.as_ref(),
+        1,
+        "T-shirt",
+        &[(2, "Colour", "Blue"), (3, "Size", "S")],
+    )
+    .await
+    .unwrap();
+
+    let req = RequestFactory::get(url_to_product_variants(1).as_str());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!([
+        {
+            "productVariantId": 1,
+            "productId": 1,
+            "name": "T-shirt / Blue / S",
+            "sku": null,
+            "values": [
+                {
+                    "attributeValueId": 2,
+                    "attributeId": 1,
+                    "attributeName": "Colour",
+                    "value": "Blue"
+                },
+                {
+                    "attributeValueId": 3,
+                    "attributeId": 2,
+                    "attributeName": "Size",
+                    "value": "S"
+                }
+            ]
+        }
+    ]);
+    assert_eq!(res.into_value().await, expected_body);
+}
@@ -0,0 +1,277 @@
+use crate::products::common::{ProductFactory, StockFactory, StockLotFactory};
+use crate::{
+    common::{self},
+    products::common::PRODUCTS_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::sqlx::types::chrono::{Duration, NaiveDate, Utc};
+use lumx_sea_orm::sea_orm::{DatabaseConnection, EntityTrait};
+use portal_schema::stock_lot;
+use serde_json::json;
+use tower::ServiceExt;
+
+const EXPIRING_LOTS_URL: &str = "/api/v1/stock-lots/expiring";
+
+fn url_to_record_stock_movement(product_id: i32) -> String {
+    format!("{PRODUCTS_URL}/{product_id}/stock-movements")
+}
+
+fn days_from_today(days: i64) -> NaiveDate {
+    Utc::now().date_naive() + Duration::days(days)
+}
+
+async fn prepare_lot_tracked_product(conn: &DatabaseConnection) {
+    ProductFactory::iphone_x(conn).await.unwrap();
+    ProductFactory::track(conn, 1, "lot").await.unwrap();
+}
+
+#[tokio::test]
+async fn it_record_expiry_date_of_received_lot() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    prepare_lot_tracked_product(conn.as_ref()).await;
+
+    let movement_info = json!({
+        "kind": "receipt",
+        "quantity": 12,
+        "lotNumber": "L-1",
+        "expiresOn": "2031-05-20"
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(1).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let stock_lot_saved = stock_lot::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stock_lot_saved.lot_number, "L-1");
+    assert_eq!(
+        stock_lot_saved.expires_on,
+        NaiveDate::from_ymd_opt(2031, 5, 20).unwrap()
+    );
+}
+
+#[tokio::test]
+async fn it_not_accept_another_expiry_date_for_known_lot() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    prepare_lot_tracked_product(conn.as_ref()).await;
+    StockLotFactory::expiring_on(conn.as_ref(), 1, "L-1", days_from_today(30))
+        .await
+        .unwrap();
+
+    let movement_info = json!({
+        "kind": "receipt",
+        "quantity": 12,
+        "lotNumber": "L-1",
+        "expiresOn": "2031-05-20"
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(1).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_not_issue_expired_lot_without_expiry_override() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    prepare_lot_tracked_product(conn.as_ref()).await;
+    StockLotFactory::expiring_on(conn.as_ref(), 1, "L-1", days_from_today(-2))
+        .await
+        .unwrap();
+    StockFactory::tracked_receipt(conn.as_ref(), 1, Some("L-1"), None, 6)
+        .await
+        .unwrap();
+
+    let movement_info = json!({
+        "kind": "issue",
+        "quantity": 2,
+        "lotNumber": "L-1",
+        "reason": "customer asked"
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(1).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "override_expiry",
+                "error": "expired lot can only be issued with an expiry override"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_override_expiry_without_reason() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    prepare_lot_tracked_product(conn.as_ref()).await;
+    StockLotFactory::expiring_on(conn.as_ref(), 1, "L-1", days_from_today(-2))
+        .await
+        .unwrap();
+    StockFactory::tracked_receipt(conn.as_ref(), 1, Some("L-1"), None, 6)
+        .await
+        .unwrap();
+
+    let movement_info = json!({
+        "kind": "issue",
+        "quantity": 2,
+        "lotNumber": "L-1",
+        "overrideExpiry": true
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(1).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "reason",
+                "error": "expiry override requires a reason"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_issue_expired_lot_with_expiry_override() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    prepare_lot_tracked_product(conn.as_ref()).await;
+    StockLotFactory::expiring_on(conn.as_ref(), 1, "L-1", days_from_today(-2))
+        .await
+        .unwrap();
+    StockFactory::tracked_receipt(conn.as_ref(), 1, Some("L-1"), None, 6)
+        .await
+        .unwrap();
+
+    let movement_info = json!({
+        "kind": "issue",
+        "quantity": 6,
+        "lotNumber": "L-1",
+        "overrideExpiry": true,
+        "reason": "disposed as expired"
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(1).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn it_report_lots_expiring_within_days() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    prepare_lot_tracked_product(conn.as_ref()).await;
+    StockLotFactory::expiring_on(conn.as_ref(), 1, "L-1", days_from_today(20))
+        .await
+        .unwrap();
+    StockLotFactory::expiring_on(conn.as_ref(), 1, "L-2", days_from_today(5))
+        .await
+        .unwrap();
+    StockLotFactory::expiring_on(conn.as_ref(), 1, "L-3", days_from_today(90))
+        .await
+        .unwrap();
+    StockLotFactory::expiring_on(conn.as_ref(), 1, "L-4", days_from_today(3))
+        .await
+        .unwrap();
+    StockFactory::tracked_receipt(conn.as_ref(), 1, Some("L-1"), None, 4)
+        .await
+        .unwrap();
+    StockFactory::tracked_receipt(conn.as_ref(), 1, Some("L-2"), None, 7)
+        .await
+        .unwrap();
+    StockFactory::tracked_receipt(conn.as_ref(), 1, Some("L-3"), None, 9)
+        .await
+        .unwrap();
+
+    let req = RequestFactory::get(format!("{EXPIRING_LOTS_URL}?withinDays=30").as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body = res.into_value().await;
+    assert_eq!(body.as_array().unwrap().len(), 2);
+    assert_eq!(body[0]["lotNumber"], json!("L-2"));
+    assert_eq!(body[0]["onHand"], json!(7));
+    assert_eq!(body[0]["expired"], json!(false));
+    assert_eq!(body[1]["lotNumber"], json!("L-1"));
+    assert_eq!(body[1]["onHand"], json!(4));
+}
+
+#[tokio::test]
+async fn it_plan_first_expired_first_out_pick() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    prepare_lot_tracked_product(conn.as_ref()).await;
+    StockLotFactory::expiring_on(conn.as_ref(), 1, "L-1", days_from_today(-1))
+        .await
+        .unwrap();
+    StockLotFactory::expiring_on(conn.as_ref(), 1, "L-2", days_from_today(40))
+        .await
+        .unwrap();
+    StockLotFactory::expiring_on(conn.as_ref(), 1, "L-3", days_from_today(10))
+        .await
+        .unwrap();
+    StockFactory::tracked_receipt(conn.as_ref(), 1, Some("L-1"), None, 5)
+        .await
+        .unwrap();
+    StockFactory::tracked_receipt(conn.as_ref(), 1, Some("L-2"), None, 5)
+        .await
+        .unwrap();
+    StockFactory::tracked_receipt(conn.as_ref(), 1, Some("L-3"), None, 3)
+        .await
+        .unwrap();
+
+    let req = RequestFactory::get(format!("{PRODUCTS_URL}/1/fefo-pick?quantity=6").as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body = res.into_value().await;
+    assert_eq!(body["shortage"], json!(0));
+    assert_eq!(body["picks"][0]["lotNumber"], json!("L-3"));
+    assert_eq!(body["picks"][0]["quantity"], json!(3));
+    assert_eq!(body["picks"][1]["lotNumber"], json!("L-2"));
+    assert_eq!(body["picks"][1]["quantity"], json!(3));
+}
@@ -0,0 +1,38 @@
+use crate::products::common::{ProductFactory, StockFactory};
+use crate::{
+    common::{self},
+    products::common::PRODUCTS_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::http::StatusCode;
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::DatabaseConnection;
+use serde_json::json;
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn it_retrieve_paginate_stock_movements() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    StockFactory::receipt(conn.as_ref(), 1, 10).await.unwrap();
+    StockFactory::receipt(conn.as_ref(), 1, 5).await.unwrap();
+
+    let req = RequestFactory::get(
+        format!("{PRODUCTS_URL}/1/stock-movements?page=1&page_size=10").as_str(),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body = res.into_value().await;
+    assert_eq!(body["total"], json!(2));
+    assert_eq!(body["data"][0]["stockMovementId"], json!(2));
+    assert_eq!(body["data"][0]["kind"], json!("receipt"));
+    assert_eq!(body["data"][0]["quantity"], json!(5));
+    assert_eq!(body["data"][1]["quantity"], json!(10));
+}
@@ -0,0 +1,442 @@
+use crate::products::common::{ProductComponentFactory, ProductFactory, StockFactory};
+use crate::{
+    common::{self},
+    products::common::PRODUCTS_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{DatabaseConnection, EntityTrait};
+use portal_schema::{product_component, stock_movement};
+use serde_json::json;
+use tower::ServiceExt;
+
+fn url_to_add_kit_component(product_id: i32) -> String {
+    format!("{PRODUCTS_URL}/{product_id}/components")
+}
+
+fn url_to_find_kit_availability(product_id: i32) -> String {
+    format!("{PRODUCTS_URL}/{product_id}/kit-availability")
+}
+
+fn url_to_assemble_kit(product_id: i32) -> String {
+    format!("{PRODUCTS_URL}/{product_id}/kit-assemblies")
+}
+
+fn url_to_record_stock_movement(product_id: i32) -> String {
+    format!("{PRODUCTS_URL}/{product_id}/stock-movements")
+}
+
+async fn phone_bundle(conn: &DatabaseConnection) {
+    ProductFactory::iphone_x(conn).await.unwrap();
+    ProductFactory::in_family(conn, "Charger", 1).await.unwrap();
+    ProductFactory::in_family(conn, "iPhone X Bundle", 1)
+        .await
+        .unwrap();
+}
+
+async fn phone_bundle_with_components(conn: &DatabaseConnection) {
+    phone_bundle(conn).await;
+    ProductComponentFactory::of(conn, 3, 1, 1).await.unwrap();
+    ProductComponentFactory::of(conn, 3, 2, 2).await.unwrap();
+}
+
+#[tokio::test]
+async fn it_add_kit_component() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    phone_bundle(conn.as_ref()).await;
+
+    let component_info = json!({
+        "componentId": 2,
+        "quantity": 2
+    });
+    let req = RequestFactory::post(
+        url_to_add_kit_component(3).as_str(),
+        Body::from(serde_json::to_string(&component_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.into_value().await, json!({ "id": 1 }));
+
+    let component_saved = product_component::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(component_saved.kit_product_id, 3);
+    assert_eq!(component_saved.component_product_id, 2);
+    assert_eq!(component_saved.quantity, 2);
+}
+
+#[tokio::test]
+async fn it_find_kit_components() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    phone_bundle_with_components(conn.as_ref()).await;
+
+    let req = RequestFactory::get(url_to_add_kit_component(3).as_str());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!([
+        {
+            "productComponentId": 1,
+            "componentId": 1,
+            "quantity": 1
+        },
+        {
+            "productComponentId": 2,
+            "componentId": 2,
+            "quantity": 2
+        }
+    ]);
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_add_kit_into_its_own_component() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    phone_bundle_with_components(conn.as_ref()).await;
+
+    let component_info = json!({
+        "componentId": 3,
+        "quantity": 1
+    });
+    let req = RequestFactory::post(
+        url_to_add_kit_component(2).as_str(),
+        Body::from(serde_json::to_string(&component_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "component_id",
+                "error": "kit cannot contain itself"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_add_same_kit_component_twice() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    phone_bundle_with_components(conn.as_ref()).await;
+
+    let component_info = json!({
+        "componentId": 1,
+        "quantity": 3
+    });
+    let req = RequestFactory::post(
+        url_to_add_kit_component(3).as_str(),
+        Body::from(serde_json::to_string(&component_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_find_kit_availability_from_components() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    phone_bundle_with_components(conn.as_ref()).await;
+    StockFactory::receipt(conn.as_ref(), 1, 10).await.unwrap();
+    StockFactory::receipt(conn.as_ref(), 2, 7).await.unwrap();
+    StockFactory::receipt(conn.as_ref(), 3, 1).await.unwrap();
+
+    let req = RequestFactory::get(url_to_find_kit_availability(3).as_str());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "productId": 3,
+        "onHand": 1,
+        "buildable": 3,
+        "available": 4,
+        "components": [
+            {
+                "componentId": 1,
+                "quantity": 1,
+                "available": 10
+            },
+            {
+                "componentId": 2,
+                "quantity": 2,
+                "available": 7
+            }
+        ]
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_explode_kit_issue_into_component_issues() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    phone_bundle_with_components(conn.as_ref()).await;
+    StockFactory::receipt(conn.as_ref(), 1, 10).await.unwrap();
+    StockFactory::receipt(conn.as_ref(), 2, 7).await.unwrap();
+    StockFactory::receipt(conn.as_ref(), 3, 1).await.unwrap();
+
+    let movement_info = json!({
+        "kind": "issue",
+        "quantity": 3
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(3).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let movements_saved = stock_movement::Entity::find()
+        .all(conn.as_ref())
+        .await
+        .unwrap();
+    let issues: Vec<(i32, i32, Option<String>)> = movements_saved
+        .into_iter()
+        .filter(|movement| movement.kind == "issue")
+        .map(|movement| (movement.product_id, movement.quantity, movement.reason))
+        .collect();
+
+    assert_eq!(
+        issues,
+        vec![
+            (3, -1, None),
+            (1, -2, Some("kit 3".to_string())),
+            (2, -4, Some("kit 3".to_string())),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn it_not_issue_kit_beyond_component_availability() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    phone_bundle_with_components(conn.as_ref()).await;
+    StockFactory::receipt(conn.as_ref(), 1, 10).await.unwrap();
+    StockFactory::receipt(conn.as_ref(), 2, 7).await.unwrap();
+    StockFactory::receipt(conn.as_ref(), 3, 1).await.unwrap();
+
+    let movement_info = json!({
+        "kind": "issue",
+        "quantity": 5
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(3).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_assemble_kit_from_components() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    phone_bundle_with_components(conn.as_ref()).await;
+    StockFactory::costed_receipt(conn.as_ref(), 1, 10, 20_000)
+        .await
+        .unwrap();
+    StockFactory::costed_receipt(conn.as_ref(), 2, 7, 5_000)
+        .await
+        .unwrap();
+
+    let assembly_info = json!({
+        "quantity": 2
+    });
+    let req = RequestFactory::post(
+        url_to_assemble_kit(3).as_str(),
+        Body::from(serde_json::to_string(&assembly_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.into_value().await, json!({ "id": 5 }));
+
+    let movements_saved = stock_movement::Entity::find()
+        .all(conn.as_ref())
+        .await
+        .unwrap();
+    let assembly: Vec<(i32, String, i32, Option<i64>)> = movements_saved
+        .into_iter()
+        .skip(2)
+        .map(|movement| {
+            (
+                movement.product_id,
+                movement.kind,
+                movement.quantity,
+                movement.unit_cost,
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        assembly,
+        vec![
+            (1, "issue".to_string(), -2, None),
+            (2, "issue".to_string(), -4, None),
+            (3, "receipt".to_string(), 2, Some(30_000)),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn it_not_assemble_kit_without_component_stock() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    phone_bundle_with_components(conn.as_ref()).await;
+    StockFactory::receipt(conn.as_ref(), 1, 10).await.unwrap();
+    StockFactory::receipt(conn.as_ref(), 2, 3).await.unwrap();
+
+    let assembly_info = json!({
+        "quantity": 2
+    });
+    let req = RequestFactory::post(
+        url_to_assemble_kit(3).as_str(),
+        Body::from(serde_json::to_string(&assembly_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_not_issue_kit_containing_itself() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    phone_bundle_with_components(conn.as_ref()).await;
+    ProductComponentFactory::of(conn.as_ref(), 2, 3, 1)
+        .await
+        .unwrap();
+    StockFactory::receipt(conn.as_ref(), 1, 10).await.unwrap();
+    StockFactory::receipt(conn.as_ref(), 2, 7).await.unwrap();
+
+    let movement_info = json!({
+        "kind": "issue",
+        "quantity": 1
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(3).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_not_issue_kit_with_tracked_component() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    phone_bundle_with_components(conn.as_ref()).await;
+    StockFactory::receipt(conn.as_ref(), 1, 10).await.unwrap();
+    StockFactory::receipt(conn.as_ref(), 2, 7).await.unwrap();
+    ProductFactory::track(conn.as_ref(), 2, "lot")
+        .await
+        .unwrap();
+
+    let movement_info = json!({
+        "kind": "issue",
+        "quantity": 1
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(3).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+
+    let movements_saved = stock_movement::Entity::find()
+        .all(conn.as_ref())
+        .await
+        .unwrap();
+    assert_eq!(movements_saved.len(), 2);
+}
+
+#[tokio::test]
+async fn it_not_assemble_kit_containing_itself() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    phone_bundle_with_components(conn.as_ref()).await;
+    ProductComponentFactory::of(conn.as_ref(), 2, 3, 1)
+        .await
+        .unwrap();
+    StockFactory::receipt(conn.as_ref(), 1, 10).await.unwrap();
+    StockFactory::receipt(conn.as_ref(), 2, 7).await.unwrap();
+
+    let assembly_info = json!({
+        "quantity": 1
+    });
+    let req = RequestFactory::post(
+        url_to_assemble_kit(3).as_str(),
+        Body::from(serde_json::to_string(&assembly_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_not_assemble_kit_with_tracked_component() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    phone_bundle_with_components(conn.as_ref()).await;
+    StockFactory::receipt(conn.as_ref(), 1, 10).await.unwrap();
+    StockFactory::receipt(conn.as_ref(), 2, 7).await.unwrap();
+    ProductFactory::track(conn.as_ref(), 1, "serial")
+        .await
+        .unwrap();
+
+    let assembly_info = json!({
+        "quantity": 1
+    });
+    let req = RequestFactory::post(
+        url_to_assemble_kit(3).as_str(),
+        Body::from(serde_json::to_string(&assembly_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+
+    let movements_saved = stock_movement::Entity::find()
+        .all(conn.as_ref())
+        .await
+        .unwrap();
+    assert_eq!(movements_saved.len(), 2);
+}
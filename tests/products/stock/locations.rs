@@ -0,0 +1,93 @@
+use crate::products::common::{ProductFactory, StockFactory};
+use crate::storage::common::{StorageLocationFactory, WarehouseFactory};
+use crate::{
+    common::{self},
+    products::common::PRODUCTS_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::http::StatusCode;
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::DatabaseConnection;
+use serde_json::json;
+use tower::ServiceExt;
+
+fn url_to_locate_product(product_id: i32) -> String {
+    format!("{PRODUCTS_URL}/{product_id}/locations")
+}
+
+#[tokio::test]
+async fn it_not_accept_invalid_product_id() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let req = RequestFactory::get(url_to_locate_product(10).as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn it_locate_product_stock_across_warehouses() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    WarehouseFactory::main(conn.as_ref()).await.unwrap();
+    WarehouseFactory::north(conn.as_ref()).await.unwrap();
+    StorageLocationFactory::at(conn.as_ref(), 2, "A", "01", "01")
+        .await
+        .unwrap();
+    StorageLocationFactory::at(conn.as_ref(), 1, "B", "02", "03")
+        .await
+        .unwrap();
+    StorageLocationFactory::at(conn.as_ref(), 1, "C", "01", "01")
+        .await
+        .unwrap();
+
+    StockFactory::receipt_at(conn.as_ref(), 1, 1, 4)
+        .await
+        .unwrap();
+    StockFactory::receipt_at(conn.as_ref(), 1, 2, 10)
+        .await
+        .unwrap();
+    StockFactory::receipt_at(conn.as_ref(), 1, 2, -3)
+        .await
+        .unwrap();
+    StockFactory::receipt_at(conn.as_ref(), 1, 3, 2)
+        .await
+        .unwrap();
+    StockFactory::receipt_at(conn.as_ref(), 1, 3, -2)
+        .await
+        .unwrap();
+    StockFactory::receipt(conn.as_ref(), 1, 5).await.unwrap();
+
+    let req = RequestFactory::get(url_to_locate_product(1).as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!([
+        {
+            "storageLocationId": 2,
+            "warehouseId": 1,
+            "warehouseCode": "MAIN",
+            "aisle": "B",
+            "shelf": "02",
+            "bin": "03",
+            "onHand": 7
+        },
+        {
+            "storageLocationId": 1,
+            "warehouseId": 2,
+            "warehouseCode": "NORTH",
+            "aisle": "A",
+            "shelf": "01",
+            "bin": "01",
+            "onHand": 4
+        }
+    ]);
+    assert_eq!(res.into_value().await, expected_body);
+}
@@ -0,0 +1,10 @@
+pub mod expiry;
+pub mod find_all;
+pub mod kit;
+pub mod locations;
+pub mod on_hand;
+pub mod record;
+pub mod reorder;
+pub mod trace;
+pub mod tracking;
+pub mod valuation;
@@ -0,0 +1,322 @@
+use crate::products::common::{ProductFactory, StockFactory};
+use crate::storage::common::{StorageLocationFactory, WarehouseFactory};
+use crate::{
+    common::{self},
+    products::common::PRODUCTS_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{ActiveModelTrait, ActiveValue, DatabaseConnection, EntityTrait};
+use portal_schema::{product, stock_movement};
+use serde_json::json;
+use tower::ServiceExt;
+
+fn url_to_record_stock_movement(product_id: i32) -> String {
+    format!("{PRODUCTS_URL}/{product_id}/stock-movements")
+}
+
+#[tokio::test]
+async fn it_not_accept_invalid_product_id() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let movement_info = json!({
+        "kind": "receipt",
+        "quantity": 10
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(10).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "product_id",
+                "error": "product does not exist"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_accept_negative_receipt() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+
+    let movement_info = json!({
+        "kind": "receipt",
+        "quantity": -10
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(1).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "quantity",
+                "error": "quantity is not valid for the movement kind"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_record_stock_receipt() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+
+    let movement_info = json!({
+        "kind": "receipt",
+        "quantity": 10,
+        "reason": "initial load"
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(1).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "id": 1
+    });
+    assert_eq!(res.into_value().await, expected_body);
+
+    let movement_saved = stock_movement::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(movement_saved.kind, "receipt");
+    assert_eq!(movement_saved.quantity, 10);
+    assert_eq!(movement_saved.reason, Some("initial load".to_string()));
+}
+
+#[tokio::test]
+async fn it_record_issue_as_outgoing_quantity() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    StockFactory::receipt(conn.as_ref(), 1, 10).await.unwrap();
+
+    let movement_info = json!({
+        "kind": "issue",
+        "quantity": 4
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(1).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let movement_saved = stock_movement::Entity::find_by_id(2)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(movement_saved.kind, "issue");
+    assert_eq!(movement_saved.quantity, -4);
+}
+
+#[tokio::test]
+async fn it_not_issue_more_than_on_hand() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    StockFactory::receipt(conn.as_ref(), 1, 3).await.unwrap();
+
+    let movement_info = json!({
+        "kind": "issue",
+        "quantity": 4
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(1).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_issue_without_stock_when_product_allows_it() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    let product_model = product::ActiveModel {
+        product_id: ActiveValue::Unchanged(1),
+        saleable_without_stock: ActiveValue::Set(true.into()),
+        ..Default::default()
+    };
+    product_model.update(conn.as_ref()).await.unwrap();
+
+    let movement_info = json!({
+        "kind": "issue",
+        "quantity": 4
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(1).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn it_not_accept_invalid_storage_location_id() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+
+    let movement_info = json!({
+        "kind": "receipt",
+        "quantity": 10,
+        "storageLocationId": 10
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(1).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "storage_location_id",
+                "error": "storage location does not exist"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_issue_more_than_on_hand_at_location() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    WarehouseFactory::main(conn.as_ref()).await.unwrap();
+    StorageLocationFactory::at(conn.as_ref(), 1, "A", "01", "01")
+        .await
+        .unwrap();
+    StorageLocationFactory::at(conn.as_ref(), 1, "A", "01", "02")
+        .await
+        .unwrap();
+    StockFactory::receipt_at(conn.as_ref(), 1, 1, 2)
+        .await
+        .unwrap();
+    StockFactory::receipt_at(conn.as_ref(), 1, 2, 8)
+        .await
+        .unwrap();
+
+    let movement_info = json!({
+        "kind": "issue",
+        "quantity": 3,
+        "storageLocationId": 1
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(1).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_record_receipt_into_storage_location() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    WarehouseFactory::main(conn.as_ref()).await.unwrap();
+    StorageLocationFactory::at(conn.as_ref(), 1, "A", "01", "01")
+        .await
+        .unwrap();
+
+    let movement_info = json!({
+        "kind": "receipt",
+        "quantity": 6,
+        "storageLocationId": 1
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(1).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let movement_saved = stock_movement::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(movement_saved.storage_location_id, Some(1));
+    assert_eq!(movement_saved.quantity, 6);
+}
+
+#[tokio::test]
+async fn it_not_accept_transfer_kind_outside_stock_transfers() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+
+    let movement_info = json!({
+        "kind": "transferIn",
+        "quantity": 5
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(1).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "kind",
+                "error": "transfer movements are booked through stock transfers"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
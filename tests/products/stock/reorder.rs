@@ -0,0 +1,139 @@
+use crate::products::common::{ProductFactory, ReorderPointFactory, StockFactory};
+use crate::{
+    common::{self},
+    products::common::PRODUCTS_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use portal_schema::reorder_point;
+use serde_json::json;
+use tower::ServiceExt;
+
+const LOW_STOCK_URL: &str = "/api/v1/products/low-stock";
+
+fn url_to_reorder_point(product_id: i32) -> String {
+    format!("{PRODUCTS_URL}/{product_id}/reorder-point")
+}
+
+#[tokio::test]
+async fn it_not_accept_max_quantity_below_min_quantity() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+
+    let reorder_point_info = json!({
+        "minQuantity": 10,
+        "maxQuantity": 5
+    });
+    let req = RequestFactory::put(
+        url_to_reorder_point(1).as_str(),
+        Body::from(serde_json::to_string(&reorder_point_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "max_quantity",
+                "error": "max quantity must not be lower than the min quantity"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_replace_reorder_point_of_product() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ReorderPointFactory::between(conn.as_ref(), 1, 2, None)
+        .await
+        .unwrap();
+
+    let reorder_point_info = json!({
+        "minQuantity": 5,
+        "maxQuantity": 20
+    });
+    let req = RequestFactory::put(
+        url_to_reorder_point(1).as_str(),
+        Body::from(serde_json::to_string(&reorder_point_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let reorder_points_saved = reorder_point::Entity::find()
+        .filter(reorder_point::Column::ProductId.eq(1))
+        .all(conn.as_ref())
+        .await
+        .unwrap();
+
+    assert_eq!(reorder_points_saved.len(), 1);
+    assert_eq!(reorder_points_saved[0].min_quantity, 5);
+    assert_eq!(reorder_points_saved[0].max_quantity, Some(20));
+}
+
+#[tokio::test]
+async fn it_suggest_purchasable_products_below_reorder_point() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::macbook_pro(conn.as_ref()).await.unwrap();
+    ProductFactory::stop_purchasing(conn.as_ref(), 2)
+        .await
+        .unwrap();
+    StockFactory::receipt(conn.as_ref(), 1, 3).await.unwrap();
+    ReorderPointFactory::between(conn.as_ref(), 1, 5, Some(20))
+        .await
+        .unwrap();
+    ReorderPointFactory::between(conn.as_ref(), 2, 5, None)
+        .await
+        .unwrap();
+
+    let req = RequestFactory::get(LOW_STOCK_URL);
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!([
+        {
+            "productId": 1,
+            "productName": "iPhone X",
+            "onHand": 3,
+            "minQuantity": 5,
+            "maxQuantity": 20,
+            "suggestedQuantity": 17
+        }
+    ]);
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_suggest_products_at_reorder_point() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    StockFactory::receipt(conn.as_ref(), 1, 5).await.unwrap();
+    ReorderPointFactory::between(conn.as_ref(), 1, 5, Some(20))
+        .await
+        .unwrap();
+
+    let req = RequestFactory::get(LOW_STOCK_URL);
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    assert_eq!(res.into_value().await, json!([]));
+}
@@ -0,0 +1,74 @@
+use crate::common::{self};
+use crate::products::common::{ProductFactory, StockFactory};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::http::StatusCode;
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::DatabaseConnection;
+use serde_json::json;
+use tower::ServiceExt;
+
+const STOCK_TRACE_URL: &str = "/api/v1/stock-movements/trace";
+
+#[tokio::test]
+async fn it_trace_movements_of_lot() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::track(conn.as_ref(), 1, "lot")
+        .await
+        .unwrap();
+    StockFactory::tracked_receipt(conn.as_ref(), 1, Some("L-1"), None, 8)
+        .await
+        .unwrap();
+    StockFactory::tracked_receipt(conn.as_ref(), 1, Some("L-2"), None, 4)
+        .await
+        .unwrap();
+    StockFactory::tracked_receipt(conn.as_ref(), 1, Some("L-1"), None, -3)
+        .await
+        .unwrap();
+
+    let req = RequestFactory::get(format!("{STOCK_TRACE_URL}/L-1?page=1&page_size=10").as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body = res.into_value().await;
+    assert_eq!(body["total"], json!(2));
+    assert_eq!(body["data"][0]["stockMovementId"], json!(1));
+    assert_eq!(body["data"][0]["lotNumber"], json!("L-1"));
+    assert_eq!(body["data"][0]["quantity"], json!(8));
+    assert_eq!(body["data"][1]["stockMovementId"], json!(3));
+    assert_eq!(body["data"][1]["quantity"], json!(-3));
+}
+
+#[tokio::test]
+async fn it_trace_movements_of_serial_number() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::track(conn.as_ref(), 1, "serial")
+        .await
+        .unwrap();
+    StockFactory::tracked_receipt(conn.as_ref(), 1, None, Some("SN-001"), 1)
+        .await
+        .unwrap();
+    StockFactory::tracked_receipt(conn.as_ref(), 1, None, Some("SN-002"), 1)
+        .await
+        .unwrap();
+
+    let req = RequestFactory::get(format!("{STOCK_TRACE_URL}/SN-002?page=1&page_size=10").as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body = res.into_value().await;
+    assert_eq!(body["total"], json!(1));
+    assert_eq!(body["data"][0]["stockMovementId"], json!(2));
+    assert_eq!(body["data"][0]["serialNumber"], json!("SN-002"));
+}
@@ -0,0 +1,192 @@
+use crate::products::common::{ProductFactory, StockFactory};
+use crate::{
+    common::{self},
+    products::common::PRODUCTS_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{DatabaseConnection, EntityTrait};
+use portal_schema::stock_movement;
+use serde_json::json;
+use tower::ServiceExt;
+
+fn url_to_record_stock_movement(product_id: i32) -> String {
+    format!("{PRODUCTS_URL}/{product_id}/stock-movements")
+}
+
+#[tokio::test]
+async fn it_require_lot_number_for_lot_tracked_product() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::track(conn.as_ref(), 1, "lot")
+        .await
+        .unwrap();
+
+    let movement_info = json!({
+        "kind": "receipt",
+        "quantity": 10
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(1).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "lot_number",
+                "error": "lot number is required for lot tracked products"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_accept_tracking_codes_for_untracked_product() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+
+    let movement_info = json!({
+        "kind": "receipt",
+        "quantity": 1,
+        "serialNumber": "SN-001"
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(1).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let body = res.into_value().await;
+    assert_eq!(body["errors"][0]["field"], json!("serial_number"));
+}
+
+#[tokio::test]
+async fn it_receive_lot_of_tracked_product() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::track(conn.as_ref(), 1, "lot")
+        .await
+        .unwrap();
+
+    let movement_info = json!({
+        "kind": "receipt",
+        "quantity": 10,
+        "lotNumber": "L-2024-01"
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(1).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let movement = stock_movement::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(movement.lot_number, Some("L-2024-01".to_string()));
+    assert_eq!(movement.serial_number, None);
+}
+
+#[tokio::test]
+async fn it_not_issue_more_than_lot_on_hand() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::track(conn.as_ref(), 1, "lot")
+        .await
+        .unwrap();
+    StockFactory::tracked_receipt(conn.as_ref(), 1, Some("L-1"), None, 3)
+        .await
+        .unwrap();
+    StockFactory::tracked_receipt(conn.as_ref(), 1, Some("L-2"), None, 10)
+        .await
+        .unwrap();
+
+    let movement_info = json!({
+        "kind": "issue",
+        "quantity": 5,
+        "lotNumber": "L-1"
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(1).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_not_receive_serial_number_already_in_stock() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::track(conn.as_ref(), 1, "serial")
+        .await
+        .unwrap();
+    StockFactory::tracked_receipt(conn.as_ref(), 1, None, Some("SN-001"), 1)
+        .await
+        .unwrap();
+
+    let movement_info = json!({
+        "kind": "receipt",
+        "quantity": 1,
+        "serialNumber": "SN-001"
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(1).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_not_move_many_units_of_one_serial_number() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::track(conn.as_ref(), 1, "serial")
+        .await
+        .unwrap();
+
+    let movement_info = json!({
+        "kind": "receipt",
+        "quantity": 2,
+        "serialNumber": "SN-001"
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(1).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let body = res.into_value().await;
+    assert_eq!(body["errors"][0]["field"], json!("quantity"));
+}
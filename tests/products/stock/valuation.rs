@@ -0,0 +1,357 @@
+use crate::products::common::{ProductFactory, StockFactory};
+use crate::{
+    common::{self},
+    products::common::PRODUCTS_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{DatabaseConnection, EntityTrait};
+use portal_schema::{product, stock_movement};
+use serde_json::json;
+use tower::ServiceExt;
+
+const STOCK_VALUATION_URL: &str = "/api/v1/stock-valuation";
+
+fn url_to_record_stock_movement(product_id: i32) -> String {
+    format!("{PRODUCTS_URL}/{product_id}/stock-movements")
+}
+
+fn url_to_set_costing_method(product_id: i32) -> String {
+    format!("{PRODUCTS_URL}/{product_id}/costing-method")
+}
+
+fn url_to_value_product(product_id: i32) -> String {
+    format!("{PRODUCTS_URL}/{product_id}/valuation")
+}
+
+#[tokio::test]
+async fn it_record_unit_cost_on_receipt() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+
+    let movement_info = json!({
+        "kind": "receipt",
+        "quantity": 10,
+        "unitCost": "2.5"
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(1).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let movement_saved = stock_movement::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(movement_saved.unit_cost, Some(25_000));
+}
+
+#[tokio::test]
+async fn it_cost_receipt_without_unit_cost_at_current_unit_cost() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    StockFactory::costed_receipt(conn.as_ref(), 1, 10, 20_000)
+        .await
+        .unwrap();
+    StockFactory::costed_receipt(conn.as_ref(), 1, 10, 40_000)
+        .await
+        .unwrap();
+
+    let movement_info = json!({
+        "kind": "receipt",
+        "quantity": 5
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(1).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let movement_saved = stock_movement::Entity::find_by_id(3)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(movement_saved.unit_cost, Some(30_000));
+}
+
+#[tokio::test]
+async fn it_not_accept_unit_cost_on_issue() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    StockFactory::receipt(conn.as_ref(), 1, 10).await.unwrap();
+
+    let movement_info = json!({
+        "kind": "issue",
+        "quantity": 5,
+        "unitCost": "2"
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(1).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "unit_cost",
+                "error": "unit cost is only recorded on inbound quantities"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_accept_negative_unit_cost() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+
+    let movement_info = json!({
+        "kind": "receipt",
+        "quantity": 5,
+        "unitCost": "-2"
+    });
+    let req = RequestFactory::post(
+        url_to_record_stock_movement(1).as_str(),
+        Body::from(serde_json::to_string(&movement_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "unit_cost",
+                "error": "unit cost must be a positive decimal with up to four decimal places"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_set_costing_method() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+
+    let costing_info = json!({
+        "costingMethod": "fifo"
+    });
+    let req = RequestFactory::put(
+        url_to_set_costing_method(1).as_str(),
+        Body::from(serde_json::to_string(&costing_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let product_saved = product::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(product_saved.costing_method, "fifo");
+}
+
+#[tokio::test]
+async fn it_not_set_costing_method_once_stock_has_moved() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    StockFactory::costed_receipt(conn.as_ref(), 1, 10, 20_000)
+        .await
+        .unwrap();
+
+    let costing_info = json!({
+        "costingMethod": "fifo"
+    });
+    let req = RequestFactory::put(
+        url_to_set_costing_method(1).as_str(),
+        Body::from(serde_json::to_string(&costing_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+
+    let product_saved = product::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(product_saved.costing_method, "moving_average");
+}
+
+#[tokio::test]
+async fn it_value_stock_at_moving_average() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    StockFactory::costed_receipt(conn.as_ref(), 1, 10, 20_000)
+        .await
+        .unwrap();
+    StockFactory::costed_receipt(conn.as_ref(), 1, 10, 40_000)
+        .await
+        .unwrap();
+    StockFactory::issue(conn.as_ref(), 1, 5).await.unwrap();
+
+    let req = RequestFactory::get(STOCK_VALUATION_URL);
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body = res.into_value().await;
+    assert_eq!(body["value"], json!("45.00"));
+    assert_eq!(
+        body["families"],
+        json!([
+            {
+                "familyId": 1,
+                "quantity": 15,
+                "value": "45.00"
+            }
+        ])
+    );
+    assert_eq!(
+        body["products"],
+        json!([
+            {
+                "productId": 1,
+                "familyId": 1,
+                "costingMethod": "movingAverage",
+                "quantity": 15,
+                "unitCost": "3.00",
+                "value": "45.00",
+                "issuedQuantity": 5,
+                "issuedCost": "15.00"
+            }
+        ])
+    );
+}
+
+#[tokio::test]
+async fn it_value_stock_with_fifo_layers() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::cost_with(conn.as_ref(), 1, "fifo")
+        .await
+        .unwrap();
+    StockFactory::costed_receipt(conn.as_ref(), 1, 10, 20_000)
+        .await
+        .unwrap();
+    StockFactory::costed_receipt(conn.as_ref(), 1, 10, 40_000)
+        .await
+        .unwrap();
+    StockFactory::issue(conn.as_ref(), 1, 15).await.unwrap();
+
+    let req = RequestFactory::get(url_to_value_product(1).as_str());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "productId": 1,
+        "familyId": 1,
+        "costingMethod": "fifo",
+        "quantity": 5,
+        "unitCost": "4.00",
+        "value": "20.00",
+        "issuedQuantity": 15,
+        "issuedCost": "40.00"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_value_movements_after_the_date() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    StockFactory::costed_receipt(conn.as_ref(), 1, 10, 20_000)
+        .await
+        .unwrap();
+
+    let req = RequestFactory::get(format!("{STOCK_VALUATION_URL}?asOf=2000-01-01").as_str());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "asOf": "2000-01-01",
+        "value": "0.00",
+        "families": [],
+        "products": []
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_value_invalid_product_id() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let req = RequestFactory::get(url_to_value_product(10).as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn it_value_only_products_in_stock() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::macbook_pro(conn.as_ref()).await.unwrap();
+    StockFactory::costed_receipt(conn.as_ref(), 1, 10, 20_000)
+        .await
+        .unwrap();
+    StockFactory::costed_receipt(conn.as_ref(), 2, 4, 150_000)
+        .await
+        .unwrap();
+    StockFactory::issue(conn.as_ref(), 2, 4).await.unwrap();
+
+    let req = RequestFactory::get(STOCK_VALUATION_URL);
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body = res.into_value().await;
+    assert_eq!(body["value"], json!("20.00"));
+    assert_eq!(body["products"].as_array().unwrap().len(), 1);
+    assert_eq!(body["products"][0]["productId"], json!(1));
+}
@@ -0,0 +1,106 @@
+use lumx_sea_orm::sea_orm;
+use lumx_sea_orm::sea_orm::sqlx::types::chrono::Utc;
+use lumx_sea_orm::sea_orm::{ActiveModelTrait, ActiveValue, DatabaseConnection};
+use portal_schema::{purchase_order, purchase_order_line, supplier, supplier_product};
+
+pub const SUPPLIERS_URL: &str = "/api/v1/suppliers";
+pub const PURCHASE_ORDERS_URL: &str = "/api/v1/purchase-orders";
+
+pub struct SupplierFactory;
+
+impl SupplierFactory {
+    pub async fn named(
+        conn: &DatabaseConnection,
+        name: &str,
+    ) -> Result<supplier::ActiveModel, sea_orm::DbErr> {
+        let supplier_model = supplier::ActiveModel {
+            name: ActiveValue::Set(name.to_string()),
+            contact_person_id: ActiveValue::Set(None),
+            currency: ActiveValue::Set("EUR".to_string()),
+            ..Default::default()
+        };
+
+        supplier_model.save(conn).await
+    }
+}
+
+pub struct SupplierProductFactory;
+
+impl SupplierProductFactory {
+    pub async fn offer(
+        conn: &DatabaseConnection,
+        supplier_id: i32,
+        product_id: i32,
+        supplier_code: &str,
+        lead_time_days: i32,
+        last_price: Option<i64>,
+        preferred: bool,
+    ) -> Result<supplier_product::ActiveModel, sea_orm::DbErr> {
+        let supplier_product_model = supplier_product::ActiveModel {
+            supplier_id: ActiveValue::Set(supplier_id),
+            product_id: ActiveValue::Set(product_id),
+            supplier_code: ActiveValue::Set(supplier_code.to_string()),
+            pack_size: ActiveValue::Set(1),
+            lead_time_days: ActiveValue::Set(lead_time_days),
+            last_price: ActiveValue::Set(last_price),
+            preferred: ActiveValue::Set(preferred.into()),
+            ..Default::default()
+        };
+
+        supplier_product_model.save(conn).await
+    }
+}
+
+pub struct PurchaseOrderFactory;
+
+impl PurchaseOrderFactory {
+    pub async fn in_status(
+        conn: &DatabaseConnection,
+        supplier_id: i32,
+        status: &str,
+    ) -> Result<purchase_order::ActiveModel, sea_orm::DbErr> {
+        let purchase_order_model = purchase_order::ActiveModel {
+            supplier_id: ActiveValue::Set(supplier_id),
+            status: ActiveValue::Set(status.to_string()),
+            currency: ActiveValue::Set("EUR".to_string()),
+            created_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        purchase_order_model.save(conn).await
+    }
+
+    pub async fn line(
+        conn: &DatabaseConnection,
+        purchase_order_id: i32,
+        product_id: i32,
+        ordered_quantity: i32,
+        received_quantity: i32,
+        unit_price: i64,
+    ) -> Result<purchase_order_line::ActiveModel, sea_orm::DbErr> {
+        let purchase_order_line_model = purchase_order_line::ActiveModel {
+            purchase_order_id: ActiveValue::Set(purchase_order_id),
+            product_id: ActiveValue::Set(product_id),
+            ordered_quantity: ActiveValue::Set(ordered_quantity),
+            received_quantity: ActiveValue::Set(received_quantity),
+            unit_price: ActiveValue::Set(unit_price),
+            ..Default::default()
+        };
+
+        purchase_order_line_model.save(conn).await
+    }
+
+    pub async fn order_in(
+        conn: &DatabaseConnection,
+        purchase_order_line_id: i32,
+        unit_id: i32,
+    ) -> Result<purchase_order_line::Model, sea_orm::DbErr> {
+        let purchase_order_line_model = purchase_order_line::ActiveModel {
+            purchase_order_line_id: ActiveValue::Unchanged(purchase_order_line_id),
+            unit_id: ActiveValue::Set(Some(unit_id)),
+            ..Default::default()
+        };
+
+        purchase_order_line_model.update(conn).await
+    }
+}
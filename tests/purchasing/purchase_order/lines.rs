@@ -0,0 +1,151 @@
+use crate::common::{self};
+use crate::products::common::ProductFactory;
+use crate::purchasing::common::{
+    PurchaseOrderFactory, SupplierFactory, SupplierProductFactory, PURCHASE_ORDERS_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{DatabaseConnection, EntityTrait};
+use portal_schema::{purchase_order, purchase_order_line};
+use serde_json::json;
+use tower::ServiceExt;
+
+fn url_to_purchase_order_lines(purchase_order_id: i32) -> String {
+    format!("{PURCHASE_ORDERS_URL}/{purchase_order_id}/lines")
+}
+
+#[tokio::test]
+async fn it_accepts_and_create_purchase_order() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    SupplierFactory::named(conn.as_ref(), "Acme Distribution")
+        .await
+        .unwrap();
+
+    let order_info = json!({ "supplierId": 1 });
+    let req = RequestFactory::post(
+        PURCHASE_ORDERS_URL,
+        Body::from(serde_json::to_string(&order_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.into_value().await, json!({ "id": 1 }));
+
+    let order_saved = purchase_order::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(order_saved.status, "draft");
+    assert_eq!(order_saved.currency, "EUR");
+}
+
+#[tokio::test]
+async fn it_not_create_purchase_order_for_unknown_supplier() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let order_info = json!({ "supplierId": 1 });
+    let req = RequestFactory::post(
+        PURCHASE_ORDERS_URL,
+        Body::from(serde_json::to_string(&order_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn it_add_line_priced_from_supplier_last_price() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    SupplierFactory::named(conn.as_ref(), "Acme Distribution")
+        .await
+        .unwrap();
+    SupplierProductFactory::offer(conn.as_ref(), 1, 1, "AC-IPHX", 5, Some(5_125_000), true)
+        .await
+        .unwrap();
+    PurchaseOrderFactory::in_status(conn.as_ref(), 1, "draft")
+        .await
+        .unwrap();
+
+    let line_info = json!({ "productId": 1, "quantity": 10 });
+    let req = RequestFactory::post(
+        url_to_purchase_order_lines(1).as_str(),
+        Body::from(serde_json::to_string(&line_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.into_value().await, json!({ "id": 1 }));
+
+    let line_saved = purchase_order_line::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(line_saved.ordered_quantity, 10);
+    assert_eq!(line_saved.received_quantity, 0);
+    assert_eq!(line_saved.unit_price, 5_125_000);
+}
+
+#[tokio::test]
+async fn it_not_add_line_for_product_not_purchasable() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::stop_purchasing(conn.as_ref(), 1)
+        .await
+        .unwrap();
+    SupplierFactory::named(conn.as_ref(), "Acme Distribution")
+        .await
+        .unwrap();
+    PurchaseOrderFactory::in_status(conn.as_ref(), 1, "draft")
+        .await
+        .unwrap();
+
+    let line_info = json!({ "productId": 1, "quantity": 10, "unitPrice": "500" });
+    let req = RequestFactory::post(
+        url_to_purchase_order_lines(1).as_str(),
+        Body::from(serde_json::to_string(&line_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [{ "field": "product_id", "error": "product is not purchasable" }],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_add_line_to_approved_purchase_order() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    SupplierFactory::named(conn.as_ref(), "Acme Distribution")
+        .await
+        .unwrap();
+    PurchaseOrderFactory::in_status(conn.as_ref(), 1, "approved")
+        .await
+        .unwrap();
+
+    let line_info = json!({ "productId": 1, "quantity": 10, "unitPrice": "500" });
+    let req = RequestFactory::post(
+        url_to_purchase_order_lines(1).as_str(),
+        Body::from(serde_json::to_string(&line_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
@@ -0,0 +1,477 @@
+use crate::common::{self};
+use crate::products::common::{ProductFactory, StockFactory, UnitOfMeasureFactory};
+use crate::purchasing::common::{
+    PurchaseOrderFactory, SupplierFactory, SupplierProductFactory, PURCHASE_ORDERS_URL,
+};
+use crate::storage::common::{StorageLocationFactory, WarehouseFactory};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::sqlx::types::chrono::NaiveDate;
+use lumx_sea_orm::sea_orm::{DatabaseConnection, EntityTrait};
+use portal_schema::{
+    purchase_order, purchase_order_line, stock_lot, stock_movement, supplier_product,
+};
+use serde_json::json;
+use tower::ServiceExt;
+
+fn url_to_purchase_order(purchase_order_id: i32) -> String {
+    format!("{PURCHASE_ORDERS_URL}/{purchase_order_id}")
+}
+
+fn url_to_purchase_order_action(purchase_order_id: i32, action: &str) -> String {
+    format!("{PURCHASE_ORDERS_URL}/{purchase_order_id}/{action}")
+}
+
+#[tokio::test]
+async fn it_not_approve_purchase_order_without_lines() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    SupplierFactory::named(conn.as_ref(), "Acme Distribution")
+        .await
+        .unwrap();
+    PurchaseOrderFactory::in_status(conn.as_ref(), 1, "draft")
+        .await
+        .unwrap();
+
+    let req = RequestFactory::post(
+        url_to_purchase_order_action(1, "approve").as_str(),
+        Body::empty(),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_approve_draft_purchase_order() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    SupplierFactory::named(conn.as_ref(), "Acme Distribution")
+        .await
+        .unwrap();
+    PurchaseOrderFactory::in_status(conn.as_ref(), 1, "draft")
+        .await
+        .unwrap();
+    PurchaseOrderFactory::line(conn.as_ref(), 1, 1, 10, 0, 5_000_000)
+        .await
+        .unwrap();
+
+    let req = RequestFactory::post(
+        url_to_purchase_order_action(1, "approve").as_str(),
+        Body::empty(),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let order_saved = purchase_order::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(order_saved.status, "approved");
+    assert!(order_saved.approved_at.is_some());
+}
+
+#[tokio::test]
+async fn it_not_receive_purchase_order_not_sent() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    SupplierFactory::named(conn.as_ref(), "Acme Distribution")
+        .await
+        .unwrap();
+    PurchaseOrderFactory::in_status(conn.as_ref(), 1, "approved")
+        .await
+        .unwrap();
+    PurchaseOrderFactory::line(conn.as_ref(), 1, 1, 10, 0, 5_000_000)
+        .await
+        .unwrap();
+
+    let receipt_info = json!({ "lines": [{ "purchaseOrderLineId": 1, "quantity": 4 }] });
+    let req = RequestFactory::post(
+        url_to_purchase_order_action(1, "receive").as_str(),
+        Body::from(serde_json::to_string(&receipt_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_partially_receive_sent_purchase_order() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    WarehouseFactory::main(conn.as_ref()).await.unwrap();
+    StorageLocationFactory::at(conn.as_ref(), 1, "A", "1", "1")
+        .await
+        .unwrap();
+    SupplierFactory::named(conn.as_ref(), "Acme Distribution")
+        .await
+        .unwrap();
+    SupplierProductFactory::offer(conn.as_ref(), 1, 1, "AC-IPHX", 5, Some(5_125_000), true)
+        .await
+        .unwrap();
+    PurchaseOrderFactory::in_status(conn.as_ref(), 1, "sent")
+        .await
+        .unwrap();
+    PurchaseOrderFactory::line(conn.as_ref(), 1, 1, 10, 0, 5_000_000)
+        .await
+        .unwrap();
+
+    let receipt_info = json!({
+        "storageLocationId": 1,
+        "lines": [{ "purchaseOrderLineId": 1, "quantity": 4 }]
+    });
+    let req = RequestFactory::post(
+        url_to_purchase_order_action(1, "receive").as_str(),
+        Body::from(serde_json::to_string(&receipt_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let order_saved = purchase_order::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(order_saved.status, "partially_received");
+
+    let line_saved = purchase_order_line::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(line_saved.received_quantity, 4);
+
+    let movement_saved = stock_movement::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(movement_saved.kind, "receipt");
+    assert_eq!(movement_saved.quantity, 4);
+    assert_eq!(movement_saved.storage_location_id, Some(1));
+    assert_eq!(movement_saved.reason, Some("purchase order 1".to_string()));
+
+    let supplier_product_saved = supplier_product::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(supplier_product_saved.last_price, Some(5_000_000));
+}
+
+#[tokio::test]
+async fn it_close_and_flag_over_received_purchase_order() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    SupplierFactory::named(conn.as_ref(), "Acme Distribution")
+        .await
+        .unwrap();
+    PurchaseOrderFactory::in_status(conn.as_ref(), 1, "partially_received")
+        .await
+        .unwrap();
+    PurchaseOrderFactory::line(conn.as_ref(), 1, 1, 10, 0, 5_000_000)
+        .await
+        .unwrap();
+
+    let receipt_info = json!({
+        "allowOverReceipt": true,
+        "lines": [{ "purchaseOrderLineId": 1, "quantity": 12 }]
+    });
+    let req = RequestFactory::post(
+        url_to_purchase_order_action(1, "receive").as_str(),
+        Body::from(serde_json::to_string(&receipt_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let order_saved = purchase_order::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(order_saved.status, "closed");
+    assert!(order_saved.closed_at.is_some());
+
+    let line_saved = purchase_order_line::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(line_saved.received_quantity, 12);
+}
+
+#[tokio::test]
+async fn it_not_over_receive_purchase_order_unless_allowed() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    SupplierFactory::named(conn.as_ref(), "Acme Distribution")
+        .await
+        .unwrap();
+    PurchaseOrderFactory::in_status(conn.as_ref(), 1, "sent")
+        .await
+        .unwrap();
+    PurchaseOrderFactory::line(conn.as_ref(), 1, 1, 10, 0, 5_000_000)
+        .await
+        .unwrap();
+
+    let receipt_info = json!({ "lines": [{ "purchaseOrderLineId": 1, "quantity": 12 }] });
+    let req = RequestFactory::post(
+        url_to_purchase_order_action(1, "receive").as_str(),
+        Body::from(serde_json::to_string(&receipt_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+
+    let line_saved = purchase_order_line::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(line_saved.received_quantity, 0);
+
+    let movements = stock_movement::Entity::find()
+        .all(conn.as_ref())
+        .await
+        .unwrap();
+    assert!(movements.is_empty());
+}
+
+#[tokio::test]
+async fn it_receive_purchase_unit_in_base_units() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    UnitOfMeasureFactory::code(conn.as_ref(), "pcs", "Piece")
+        .await
+        .unwrap();
+    UnitOfMeasureFactory::code(conn.as_ref(), "box", "Box")
+        .await
+        .unwrap();
+    UnitOfMeasureFactory::conversion(conn.as_ref(), 2, 1, 12)
+        .await
+        .unwrap();
+    ProductFactory::measure_in(conn.as_ref(), 1, 1)
+        .await
+        .unwrap();
+    SupplierFactory::named(conn.as_ref(), "Acme Distribution")
+        .await
+        .unwrap();
+    PurchaseOrderFactory::in_status(conn.as_ref(), 1, "sent")
+        .await
+        .unwrap();
+    PurchaseOrderFactory::line(conn.as_ref(), 1, 1, 24, 0, 1_200_000)
+        .await
+        .unwrap();
+    PurchaseOrderFactory::order_in(conn.as_ref(), 1, 2)
+        .await
+        .unwrap();
+
+    let receipt_info = json!({ "lines": [{ "purchaseOrderLineId": 1, "quantity": 2 }] });
+    let req = RequestFactory::post(
+        url_to_purchase_order_action(1, "receive").as_str(),
+        Body::from(serde_json::to_string(&receipt_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let line_saved = purchase_order_line::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(line_saved.received_quantity, 24);
+
+    let movement_saved = stock_movement::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(movement_saved.quantity, 24);
+    assert_eq!(movement_saved.unit_cost, Some(100_000));
+}
+
+#[tokio::test]
+async fn it_keep_the_whole_pack_cost_when_receiving_in_base_units() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    UnitOfMeasureFactory::code(conn.as_ref(), "pcs", "Piece")
+        .await
+        .unwrap();
+    UnitOfMeasureFactory::code(conn.as_ref(), "box", "Box")
+        .await
+        .unwrap();
+    UnitOfMeasureFactory::conversion(conn.as_ref(), 2, 1, 3)
+        .await
+        .unwrap();
+    ProductFactory::measure_in(conn.as_ref(), 1, 1)
+        .await
+        .unwrap();
+    SupplierFactory::named(conn.as_ref(), "Acme Distribution")
+        .await
+        .unwrap();
+    PurchaseOrderFactory::in_status(conn.as_ref(), 1, "sent")
+        .await
+        .unwrap();
+    PurchaseOrderFactory::line(conn.as_ref(), 1, 1, 3, 0, 100_000)
+        .await
+        .unwrap();
+    PurchaseOrderFactory::order_in(conn.as_ref(), 1, 2)
+        .await
+        .unwrap();
+
+    let receipt_info = json!({ "lines": [{ "purchaseOrderLineId": 1, "quantity": 1 }] });
+    let req = RequestFactory::post(
+        url_to_purchase_order_action(1, "receive").as_str(),
+        Body::from(serde_json::to_string(&receipt_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let movements_saved = stock_movement::Entity::find()
+        .all(conn.as_ref())
+        .await
+        .unwrap();
+    let costs: Vec<(i32, Option<i64>)> = movements_saved
+        .iter()
+        .map(|movement| (movement.quantity, movement.unit_cost))
+        .collect();
+    assert_eq!(costs, vec![(2, Some(33_333)), (1, Some(33_334))]);
+}
+
+#[tokio::test]
+async fn it_not_receive_serial_number_already_in_stock() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::track(conn.as_ref(), 1, "serial")
+        .await
+        .unwrap();
+    StockFactory::tracked_receipt(conn.as_ref(), 1, None, Some("SN-0001"), 1)
+        .await
+        .unwrap();
+    SupplierFactory::named(conn.as_ref(), "Acme Distribution")
+        .await
+        .unwrap();
+    PurchaseOrderFactory::in_status(conn.as_ref(), 1, "sent")
+        .await
+        .unwrap();
+    PurchaseOrderFactory::line(conn.as_ref(), 1, 1, 2, 0, 5_000_000)
+        .await
+        .unwrap();
+
+    let receipt_info = json!({
+        "lines": [{ "purchaseOrderLineId": 1, "quantity": 1, "serialNumber": "SN-0001" }]
+    });
+    let req = RequestFactory::post(
+        url_to_purchase_order_action(1, "receive").as_str(),
+        Body::from(serde_json::to_string(&receipt_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_record_lot_expiry_when_receiving_purchase_order() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::track(conn.as_ref(), 1, "lot")
+        .await
+        .unwrap();
+    SupplierFactory::named(conn.as_ref(), "Acme Distribution")
+        .await
+        .unwrap();
+    PurchaseOrderFactory::in_status(conn.as_ref(), 1, "sent")
+        .await
+        .unwrap();
+    PurchaseOrderFactory::line(conn.as_ref(), 1, 1, 10, 0, 5_000_000)
+        .await
+        .unwrap();
+
+    let receipt_info = json!({
+        "lines": [{
+            "purchaseOrderLineId": 1,
+            "quantity": 10,
+            "lotNumber": "L-2025-01",
+            "expiresOn": "2025-06-30"
+        }]
+    });
+    let req = RequestFactory::post(
+        url_to_purchase_order_action(1, "receive").as_str(),
+        Body::from(serde_json::to_string(&receipt_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let lot_saved = stock_lot::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(lot_saved.lot_number, "L-2025-01");
+    assert_eq!(
+        lot_saved.expires_on,
+        NaiveDate::from_ymd_opt(2025, 6, 30).unwrap()
+    );
+}
+
+#[tokio::test]
+async fn it_find_purchase_order_with_over_received_lines() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    SupplierFactory::named(conn.as_ref(), "Acme Distribution")
+        .await
+        .unwrap();
+    PurchaseOrderFactory::in_status(conn.as_ref(), 1, "closed")
+        .await
+        .unwrap();
+    PurchaseOrderFactory::line(conn.as_ref(), 1, 1, 10, 12, 5_000_000)
+        .await
+        .unwrap();
+
+    let req = RequestFactory::get(url_to_purchase_order(1).as_str());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body = res.into_value().await;
+    assert_eq!(body["status"], json!("closed"));
+    assert_eq!(body["lines"][0]["receivedQuantity"], json!(12));
+    assert_eq!(body["lines"][0]["outstandingQuantity"], json!(0));
+    assert_eq!(body["lines"][0]["overReceivedQuantity"], json!(2));
+    assert_eq!(body["lines"][0]["overReceived"], json!(true));
+    assert_eq!(body["lines"][0]["unitPrice"], json!("500.00"));
+}
@@ -0,0 +1,289 @@
+use crate::common::{self};
+use crate::products::common::{ProductFactory, PRODUCTS_URL};
+use crate::purchasing::common::{SupplierFactory, SupplierProductFactory, SUPPLIERS_URL};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{ActiveModelTrait, ActiveValue, DatabaseConnection, EntityTrait};
+use portal_schema::supplier_product;
+use serde_json::json;
+use tower::ServiceExt;
+
+fn url_to_supplier_products(supplier_id: i32) -> String {
+    format!("{SUPPLIERS_URL}/{supplier_id}/products")
+}
+
+fn url_to_supplier_product(supplier_id: i32, product_id: i32) -> String {
+    format!("{SUPPLIERS_URL}/{supplier_id}/products/{product_id}")
+}
+
+fn url_to_preferred_supplier(product_id: i32) -> String {
+    format!("{PRODUCTS_URL}/{product_id}/preferred-supplier")
+}
+
+#[tokio::test]
+async fn it_accepts_and_add_supplier_product() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    SupplierFactory::named(conn.as_ref(), "Acme Distribution")
+        .await
+        .unwrap();
+
+    let supplier_product_info = json!({
+        "productId": 1,
+        "supplierCode": " ac-iphx ",
+        "packSize": 10,
+        "leadTimeDays": 5,
+        "lastPrice": "512.5"
+    });
+    let req = RequestFactory::post(
+        url_to_supplier_products(1).as_str(),
+        Body::from(serde_json::to_string(&supplier_product_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.into_value().await, json!({ "id": 1 }));
+
+    let supplier_product_saved = supplier_product::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(supplier_product_saved.supplier_code, "AC-IPHX");
+    assert_eq!(supplier_product_saved.last_price, Some(5_125_000));
+}
+
+#[tokio::test]
+async fn it_not_accept_product_that_is_not_purchasable() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::stop_purchasing(conn.as_ref(), 1)
+        .await
+        .unwrap();
+    SupplierFactory::named(conn.as_ref(), "Acme Distribution")
+        .await
+        .unwrap();
+
+    let supplier_product_info = json!({
+        "productId": 1,
+        "supplierCode": "AC-IPHX",
+        "packSize": 1,
+        "leadTimeDays": 5
+    });
+    let req = RequestFactory::post(
+        url_to_supplier_products(1).as_str(),
+        Body::from(serde_json::to_string(&supplier_product_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "product_id",
+                "error": "product is not purchasable"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_accept_duplicated_supplier_code() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::macbook_pro(conn.as_ref()).await.unwrap();
+    SupplierFactory::named(conn.as_ref(), "Acme Distribution")
+        .await
+        .unwrap();
+    SupplierProductFactory::offer(conn.as_ref(), 1, 1, "AC-100", 5, None, false)
+        .await
+        .unwrap();
+
+    let supplier_product_info = json!({
+        "productId": 2,
+        "supplierCode": "ac-100",
+        "packSize": 1,
+        "leadTimeDays": 5
+    });
+    let req = RequestFactory::post(
+        url_to_supplier_products(1).as_str(),
+        Body::from(serde_json::to_string(&supplier_product_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_keep_a_single_preferred_supplier_per_product() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    SupplierFactory::named(conn.as_ref(), "Acme Distribution")
+        .await
+        .unwrap();
+    SupplierFactory::named(conn.as_ref(), "Globex")
+        .await
+        .unwrap();
+    SupplierProductFactory::offer(conn.as_ref(), 1, 1, "AC-100", 5, None, true)
+        .await
+        .unwrap();
+    SupplierProductFactory::offer(conn.as_ref(), 2, 1, "GX-100", 3, None, false)
+        .await
+        .unwrap();
+
+    let supplier_product_info = json!({
+        "supplierCode": "GX-100",
+        "packSize": 6,
+        "leadTimeDays": 3,
+        "lastPrice": "480.00",
+        "preferred": true
+    });
+    let req = RequestFactory::put(
+        url_to_supplier_product(2, 1).as_str(),
+        Body::from(serde_json::to_string(&supplier_product_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let previous_preferred = supplier_product::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(previous_preferred.preferred, 0);
+
+    let new_preferred = supplier_product::Entity::find_by_id(2)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(new_preferred.preferred, 1);
+    assert_eq!(new_preferred.pack_size, 6);
+}
+
+#[tokio::test]
+async fn it_find_flagged_preferred_supplier_before_faster_ones() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    SupplierFactory::named(conn.as_ref(), "Acme Distribution")
+        .await
+        .unwrap();
+    SupplierFactory::named(conn.as_ref(), "Globex")
+        .await
+        .unwrap();
+    SupplierProductFactory::offer(conn.as_ref(), 1, 1, "AC-100", 2, Some(4_000_000), false)
+        .await
+        .unwrap();
+    SupplierProductFactory::offer(conn.as_ref(), 2, 1, "GX-100", 9, Some(5_000_000), true)
+        .await
+        .unwrap();
+
+    let req = RequestFactory::get(url_to_preferred_supplier(1).as_str());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "productId": 1,
+        "supplierId": 2,
+        "supplierName": "Globex",
+        "currency": "EUR",
+        "supplierCode": "GX-100",
+        "packSize": 1,
+        "leadTimeDays": 9,
+        "lastPrice": "500.00",
+        "preferred": true
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_find_fastest_supplier_when_none_is_preferred() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    SupplierFactory::named(conn.as_ref(), "Acme Distribution")
+        .await
+        .unwrap();
+    SupplierFactory::named(conn.as_ref(), "Globex")
+        .await
+        .unwrap();
+    SupplierProductFactory::offer(conn.as_ref(), 1, 1, "AC-100", 7, Some(4_000_000), false)
+        .await
+        .unwrap();
+    SupplierProductFactory::offer(conn.as_ref(), 2, 1, "GX-100", 3, None, false)
+        .await
+        .unwrap();
+
+    let req = RequestFactory::get(url_to_preferred_supplier(1).as_str());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body = res.into_value().await;
+    assert_eq!(body["supplierId"], json!(2));
+    assert_eq!(body["lastPrice"], json!(null));
+}
+
+#[tokio::test]
+async fn it_not_rank_suppliers_by_prices_in_other_currencies() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    SupplierFactory::named(conn.as_ref(), "Acme Distribution")
+        .await
+        .unwrap();
+    let mut globex_model = SupplierFactory::named(conn.as_ref(), "Globex")
+        .await
+        .unwrap();
+    globex_model.currency = ActiveValue::Set("JPY".to_string());
+    globex_model.update(conn.as_ref()).await.unwrap();
+    SupplierProductFactory::offer(conn.as_ref(), 1, 1, "AC-100", 3, Some(4_000_000), false)
+        .await
+        .unwrap();
+    SupplierProductFactory::offer(conn.as_ref(), 2, 1, "GX-100", 3, Some(10_000), false)
+        .await
+        .unwrap();
+
+    let req = RequestFactory::get(url_to_preferred_supplier(1).as_str());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body = res.into_value().await;
+    assert_eq!(body["supplierId"], json!(1));
+}
+
+#[tokio::test]
+async fn it_not_find_preferred_supplier_for_product_without_suppliers() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+
+    let req = RequestFactory::get(url_to_preferred_supplier(1).as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+}
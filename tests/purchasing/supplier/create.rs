@@ -0,0 +1,94 @@
+use crate::common::{self};
+use crate::people::common::insert_person_sample_1;
+use crate::purchasing::common::{SupplierFactory, SUPPLIERS_URL};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{DatabaseConnection, EntityTrait};
+use portal_schema::supplier;
+use serde_json::json;
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn it_accepts_and_create_supplier_with_contact_person() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+
+    let supplier_info = json!({
+        "name": "Acme Distribution",
+        "contactPersonId": 1,
+        "currency": "eur"
+    });
+    let req = RequestFactory::post(
+        SUPPLIERS_URL,
+        Body::from(serde_json::to_string(&supplier_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.into_value().await, json!({ "id": 1 }));
+
+    let supplier_saved = supplier::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(supplier_saved.contact_person_id, Some(1));
+    assert_eq!(supplier_saved.currency, "EUR");
+}
+
+#[tokio::test]
+async fn it_not_accept_supplier_with_unknown_contact_person() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let supplier_info = json!({
+        "name": "Acme Distribution",
+        "contactPersonId": 10,
+        "currency": "EUR"
+    });
+    let req = RequestFactory::post(
+        SUPPLIERS_URL,
+        Body::from(serde_json::to_string(&supplier_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "contact_person_id",
+                "error": "contact person does not exist"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_accept_duplicated_supplier_name() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    SupplierFactory::named(conn.as_ref(), "Acme Distribution")
+        .await
+        .unwrap();
+
+    let supplier_info = json!({
+        "name": "Acme Distribution",
+        "currency": "EUR"
+    });
+    let req = RequestFactory::post(
+        SUPPLIERS_URL,
+        Body::from(serde_json::to_string(&supplier_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
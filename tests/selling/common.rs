@@ -0,0 +1,45 @@
+use lumx_sea_orm::sea_orm;
+use lumx_sea_orm::sea_orm::sqlx::types::chrono::Utc;
+use lumx_sea_orm::sea_orm::{ActiveModelTrait, ActiveValue, DatabaseConnection};
+use portal_schema::{sales_order, sales_order_line};
+
+pub const SALES_ORDERS_URL: &str = "/api/v1/sales-orders";
+
+pub struct SalesOrderFactory;
+
+impl SalesOrderFactory {
+    pub async fn in_status(
+        conn: &DatabaseConnection,
+        customer_id: i32,
+        status: &str,
+    ) -> Result<sales_order::ActiveModel, sea_orm::DbErr> {
+        let sales_order_model = sales_order::ActiveModel {
+            customer_id: ActiveValue::Set(customer_id),
+            status: ActiveValue::Set(status.to_string()),
+            created_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        sales_order_model.save(conn).await
+    }
+
+    pub async fn line(
+        conn: &DatabaseConnection,
+        sales_order_id: i32,
+        product_id: i32,
+        ordered_quantity: i32,
+        reserved_quantity: i32,
+        backordered_quantity: i32,
+    ) -> Result<sales_order_line::ActiveModel, sea_orm::DbErr> {
+        let sales_order_line_model = sales_order_line::ActiveModel {
+            sales_order_id: ActiveValue::Set(sales_order_id),
+            product_id: ActiveValue::Set(product_id),
+            ordered_quantity: ActiveValue::Set(ordered_quantity),
+            reserved_quantity: ActiveValue::Set(reserved_quantity),
+            backordered_quantity: ActiveValue::Set(backordered_quantity),
+            ..Default::default()
+        };
+
+        sales_order_line_model.save(conn).await
+    }
+}
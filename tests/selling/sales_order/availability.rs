@@ -0,0 +1,107 @@
+use crate::common::{self};
+use crate::people::common::insert_person_sample_1;
+use crate::products::common::{
+    ProductComponentFactory, ProductFactory, StockFactory, PRODUCTS_URL,
+};
+use crate::selling::common::SalesOrderFactory;
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::http::StatusCode;
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::DatabaseConnection;
+use serde_json::json;
+use tower::ServiceExt;
+
+fn url_to_available_to_promise(product_id: i32) -> String {
+    format!("{PRODUCTS_URL}/{product_id}/available-to-promise")
+}
+
+#[tokio::test]
+async fn it_report_available_to_promise_of_confirmed_orders_only() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    StockFactory::receipt(conn.as_ref(), 1, 10).await.unwrap();
+    SalesOrderFactory::in_status(conn.as_ref(), 1, "confirmed")
+        .await
+        .unwrap();
+    SalesOrderFactory::line(conn.as_ref(), 1, 1, 6, 6, 0)
+        .await
+        .unwrap();
+    SalesOrderFactory::in_status(conn.as_ref(), 1, "draft")
+        .await
+        .unwrap();
+    SalesOrderFactory::line(conn.as_ref(), 2, 1, 3, 0, 0)
+        .await
+        .unwrap();
+
+    let req = RequestFactory::get(url_to_available_to_promise(1).as_str());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "productId": 1,
+        "onHand": 10,
+        "reserved": 6,
+        "backordered": 0,
+        "availableToPromise": 4
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_reduce_component_available_to_promise_by_kit_reservations() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::in_family(conn.as_ref(), "Charger", 1)
+        .await
+        .unwrap();
+    ProductFactory::in_family(conn.as_ref(), "iPhone X Bundle", 1)
+        .await
+        .unwrap();
+    ProductComponentFactory::of(conn.as_ref(), 3, 1, 1)
+        .await
+        .unwrap();
+    ProductComponentFactory::of(conn.as_ref(), 3, 2, 2)
+        .await
+        .unwrap();
+    StockFactory::receipt(conn.as_ref(), 1, 5).await.unwrap();
+    StockFactory::receipt(conn.as_ref(), 2, 10).await.unwrap();
+    SalesOrderFactory::in_status(conn.as_ref(), 1, "confirmed")
+        .await
+        .unwrap();
+    SalesOrderFactory::line(conn.as_ref(), 1, 3, 3, 3, 0)
+        .await
+        .unwrap();
+
+    let req = RequestFactory::get(url_to_available_to_promise(1).as_str());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "productId": 1,
+        "onHand": 5,
+        "reserved": 3,
+        "backordered": 0,
+        "availableToPromise": 2
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_report_available_to_promise_of_unknown_product() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let req = RequestFactory::get(url_to_available_to_promise(10).as_str());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,171 @@
+use crate::common::{self};
+use crate::people::common::insert_person_sample_1;
+use crate::products::common::ProductFactory;
+use crate::selling::common::{SalesOrderFactory, SALES_ORDERS_URL};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{DatabaseConnection, EntityTrait};
+use portal_schema::{sales_order, sales_order_line};
+use serde_json::json;
+use tower::ServiceExt;
+
+fn url_to_sales_order_lines(sales_order_id: i32) -> String {
+    format!("{SALES_ORDERS_URL}/{sales_order_id}/lines")
+}
+
+#[tokio::test]
+async fn it_accepts_and_create_sales_order() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+
+    let order_info = json!({ "customerId": 1 });
+    let req = RequestFactory::post(
+        SALES_ORDERS_URL,
+        Body::from(serde_json::to_string(&order_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.into_value().await, json!({ "id": 1 }));
+
+    let order_saved = sales_order::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(order_saved.status, "draft");
+    assert_eq!(order_saved.customer_id, 1);
+}
+
+#[tokio::test]
+async fn it_not_create_sales_order_for_unknown_customer() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let order_info = json!({ "customerId": 1 });
+    let req = RequestFactory::post(
+        SALES_ORDERS_URL,
+        Body::from(serde_json::to_string(&order_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn it_accepts_and_add_sales_order_line() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    SalesOrderFactory::in_status(conn.as_ref(), 1, "draft")
+        .await
+        .unwrap();
+
+    let line_info = json!({ "productId": 1, "quantity": 3 });
+    let req = RequestFactory::post(
+        url_to_sales_order_lines(1).as_str(),
+        Body::from(serde_json::to_string(&line_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.into_value().await, json!({ "id": 1 }));
+
+    let line_saved = sales_order_line::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(line_saved.ordered_quantity, 3);
+    assert_eq!(line_saved.reserved_quantity, 0);
+}
+
+#[tokio::test]
+async fn it_not_add_line_for_product_not_saleable() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::stop_selling(conn.as_ref(), 1)
+        .await
+        .unwrap();
+    SalesOrderFactory::in_status(conn.as_ref(), 1, "draft")
+        .await
+        .unwrap();
+
+    let line_info = json!({ "productId": 1, "quantity": 3 });
+    let req = RequestFactory::post(
+        url_to_sales_order_lines(1).as_str(),
+        Body::from(serde_json::to_string(&line_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [{ "field": "product_id", "error": "product is not saleable" }],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_add_line_for_tracked_product() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::track(conn.as_ref(), 1, "serial")
+        .await
+        .unwrap();
+    SalesOrderFactory::in_status(conn.as_ref(), 1, "draft")
+        .await
+        .unwrap();
+
+    let line_info = json!({ "productId": 1, "quantity": 1 });
+    let req = RequestFactory::post(
+        url_to_sales_order_lines(1).as_str(),
+        Body::from(serde_json::to_string(&line_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [{
+            "field": "product_id",
+            "error": "product tracked by lot or serial cannot be ordered"
+        }],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_add_line_to_confirmed_sales_order() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    SalesOrderFactory::in_status(conn.as_ref(), 1, "confirmed")
+        .await
+        .unwrap();
+
+    let line_info = json!({ "productId": 1, "quantity": 3 });
+    let req = RequestFactory::post(
+        url_to_sales_order_lines(1).as_str(),
+        Body::from(serde_json::to_string(&line_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
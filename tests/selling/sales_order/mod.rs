@@ -0,0 +1,3 @@
+pub mod availability;
+pub mod lines;
+pub mod workflow;
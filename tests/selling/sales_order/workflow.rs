@@ -0,0 +1,368 @@
+use crate::common::{self};
+use crate::people::common::insert_person_sample_1;
+use crate::products::common::{ProductComponentFactory, ProductFactory, StockFactory};
+use crate::selling::common::{SalesOrderFactory, SALES_ORDERS_URL};
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{
+    ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+};
+use portal_schema::{sales_order, sales_order_line, stock_movement};
+use serde_json::json;
+use tower::ServiceExt;
+
+fn url_to_sales_order_action(sales_order_id: i32, action: &str) -> String {
+    format!("{SALES_ORDERS_URL}/{sales_order_id}/{action}")
+}
+
+#[tokio::test]
+async fn it_reserve_available_stock_on_confirmation() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    StockFactory::receipt(conn.as_ref(), 1, 10).await.unwrap();
+    SalesOrderFactory::in_status(conn.as_ref(), 1, "draft")
+        .await
+        .unwrap();
+    SalesOrderFactory::line(conn.as_ref(), 1, 1, 4, 0, 0)
+        .await
+        .unwrap();
+
+    let req = RequestFactory::post(
+        url_to_sales_order_action(1, "confirm").as_str(),
+        Body::empty(),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let order_saved = sales_order::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(order_saved.status, "confirmed");
+    assert!(order_saved.confirmed_at.is_some());
+
+    let line_saved = sales_order_line::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(line_saved.reserved_quantity, 4);
+    assert_eq!(line_saved.backordered_quantity, 0);
+}
+
+#[tokio::test]
+async fn it_not_confirm_when_stock_is_already_reserved() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    StockFactory::receipt(conn.as_ref(), 1, 10).await.unwrap();
+    SalesOrderFactory::in_status(conn.as_ref(), 1, "confirmed")
+        .await
+        .unwrap();
+    SalesOrderFactory::line(conn.as_ref(), 1, 1, 8, 8, 0)
+        .await
+        .unwrap();
+    SalesOrderFactory::in_status(conn.as_ref(), 1, "draft")
+        .await
+        .unwrap();
+    SalesOrderFactory::line(conn.as_ref(), 2, 1, 4, 0, 0)
+        .await
+        .unwrap();
+
+    let req = RequestFactory::post(
+        url_to_sales_order_action(2, "confirm").as_str(),
+        Body::empty(),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+
+    let line_saved = sales_order_line::Entity::find_by_id(2)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(line_saved.reserved_quantity, 0);
+}
+
+#[tokio::test]
+async fn it_backorder_shortfall_for_product_saleable_without_stock() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::sell_without_stock(conn.as_ref(), 1)
+        .await
+        .unwrap();
+    StockFactory::receipt(conn.as_ref(), 1, 3).await.unwrap();
+    SalesOrderFactory::in_status(conn.as_ref(), 1, "draft")
+        .await
+        .unwrap();
+    SalesOrderFactory::line(conn.as_ref(), 1, 1, 5, 0, 0)
+        .await
+        .unwrap();
+
+    let req = RequestFactory::post(
+        url_to_sales_order_action(1, "confirm").as_str(),
+        Body::empty(),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let line_saved = sales_order_line::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(line_saved.reserved_quantity, 3);
+    assert_eq!(line_saved.backordered_quantity, 2);
+}
+
+#[tokio::test]
+async fn it_not_confirm_sales_order_without_lines() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+    SalesOrderFactory::in_status(conn.as_ref(), 1, "draft")
+        .await
+        .unwrap();
+
+    let req = RequestFactory::post(
+        url_to_sales_order_action(1, "confirm").as_str(),
+        Body::empty(),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_release_reservations_on_cancellation() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    StockFactory::receipt(conn.as_ref(), 1, 10).await.unwrap();
+    SalesOrderFactory::in_status(conn.as_ref(), 1, "confirmed")
+        .await
+        .unwrap();
+    SalesOrderFactory::line(conn.as_ref(), 1, 1, 4, 4, 0)
+        .await
+        .unwrap();
+
+    let req = RequestFactory::post(
+        url_to_sales_order_action(1, "cancel").as_str(),
+        Body::empty(),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let order_saved = sales_order::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(order_saved.status, "cancelled");
+    assert!(order_saved.cancelled_at.is_some());
+
+    let line_saved = sales_order_line::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(line_saved.reserved_quantity, 0);
+}
+
+#[tokio::test]
+async fn it_not_cancel_cancelled_sales_order() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+    SalesOrderFactory::in_status(conn.as_ref(), 1, "cancelled")
+        .await
+        .unwrap();
+
+    let req = RequestFactory::post(
+        url_to_sales_order_action(1, "cancel").as_str(),
+        Body::empty(),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_fulfil_confirmed_sales_order() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    StockFactory::receipt(conn.as_ref(), 1, 10).await.unwrap();
+    SalesOrderFactory::in_status(conn.as_ref(), 1, "confirmed")
+        .await
+        .unwrap();
+    SalesOrderFactory::line(conn.as_ref(), 1, 1, 4, 4, 0)
+        .await
+        .unwrap();
+
+    let req = RequestFactory::post(
+        url_to_sales_order_action(1, "fulfil").as_str(),
+        Body::from(serde_json::to_string(&json!({})).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let order_saved = sales_order::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(order_saved.status, "fulfilled");
+    assert!(order_saved.fulfilled_at.is_some());
+
+    let line_saved = sales_order_line::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(line_saved.reserved_quantity, 0);
+    assert_eq!(line_saved.backordered_quantity, 0);
+
+    let movement_saved = stock_movement::Entity::find_by_id(2)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(movement_saved.product_id, 1);
+    assert_eq!(movement_saved.kind, "issue");
+    assert_eq!(movement_saved.quantity, -4);
+    assert_eq!(movement_saved.reason, Some("sales order 1".to_string()));
+}
+
+#[tokio::test]
+async fn it_fulfil_kit_from_components() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::in_family(conn.as_ref(), "Charger", 1)
+        .await
+        .unwrap();
+    ProductFactory::in_family(conn.as_ref(), "iPhone X Bundle", 1)
+        .await
+        .unwrap();
+    ProductComponentFactory::of(conn.as_ref(), 3, 1, 1)
+        .await
+        .unwrap();
+    ProductComponentFactory::of(conn.as_ref(), 3, 2, 2)
+        .await
+        .unwrap();
+    StockFactory::receipt(conn.as_ref(), 1, 5).await.unwrap();
+    StockFactory::receipt(conn.as_ref(), 2, 10).await.unwrap();
+    SalesOrderFactory::in_status(conn.as_ref(), 1, "confirmed")
+        .await
+        .unwrap();
+    SalesOrderFactory::line(conn.as_ref(), 1, 3, 2, 2, 0)
+        .await
+        .unwrap();
+
+    let req = RequestFactory::post(
+        url_to_sales_order_action(1, "fulfil").as_str(),
+        Body::from(serde_json::to_string(&json!({})).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let movements_saved = stock_movement::Entity::find()
+        .filter(stock_movement::Column::Reason.eq("sales order 1"))
+        .order_by_asc(stock_movement::Column::StockMovementId)
+        .all(conn.as_ref())
+        .await
+        .unwrap();
+    let issued: Vec<(i32, i32)> = movements_saved
+        .iter()
+        .map(|movement| (movement.product_id, movement.quantity))
+        .collect();
+    assert_eq!(issued, vec![(1, -2), (2, -4)]);
+}
+
+#[tokio::test]
+async fn it_not_fulfil_sales_order_not_confirmed() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    StockFactory::receipt(conn.as_ref(), 1, 10).await.unwrap();
+    SalesOrderFactory::in_status(conn.as_ref(), 1, "draft")
+        .await
+        .unwrap();
+    SalesOrderFactory::line(conn.as_ref(), 1, 1, 4, 0, 0)
+        .await
+        .unwrap();
+
+    let req = RequestFactory::post(
+        url_to_sales_order_action(1, "fulfil").as_str(),
+        Body::from(serde_json::to_string(&json!({})).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_not_fulfil_beyond_stock_on_hand() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    insert_person_sample_1(conn.as_ref()).await;
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    StockFactory::receipt(conn.as_ref(), 1, 10).await.unwrap();
+    StockFactory::issue(conn.as_ref(), 1, 8).await.unwrap();
+    SalesOrderFactory::in_status(conn.as_ref(), 1, "confirmed")
+        .await
+        .unwrap();
+    SalesOrderFactory::line(conn.as_ref(), 1, 1, 4, 4, 0)
+        .await
+        .unwrap();
+
+    let req = RequestFactory::post(
+        url_to_sales_order_action(1, "fulfil").as_str(),
+        Body::from(serde_json::to_string(&json!({})).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+
+    let order_saved = sales_order::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(order_saved.status, "confirmed");
+
+    let movements_saved = stock_movement::Entity::find()
+        .all(conn.as_ref())
+        .await
+        .unwrap();
+    assert_eq!(movements_saved.len(), 2);
+}
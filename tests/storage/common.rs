@@ -0,0 +1,159 @@
+use crate::products::common::{ProductFactory, StockFactory};
+use lumx_sea_orm::sea_orm;
+use lumx_sea_orm::sea_orm::sqlx::types::chrono::Utc;
+use lumx_sea_orm::sea_orm::{ActiveModelTrait, ActiveValue, DatabaseConnection};
+use portal_schema::{
+    inventory_count, inventory_count_line, stock_movement, stock_transfer, storage_location,
+    warehouse,
+};
+
+pub const WAREHOUSES_URL: &str = "/api/v1/warehouses";
+pub const STORAGE_LOCATIONS_URL: &str = "/api/v1/storage-locations";
+pub const STOCK_TRANSFERS_URL: &str = "/api/v1/stock-transfers";
+pub const INVENTORY_COUNTS_URL: &str = "/api/v1/inventory-counts";
+
+pub struct WarehouseFactory;
+
+impl WarehouseFactory {
+    pub async fn main(conn: &DatabaseConnection) -> Result<warehouse::ActiveModel, sea_orm::DbErr> {
+        let warehouse_model = warehouse::ActiveModel {
+            code: ActiveValue::Set("MAIN".to_string()),
+            name: ActiveValue::Set("Main Warehouse".to_string()),
+            address: ActiveValue::Set(Some("1 Harbour Road".to_string())),
+            ..Default::default()
+        };
+
+        warehouse_model.save(conn).await
+    }
+
+    pub async fn north(
+        conn: &DatabaseConnection,
+    ) -> Result<warehouse::ActiveModel, sea_orm::DbErr> {
+        let warehouse_model = warehouse::ActiveModel {
+            code: ActiveValue::Set("NORTH".to_string()),
+            name: ActiveValue::Set("North Depot".to_string()),
+            address: ActiveValue::Set(None),
+            ..Default::default()
+        };
+
+        warehouse_model.save(conn).await
+    }
+}
+
+pub struct StorageLocationFactory;
+
+impl StorageLocationFactory {
+    pub async fn at(
+        conn: &DatabaseConnection,
+        warehouse_id: i32,
+        aisle: &str,
+        shelf: &str,
+        bin: &str,
+    ) -> Result<storage_location::ActiveModel, sea_orm::DbErr> {
+        let location_model = storage_location::ActiveModel {
+            warehouse_id: ActiveValue::Set(warehouse_id),
+            aisle: ActiveValue::Set(aisle.to_string()),
+            shelf: ActiveValue::Set(shelf.to_string()),
+            bin: ActiveValue::Set(bin.to_string()),
+            ..Default::default()
+        };
+
+        location_model.save(conn).await
+    }
+}
+
+pub struct StockTransferFactory;
+
+impl StockTransferFactory {
+    pub async fn in_transit(
+        conn: &DatabaseConnection,
+        product_id: i32,
+        source_location_id: i32,
+        source_warehouse_id: i32,
+        destination_warehouse_id: i32,
+        quantity: i32,
+    ) -> Result<stock_transfer::ActiveModel, sea_orm::DbErr> {
+        let transfer_model = stock_transfer::ActiveModel {
+            product_id: ActiveValue::Set(product_id),
+            source_location_id: ActiveValue::Set(source_location_id),
+            source_warehouse_id: ActiveValue::Set(source_warehouse_id),
+            destination_warehouse_id: ActiveValue::Set(destination_warehouse_id),
+            quantity: ActiveValue::Set(quantity),
+            received_quantity: ActiveValue::Set(0),
+            status: ActiveValue::Set("in_transit".to_string()),
+            transferred_at: ActiveValue::Set(Utc::now().naive_utc()),
+            closed_at: ActiveValue::Set(None),
+            ..Default::default()
+        };
+        let transfer = transfer_model.save(conn).await?;
+
+        let movement_model = stock_movement::ActiveModel {
+            product_id: ActiveValue::Set(product_id),
+            kind: ActiveValue::Set("transfer_out".to_string()),
+            quantity: ActiveValue::Set(-quantity),
+            storage_location_id: ActiveValue::Set(Some(source_location_id)),
+            stock_transfer_id: ActiveValue::Set(Some(transfer.stock_transfer_id.clone().unwrap())),
+            reason: ActiveValue::Set(None),
+            moved_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+        movement_model.save(conn).await?;
+
+        Ok(transfer)
+    }
+}
+
+pub struct InventoryCountFactory;
+
+impl InventoryCountFactory {
+    pub async fn in_warehouse(
+        conn: &DatabaseConnection,
+        warehouse_id: i32,
+        status: &str,
+    ) -> Result<inventory_count::ActiveModel, sea_orm::DbErr> {
+        let count_model = inventory_count::ActiveModel {
+            warehouse_id: ActiveValue::Set(Some(warehouse_id)),
+            family_id: ActiveValue::Set(None),
+            status: ActiveValue::Set(status.to_string()),
+            opened_at: ActiveValue::Set(Utc::now().naive_utc()),
+            posted_at: ActiveValue::Set(None),
+            ..Default::default()
+        };
+
+        count_model.save(conn).await
+    }
+
+    pub async fn line(
+        conn: &DatabaseConnection,
+        inventory_count_id: i32,
+        product_id: i32,
+        storage_location_id: i32,
+        book_quantity: i32,
+        counted_quantity: Option<i32>,
+    ) -> Result<inventory_count_line::ActiveModel, sea_orm::DbErr> {
+        let line_model = inventory_count_line::ActiveModel {
+            inventory_count_id: ActiveValue::Set(inventory_count_id),
+            product_id: ActiveValue::Set(product_id),
+            storage_location_id: ActiveValue::Set(Some(storage_location_id)),
+            book_quantity: ActiveValue::Set(book_quantity),
+            counted_quantity: ActiveValue::Set(counted_quantity),
+            ..Default::default()
+        };
+
+        line_model.save(conn).await
+    }
+}
+
+pub async fn prepare_two_sites_with_stock(
+    conn: &DatabaseConnection,
+    quantity: i32,
+) -> Result<(), sea_orm::DbErr> {
+    ProductFactory::iphone_x(conn).await?;
+    WarehouseFactory::main(conn).await?;
+    WarehouseFactory::north(conn).await?;
+    StorageLocationFactory::at(conn, 1, "A", "01", "01").await?;
+    StorageLocationFactory::at(conn, 2, "A", "01", "01").await?;
+    StockFactory::receipt_at(conn, 1, 1, quantity).await?;
+
+    Ok(())
+}
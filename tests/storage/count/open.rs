@@ -0,0 +1,98 @@
+use crate::common::{self};
+use crate::storage::common::{WarehouseFactory, INVENTORY_COUNTS_URL};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{DatabaseConnection, EntityTrait};
+use portal_schema::inventory_count;
+use serde_json::json;
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn it_not_open_count_without_scope() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let count_info = json!({});
+    let req = RequestFactory::post(
+        INVENTORY_COUNTS_URL,
+        Body::from(serde_json::to_string(&count_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "warehouse_id",
+                "error": "inventory count must be scoped to a warehouse or a product family"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_open_count_for_unknown_warehouse() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let count_info = json!({
+        "warehouseId": 1
+    });
+    let req = RequestFactory::post(
+        INVENTORY_COUNTS_URL,
+        Body::from(serde_json::to_string(&count_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "warehouse_id",
+                "error": "warehouse does not exist"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_open_warehouse_count() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    WarehouseFactory::main(conn.as_ref()).await.unwrap();
+
+    let count_info = json!({
+        "warehouseId": 1
+    });
+    let req = RequestFactory::post(
+        INVENTORY_COUNTS_URL,
+        Body::from(serde_json::to_string(&count_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "id": 1
+    });
+    assert_eq!(res.into_value().await, expected_body);
+
+    let count_saved = inventory_count::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(count_saved.warehouse_id, Some(1));
+    assert_eq!(count_saved.family_id, None);
+    assert_eq!(count_saved.status, "open");
+    assert_eq!(count_saved.posted_at, None);
+}
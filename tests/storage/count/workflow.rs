@@ -0,0 +1,301 @@
+use crate::common::{self};
+use crate::products::common::StockFactory;
+use crate::storage::common::{
+    prepare_two_sites_with_stock, InventoryCountFactory, INVENTORY_COUNTS_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use portal_schema::{inventory_count, inventory_count_line, stock_movement};
+use serde_json::json;
+use tower::ServiceExt;
+
+fn url_to_inventory_count_action(inventory_count_id: i32, action: &str) -> String {
+    format!("{INVENTORY_COUNTS_URL}/{inventory_count_id}/{action}")
+}
+
+#[tokio::test]
+async fn it_start_count_with_book_quantities() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    prepare_two_sites_with_stock(conn.as_ref(), 10)
+        .await
+        .unwrap();
+    InventoryCountFactory::in_warehouse(conn.as_ref(), 1, "open")
+        .await
+        .unwrap();
+
+    let req = RequestFactory::post(
+        url_to_inventory_count_action(1, "start").as_str(),
+        Body::empty(),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let count_saved = inventory_count::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(count_saved.status, "counting");
+
+    let lines_saved = inventory_count_line::Entity::find()
+        .filter(inventory_count_line::Column::InventoryCountId.eq(1))
+        .all(conn.as_ref())
+        .await
+        .unwrap();
+
+    assert_eq!(lines_saved.len(), 1);
+    assert_eq!(lines_saved[0].product_id, 1);
+    assert_eq!(lines_saved[0].storage_location_id, Some(1));
+    assert_eq!(lines_saved[0].book_quantity, 10);
+    assert_eq!(lines_saved[0].counted_quantity, None);
+}
+
+#[tokio::test]
+async fn it_not_count_location_outside_counted_warehouse() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    prepare_two_sites_with_stock(conn.as_ref(), 10)
+        .await
+        .unwrap();
+    InventoryCountFactory::in_warehouse(conn.as_ref(), 1, "counting")
+        .await
+        .unwrap();
+
+    let counted_info = json!({
+        "productId": 1,
+        "storageLocationId": 2,
+        "countedQuantity": 3
+    });
+    let req = RequestFactory::post(
+        url_to_inventory_count_action(1, "counts").as_str(),
+        Body::from(serde_json::to_string(&counted_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "storage_location_id",
+                "error": "storage location is not in the counted warehouse"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_capture_counted_quantity() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    prepare_two_sites_with_stock(conn.as_ref(), 10)
+        .await
+        .unwrap();
+    InventoryCountFactory::in_warehouse(conn.as_ref(), 1, "counting")
+        .await
+        .unwrap();
+    InventoryCountFactory::line(conn.as_ref(), 1, 1, 1, 10, None)
+        .await
+        .unwrap();
+
+    let counted_info = json!({
+        "productId": 1,
+        "storageLocationId": 1,
+        "countedQuantity": 7
+    });
+    let req = RequestFactory::post(
+        url_to_inventory_count_action(1, "counts").as_str(),
+        Body::from(serde_json::to_string(&counted_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let line_saved = inventory_count_line::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(line_saved.book_quantity, 10);
+    assert_eq!(line_saved.counted_quantity, Some(7));
+}
+
+#[tokio::test]
+async fn it_show_variance_of_counted_lines() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    prepare_two_sites_with_stock(conn.as_ref(), 10)
+        .await
+        .unwrap();
+    InventoryCountFactory::in_warehouse(conn.as_ref(), 1, "counting")
+        .await
+        .unwrap();
+    InventoryCountFactory::line(conn.as_ref(), 1, 1, 1, 10, Some(7))
+        .await
+        .unwrap();
+
+    let req = RequestFactory::get(format!("{INVENTORY_COUNTS_URL}/1").as_str());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body = res.into_value().await;
+    assert_eq!(body["status"], json!("counting"));
+    assert_eq!(
+        body["lines"],
+        json!([
+            {
+                "inventoryCountLineId": 1,
+                "productId": 1,
+                "storageLocationId": 1,
+                "bookQuantity": 10,
+                "countedQuantity": 7,
+                "variance": -3
+            }
+        ])
+    );
+}
+
+#[tokio::test]
+async fn it_not_review_count_with_uncounted_lines() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    prepare_two_sites_with_stock(conn.as_ref(), 10)
+        .await
+        .unwrap();
+    InventoryCountFactory::in_warehouse(conn.as_ref(), 1, "counting")
+        .await
+        .unwrap();
+    InventoryCountFactory::line(conn.as_ref(), 1, 1, 1, 10, None)
+        .await
+        .unwrap();
+
+    let req = RequestFactory::post(
+        url_to_inventory_count_action(1, "review").as_str(),
+        Body::empty(),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_not_post_count_before_review() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    prepare_two_sites_with_stock(conn.as_ref(), 10)
+        .await
+        .unwrap();
+    InventoryCountFactory::in_warehouse(conn.as_ref(), 1, "counting")
+        .await
+        .unwrap();
+    InventoryCountFactory::line(conn.as_ref(), 1, 1, 1, 10, Some(7))
+        .await
+        .unwrap();
+
+    let req = RequestFactory::post(
+        url_to_inventory_count_action(1, "post").as_str(),
+        Body::empty(),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_post_variance_as_adjustment() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    prepare_two_sites_with_stock(conn.as_ref(), 10)
+        .await
+        .unwrap();
+    InventoryCountFactory::in_warehouse(conn.as_ref(), 1, "reviewed")
+        .await
+        .unwrap();
+    InventoryCountFactory::line(conn.as_ref(), 1, 1, 1, 10, Some(7))
+        .await
+        .unwrap();
+
+    let req = RequestFactory::post(
+        url_to_inventory_count_action(1, "post").as_str(),
+        Body::empty(),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let count_saved = inventory_count::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(count_saved.status, "posted");
+    assert!(count_saved.posted_at.is_some());
+
+    let movement_saved = stock_movement::Entity::find_by_id(2)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(movement_saved.kind, "adjustment");
+    assert_eq!(movement_saved.quantity, -3);
+    assert_eq!(movement_saved.storage_location_id, Some(1));
+    assert_eq!(movement_saved.reason, Some("inventory count 1".to_string()));
+}
+
+#[tokio::test]
+async fn it_post_reviewed_variance_despite_later_movements() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    prepare_two_sites_with_stock(conn.as_ref(), 10)
+        .await
+        .unwrap();
+    InventoryCountFactory::in_warehouse(conn.as_ref(), 1, "reviewed")
+        .await
+        .unwrap();
+    InventoryCountFactory::line(conn.as_ref(), 1, 1, 1, 10, Some(7))
+        .await
+        .unwrap();
+    StockFactory::receipt_at(conn.as_ref(), 1, 1, 5)
+        .await
+        .unwrap();
+
+    let req = RequestFactory::post(
+        url_to_inventory_count_action(1, "post").as_str(),
+        Body::empty(),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let movement_saved = stock_movement::Entity::find_by_id(3)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(movement_saved.kind, "adjustment");
+    assert_eq!(movement_saved.quantity, -3);
+    assert_eq!(movement_saved.storage_location_id, Some(1));
+}
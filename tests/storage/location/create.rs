@@ -0,0 +1,113 @@
+use crate::storage::common::{StorageLocationFactory, WarehouseFactory};
+use crate::{
+    common::{self},
+    storage::common::WAREHOUSES_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{DatabaseConnection, EntityTrait};
+use portal_schema::storage_location;
+use serde_json::json;
+use tower::ServiceExt;
+
+fn url_to_create_storage_location(warehouse_id: i32) -> String {
+    format!("{WAREHOUSES_URL}/{warehouse_id}/locations")
+}
+
+#[tokio::test]
+async fn it_not_accept_invalid_warehouse_id() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let location_info = json!({
+        "aisle": "A",
+        "shelf": "01",
+        "bin": "01"
+    });
+    let req = RequestFactory::post(
+        url_to_create_storage_location(10).as_str(),
+        Body::from(serde_json::to_string(&location_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "warehouse_id",
+                "error": "warehouse does not exist"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_accept_duplicate_location() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    WarehouseFactory::main(conn.as_ref()).await.unwrap();
+    StorageLocationFactory::at(conn.as_ref(), 1, "A", "01", "01")
+        .await
+        .unwrap();
+
+    let location_info = json!({
+        "aisle": "a",
+        "shelf": "01",
+        "bin": " 01 "
+    });
+    let req = RequestFactory::post(
+        url_to_create_storage_location(1).as_str(),
+        Body::from(serde_json::to_string(&location_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_accepts_same_address_in_another_warehouse() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    WarehouseFactory::main(conn.as_ref()).await.unwrap();
+    WarehouseFactory::north(conn.as_ref()).await.unwrap();
+    StorageLocationFactory::at(conn.as_ref(), 1, "A", "01", "01")
+        .await
+        .unwrap();
+
+    let location_info = json!({
+        "aisle": "a",
+        "shelf": "01",
+        "bin": "01"
+    });
+    let req = RequestFactory::post(
+        url_to_create_storage_location(2).as_str(),
+        Body::from(serde_json::to_string(&location_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "id": 2
+    });
+    assert_eq!(res.into_value().await, expected_body);
+
+    let location_saved = storage_location::Entity::find_by_id(2)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(location_saved.warehouse_id, 2);
+    assert_eq!(location_saved.aisle, "A");
+    assert_eq!(location_saved.shelf, "01");
+    assert_eq!(location_saved.bin, "01");
+}
@@ -0,0 +1,81 @@
+use crate::storage::common::{StorageLocationFactory, WarehouseFactory};
+use crate::{
+    common::{self},
+    storage::common::{STORAGE_LOCATIONS_URL, WAREHOUSES_URL},
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::http::StatusCode;
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::DatabaseConnection;
+use serde_json::json;
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn it_not_accept_invalid_storage_location_id() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let req = RequestFactory::get(format!("{STORAGE_LOCATIONS_URL}/10").as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn it_retrieve_storage_location_details() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    WarehouseFactory::main(conn.as_ref()).await.unwrap();
+    StorageLocationFactory::at(conn.as_ref(), 1, "A", "01", "02")
+        .await
+        .unwrap();
+
+    let req = RequestFactory::get(format!("{STORAGE_LOCATIONS_URL}/1").as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "storageLocationId": 1,
+        "warehouseId": 1,
+        "warehouseCode": "MAIN",
+        "aisle": "A",
+        "shelf": "01",
+        "bin": "02"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_retrieve_paginate_warehouse_locations() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    WarehouseFactory::main(conn.as_ref()).await.unwrap();
+    WarehouseFactory::north(conn.as_ref()).await.unwrap();
+    StorageLocationFactory::at(conn.as_ref(), 1, "B", "01", "01")
+        .await
+        .unwrap();
+    StorageLocationFactory::at(conn.as_ref(), 1, "A", "01", "01")
+        .await
+        .unwrap();
+    StorageLocationFactory::at(conn.as_ref(), 2, "A", "01", "01")
+        .await
+        .unwrap();
+
+    let req =
+        RequestFactory::get(format!("{WAREHOUSES_URL}/1/locations?page=1&page_size=10").as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body = res.into_value().await;
+    assert_eq!(body["total"], json!(2));
+    assert_eq!(body["data"][0]["storageLocationId"], json!(2));
+    assert_eq!(body["data"][1]["storageLocationId"], json!(1));
+}
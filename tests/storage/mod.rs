@@ -0,0 +1,5 @@
+pub mod common;
+pub mod count;
+pub mod location;
+pub mod transfer;
+pub mod warehouse;
@@ -0,0 +1,96 @@
+use crate::storage::common::{prepare_two_sites_with_stock, StockTransferFactory};
+use crate::{
+    common::{self},
+    storage::common::STOCK_TRANSFERS_URL,
+};
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{ActiveModelTrait, ActiveValue, DatabaseConnection, EntityTrait};
+use portal_schema::{stock_movement, stock_transfer};
+use tower::ServiceExt;
+
+fn url_to_cancel_stock_transfer(stock_transfer_id: i32) -> String {
+    format!("{STOCK_TRANSFERS_URL}/{stock_transfer_id}/cancel")
+}
+
+#[tokio::test]
+async fn it_not_accept_invalid_stock_transfer_id() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let req = RequestFactory::post(url_to_cancel_stock_transfer(10).as_str(), Body::empty());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn it_not_cancel_closed_transfer() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    prepare_two_sites_with_stock(conn.as_ref(), 10)
+        .await
+        .unwrap();
+    StockTransferFactory::in_transit(conn.as_ref(), 1, 1, 1, 2, 4)
+        .await
+        .unwrap();
+    let transfer_model = stock_transfer::ActiveModel {
+        stock_transfer_id: ActiveValue::Unchanged(1),
+        status: ActiveValue::Set("received".to_string()),
+        ..Default::default()
+    };
+    transfer_model.update(conn.as_ref()).await.unwrap();
+
+    let req = RequestFactory::post(url_to_cancel_stock_transfer(1).as_str(), Body::empty());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_return_remaining_transit_to_source() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    prepare_two_sites_with_stock(conn.as_ref(), 10)
+        .await
+        .unwrap();
+    StockTransferFactory::in_transit(conn.as_ref(), 1, 1, 1, 2, 4)
+        .await
+        .unwrap();
+    let transfer_model = stock_transfer::ActiveModel {
+        stock_transfer_id: ActiveValue::Unchanged(1),
+        received_quantity: ActiveValue::Set(1),
+        status: ActiveValue::Set("partially_received".to_string()),
+        ..Default::default()
+    };
+    transfer_model.update(conn.as_ref()).await.unwrap();
+
+    let req = RequestFactory::post(url_to_cancel_stock_transfer(1).as_str(), Body::empty());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let transfer_saved = stock_transfer::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(transfer_saved.status, "cancelled");
+    assert!(transfer_saved.closed_at.is_some());
+
+    let movement_saved = stock_movement::Entity::find_by_id(3)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(movement_saved.kind, "transfer_in");
+    assert_eq!(movement_saved.quantity, 3);
+    assert_eq!(movement_saved.storage_location_id, Some(1));
+}
@@ -0,0 +1,197 @@
+use crate::products::common::{ProductFactory, StockFactory};
+use crate::storage::common::prepare_two_sites_with_stock;
+use crate::{
+    common::{self},
+    storage::common::STOCK_TRANSFERS_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{DatabaseConnection, EntityTrait};
+use portal_schema::{stock_movement, stock_transfer};
+use serde_json::json;
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn it_not_accept_invalid_source_location_id() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    prepare_two_sites_with_stock(conn.as_ref(), 10)
+        .await
+        .unwrap();
+
+    let transfer_info = json!({
+        "productId": 1,
+        "sourceLocationId": 10,
+        "destinationWarehouseId": 2,
+        "quantity": 4
+    });
+    let req = RequestFactory::post(
+        STOCK_TRANSFERS_URL,
+        Body::from(serde_json::to_string(&transfer_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "source_location_id",
+                "error": "storage location does not exist"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_accept_transfer_within_same_warehouse() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    prepare_two_sites_with_stock(conn.as_ref(), 10)
+        .await
+        .unwrap();
+
+    let transfer_info = json!({
+        "productId": 1,
+        "sourceLocationId": 1,
+        "destinationWarehouseId": 1,
+        "quantity": 4
+    });
+    let req = RequestFactory::post(
+        STOCK_TRANSFERS_URL,
+        Body::from(serde_json::to_string(&transfer_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "destination_warehouse_id",
+                "error": "destination warehouse must differ from the source warehouse"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_transfer_more_than_on_hand_at_source() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    prepare_two_sites_with_stock(conn.as_ref(), 3)
+        .await
+        .unwrap();
+
+    let transfer_info = json!({
+        "productId": 1,
+        "sourceLocationId": 1,
+        "destinationWarehouseId": 2,
+        "quantity": 4
+    });
+    let req = RequestFactory::post(
+        STOCK_TRANSFERS_URL,
+        Body::from(serde_json::to_string(&transfer_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_not_transfer_lot_held_at_another_location() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    prepare_two_sites_with_stock(conn.as_ref(), 0)
+        .await
+        .unwrap();
+    ProductFactory::track(conn.as_ref(), 1, "lot")
+        .await
+        .unwrap();
+    StockFactory::tracked_receipt_at(conn.as_ref(), 1, 1, "L-001", 10)
+        .await
+        .unwrap();
+    StockFactory::tracked_receipt_at(conn.as_ref(), 1, 2, "L-002", 10)
+        .await
+        .unwrap();
+
+    let transfer_info = json!({
+        "productId": 1,
+        "sourceLocationId": 1,
+        "destinationWarehouseId": 2,
+        "quantity": 4,
+        "lotNumber": "L-002"
+    });
+    let req = RequestFactory::post(
+        STOCK_TRANSFERS_URL,
+        Body::from(serde_json::to_string(&transfer_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_move_stock_from_source_into_transit() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    prepare_two_sites_with_stock(conn.as_ref(), 10)
+        .await
+        .unwrap();
+
+    let transfer_info = json!({
+        "productId": 1,
+        "sourceLocationId": 1,
+        "destinationWarehouseId": 2,
+        "quantity": 4
+    });
+    let req = RequestFactory::post(
+        STOCK_TRANSFERS_URL,
+        Body::from(serde_json::to_string(&transfer_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "id": 1
+    });
+    assert_eq!(res.into_value().await, expected_body);
+
+    let transfer_saved = stock_transfer::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(transfer_saved.source_warehouse_id, 1);
+    assert_eq!(transfer_saved.destination_warehouse_id, 2);
+    assert_eq!(transfer_saved.quantity, 4);
+    assert_eq!(transfer_saved.received_quantity, 0);
+    assert_eq!(transfer_saved.status, "in_transit");
+
+    let movement_saved = stock_movement::Entity::find_by_id(2)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(movement_saved.kind, "transfer_out");
+    assert_eq!(movement_saved.quantity, -4);
+    assert_eq!(movement_saved.storage_location_id, Some(1));
+    assert_eq!(movement_saved.stock_transfer_id, Some(1));
+}
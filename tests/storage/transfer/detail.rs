@@ -0,0 +1,53 @@
+use crate::storage::common::{prepare_two_sites_with_stock, StockTransferFactory};
+use crate::{
+    common::{self},
+    storage::common::STOCK_TRANSFERS_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::http::StatusCode;
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::DatabaseConnection;
+use serde_json::json;
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn it_not_accept_invalid_stock_transfer_id() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let req = RequestFactory::get(format!("{STOCK_TRANSFERS_URL}/10").as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn it_retrieve_stock_transfer_with_transit_quantity() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    prepare_two_sites_with_stock(conn.as_ref(), 10)
+        .await
+        .unwrap();
+    StockTransferFactory::in_transit(conn.as_ref(), 1, 1, 1, 2, 4)
+        .await
+        .unwrap();
+
+    let req = RequestFactory::get(format!("{STOCK_TRANSFERS_URL}/1").as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body = res.into_value().await;
+    assert_eq!(body["stockTransferId"], json!(1));
+    assert_eq!(body["sourceWarehouseId"], json!(1));
+    assert_eq!(body["destinationWarehouseId"], json!(2));
+    assert_eq!(body["quantity"], json!(4));
+    assert_eq!(body["receivedQuantity"], json!(0));
+    assert_eq!(body["inTransit"], json!(4));
+    assert_eq!(body["status"], json!("inTransit"));
+    assert_eq!(body["closedAt"], json!(null));
+}
@@ -0,0 +1,4 @@
+pub mod cancel;
+pub mod create;
+pub mod detail;
+pub mod receive;
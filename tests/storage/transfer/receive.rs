@@ -0,0 +1,298 @@
+use crate::products::common::{ProductFactory, StockFactory, PRODUCTS_URL};
+use crate::storage::common::{
+    prepare_two_sites_with_stock, StockTransferFactory, StorageLocationFactory, WarehouseFactory,
+};
+use crate::{
+    common::{self},
+    storage::common::STOCK_TRANSFERS_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{DatabaseConnection, EntityTrait};
+use portal_schema::{stock_movement, stock_transfer};
+use serde_json::json;
+use tower::ServiceExt;
+
+fn url_to_receive_stock_transfer(stock_transfer_id: i32) -> String {
+    format!("{STOCK_TRANSFERS_URL}/{stock_transfer_id}/receive")
+}
+
+#[tokio::test]
+async fn it_not_accept_location_outside_destination() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    prepare_two_sites_with_stock(conn.as_ref(), 10)
+        .await
+        .unwrap();
+    StockTransferFactory::in_transit(conn.as_ref(), 1, 1, 1, 2, 4)
+        .await
+        .unwrap();
+
+    let receipt_info = json!({
+        "storageLocationId": 1,
+        "quantity": 4
+    });
+    let req = RequestFactory::post(
+        url_to_receive_stock_transfer(1).as_str(),
+        Body::from(serde_json::to_string(&receipt_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "storage_location_id",
+                "error": "storage location is not in the destination warehouse"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_receive_more_than_in_transit() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    prepare_two_sites_with_stock(conn.as_ref(), 10)
+        .await
+        .unwrap();
+    StockTransferFactory::in_transit(conn.as_ref(), 1, 1, 1, 2, 4)
+        .await
+        .unwrap();
+
+    let receipt_info = json!({
+        "storageLocationId": 2,
+        "quantity": 5
+    });
+    let req = RequestFactory::post(
+        url_to_receive_stock_transfer(1).as_str(),
+        Body::from(serde_json::to_string(&receipt_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn it_book_partial_receipt_into_destination() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    prepare_two_sites_with_stock(conn.as_ref(), 10)
+        .await
+        .unwrap();
+    StockTransferFactory::in_transit(conn.as_ref(), 1, 1, 1, 2, 4)
+        .await
+        .unwrap();
+
+    let receipt_info = json!({
+        "storageLocationId": 2,
+        "quantity": 3
+    });
+    let req = RequestFactory::post(
+        url_to_receive_stock_transfer(1).as_str(),
+        Body::from(serde_json::to_string(&receipt_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let transfer_saved = stock_transfer::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(transfer_saved.received_quantity, 3);
+    assert_eq!(transfer_saved.status, "partially_received");
+    assert_eq!(transfer_saved.closed_at, None);
+
+    let movement_saved = stock_movement::Entity::find_by_id(3)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(movement_saved.kind, "transfer_in");
+    assert_eq!(movement_saved.quantity, 3);
+    assert_eq!(movement_saved.storage_location_id, Some(2));
+}
+
+#[tokio::test]
+async fn it_close_transfer_once_fully_received() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    prepare_two_sites_with_stock(conn.as_ref(), 10)
+        .await
+        .unwrap();
+    StockTransferFactory::in_transit(conn.as_ref(), 1, 1, 1, 2, 4)
+        .await
+        .unwrap();
+
+    let receipt_info = json!({
+        "storageLocationId": 2,
+        "quantity": 4
+    });
+    let req = RequestFactory::post(
+        url_to_receive_stock_transfer(1).as_str(),
+        Body::from(serde_json::to_string(&receipt_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let transfer_saved = stock_transfer::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(transfer_saved.received_quantity, 4);
+    assert_eq!(transfer_saved.status, "received");
+    assert!(transfer_saved.closed_at.is_some());
+}
+
+#[tokio::test]
+async fn it_keep_fifo_valuation_when_transferring_stock() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::cost_with(conn.as_ref(), 1, "fifo")
+        .await
+        .unwrap();
+    WarehouseFactory::main(conn.as_ref()).await.unwrap();
+    WarehouseFactory::north(conn.as_ref()).await.unwrap();
+    StorageLocationFactory::at(conn.as_ref(), 1, "A", "01", "01")
+        .await
+        .unwrap();
+    StorageLocationFactory::at(conn.as_ref(), 2, "A", "01", "01")
+        .await
+        .unwrap();
+    StockFactory::costed_receipt_at(conn.as_ref(), 1, 1, 10, 20_000)
+        .await
+        .unwrap();
+    StockFactory::costed_receipt_at(conn.as_ref(), 1, 1, 10, 40_000)
+        .await
+        .unwrap();
+
+    let transfer_info = json!({
+        "productId": 1,
+        "sourceLocationId": 1,
+        "destinationWarehouseId": 2,
+        "quantity": 10
+    });
+    let req = RequestFactory::post(
+        STOCK_TRANSFERS_URL,
+        Body::from(serde_json::to_string(&transfer_info).unwrap()),
+    );
+    let res = app.clone().oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let receipt_info = json!({
+        "storageLocationId": 2,
+        "quantity": 10
+    });
+    let req = RequestFactory::post(
+        url_to_receive_stock_transfer(1).as_str(),
+        Body::from(serde_json::to_string(&receipt_info).unwrap()),
+    );
+    let res = app.clone().oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let transfer_saved = stock_transfer::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(transfer_saved.unit_cost, Some(20_000));
+
+    let req = RequestFactory::get(format!("{PRODUCTS_URL}/1/valuation").as_str());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body = res.into_value().await;
+    assert_eq!(body["quantity"], json!(20));
+    assert_eq!(body["value"], json!("60.00"));
+}
+
+#[tokio::test]
+async fn it_keep_fifo_layers_when_stock_returns_from_transfer() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    ProductFactory::iphone_x(conn.as_ref()).await.unwrap();
+    ProductFactory::cost_with(conn.as_ref(), 1, "fifo")
+        .await
+        .unwrap();
+    WarehouseFactory::main(conn.as_ref()).await.unwrap();
+    WarehouseFactory::north(conn.as_ref()).await.unwrap();
+    StorageLocationFactory::at(conn.as_ref(), 1, "A", "01", "01")
+        .await
+        .unwrap();
+    StorageLocationFactory::at(conn.as_ref(), 2, "A", "01", "01")
+        .await
+        .unwrap();
+    StockFactory::costed_receipt_at(conn.as_ref(), 1, 1, 10, 20_000)
+        .await
+        .unwrap();
+    StockFactory::costed_receipt_at(conn.as_ref(), 1, 1, 10, 40_000)
+        .await
+        .unwrap();
+
+    let transfer_info = json!({
+        "productId": 1,
+        "sourceLocationId": 1,
+        "destinationWarehouseId": 2,
+        "quantity": 5
+    });
+    let req = RequestFactory::post(
+        STOCK_TRANSFERS_URL,
+        Body::from(serde_json::to_string(&transfer_info).unwrap()),
+    );
+    let res = app.clone().oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let req = RequestFactory::get(format!("{PRODUCTS_URL}/1/valuation").as_str());
+    let res = app.clone().oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body = res.into_value().await;
+    assert_eq!(body["quantity"], json!(20));
+    assert_eq!(body["value"], json!("60.00"));
+
+    let receipt_info = json!({
+        "storageLocationId": 2,
+        "quantity": 5
+    });
+    let req = RequestFactory::post(
+        url_to_receive_stock_transfer(1).as_str(),
+        Body::from(serde_json::to_string(&receipt_info).unwrap()),
+    );
+    let res = app.clone().oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    StockFactory::issue(conn.as_ref(), 1, 10).await.unwrap();
+
+    let req = RequestFactory::get(format!("{PRODUCTS_URL}/1/valuation").as_str());
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body = res.into_value().await;
+    assert_eq!(body["quantity"], json!(10));
+    assert_eq!(body["value"], json!("40.00"));
+    assert_eq!(body["issuedCost"], json!("20.00"));
+}
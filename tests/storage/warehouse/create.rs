@@ -0,0 +1,87 @@
+use crate::storage::common::WarehouseFactory;
+use crate::{
+    common::{self},
+    storage::common::WAREHOUSES_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{DatabaseConnection, EntityTrait};
+use portal_schema::warehouse;
+use serde_json::json;
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn it_not_accept_empty_name() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let warehouse_info = json!({
+        "code": "main",
+        "name": ""
+    });
+    let req = RequestFactory::post(
+        WAREHOUSES_URL,
+        Body::from(serde_json::to_string(&warehouse_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn it_not_accept_duplicate_code() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    WarehouseFactory::main(conn.as_ref()).await.unwrap();
+
+    let warehouse_info = json!({
+        "code": " main ",
+        "name": "Another Main"
+    });
+    let req = RequestFactory::post(
+        WAREHOUSES_URL,
+        Body::from(serde_json::to_string(&warehouse_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_accepts_and_create_valid_warehouse() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    let warehouse_info = json!({
+        "code": "south 01",
+        "name": "South Depot",
+        "address": "5 Quay Street"
+    });
+    let req = RequestFactory::post(
+        WAREHOUSES_URL,
+        Body::from(serde_json::to_string(&warehouse_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "id": 1
+    });
+    assert_eq!(res.into_value().await, expected_body);
+
+    let warehouse_saved = warehouse::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(warehouse_saved.code, "SOUTH-01");
+    assert_eq!(warehouse_saved.name, "South Depot");
+    assert_eq!(warehouse_saved.address, Some("5 Quay Street".to_string()));
+}
@@ -0,0 +1,50 @@
+use crate::storage::common::WarehouseFactory;
+use crate::{
+    common::{self},
+    storage::common::WAREHOUSES_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::http::StatusCode;
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::DatabaseConnection;
+use serde_json::json;
+use tower::ServiceExt;
+
+fn url_to_retrieve_warehouse(warehouse_id: i32) -> String {
+    format!("{WAREHOUSES_URL}/{warehouse_id}")
+}
+
+#[tokio::test]
+async fn it_not_accept_invalid_warehouse_id() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let req = RequestFactory::get(url_to_retrieve_warehouse(10).as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn it_retrieve_warehouse_details() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    WarehouseFactory::main(conn.as_ref()).await.unwrap();
+
+    let req = RequestFactory::get(url_to_retrieve_warehouse(1).as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let expected_body = json!({
+        "warehouseId": 1,
+        "code": "MAIN",
+        "name": "Main Warehouse",
+        "address": "1 Harbour Road"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
@@ -0,0 +1,43 @@
+use crate::storage::common::WarehouseFactory;
+use crate::{
+    common::{self},
+    storage::common::WAREHOUSES_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::http::StatusCode;
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::DatabaseConnection;
+use serde_json::json;
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn it_retrieve_paginate_warehouses_filtered_by_name() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    WarehouseFactory::main(conn.as_ref()).await.unwrap();
+    WarehouseFactory::north(conn.as_ref()).await.unwrap();
+
+    let req =
+        RequestFactory::get(format!("{WAREHOUSES_URL}?page=1&page_size=10&name=Depot").as_str());
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let body = res.into_value().await;
+    assert_eq!(body["total"], json!(1));
+    assert_eq!(
+        body["data"],
+        json!([
+            {
+                "warehouseId": 2,
+                "code": "NORTH",
+                "name": "North Depot",
+                "address": null
+            }
+        ])
+    );
+}
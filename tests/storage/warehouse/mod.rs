@@ -0,0 +1,4 @@
+pub mod create;
+pub mod detail;
+pub mod find_all;
+pub mod update;
@@ -0,0 +1,99 @@
+use crate::storage::common::WarehouseFactory;
+use crate::{
+    common::{self},
+    storage::common::WAREHOUSES_URL,
+};
+use ids_std_rest_testing::extractors::IntoValueExt;
+use ids_std_rest_testing::factory::RequestFactory;
+use lumx_axum::axum::{body::Body, http::StatusCode};
+use lumx_axum_test::program_ext::IntoTestableEndpoints;
+use lumx_core::tokio;
+use lumx_sea_orm::sea_orm::{DatabaseConnection, EntityTrait};
+use portal_schema::warehouse;
+use serde_json::json;
+use tower::ServiceExt;
+
+fn url_to_update_warehouse(warehouse_id: i32) -> String {
+    format!("{WAREHOUSES_URL}/{warehouse_id}")
+}
+
+#[tokio::test]
+async fn it_not_accept_invalid_warehouse_id() {
+    let program = common::configure().await;
+    let app = program.into_testable_endpoints();
+
+    let warehouse_info = json!({
+        "code": "MAIN",
+        "name": "Main Warehouse"
+    });
+    let req = RequestFactory::put(
+        url_to_update_warehouse(10).as_str(),
+        Body::from(serde_json::to_string(&warehouse_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let expected_body = json!({
+        "errors": [
+            {
+                "field": "warehouse_id",
+                "error": "warehouse does not exist"
+            }
+        ],
+        "message": "validation error"
+    });
+    assert_eq!(res.into_value().await, expected_body);
+}
+
+#[tokio::test]
+async fn it_not_accept_code_of_another_warehouse() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    WarehouseFactory::main(conn.as_ref()).await.unwrap();
+    WarehouseFactory::north(conn.as_ref()).await.unwrap();
+
+    let warehouse_info = json!({
+        "code": "main",
+        "name": "North Depot"
+    });
+    let req = RequestFactory::put(
+        url_to_update_warehouse(2).as_str(),
+        Body::from(serde_json::to_string(&warehouse_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+
+    assert_eq!(res.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn it_accepts_and_update_valid_warehouse() {
+    let program = common::configure().await;
+    let conn = program.get_expect_component::<DatabaseConnection>();
+    let app = program.into_testable_endpoints();
+
+    WarehouseFactory::main(conn.as_ref()).await.unwrap();
+
+    let warehouse_info = json!({
+        "code": "main",
+        "name": "Main Distribution Centre",
+        "address": "2 Harbour Road"
+    });
+    let req = RequestFactory::put(
+        url_to_update_warehouse(1).as_str(),
+        Body::from(serde_json::to_string(&warehouse_info).unwrap()),
+    );
+    let res = app.oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let warehouse_saved = warehouse::Entity::find_by_id(1)
+        .one(conn.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(warehouse_saved.code, "MAIN");
+    assert_eq!(warehouse_saved.name, "Main Distribution Centre");
+    assert_eq!(warehouse_saved.address, Some("2 Harbour Road".to_string()));
+}